@@ -1,5 +1,5 @@
 from __future__ import annotations
-from .pyhornedowl import PyIndexedOntology, IndexCreationStrategy, PrefixMapping, open_ontology, open_ontology_from_file, open_ontology_from_string
+from .pyhornedowl import PyIndexedOntology, IndexCreationStrategy, PrefixMapping, SearchHit, ProfileReport, ProfileViolation, OntologyMetrics, LintFinding, open_ontology, open_ontology_from_file, open_ontology_from_string, open_ontology_from_rdflib, transform
 
 
-__all__ = ["PyIndexedOntology", "IndexCreationStrategy", "PrefixMapping", "open_ontology", "open_ontology_from_file", "open_ontology_from_string"]
+__all__ = ["PyIndexedOntology", "IndexCreationStrategy", "PrefixMapping", "SearchHit", "ProfileReport", "ProfileViolation", "OntologyMetrics", "LintFinding", "open_ontology", "open_ontology_from_file", "open_ontology_from_string", "open_ontology_from_rdflib", "transform"]
@@ -9,6 +9,54 @@ class PyIndexedOntology:
     """
     Represents a loaded ontology.
     """
+    def signature(self) -> Set[typing.Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]:
+        """
+        Returns all named entities used.
+        """
+        ...
+
+    def classes(self) -> Set[model.Class]:
+        """
+        Returns all classes used.
+        """
+        ...
+
+    def object_properties(self) -> Set[model.ObjectProperty]:
+        """
+        Returns all object properties used.
+        """
+        ...
+
+    def data_properties(self) -> Set[model.DataProperty]:
+        """
+        Returns all data properties used.
+        """
+        ...
+
+    def annotation_properties(self) -> Set[model.AnnotationProperty]:
+        """
+        Returns all annotation properties used.
+        """
+        ...
+
+    def named_individuals(self) -> Set[model.NamedIndividual]:
+        """
+        Returns all named individuals used.
+        """
+        ...
+
+    def datatypes(self) -> Set[model.Datatype]:
+        """
+        Returns all datatypes used, including the datatypes of typed literals.
+        """
+        ...
+
+    def literals(self) -> Set[model.Literal]:
+        """
+        Returns all literals used.
+        """
+        ...
+
     def get_id_for_iri(self, iri: model.IRIParam) -> Optional[str]:
         """
         Gets the ID of term by it IRI.
@@ -31,22 +79,97 @@ class PyIndexedOntology:
         """
         ...
 
-    def set_label(self, iri: model.IRIParam, label: str) -> None:
+    def set_label(self, iri: model.IRIParam, label: str, lang: Optional[str]=None) -> None:
         """
         Sets the label of a term by iri.
         
-        Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`.
+        Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`. If `lang` is given,
+        a language tagged literal is written and only the label with the same language is replaced.
         """
         ...
 
-    def get_iri_for_label(self, label: str) -> Optional[str]:
+    def get_iri_for_label(self, label: str, lang: Optional[str]=None) -> Optional[str]:
         """
         Returns the IRI of a term by its label if it exists.
         
+        Labels are matched regardless of their language tag unless `lang` is given. In that case only
+        labels whose language tag matches `lang` are considered (`"en"` matches `"en"` and `"en-GB"`,
+        `""` matches labels without language tag).
+        
         If the term does not have a label, `None` is returned.
         """
         ...
 
+    def get_label(self, iri: model.IRIParam, lang: Optional[str|List[str]]=None) -> Optional[model.Literal]:
+        """
+        Returns the label of a term as literal including its language tag or datatype.
+        
+        `lang` is a preferred language or a list of preferred languages in descending priority. The
+        first label matching a language in the list is returned (`"en"` matches `"en"` and `"en-GB"`,
+        `""` matches labels without language tag and `"*"` matches any label). If no `lang` is given,
+        labels without language tag are preferred over tagged ones.
+        
+        If the term does not have a matching label, `None` is returned.
+        """
+        ...
+
+    def get_labels(self, iri: model.IRIParam) -> List[model.Literal]:
+        """
+        Returns all labels of a term as literals including their language tags or datatypes.
+        """
+        ...
+
+    def get_iris_for_label(self, label: str, synonyms: bool=False, ignore_case: bool=False, normalize_whitespace: bool=False) -> List[str]:
+        """
+        Returns the IRIs of all terms with the label `label`.
+        
+        If `synonyms` is true, the synonym properties (see `PyIndexedOntology.get_synonym_properties`)
+        are searched as well. With `ignore_case` labels are compared case-insensitively and with
+        `normalize_whitespace` leading, trailing and repeated whitespace is ignored.
+        """
+        ...
+
+    def search(self, query: str, properties: Optional[List[model.IRIParam]]=None, limit: int=20) -> List[SearchHit]:
+        """
+        Searches terms by the words in their annotations.
+        
+        Words of the query match words in the annotations exactly, as prefix, or with a few typos.
+        Terms are ranked by their best matching annotation: exact matches of the whole annotation
+        rank highest, and labels rank higher than synonyms (see
+        `PyIndexedOntology.get_synonym_properties`) which rank higher than definitions.
+        
+        By default labels, synonyms and definitions are searched. Pass `properties` to search other
+        annotation properties.
+        """
+        ...
+
+    def get_synonym_properties(self) -> List[model.IRI]:
+        """
+        Returns the annotation properties treated as synonyms by `PyIndexedOntology.get_iris_for_label`.
+        
+        Defaults to the oboInOwl synonym properties, `skos:prefLabel` and `skos:altLabel`.
+        """
+        ...
+
+    def set_synonym_properties(self, properties: List[model.IRIParam]) -> None:
+        """
+        Sets the annotation properties treated as synonyms by `PyIndexedOntology.get_iris_for_label`.
+        """
+        ...
+
+    def deprecate_entity(self, iri: model.IRIParam, replaced_by: Optional[model.IRIParam]=None, consider: List[model.IRIParam]=[], reason: Optional[model.IRIParam]=None, remove_references: bool=False) -> List[model.AnnotatedComponent]:
+        """
+        Marks a term as obsolete following the OBO obsoletion workflow.
+        
+        Adds `owl:deprecated true`, prefixes all labels with "obsolete " unless they already start
+        with it in any case, adds `IAO:0100001` (term replaced by) and `oboInOwl:consider`
+        annotations, and `IAO:0000231` (has obsolescence reason) if a reason such as `IAO:0000227`
+        is given. The logical axioms defining the term, e.g. `SubClassOf` axioms with the term as
+        sub class, are removed and returned. With `remove_references` the logical axioms of other
+        terms referencing it are removed as well.
+        """
+        ...
+
     def get_iri(self) -> Optional[str]:
         """
         Returns the ontology iri, if it exists.
@@ -59,6 +182,62 @@ class PyIndexedOntology:
         """
         ...
 
+    def set_iri(self, iri: Optional[model.IRIParam]) -> None:
+        """
+        Sets the ontology iri. If `None` is passed, the iri is removed.
+        """
+        ...
+
+    def set_version_iri(self, iri: Optional[model.IRIParam]) -> None:
+        """
+        Sets the ontology version iri. If `None` is passed, the version iri is removed.
+        """
+        ...
+
+    def get_imports(self) -> List[model.IRI]:
+        """
+        Returns the IRIs of all ontologies imported by this ontology.
+        """
+        ...
+
+    def add_import(self, iri: model.IRIParam) -> bool:
+        """
+        Adds an import of the ontology identified by `iri`. Returns `False` if it was already imported.
+        """
+        ...
+
+    def remove_import(self, iri: model.IRIParam) -> bool:
+        """
+        Removes the import of the ontology identified by `iri`. Returns `False` if it was not imported.
+        """
+        ...
+
+    def get_ontology_annotations(self, ann_iri: Optional[model.IRIParam]=None) -> List[model.Annotation]:
+        """
+        Returns the annotations of the ontology itself, optionally restricted to the annotation property `ann_iri`.
+        """
+        ...
+
+    def add_ontology_annotation(self, ann_iri: model.IRIParam, value: model.AnnotationValue | str) -> bool:
+        """
+        Adds an annotation to the ontology itself. Strings are added as simple literals.
+        """
+        ...
+
+    def set_ontology_annotation(self, ann_iri: model.IRIParam, value: model.AnnotationValue | str) -> None:
+        """
+        Sets an annotation of the ontology itself, replacing all existing values for `ann_iri`.
+        Strings are added as simple literals.
+        """
+        ...
+
+    def remove_ontology_annotation(self, ann_iri: model.IRIParam, value: Optional[model.AnnotationValue | str]=None) -> bool:
+        """
+        Removes annotations of the ontology itself with the annotation property `ann_iri`. If
+        `value` is given, only annotations with that value are removed. Returns `False` if nothing was removed.
+        """
+        ...
+
     def get_subclasses(self, iri: model.IRIParam) -> Set[str]:
         """
         Gets all asserted (named) direct subclasses of a class.
@@ -77,39 +256,100 @@ class PyIndexedOntology:
         """
         ...
 
-    def get_classes(self) -> Set[str]:
+    def get_classes(self, include_undeclared: bool = False) -> Set[str]:
+        """
+        Returns the IRIs of all declared classes in the ontology. With `include_undeclared`, all
+        classes used anywhere in the ontology are returned. See `classes` for model objects.
+        """
+        ...
+
+    def get_datatypes(self, include_undeclared: bool = False) -> Set[str]:
         """
-        Returns the IRIs of all declared classes in the ontology.
+        Returns the IRIs of all declared datatypes in the ontology. With `include_undeclared`, all
+        datatypes used anywhere in the ontology are returned. See `datatypes` for model objects.
         """
         ...
 
-    def get_datatypes(self) -> Set[str]:
+    def get_object_properties(self, include_undeclared: bool = False) -> Set[str]:
         """
-        Returns the IRIs of all declared datatypes in the ontology.
+        Returns the IRIs of all declared object properties in the ontology. With
+        `include_undeclared`, all object properties used anywhere in the ontology are returned. See
+        `object_properties` for model objects.
         """
         ...
 
-    def get_object_properties(self) -> Set[str]:
+    def get_annotation_properties(self, include_undeclared: bool = False) -> Set[str]:
         """
-        Returns the IRIs of all declared object properties in the ontology.
+        Returns the IRIs of all declared annotation properties in the ontology. With
+        `include_undeclared`, all annotation properties used anywhere in the ontology are returned.
+        See `annotation_properties` for model objects.
         """
         ...
 
-    def get_annotation_properties(self) -> Set[str]:
+    def get_data_properties(self, include_undeclared: bool = False) -> Set[str]:
         """
-        Returns the IRIs of all declared annotation properties in the ontology.
+        Returns the IRIs of all declared data properties in the ontology. With `include_undeclared`,
+        all data properties used anywhere in the ontology are returned. See `data_properties` for
+        model objects.
         """
         ...
 
-    def get_data_properties(self) -> Set[str]:
+    def get_named_individuals(self, include_undeclared: bool = False) -> Set[str]:
         """
-        Returns the IRIs of all declared data properties in the ontology.
+        Returns the IRIs of all declared named individuals in the ontology. With
+        `include_undeclared`, all named individuals used anywhere in the ontology are returned. See
+        `named_individuals` for model objects.
         """
         ...
 
-    def get_named_individuals(self) -> Set[str]:
+    def get_undeclared_entities(self) -> Set[typing.Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]:
         """
-        Returns the IRIs of all declared named individuals in the ontology.
+        Returns all entities used in a component of the ontology without being declared as the
+        type they are used as. Entities of the OWL, RDF, RDFS and XSD vocabularies need no
+        declaration and are not returned.
+        """
+        ...
+
+    def add_missing_declarations(self) -> List[model.Component]:
+        """
+        Declares all entities returned by `get_undeclared_entities`. The type of each entity
+        follows from the position it is used in. Returns the added declarations.
+        """
+        ...
+
+    def iter_classes(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared classes in the ontology.
+        """
+        ...
+
+    def iter_datatypes(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared datatypes in the ontology.
+        """
+        ...
+
+    def iter_object_properties(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared object properties in the ontology.
+        """
+        ...
+
+    def iter_annotation_properties(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared annotation properties in the ontology.
+        """
+        ...
+
+    def iter_data_properties(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared data properties in the ontology.
+        """
+        ...
+
+    def iter_named_individuals(self) -> Iterator[str]:
+        """
+        Returns a lazy iterator over the IRIs of all declared named individuals in the ontology.
         """
         ...
 
@@ -132,6 +372,17 @@ class PyIndexedOntology:
         """
         ...
 
+    def get_annotation_values(self, entity_iri: model.IRIParam, ann_iri: model.IRIParam) -> List[Tuple[model.AnnotationValue, Set[model.Annotation]]]:
+        """
+        Gets all annotation values for an entity and annotation property together with the
+        annotations on the respective annotation assertion.
+        
+        In contrast to `PyIndexedOntology.get_annotations` the values are returned as model objects,
+        i.e. literals keep their language tag or datatype and IRI and anonymous individual values
+        are included.
+        """
+        ...
+
     def save_to_string(self, serialization: typing.Literal['owl', 'rdf','ofn', 'owx']) -> str:
         """
         Saves the ontology to a UTF8 string.
@@ -145,6 +396,36 @@ class PyIndexedOntology:
         """
         ...
 
+    def to_triples(self) -> Iterator[typing.Tuple[typing.Union[model.IRI, model.AnonymousIndividual], model.IRI, typing.Union[model.IRI, model.AnonymousIndividual, model.Literal]]]:
+        """
+        Yields the ontology as RDF triples using the same OWL to RDF mapping as the RDF/XML writer.
+        
+        IRIs are returned as `model.IRI`, blank nodes as `model.AnonymousIndividual` and literals as
+        `model.Literal`.
+        """
+        ...
+
+    @staticmethod
+    def from_triples(triples: typing.Iterable[typing.Tuple[typing.Union[model.IRI, model.AnonymousIndividual, str], typing.Union[model.IRI, str], typing.Union[model.IRI, model.AnonymousIndividual, model.Literal, str]]], index_strategy = IndexCreationStrategy.OnQuery) -> PyIndexedOntology:
+        """
+        Creates an ontology from RDF triples using the same RDF to OWL mapping as the RDF/XML parser.
+        
+        Terms are given as returned by `to_triples`. Strings are interpreted as absolute IRIs.
+        
+        The parser only accepts blank nodes it has read itself, so each triple is written as an
+        N-Triples line when the parser asks for more input. The triples are not collected first.
+        """
+        ...
+
+    def to_rdflib(self) -> typing.Any:
+        """
+        Converts the ontology to an `rdflib.Graph` using the same OWL to RDF mapping as the RDF/XML
+        writer. The prefixes of the ontology are bound as namespaces of the graph.
+        
+        Requires rdflib to be installed.
+        """
+        ...
+
     def get_axioms_for_iri(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]:
         """
         Gets all axioms for an entity.
@@ -157,6 +438,20 @@ class PyIndexedOntology:
         """
         ...
 
+    def get_defining_axioms(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]:
+        """
+        Gets all components defining an entity: its declarations, the annotation assertions on it
+        and the axioms it is the subject of, e.g. `SubClassOf` axioms with the class as sub class
+        or property assertions with the individual as source.
+        """
+        ...
+
+    def get_usages(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]:
+        """
+        Gets all components referencing an entity without defining it. See `get_defining_axioms`.
+        """
+        ...
+
     def get_axioms(self) -> List[model.AnnotatedComponent]:
         """
         Returns all axioms of the ontology.
@@ -169,6 +464,60 @@ class PyIndexedOntology:
         """
         ...
 
+    def get_components_by_kind(self, kinds: model.ComponentKind | type | List[model.ComponentKind | type]) -> List[model.AnnotatedComponent]:
+        """
+        Returns all components of one or more kinds. Kinds are given either as `model.ComponentKind`
+        or as model class such as `model.SubClassOf`.
+        """
+        ...
+
+    def count_by_kind(self) -> Dict[model.ComponentKind, int]:
+        """
+        Returns the number of components for each kind occurring in the ontology.
+        """
+        ...
+
+    def iter_components(self, kind: Optional[model.ComponentKind | type]=None) -> Iterator[model.AnnotatedComponent]:
+        """
+        Returns a lazy iterator over all components of the ontology, optionally restricted to a
+        `model.ComponentKind` or the components of a model class such as `model.SubClassOf`.
+        
+        Components are converted to Python objects one at a time. Modifying the ontology while
+        iterating raises a `RuntimeError` on the next iteration step.
+        """
+        ...
+
+    def iter_axioms(self, kind: Optional[model.ComponentKind | type]=None) -> Iterator[model.AnnotatedComponent]:
+        """
+        Returns a lazy iterator over all axioms of the ontology, optionally restricted to a
+        `model.ComponentKind` or the axioms of a model class such as `model.SubClassOf`.
+        
+        Axioms are converted to Python objects one at a time. Modifying the ontology while
+        iterating raises a `RuntimeError` on the next iteration step.
+        """
+        ...
+
+    def iter_components_for_iri(self, iri: model.IRIParam) -> Iterator[model.AnnotatedComponent]:
+        """
+        Returns a lazy iterator over all components (axiom, swrl, and meta component) for an entity.
+        
+        Modifying the ontology while iterating raises a `RuntimeError` on the next iteration step.
+        """
+        ...
+
+    def match_pattern(self, pattern: model.Component) -> List[Dict[str, typing.Any]]:
+        """
+        Matches a component containing `model.Var` placeholders against the components of the
+        ontology and returns the variable bindings of every match, e.g.
+        `SubClassOf(Var("x"), ObjectSomeValuesFrom(part_of, Var("y")))`.
+        
+        An entity wrapping a variable such as `AnnotationProperty(Var("p"))` matches any entity of
+        that type. All occurrences of a variable have to match equal nodes. Lists are matched in
+        order. Candidates are narrowed down by the kind of the pattern and by the IRIs occurring in
+        it.
+        """
+        ...
+
     def add_component(self, component: model.Component, annotations: Optional[List[model.Annotation]|Set[model.Annotation]]=None) -> None:
         """
         Adds an axiom to the ontology with optional annotations.
@@ -209,6 +558,138 @@ class PyIndexedOntology:
         """
         ...
 
+    def parse_class_expression(self, expression: str) -> model.ClassExpression:
+        """
+        Parses a class expression in Manchester syntax, e.g. `"part_of some (nucleus and not cytoplasm)"`.
+        
+        Names are written as full IRIs (`<https://example.com/A>`), prefixed names (`ex:A`, `:A`),
+        quoted labels (`'cell nucleus'`) or bare labels. Bare names without prefix are looked up as label
+        first and then in the default prefix. A property is read as data property if it is declared as one
+        or its filler is a literal or a datatype.
+        
+        Raises a `ValueError` pointing at the offending part of `expression` if it cannot be parsed.
+        """
+        ...
+
+    def parse_axiom(self, axiom: str) -> model.AnnotatedComponent:
+        """
+        Parses a single axiom in functional syntax, e.g. `"SubClassOf(:A ObjectSomeValuesFrom(:part_of :B))"`.
+        
+        Prefixed names are expanded with the prefix mapping of the ontology, which is extended by the
+        `owl`, `rdf`, `rdfs` and `xsd` prefixes. Entities can also be referenced by their quoted label
+        (`'cell nucleus'`). Axiom annotations are returned with the component.
+        
+        Raises a `ValueError` pointing at the offending part of `axiom` if it cannot be parsed.
+        """
+        ...
+
+    def render(self, obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.Annotation], syntax: typing.Literal["manchester", "functional"] = "manchester", use_labels: bool = True, use_curies: bool = True) -> str:
+        """
+        Renders a component or class expression in Manchester or functional syntax.
+        
+        With `use_labels`, entities are written as their `rdfs:label`, quoted if it contains spaces or
+        other characters not allowed in names. Otherwise, or for entities without label, they are
+        written as prefixed names if `use_curies` is set and the prefix mapping allows it, and as full
+        IRIs else. Components are written in Manchester syntax as in Protégé, e.g. `A SubClassOf B`.
+        """
+        ...
+
+    def check_profile(self, profile: typing.Literal["EL", "QL", "RL", "DL"]) -> ProfileReport:
+        """
+        Checks the ontology against an OWL 2 profile.
+        
+        Returns a report listing each violating component with the reason, e.g. a disallowed
+        constructor, a non-simple property in a cardinality restriction or an undeclared or punned
+        entity. A component is reported once per reason. The global restrictions of OWL 2 DL are
+        checked for every profile, except for the regularity of property hierarchies.
+        """
+        ...
+
+    def metrics(self) -> OntologyMetrics:
+        """
+        Computes statistics of the ontology: the number of components per kind and of entities per
+        type, the number of logical and annotation axioms, the depth of the asserted class hierarchy,
+        the number of root classes, the namespaces of the entities and the DL expressivity.
+        """
+        ...
+
+    def lint(self, rules: Optional[Union[List[str], Dict[str, typing.Literal["ERROR", "WARN", "INFO"]]]] = None) -> List[LintFinding]:
+        """
+        Checks the ontology for common quality problems, similar to the ROBOT report.
+        
+        The available rules are `missing_label`, `multiple_labels` (per language), `duplicate_label`
+        (across entities), `missing_definition` (for classes and properties that are not deprecated),
+        `undeclared_entity`, `punned_entity`, `deprecated_entity_reference` (in logical axioms) and
+        `self_referential_subclass`. By default all rules are checked with their default severity.
+        `rules` selects the rules to check, either as list of rule ids or as dictionary from rule id
+        to severity.
+        
+        Findings are ordered by rule, subject and component.
+        """
+        ...
+
+    def to_tables(self) -> Dict[str, Dict[str, List[Optional[str]]]]:
+        """
+        Flattens the ontology into normalized tables, each given as dictionary from column name to
+        a list of strings or `None`:
+        
+        - `entities`: `iri`, `type` (e.g. "Class") and `label` of each entity in the signature. The
+          label is the one returned by `get_label`.
+        - `annotation_assertions`: `subject`, `property`, `value`, `value_type` ("literal", "iri" or
+          "anonymous"), `lang` and `datatype`.
+        - `subclass_edges`: `subclass` and `superclass` of each `SubClassOf` between named classes.
+        - `existential_edges`: `subclass`, `property` and `filler` of each `SubClassOf` of a named
+          class and an `ObjectSomeValuesFrom` on a named property and class, also as operand of an
+          `ObjectIntersectionOf`.
+        - `components`: `kind` and functional syntax `text` of each component.
+        """
+        ...
+
+    def to_arrow(self) -> Dict[str, typing.Any]:
+        """
+        Returns the tables of `to_tables` as `pyarrow.Table` with string columns.
+        
+        Requires pyarrow to be installed.
+        """
+        ...
+
+    def to_pandas(self) -> Dict[str, typing.Any]:
+        """
+        Returns the tables of `to_tables` as `pandas.DataFrame`.
+        
+        Requires pandas to be installed.
+        """
+        ...
+
+    def to_graph(self, edges: Optional[List[typing.Literal["subclass", "some_values_from", "equivalent"]]] = None, use_labels: bool = True) -> Tuple[List[Tuple[str, Dict[str, str]]], List[Tuple[str, str, Dict[str, str]]]]:
+        """
+        Returns the graph of the named classes as node and edge lists, which can be passed to
+        `networkx.DiGraph.add_nodes_from` and `networkx.DiGraph.add_edges_from`.
+        
+        Nodes are `(iri, {"label": label})` for each class. Edges are `(source, target, {"type": type})`
+        of the types given in `edges`, by default all of them:
+        
+        - `subclass`: from `A` to `B` for `SubClassOf(A B)`, also if `B` is an operand of an
+          `ObjectIntersectionOf`.
+        - `some_values_from`: from `A` to `B` for `SubClassOf(A ObjectSomeValuesFrom(p B))`, also
+          as operand of an `ObjectIntersectionOf`. These edges also have the `property` `p` and its
+          `label`.
+        - `equivalent`: between each pair of named classes in `EquivalentClasses`.
+        
+        `EquivalentClasses(A C)` of a named class `A` and a class expression `C` also gives the
+        edges of `SubClassOf(A C)`. With `use_labels` the labels are the ones returned by
+        `get_label`, otherwise they are IRIs.
+        """
+        ...
+
+    def to_dot(self, edges: Optional[List[typing.Literal["subclass", "some_values_from", "equivalent"]]] = None, use_labels: bool = True) -> str:
+        """
+        Returns the graph of `to_graph` in the Graphviz DOT language. `subclass` edges have an empty
+        arrowhead, `some_values_from` edges are labelled with the property and `equivalent` edges
+        are dashed and undirected.
+        """
+        ...
+
     def clazz(self, iri: model.IRIParam) -> model.Class:
         """
         Convenience method to create a Class from an IRI.
@@ -326,6 +807,18 @@ class PyIndexedOntology:
         """
         ...
 
+    def build_label_index(self) -> None:
+        """
+        Builds an index by label and synonym.
+        """
+        ...
+
+    def build_search_index(self) -> None:
+        """
+        Builds the full text index used by `PyIndexedOntology.search`.
+        """
+        ...
+
     def build_indexes(self) -> None:
         """
         Builds indexes to allow (a quicker) access to axioms and entities.
@@ -406,6 +899,31 @@ class PrefixMapping:
         ...
 
 
+class SearchHit:
+    """
+    A single search result.
+    """
+
+class ProfileReport:
+    """
+    Result of checking an ontology against an OWL 2 profile.
+    """
+
+class ProfileViolation:
+    """
+    A component violating a profile.
+    """
+
+class OntologyMetrics:
+    """
+    Statistics of an ontology.
+    """
+
+class LintFinding:
+    """
+    A problem found by a lint rule.
+    """
+
 def open_ontology(ontology: str, serialization: Optional[typing.Literal['owl', 'rdf','ofn', 'owx']]=None, index_strategy = IndexCreationStrategy.OnQuery) -> PyIndexedOntology:
     """
     Opens an ontology from a path or plain text.
@@ -436,3 +954,28 @@ def open_ontology_from_string(ontology: str, serialization: Optional[typing.Lite
     ...
 
 
+def open_ontology_from_rdflib(graph: typing.Any, index_strategy = IndexCreationStrategy.OnQuery) -> PyIndexedOntology:
+    """
+    Opens an ontology from an `rdflib.Graph` using the same RDF to OWL mapping as the RDF/XML parser.
+    The parser only accepts blank nodes it has read itself, so each triple of the graph is written
+    as an N-Triples line when the parser asks for more input. The graph is not serialized as a
+    whole.
+    
+    The namespaces bound in the graph are added to the prefix mapping of the ontology.
+    """
+    ...
+
+
+def transform(obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.Annotation], fn: typing.Callable[[typing.Any], typing.Any]) -> typing.Any:
+    """
+    Rewrites a model object bottom-up by calling `fn` on each of its nodes.
+    
+    `fn` is called with every entity, individual, literal, class expression, object property expression,
+    data range, annotation and component, after their children have been transformed. It returns either
+    a replacement or `None` to keep the node. A named class (object property, datatype) in a position
+    that accepts a class expression (object property expression, data range) is passed only once and may
+    be replaced by an expression. Raises a `TypeError` if a replacement does not fit its position.
+    """
+    ...
+
+
@@ -5,6 +5,15 @@ class IRI:
     parse: Any
     ...
 
+class Var(IRI):
+    """
+    A named placeholder in a pattern. A `Var` can be used wherever an IRI, a class expression, an
+    object property expression, a data range, an individual or a literal is expected.
+    """
+    name: str
+    def __init__(self, name: str):
+        ...
+
 class Facet:
     Length: Facet
     MinLength: Facet
@@ -19,6 +28,56 @@ class Facet:
     LangRange: Facet
     
 
+class ComponentKind:
+    OntologyID: ComponentKind
+    DocIRI: ComponentKind
+    OntologyAnnotation: ComponentKind
+    Import: ComponentKind
+    DeclareClass: ComponentKind
+    DeclareObjectProperty: ComponentKind
+    DeclareAnnotationProperty: ComponentKind
+    DeclareDataProperty: ComponentKind
+    DeclareNamedIndividual: ComponentKind
+    DeclareDatatype: ComponentKind
+    SubClassOf: ComponentKind
+    EquivalentClasses: ComponentKind
+    DisjointClasses: ComponentKind
+    DisjointUnion: ComponentKind
+    SubObjectPropertyOf: ComponentKind
+    EquivalentObjectProperties: ComponentKind
+    DisjointObjectProperties: ComponentKind
+    InverseObjectProperties: ComponentKind
+    ObjectPropertyDomain: ComponentKind
+    ObjectPropertyRange: ComponentKind
+    FunctionalObjectProperty: ComponentKind
+    InverseFunctionalObjectProperty: ComponentKind
+    ReflexiveObjectProperty: ComponentKind
+    IrreflexiveObjectProperty: ComponentKind
+    SymmetricObjectProperty: ComponentKind
+    AsymmetricObjectProperty: ComponentKind
+    TransitiveObjectProperty: ComponentKind
+    SubDataPropertyOf: ComponentKind
+    EquivalentDataProperties: ComponentKind
+    DisjointDataProperties: ComponentKind
+    DataPropertyDomain: ComponentKind
+    DataPropertyRange: ComponentKind
+    FunctionalDataProperty: ComponentKind
+    DatatypeDefinition: ComponentKind
+    HasKey: ComponentKind
+    SameIndividual: ComponentKind
+    DifferentIndividuals: ComponentKind
+    ClassAssertion: ComponentKind
+    ObjectPropertyAssertion: ComponentKind
+    NegativeObjectPropertyAssertion: ComponentKind
+    DataPropertyAssertion: ComponentKind
+    NegativeDataPropertyAssertion: ComponentKind
+    AnnotationAssertion: ComponentKind
+    SubAnnotationPropertyOf: ComponentKind
+    AnnotationPropertyDomain: ComponentKind
+    AnnotationPropertyRange: ComponentKind
+    Rule: ComponentKind
+
+
 IRIParam = Union[str, IRI, Tuple[str, bool]]
 """
 One of the following:
@@ -50,6 +109,56 @@ class Class:
     def __invert__(self) -> ObjectIntersectionOf:
         """Complement of a class expression"""
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Class":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "Class":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class AnonymousIndividual:
@@ -63,6 +172,56 @@ class AnonymousIndividual:
 
     def __str__(self) -> str:
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnonymousIndividual":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "AnonymousIndividual":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class NamedIndividual:
@@ -76,6 +235,56 @@ class NamedIndividual:
 
     def __str__(self) -> str:
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "NamedIndividual":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "NamedIndividual":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class ObjectProperty:
@@ -120,6 +329,56 @@ class ObjectProperty:
     def __invert__(self) -> ObjectPropertyExpression:
         """Inverse of object property expression"""
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class Datatype:
@@ -133,6 +392,56 @@ class Datatype:
 
     def __str__(self) -> str:
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Datatype":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "Datatype":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class DataProperty:
@@ -146,6 +455,56 @@ class DataProperty:
 
     def __str__(self) -> str:
         ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 class FacetRestriction:
@@ -156,6 +515,56 @@ class FacetRestriction:
     def __init__(self,f: Facet,l: Literal,/):
         ...
 
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "FacetRestriction":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "FacetRestriction":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
     ...
 
 Individual = typing.Union[AnonymousIndividual,NamedIndividual,]
@@ -202,415 +611,567 @@ class InverseObjectProperty:
     def __invert__(self) -> ObjectPropertyExpression:
         """Inverse of object property expression"""
         ...
-    ...
-
-ObjectPropertyExpression = typing.Union[ObjectProperty,InverseObjectProperty,]
-
 
-class SimpleLiteral:
-    literal: str
-
-    __match_args__ = ("literal", )
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    def __init__(self,literal: str,):
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    
 
-    ...
-class LanguageLiteral:
-    literal: str
-    lang: str
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("literal", "lang", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,literal: str,lang: str,):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
-    
 
-    ...
-class DatatypeLiteral:
-    literal: str
-    datatype_iri: IRI
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    __match_args__ = ("literal", "datatype_iri", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,literal: str,datatype_iri: IRI,):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "InverseObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
-    
 
+    @classmethod
+    def from_json(cls, value: str) -> "InverseObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
-Literal = typing.Union[SimpleLiteral,LanguageLiteral,DatatypeLiteral,]
+ObjectPropertyExpression = typing.Union[ObjectProperty,InverseObjectProperty,]
 
 
-class DataIntersectionOf:
-    first: typing.List[DataRange]
+class SimpleLiteral:
+    literal: str
 
-    __match_args__ = ("first", )
+    __match_args__ = ("literal", )
 
 
-    def __init__(self,first: typing.List[DataRange],):
+    def __init__(self,literal: str,):
         ...
     
 
-    ...
-class DataUnionOf:
-    first: typing.List[DataRange]
 
-    __match_args__ = ("first", )
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    def __init__(self,first: typing.List[DataRange],):
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    
 
-    ...
-class DataComplementOf:
-    first: DataRange
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,first: DataRange,):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
-    
 
-    ...
-class DataOneOf:
-    first: typing.List[Literal]
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,first: typing.List[Literal],):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SimpleLiteral":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
-    
 
+    @classmethod
+    def from_json(cls, value: str) -> "SimpleLiteral":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
-class DatatypeRestriction:
-    first: Datatype
-    second: typing.List[FacetRestriction]
+class LanguageLiteral:
+    literal: str
+    lang: str
 
-    __match_args__ = ("first", "second", )
+    __match_args__ = ("literal", "lang", )
 
 
-    def __init__(self,first: Datatype,second: typing.List[FacetRestriction],):
+    def __init__(self,literal: str,lang: str,):
         ...
     
 
-    ...
 
-DataRange = typing.Union[Datatype,DataIntersectionOf,DataUnionOf,DataComplementOf,DataOneOf,DatatypeRestriction,]
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class ObjectIntersectionOf:
-    first: typing.List[ClassExpression]
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: typing.List[ClassExpression],):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "LanguageLiteral":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "LanguageLiteral":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class ObjectUnionOf:
-    first: typing.List[ClassExpression]
+class DatatypeLiteral:
+    literal: str
+    datatype_iri: IRI
 
-    __match_args__ = ("first", )
+    __match_args__ = ("literal", "datatype_iri", )
 
 
-    def __init__(self,first: typing.List[ClassExpression],):
+    def __init__(self,literal: str,datatype_iri: IRI,):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class ObjectComplementOf:
-    first: ClassExpression
 
-    __match_args__ = ("first", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: ClassExpression,):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DatatypeLiteral":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DatatypeLiteral":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class ObjectOneOf:
-    first: typing.List[Individual]
+
+Literal = typing.Union[SimpleLiteral,LanguageLiteral,DatatypeLiteral,]
+
+
+class DataIntersectionOf:
+    first: typing.List[DataRange]
 
     __match_args__ = ("first", )
 
 
-    def __init__(self,first: typing.List[Individual],):
+    def __init__(self,first: typing.List[DataRange],):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class ObjectSomeValuesFrom:
-    ope: ObjectPropertyExpression
-    bce: ClassExpression
 
-    __match_args__ = ("ope", "bce", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,ope: ObjectPropertyExpression,bce: ClassExpression,):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataIntersectionOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataIntersectionOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class ObjectAllValuesFrom:
-    ope: ObjectPropertyExpression
-    bce: ClassExpression
+class DataUnionOf:
+    first: typing.List[DataRange]
 
-    __match_args__ = ("ope", "bce", )
+    __match_args__ = ("first", )
 
 
-    def __init__(self,ope: ObjectPropertyExpression,bce: ClassExpression,):
+    def __init__(self,first: typing.List[DataRange],):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class ObjectHasValue:
-    ope: ObjectPropertyExpression
-    i: Individual
 
-    __match_args__ = ("ope", "i", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,ope: ObjectPropertyExpression,i: Individual,):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataUnionOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataUnionOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class ObjectHasSelf:
-    first: ObjectPropertyExpression
+class DataComplementOf:
+    first: DataRange
 
     __match_args__ = ("first", )
 
 
-    def __init__(self,first: ObjectPropertyExpression,):
+    def __init__(self,first: DataRange,):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class ObjectMinCardinality:
-    n: int
-    ope: ObjectPropertyExpression
-    bce: ClassExpression
 
-    __match_args__ = ("n", "ope", "bce", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataComplementOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataComplementOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class ObjectMaxCardinality:
-    n: int
-    ope: ObjectPropertyExpression
-    bce: ClassExpression
+class DataOneOf:
+    first: typing.List[Literal]
 
-    __match_args__ = ("n", "ope", "bce", )
+    __match_args__ = ("first", )
 
 
-    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+    def __init__(self,first: typing.List[Literal],):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class ObjectExactCardinality:
-    n: int
-    ope: ObjectPropertyExpression
-    bce: ClassExpression
 
-    __match_args__ = ("n", "ope", "bce", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataOneOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataOneOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class DataSomeValuesFrom:
-    dp: DataProperty
-    dr: DataRange
+class DatatypeRestriction:
+    first: Datatype
+    second: typing.List[FacetRestriction]
 
-    __match_args__ = ("dp", "dr", )
+    __match_args__ = ("first", "second", )
 
 
-    def __init__(self,dp: DataProperty,dr: DataRange,):
+    def __init__(self,first: Datatype,second: typing.List[FacetRestriction],):
         ...
     
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    ...
-class DataAllValuesFrom:
-    dp: DataProperty
-    dr: DataRange
 
-    __match_args__ = ("dp", "dr", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,dp: DataProperty,dr: DataRange,):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DatatypeRestriction":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DatatypeRestriction":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class DataHasValue:
-    dp: DataProperty
-    l: Literal
 
-    __match_args__ = ("dp", "l", )
+DataRange = typing.Union[Datatype,DataIntersectionOf,DataUnionOf,DataComplementOf,DataOneOf,DatatypeRestriction,]
 
 
-    def __init__(self,dp: DataProperty,l: Literal,):
+class ObjectIntersectionOf:
+    first: typing.List[ClassExpression]
+
+    __match_args__ = ("first", )
+
+
+    def __init__(self,first: typing.List[ClassExpression],):
         ...
     
 
@@ -625,64 +1186,64 @@ class DataHasValue:
     def __invert__(self) -> ObjectIntersectionOf:
         """Complement of a class expression"""
         ...
-    ...
-class DataMinCardinality:
-    n: int
-    dp: DataProperty
-    dr: DataRange
 
-    __match_args__ = ("n", "dp", "dr", )
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
-    ...
-class DataMaxCardinality:
-    n: int
-    dp: DataProperty
-    dr: DataRange
 
-    __match_args__ = ("n", "dp", "dr", )
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
         ...
-    
 
-    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Intersection of two class expressions"""
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
         ...
 
-    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
-        """Union of two class expressions"""
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectIntersectionOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
-    def __invert__(self) -> ObjectIntersectionOf:
-        """Complement of a class expression"""
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectIntersectionOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
     ...
-class DataExactCardinality:
-    n: int
-    dp: DataProperty
-    dr: DataRange
+class ObjectUnionOf:
+    first: typing.List[ClassExpression]
 
-    __match_args__ = ("n", "dp", "dr", )
+    __match_args__ = ("first", )
 
 
-    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+    def __init__(self,first: typing.List[ClassExpression],):
         ...
     
 
@@ -697,512 +1258,4081 @@ class DataExactCardinality:
     def __invert__(self) -> ObjectIntersectionOf:
         """Complement of a class expression"""
         ...
-    ...
 
-ClassExpression = typing.Union[Class,ObjectIntersectionOf,ObjectUnionOf,ObjectComplementOf,ObjectOneOf,ObjectSomeValuesFrom,ObjectAllValuesFrom,ObjectHasValue,ObjectHasSelf,ObjectMinCardinality,ObjectMaxCardinality,ObjectExactCardinality,DataSomeValuesFrom,DataAllValuesFrom,DataHasValue,DataMinCardinality,DataMaxCardinality,DataExactCardinality,]
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-PropertyExpression = typing.Union[ObjectPropertyExpression,DataProperty,AnnotationProperty,]
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-AnnotationSubject = typing.Union[IRI,AnonymousIndividual,]
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-class AnnotationProperty:
-    first: IRI
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: IRI,/):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
 
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-AnnotationValue = typing.Union[Literal,IRI,AnonymousIndividual,]
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-class Annotation:
-    ap: AnnotationProperty
-    av: AnnotationValue
-    __match_args__ = ("ap", "av", )
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,ap: AnnotationProperty,av: AnnotationValue,/):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectUnionOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectUnionOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
-
-class OntologyAnnotation:
-    first: Annotation
+class ObjectComplementOf:
+    first: ClassExpression
 
     __match_args__ = ("first", )
 
-    def __init__(self,first: Annotation,/):
+
+    def __init__(self,first: ClassExpression,):
         ...
+    
 
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
 
-    ...
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
 
-class Import:
-    first: IRI
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
 
-    __match_args__ = ("first", )
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-    def __init__(self,first: IRI,/):
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    ...
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-class DeclareClass:
-    first: Class
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,first: Class,/):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-class DeclareObjectProperty:
-    first: ObjectProperty
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    __match_args__ = ("first", )
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectComplementOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
 
-    def __init__(self,first: ObjectProperty,/):
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectComplementOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
+    ...
+class ObjectOneOf:
+    first: typing.List[Individual]
 
+    __match_args__ = ("first", )
 
-    ...
 
-class DeclareAnnotationProperty:
-    first: AnnotationProperty
+    def __init__(self,first: typing.List[Individual],):
+        ...
+    
 
-    __match_args__ = ("first", )
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
 
-    def __init__(self,first: AnnotationProperty,/):
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
         ...
 
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-    ...
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class DeclareDataProperty:
-    first: DataProperty
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    def __init__(self,first: DataProperty,/):
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
         ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    ...
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-class DeclareNamedIndividual:
-    first: NamedIndividual
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    def __init__(self,first: NamedIndividual,/):
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
         ...
 
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectOneOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectOneOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
+class ObjectSomeValuesFrom:
+    ope: ObjectPropertyExpression
+    bce: ClassExpression
 
-class DeclareDatatype:
-    first: Datatype
+    __match_args__ = ("ope", "bce", )
 
-    __match_args__ = ("first", )
 
-    def __init__(self,first: Datatype,/):
+    def __init__(self,ope: ObjectPropertyExpression,bce: ClassExpression,):
         ...
+    
 
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
 
-    ...
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
 
-class SubClassOf:
-    sub: ClassExpression
-    sup: ClassExpression
-    __match_args__ = ("sub", "sup", )
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
 
-    def __init__(self,sub: ClassExpression,sup: ClassExpression,/):
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    ...
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class EquivalentClasses:
-    first: typing.List[ClassExpression]
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    def __init__(self,first: typing.List[ClassExpression],/):
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
         ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    ...
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-class DisjointClasses:
-    first: typing.List[ClassExpression]
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    def __init__(self,first: typing.List[ClassExpression],/):
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
         ...
 
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectSomeValuesFrom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectSomeValuesFrom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
+class ObjectAllValuesFrom:
+    ope: ObjectPropertyExpression
+    bce: ClassExpression
 
-class DisjointUnion:
-    first: Class
-    second: typing.List[ClassExpression]
+    __match_args__ = ("ope", "bce", )
 
-    __match_args__ = ("first", "second", )
 
-    def __init__(self,first: Class,second: typing.List[ClassExpression],/):
+    def __init__(self,ope: ObjectPropertyExpression,bce: ClassExpression,):
         ...
+    
 
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
 
-    ...
-
-SubObjectPropertyExpression = typing.Union[typing.List[ObjectPropertyExpression],ObjectPropertyExpression,]
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
 
-class SubObjectPropertyOf:
-    sub: SubObjectPropertyExpression
-    sup: ObjectPropertyExpression
-    __match_args__ = ("sub", "sup", )
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
 
-    def __init__(self,sub: SubObjectPropertyExpression,sup: ObjectPropertyExpression,/):
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    ...
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class EquivalentObjectProperties:
-    first: typing.List[ObjectPropertyExpression]
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    def __init__(self,first: typing.List[ObjectPropertyExpression],/):
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
         ...
 
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    ...
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-class DisjointObjectProperties:
-    first: typing.List[ObjectPropertyExpression]
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    def __init__(self,first: typing.List[ObjectPropertyExpression],/):
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
         ...
 
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectAllValuesFrom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectAllValuesFrom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
+class ObjectHasValue:
+    ope: ObjectPropertyExpression
+    i: Individual
 
-class InverseObjectProperties:
-    first: ObjectProperty
-    second: ObjectProperty
+    __match_args__ = ("ope", "i", )
 
-    __match_args__ = ("first", "second", )
 
-    def __init__(self,first: ObjectProperty,second: ObjectProperty,/):
+    def __init__(self,ope: ObjectPropertyExpression,i: Individual,):
         ...
+    
 
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
 
-    ...
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectHasValue":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectHasValue":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class ObjectHasSelf:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+
+    def __init__(self,first: ObjectPropertyExpression,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectHasSelf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectHasSelf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class ObjectMinCardinality:
+    n: int
+    ope: ObjectPropertyExpression
+    bce: ClassExpression
+
+    __match_args__ = ("n", "ope", "bce", )
+
+
+    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectMinCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectMinCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class ObjectMaxCardinality:
+    n: int
+    ope: ObjectPropertyExpression
+    bce: ClassExpression
+
+    __match_args__ = ("n", "ope", "bce", )
+
+
+    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectMaxCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectMaxCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class ObjectExactCardinality:
+    n: int
+    ope: ObjectPropertyExpression
+    bce: ClassExpression
+
+    __match_args__ = ("n", "ope", "bce", )
+
+
+    def __init__(self,n: int,ope: ObjectPropertyExpression,bce: ClassExpression,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectExactCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectExactCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataSomeValuesFrom:
+    dp: DataProperty
+    dr: DataRange
+
+    __match_args__ = ("dp", "dr", )
+
+
+    def __init__(self,dp: DataProperty,dr: DataRange,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataSomeValuesFrom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataSomeValuesFrom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataAllValuesFrom:
+    dp: DataProperty
+    dr: DataRange
+
+    __match_args__ = ("dp", "dr", )
+
+
+    def __init__(self,dp: DataProperty,dr: DataRange,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataAllValuesFrom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataAllValuesFrom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataHasValue:
+    dp: DataProperty
+    l: Literal
+
+    __match_args__ = ("dp", "l", )
+
+
+    def __init__(self,dp: DataProperty,l: Literal,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataHasValue":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataHasValue":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataMinCardinality:
+    n: int
+    dp: DataProperty
+    dr: DataRange
+
+    __match_args__ = ("n", "dp", "dr", )
+
+
+    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataMinCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataMinCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataMaxCardinality:
+    n: int
+    dp: DataProperty
+    dr: DataRange
+
+    __match_args__ = ("n", "dp", "dr", )
+
+
+    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataMaxCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataMaxCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+class DataExactCardinality:
+    n: int
+    dp: DataProperty
+    dr: DataRange
+
+    __match_args__ = ("n", "dp", "dr", )
+
+
+    def __init__(self,n: int,dp: DataProperty,dr: DataRange,):
+        ...
+    
+
+    def __and__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Intersection of two class expressions"""
+        ...
+
+    def __or__(self, ce: ClassExpression) -> ObjectIntersectionOf:
+        """Union of two class expressions"""
+        ...
+
+    def __invert__(self) -> ObjectIntersectionOf:
+        """Complement of a class expression"""
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataExactCardinality":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataExactCardinality":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+ClassExpression = typing.Union[Class,ObjectIntersectionOf,ObjectUnionOf,ObjectComplementOf,ObjectOneOf,ObjectSomeValuesFrom,ObjectAllValuesFrom,ObjectHasValue,ObjectHasSelf,ObjectMinCardinality,ObjectMaxCardinality,ObjectExactCardinality,DataSomeValuesFrom,DataAllValuesFrom,DataHasValue,DataMinCardinality,DataMaxCardinality,DataExactCardinality,]
+
+PropertyExpression = typing.Union[ObjectPropertyExpression,DataProperty,AnnotationProperty,]
+
+AnnotationSubject = typing.Union[IRI,AnonymousIndividual,]
+
+class AnnotationProperty:
+    first: IRI
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: IRI,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnnotationProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "AnnotationProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+AnnotationValue = typing.Union[Literal,IRI,AnonymousIndividual,]
+
+class Annotation:
+    ap: AnnotationProperty
+    av: AnnotationValue
+    __match_args__ = ("ap", "av", )
+
+    def __init__(self,ap: AnnotationProperty,av: AnnotationValue,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Annotation":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "Annotation":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class OntologyAnnotation:
+    first: Annotation
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: Annotation,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "OntologyAnnotation":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "OntologyAnnotation":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class Import:
+    first: IRI
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: IRI,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Import":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "Import":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareClass:
+    first: Class
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: Class,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareClass":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareClass":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareObjectProperty:
+    first: ObjectProperty
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectProperty,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareAnnotationProperty:
+    first: AnnotationProperty
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: AnnotationProperty,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareAnnotationProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareAnnotationProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareDataProperty:
+    first: DataProperty
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: DataProperty,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareDataProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareDataProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareNamedIndividual:
+    first: NamedIndividual
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: NamedIndividual,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareNamedIndividual":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareNamedIndividual":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DeclareDatatype:
+    first: Datatype
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: Datatype,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DeclareDatatype":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DeclareDatatype":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class SubClassOf:
+    sub: ClassExpression
+    sup: ClassExpression
+    __match_args__ = ("sub", "sup", )
+
+    def __init__(self,sub: ClassExpression,sup: ClassExpression,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SubClassOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SubClassOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class EquivalentClasses:
+    first: typing.List[ClassExpression]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[ClassExpression],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "EquivalentClasses":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "EquivalentClasses":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DisjointClasses:
+    first: typing.List[ClassExpression]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[ClassExpression],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DisjointClasses":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DisjointClasses":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DisjointUnion:
+    first: Class
+    second: typing.List[ClassExpression]
+
+    __match_args__ = ("first", "second", )
+
+    def __init__(self,first: Class,second: typing.List[ClassExpression],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DisjointUnion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DisjointUnion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+SubObjectPropertyExpression = typing.Union[typing.List[ObjectPropertyExpression],ObjectPropertyExpression,]
+
+class SubObjectPropertyOf:
+    sub: SubObjectPropertyExpression
+    sup: ObjectPropertyExpression
+    __match_args__ = ("sub", "sup", )
+
+    def __init__(self,sub: SubObjectPropertyExpression,sup: ObjectPropertyExpression,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SubObjectPropertyOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SubObjectPropertyOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class EquivalentObjectProperties:
+    first: typing.List[ObjectPropertyExpression]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[ObjectPropertyExpression],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "EquivalentObjectProperties":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "EquivalentObjectProperties":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DisjointObjectProperties:
+    first: typing.List[ObjectPropertyExpression]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[ObjectPropertyExpression],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DisjointObjectProperties":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DisjointObjectProperties":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class InverseObjectProperties:
+    first: ObjectProperty
+    second: ObjectProperty
+
+    __match_args__ = ("first", "second", )
+
+    def __init__(self,first: ObjectProperty,second: ObjectProperty,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "InverseObjectProperties":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "InverseObjectProperties":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class ObjectPropertyDomain:
+    ope: ObjectPropertyExpression
+    ce: ClassExpression
+    __match_args__ = ("ope", "ce", )
+
+    def __init__(self,ope: ObjectPropertyExpression,ce: ClassExpression,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectPropertyDomain":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectPropertyDomain":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class ObjectPropertyRange:
+    ope: ObjectPropertyExpression
+    ce: ClassExpression
+    __match_args__ = ("ope", "ce", )
+
+    def __init__(self,ope: ObjectPropertyExpression,ce: ClassExpression,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectPropertyRange":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectPropertyRange":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class FunctionalObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "FunctionalObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "FunctionalObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class InverseFunctionalObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "InverseFunctionalObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "InverseFunctionalObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class ReflexiveObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ReflexiveObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ReflexiveObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class IrreflexiveObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "IrreflexiveObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "IrreflexiveObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class SymmetricObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SymmetricObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SymmetricObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class AsymmetricObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AsymmetricObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "AsymmetricObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class TransitiveObjectProperty:
+    first: ObjectPropertyExpression
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: ObjectPropertyExpression,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "TransitiveObjectProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "TransitiveObjectProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class SubDataPropertyOf:
+    sub: DataProperty
+    sup: DataProperty
+    __match_args__ = ("sub", "sup", )
+
+    def __init__(self,sub: DataProperty,sup: DataProperty,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SubDataPropertyOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SubDataPropertyOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class EquivalentDataProperties:
+    first: typing.List[DataProperty]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[DataProperty],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "EquivalentDataProperties":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "EquivalentDataProperties":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DisjointDataProperties:
+    first: typing.List[DataProperty]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[DataProperty],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DisjointDataProperties":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DisjointDataProperties":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DataPropertyDomain:
+    dp: DataProperty
+    ce: ClassExpression
+    __match_args__ = ("dp", "ce", )
+
+    def __init__(self,dp: DataProperty,ce: ClassExpression,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataPropertyDomain":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataPropertyDomain":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class DataPropertyRange:
+    dp: DataProperty
+    dr: DataRange
+    __match_args__ = ("dp", "dr", )
+
+    def __init__(self,dp: DataProperty,dr: DataRange,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataPropertyRange":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataPropertyRange":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class FunctionalDataProperty:
+    first: DataProperty
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: DataProperty,/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "FunctionalDataProperty":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "FunctionalDataProperty":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DatatypeDefinition:
+    kind: Datatype
+    range: DataRange
+    __match_args__ = ("kind", "range", )
+
+    def __init__(self,kind: Datatype,range: DataRange,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DatatypeDefinition":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DatatypeDefinition":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class HasKey:
+    ce: ClassExpression
+    vpe: typing.List[PropertyExpression]
+    __match_args__ = ("ce", "vpe", )
+
+    def __init__(self,ce: ClassExpression,vpe: typing.List[PropertyExpression],/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "HasKey":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "HasKey":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class SameIndividual:
+    first: typing.List[Individual]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[Individual],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SameIndividual":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SameIndividual":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class DifferentIndividuals:
+    first: typing.List[Individual]
+
+    __match_args__ = ("first", )
+
+    def __init__(self,first: typing.List[Individual],/):
+        ...
+
+
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DifferentIndividuals":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DifferentIndividuals":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+    ...
+
+class ClassAssertion:
+    ce: ClassExpression
+    i: Individual
+    __match_args__ = ("ce", "i", )
+
+    def __init__(self,ce: ClassExpression,i: Individual,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ClassAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ClassAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class ObjectPropertyAssertion:
+    ope: ObjectPropertyExpression
+    source: Individual
+    target: Individual
+    __match_args__ = ("ope", "from", "to", )
+
+    def __init__(self,ope: ObjectPropertyExpression,source: Individual,target: Individual,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectPropertyAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectPropertyAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class NegativeObjectPropertyAssertion:
+    ope: ObjectPropertyExpression
+    source: Individual
+    target: Individual
+    __match_args__ = ("ope", "from", "to", )
+
+    def __init__(self,ope: ObjectPropertyExpression,source: Individual,target: Individual,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "NegativeObjectPropertyAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "NegativeObjectPropertyAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
+    ...
+
+class DataPropertyAssertion:
+    dp: DataProperty
+    source: Individual
+    target: Literal
+    __match_args__ = ("dp", "from", "to", )
+
+    def __init__(self,dp: DataProperty,source: Individual,target: Literal,/):
+        ...
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class ObjectPropertyDomain:
-    ope: ObjectPropertyExpression
-    ce: ClassExpression
-    __match_args__ = ("ope", "ce", )
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    def __init__(self,ope: ObjectPropertyExpression,ce: ClassExpression,/):
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
         ...
 
-    ...
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-class ObjectPropertyRange:
-    ope: ObjectPropertyExpression
-    ce: ClassExpression
-    __match_args__ = ("ope", "ce", )
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,ope: ObjectPropertyExpression,ce: ClassExpression,/):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-class FunctionalObjectProperty:
-    first: ObjectPropertyExpression
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataPropertyAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "DataPropertyAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
 
     ...
 
-class InverseFunctionalObjectProperty:
-    first: ObjectPropertyExpression
+class NegativeDataPropertyAssertion:
+    dp: DataProperty
+    source: Individual
+    target: Literal
+    __match_args__ = ("dp", "from", "to", )
 
-    __match_args__ = ("first", )
+    def __init__(self,dp: DataProperty,source: Individual,target: Literal,/):
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    ...
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-class ReflexiveObjectProperty:
-    first: ObjectPropertyExpression
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
 
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-class IrreflexiveObjectProperty:
-    first: ObjectPropertyExpression
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "NegativeDataPropertyAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "NegativeDataPropertyAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
 
     ...
 
-class SymmetricObjectProperty:
-    first: ObjectPropertyExpression
+class AnnotationAssertion:
+    subject: AnnotationSubject
+    ann: Annotation
+    __match_args__ = ("subject", "ann", )
 
-    __match_args__ = ("first", )
+    def __init__(self,subject: AnnotationSubject,ann: Annotation,/):
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    ...
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-class AsymmetricObjectProperty:
-    first: ObjectPropertyExpression
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
         ...
 
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-class TransitiveObjectProperty:
-    first: ObjectPropertyExpression
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    __match_args__ = ("first", )
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,first: ObjectPropertyExpression,/):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnnotationAssertion":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "AnnotationAssertion":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
 
     ...
 
-class SubDataPropertyOf:
-    sub: DataProperty
-    sup: DataProperty
+class SubAnnotationPropertyOf:
+    sub: AnnotationProperty
+    sup: AnnotationProperty
     __match_args__ = ("sub", "sup", )
 
-    def __init__(self,sub: DataProperty,sup: DataProperty,/):
+    def __init__(self,sub: AnnotationProperty,sup: AnnotationProperty,/):
         ...
 
-    ...
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-class EquivalentDataProperties:
-    first: typing.List[DataProperty]
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    __match_args__ = ("first", )
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    def __init__(self,first: typing.List[DataProperty],/):
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
         ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    ...
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-class DisjointDataProperties:
-    first: typing.List[DataProperty]
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-    __match_args__ = ("first", )
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    def __init__(self,first: typing.List[DataProperty],/):
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SubAnnotationPropertyOf":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "SubAnnotationPropertyOf":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
 
     ...
 
-class DataPropertyDomain:
-    dp: DataProperty
-    ce: ClassExpression
-    __match_args__ = ("dp", "ce", )
+class AnnotationPropertyDomain:
+    ap: AnnotationProperty
+    iri: IRI
+    __match_args__ = ("ap", "iri", )
 
-    def __init__(self,dp: DataProperty,ce: ClassExpression,/):
+    def __init__(self,ap: AnnotationProperty,iri: IRI,/):
         ...
 
-    ...
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-class DataPropertyRange:
-    dp: DataProperty
-    dr: DataRange
-    __match_args__ = ("dp", "dr", )
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    def __init__(self,dp: DataProperty,dr: DataRange,/):
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
 
-    ...
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-class FunctionalDataProperty:
-    first: DataProperty
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,first: DataProperty,/):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    ...
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-class DatatypeDefinition:
-    kind: Datatype
-    range: DataRange
-    __match_args__ = ("kind", "range", )
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
 
-    def __init__(self,kind: Datatype,range: DataRange,/):
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnnotationPropertyDomain":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "AnnotationPropertyDomain":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
 
     ...
 
-class HasKey:
-    ce: ClassExpression
-    vpe: typing.List[PropertyExpression]
-    __match_args__ = ("ce", "vpe", )
+class AnnotationPropertyRange:
+    ap: AnnotationProperty
+    iri: IRI
+    __match_args__ = ("ap", "iri", )
 
-    def __init__(self,ce: ClassExpression,vpe: typing.List[PropertyExpression],/):
+    def __init__(self,ap: AnnotationProperty,iri: IRI,/):
         ...
 
-    ...
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-class SameIndividual:
-    first: typing.List[Individual]
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    __match_args__ = ("first", )
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    def __init__(self,first: typing.List[Individual],/):
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
         ...
 
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    ...
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-class DifferentIndividuals:
-    first: typing.List[Individual]
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
 
-    __match_args__ = ("first", )
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-    def __init__(self,first: typing.List[Individual],/):
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnnotationPropertyRange":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "AnnotationPropertyRange":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
 
     ...
 
-class ClassAssertion:
-    ce: ClassExpression
-    i: Individual
-    __match_args__ = ("ce", "i", )
+class DocIRI:
+    first: IRI
 
-    def __init__(self,ce: ClassExpression,i: Individual,/):
+    __match_args__ = ("first", )
+
+    def __init__(self,first: IRI,/):
         ...
 
-    ...
 
-class ObjectPropertyAssertion:
-    ope: ObjectPropertyExpression
-    source: Individual
-    target: Individual
-    __match_args__ = ("ope", "from", "to", )
 
-    def __init__(self,ope: ObjectPropertyExpression,source: Individual,target: Individual,/):
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
         ...
 
-    ...
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-class NegativeObjectPropertyAssertion:
-    ope: ObjectPropertyExpression
-    source: Individual
-    target: Individual
-    __match_args__ = ("ope", "from", "to", )
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-    def __init__(self,ope: ObjectPropertyExpression,source: Individual,target: Individual,/):
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
         ...
 
-    ...
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-class DataPropertyAssertion:
-    dp: DataProperty
-    source: Individual
-    target: Literal
-    __match_args__ = ("dp", "from", "to", )
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,dp: DataProperty,source: Individual,target: Literal,/):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-class NegativeDataPropertyAssertion:
-    dp: DataProperty
-    source: Individual
-    target: Literal
-    __match_args__ = ("dp", "from", "to", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    def __init__(self,dp: DataProperty,source: Individual,target: Literal,/):
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DocIRI":
+        """Creates the object from a dictionary as returned by `to_dict`"""
         ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "DocIRI":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
-class AnnotationAssertion:
-    subject: AnnotationSubject
-    ann: Annotation
-    __match_args__ = ("subject", "ann", )
+class OntologyID:
+    iri: typing.Optional[IRI]
+    viri: typing.Optional[IRI]
+    __match_args__ = ("iri", "viri", )
 
-    def __init__(self,subject: AnnotationSubject,ann: Annotation,/):
+    def __init__(self,iri: typing.Optional[IRI],viri: typing.Optional[IRI],/):
         ...
 
-    ...
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-class SubAnnotationPropertyOf:
-    sub: AnnotationProperty
-    sup: AnnotationProperty
-    __match_args__ = ("sub", "sup", )
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
 
-    def __init__(self,sub: AnnotationProperty,sup: AnnotationProperty,/):
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
         ...
 
-    ...
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-class AnnotationPropertyDomain:
-    ap: AnnotationProperty
-    iri: IRI
-    __match_args__ = ("ap", "iri", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
 
-    def __init__(self,ap: AnnotationProperty,iri: IRI,/):
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
-    ...
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
 
-class AnnotationPropertyRange:
-    ap: AnnotationProperty
-    iri: IRI
-    __match_args__ = ("ap", "iri", )
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
 
-    def __init__(self,ap: AnnotationProperty,iri: IRI,/):
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "OntologyID":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "OntologyID":
+        """Creates the object from a JSON string as returned by `to_json`"""
         ...
 
     ...
 
-class DocIRI:
+class Variable:
     first: IRI
 
     __match_args__ = ("first", )
@@ -1211,27 +5341,56 @@ class DocIRI:
         ...
 
 
-    ...
 
-class OntologyID:
-    iri: typing.Optional[IRI]
-    viri: typing.Optional[IRI]
-    __match_args__ = ("iri", "viri", )
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
 
-    def __init__(self,iri: typing.Optional[IRI],viri: typing.Optional[IRI],/):
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
         ...
 
-    ...
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
 
-class Variable:
-    first: IRI
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
 
-    __match_args__ = ("first", )
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
 
-    def __init__(self,first: IRI,/):
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
         ...
 
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Variable":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
 
+    @classmethod
+    def from_json(cls, value: str) -> "Variable":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 DArgument = typing.Union[Literal,Variable,]
@@ -1250,6 +5409,56 @@ class BuiltInAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "BuiltInAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "BuiltInAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class ClassAtom:
     pred: ClassExpression
@@ -1262,6 +5471,56 @@ class ClassAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ClassAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ClassAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class DataPropertyAtom:
     pred: DataProperty
@@ -1274,6 +5533,56 @@ class DataPropertyAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataPropertyAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataPropertyAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class DataRangeAtom:
     pred: DataRange
@@ -1286,6 +5595,56 @@ class DataRangeAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DataRangeAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DataRangeAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class DifferentIndividualsAtom:
     first: IArgument
@@ -1298,6 +5657,56 @@ class DifferentIndividualsAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "DifferentIndividualsAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "DifferentIndividualsAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class ObjectPropertyAtom:
     pred: ObjectPropertyExpression
@@ -1310,6 +5719,56 @@ class ObjectPropertyAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "ObjectPropertyAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "ObjectPropertyAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 class SameIndividualAtom:
     first: IArgument
@@ -1322,6 +5781,56 @@ class SameIndividualAtom:
         ...
     
 
+
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "SameIndividualAtom":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "SameIndividualAtom":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
     ...
 
 Atom = typing.Union[BuiltInAtom,ClassAtom,DataPropertyAtom,DataRangeAtom,DifferentIndividualsAtom,ObjectPropertyAtom,SameIndividualAtom,]
@@ -1334,6 +5843,56 @@ class Rule:
     def __init__(self,head: typing.List[Atom],body: typing.List[Atom],/):
         ...
 
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "Rule":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "Rule":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
     ...
 
 Component = typing.Union[OntologyID,DocIRI,OntologyAnnotation,Import,DeclareClass,DeclareObjectProperty,DeclareAnnotationProperty,DeclareDataProperty,DeclareNamedIndividual,DeclareDatatype,SubClassOf,EquivalentClasses,DisjointClasses,DisjointUnion,SubObjectPropertyOf,EquivalentObjectProperties,DisjointObjectProperties,InverseObjectProperties,ObjectPropertyDomain,ObjectPropertyRange,FunctionalObjectProperty,InverseFunctionalObjectProperty,ReflexiveObjectProperty,IrreflexiveObjectProperty,SymmetricObjectProperty,AsymmetricObjectProperty,TransitiveObjectProperty,SubDataPropertyOf,EquivalentDataProperties,DisjointDataProperties,DataPropertyDomain,DataPropertyRange,FunctionalDataProperty,DatatypeDefinition,HasKey,SameIndividual,DifferentIndividuals,ClassAssertion,ObjectPropertyAssertion,NegativeObjectPropertyAssertion,DataPropertyAssertion,NegativeDataPropertyAssertion,AnnotationAssertion,SubAnnotationPropertyOf,AnnotationPropertyDomain,AnnotationPropertyRange,Rule,]
@@ -1346,5 +5905,55 @@ class AnnotatedComponent:
     def __init__(self,component: Component,ann: typing.Set[Annotation],/):
         ...
 
+    def signature(self) -> typing.Set[typing.Union[Class, ObjectProperty, DataProperty, AnnotationProperty, NamedIndividual, Datatype]]:
+        """Returns all named entities used"""
+        ...
+
+    def classes(self) -> typing.Set[Class]:
+        """Returns all classes used"""
+        ...
+
+    def object_properties(self) -> typing.Set[ObjectProperty]:
+        """Returns all object properties used"""
+        ...
+
+    def data_properties(self) -> typing.Set[DataProperty]:
+        """Returns all data properties used"""
+        ...
+
+    def annotation_properties(self) -> typing.Set[AnnotationProperty]:
+        """Returns all annotation properties used"""
+        ...
+
+    def named_individuals(self) -> typing.Set[NamedIndividual]:
+        """Returns all named individuals used"""
+        ...
+
+    def datatypes(self) -> typing.Set[Datatype]:
+        """Returns all datatypes used, including the datatypes of typed literals"""
+        ...
+
+    def literals(self) -> typing.Set[Literal]:
+        """Returns all literals used"""
+        ...
+
+    def to_dict(self) -> typing.Dict[str, typing.Any]:
+        """Returns the object as JSON compatible dictionary with a "type" key per node"""
+        ...
+
+    def to_json(self) -> str:
+        """Returns the object as JSON string"""
+        ...
+
+    @classmethod
+    def from_dict(cls, value: typing.Dict[str, typing.Any]) -> "AnnotatedComponent":
+        """Creates the object from a dictionary as returned by `to_dict`"""
+        ...
+
+    @classmethod
+    def from_json(cls, value: str) -> "AnnotatedComponent":
+        """Creates the object from a JSON string as returned by `to_json`"""
+        ...
+
     ...
 
@@ -99,6 +99,9 @@ def handle_module(module: str, py_imports: list[str], pyi_imports: list[str]):
                     ):
                         continue
 
+                    if isinstance(member, (staticmethod, classmethod)):
+                        member = member.__func__
+
                     # E.g. for enums
                     if isinstance(member, entry):
                         f.write(f"    {member_name}: typing.Self\n")
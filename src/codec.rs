@@ -0,0 +1,884 @@
+//! Compact binary encoding of ontology components
+//!
+//! Used to pickle a `PyIndexedOntology`. All strings (IRIs, literals, language tags, ...) are
+//! stored once in a string table and referenced by their index, so an IRI occurring in many
+//! components costs a single varint per occurrence. Integers are encoded as LEB128 varints and
+//! enum variants by a tag of one byte.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use horned_owl::model::*;
+use horned_owl::vocab::Facet;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Marks the start of an encoding and its format version.
+const MAGIC: &[u8; 4] = b"PHO\x01";
+
+/// Collects the string table and the body of an encoding.
+#[derive(Default)]
+pub struct Encoder {
+    indices: HashMap<String, u32>,
+    strings: Vec<String>,
+    body: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn varint(out: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    pub fn tag(&mut self, tag: u8) {
+        self.body.push(tag);
+    }
+
+    pub fn usize(&mut self, value: usize) {
+        Self::varint(&mut self.body, value as u64);
+    }
+
+    pub fn str(&mut self, value: &str) {
+        let index = match self.indices.get(value) {
+            Some(index) => *index,
+            None => {
+                let index = self.strings.len() as u32;
+                self.indices.insert(value.to_string(), index);
+                self.strings.push(value.to_string());
+                index
+            }
+        };
+        Self::varint(&mut self.body, index as u64);
+    }
+
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) {
+        value.encode(self);
+    }
+
+    /// Returns the encoding, i.e. the magic bytes, the string table and the body.
+    pub fn finish(self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.body.len() + 16 * self.strings.len());
+        out.extend_from_slice(MAGIC);
+        Self::varint(&mut out, self.strings.len() as u64);
+        for s in &self.strings {
+            Self::varint(&mut out, s.len() as u64);
+            out.extend_from_slice(s.as_bytes());
+        }
+        out.extend_from_slice(&self.body);
+        out
+    }
+}
+
+/// Reads an encoding created by an `Encoder`. IRIs are created with `build` so they share the
+/// IRI cache of the ontology they are decoded into.
+pub struct Decoder<'a> {
+    input: &'a [u8],
+    pos: usize,
+    strings: Vec<&'a str>,
+    build: &'a Build<ArcStr>,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(input: &'a [u8], build: &'a Build<ArcStr>) -> PyResult<Self> {
+        let mut d = Decoder {
+            input,
+            pos: 0,
+            strings: vec![],
+            build,
+        };
+
+        if d.bytes(MAGIC.len())? != MAGIC {
+            return Err(PyValueError::new_err(
+                "Invalid ontology encoding: unknown format or version",
+            ));
+        }
+
+        let count = d.usize()?;
+        for _ in 0..count {
+            let len = d.usize()?;
+            let s = std::str::from_utf8(d.bytes(len)?)
+                .map_err(|e| d.invalid(format!("string is not valid UTF-8 ({e})")))?;
+            d.strings.push(s);
+        }
+
+        Ok(d)
+    }
+
+    pub fn invalid(&self, reason: String) -> PyErr {
+        PyValueError::new_err(format!(
+            "Invalid ontology encoding at byte {}: {}",
+            self.pos, reason
+        ))
+    }
+
+    fn bytes(&mut self, len: usize) -> PyResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.input.len())
+            .ok_or_else(|| self.invalid("unexpected end of input".to_string()))?;
+        let bytes = &self.input[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    pub fn tag(&mut self) -> PyResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn usize(&mut self) -> PyResult<usize> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.tag()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return usize::try_from(value)
+                    .map_err(|_| self.invalid(format!("integer {value} is too large")));
+            }
+        }
+        Err(self.invalid("integer is too large".to_string()))
+    }
+
+    pub fn str(&mut self) -> PyResult<&'a str> {
+        let index = self.usize()?;
+        self.strings
+            .get(index)
+            .copied()
+            .ok_or_else(|| self.invalid(format!("unknown string {index}")))
+    }
+
+    pub fn decode<T: Decode>(&mut self) -> PyResult<T> {
+        T::decode(self)
+    }
+
+    /// Fails if there is input left after the last decoded value.
+    pub fn finish(self) -> PyResult<()> {
+        if self.pos == self.input.len() {
+            Ok(())
+        } else {
+            Err(self.invalid("trailing bytes".to_string()))
+        }
+    }
+}
+
+pub trait Encode {
+    fn encode(&self, e: &mut Encoder);
+}
+
+pub trait Decode: Sized {
+    fn decode(d: &mut Decoder) -> PyResult<Self>;
+}
+
+/**************** Primitives and containers ****************/
+
+impl Encode for u32 {
+    fn encode(&self, e: &mut Encoder) {
+        e.usize(*self as usize);
+    }
+}
+
+impl Decode for u32 {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        let value = d.usize()?;
+        u32::try_from(value).map_err(|_| d.invalid(format!("integer {value} is too large")))
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, e: &mut Encoder) {
+        e.str(self);
+    }
+}
+
+impl Decode for String {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        Ok(d.str()?.to_string())
+    }
+}
+
+impl Encode for ArcStr {
+    fn encode(&self, e: &mut Encoder) {
+        e.str(self);
+    }
+}
+
+impl Decode for ArcStr {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        Ok(Arc::from(d.str()?))
+    }
+}
+
+impl Encode for IRI<ArcStr> {
+    fn encode(&self, e: &mut Encoder) {
+        e.str(self.as_ref());
+    }
+}
+
+impl Decode for IRI<ArcStr> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        let iri = d.str()?;
+        Ok(d.build.iri(iri))
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, e: &mut Encoder) {
+        match self {
+            None => e.tag(0),
+            Some(value) => {
+                e.tag(1);
+                value.encode(e);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        match d.tag()? {
+            0 => Ok(None),
+            1 => Ok(Some(d.decode()?)),
+            tag => Err(d.invalid(format!("unknown option tag {tag}"))),
+        }
+    }
+}
+
+impl<T: Encode> Encode for Box<T> {
+    fn encode(&self, e: &mut Encoder) {
+        (**self).encode(e);
+    }
+}
+
+impl<T: Decode> Decode for Box<T> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        Ok(Box::new(d.decode()?))
+    }
+}
+
+impl<T: Encode> Encode for [T] {
+    fn encode(&self, e: &mut Encoder) {
+        e.usize(self.len());
+        for value in self {
+            value.encode(e);
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, e: &mut Encoder) {
+        self.as_slice().encode(e);
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        let len = d.usize()?;
+        // Every value takes at least one byte, which bounds the allocation for corrupt input.
+        let mut values = Vec::with_capacity(len.min(d.input.len() - d.pos));
+        for _ in 0..len {
+            values.push(d.decode()?);
+        }
+        Ok(values)
+    }
+}
+
+impl<T: Encode> Encode for BTreeSet<T> {
+    fn encode(&self, e: &mut Encoder) {
+        e.usize(self.len());
+        for value in self {
+            value.encode(e);
+        }
+    }
+}
+
+impl<T: Decode + Ord> Decode for BTreeSet<T> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        Ok(Vec::<T>::decode(d)?.into_iter().collect())
+    }
+}
+
+impl<S: Encode, T: Encode> Encode for (S, T) {
+    fn encode(&self, e: &mut Encoder) {
+        self.0.encode(e);
+        self.1.encode(e);
+    }
+}
+
+impl<S: Decode, T: Decode> Decode for (S, T) {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        Ok((d.decode()?, d.decode()?))
+    }
+}
+
+/// Facets in the order of their tags.
+const FACETS: [Facet; 11] = [
+    Facet::Length,
+    Facet::MinLength,
+    Facet::MaxLength,
+    Facet::Pattern,
+    Facet::MinInclusive,
+    Facet::MinExclusive,
+    Facet::MaxInclusive,
+    Facet::MaxExclusive,
+    Facet::TotalDigits,
+    Facet::FractionDigits,
+    Facet::LangRange,
+];
+
+impl Encode for Facet {
+    fn encode(&self, e: &mut Encoder) {
+        let tag = FACETS
+            .iter()
+            .position(|f| f == self)
+            .expect("FACETS contains every facet");
+        e.tag(tag as u8);
+    }
+}
+
+impl Decode for Facet {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        let tag = d.tag()?;
+        FACETS
+            .get(tag as usize)
+            .cloned()
+            .ok_or_else(|| d.invalid(format!("unknown facet tag {tag}")))
+    }
+}
+
+/**************** Structs ****************/
+
+/// Encodes the fields of structs in the given order. Tuple structs list their field indices.
+macro_rules! struct_codec {
+    ($($name:ident { $($field:tt),* };)*) => {$(
+        impl Encode for $name<ArcStr> {
+            fn encode(&self, e: &mut Encoder) {
+                $(self.$field.encode(e);)*
+            }
+        }
+
+        impl Decode for $name<ArcStr> {
+            fn decode(d: &mut Decoder) -> PyResult<Self> {
+                Ok($name { $($field: d.decode()?),* })
+            }
+        }
+    )*};
+}
+
+struct_codec! {
+    Class { 0 };
+    ObjectProperty { 0 };
+    DataProperty { 0 };
+    AnnotationProperty { 0 };
+    NamedIndividual { 0 };
+    Datatype { 0 };
+    AnonymousIndividual { 0 };
+    Variable { 0 };
+    FacetRestriction { f, l };
+    Annotation { ap, av };
+    AnnotatedComponent { component, ann };
+
+    OntologyID { iri, viri };
+    DocIRI { 0 };
+    OntologyAnnotation { 0 };
+    Import { 0 };
+    DeclareClass { 0 };
+    DeclareObjectProperty { 0 };
+    DeclareAnnotationProperty { 0 };
+    DeclareDataProperty { 0 };
+    DeclareNamedIndividual { 0 };
+    DeclareDatatype { 0 };
+    SubClassOf { sup, sub };
+    EquivalentClasses { 0 };
+    DisjointClasses { 0 };
+    DisjointUnion { 0, 1 };
+    SubObjectPropertyOf { sup, sub };
+    EquivalentObjectProperties { 0 };
+    DisjointObjectProperties { 0 };
+    InverseObjectProperties { 0, 1 };
+    ObjectPropertyDomain { ope, ce };
+    ObjectPropertyRange { ope, ce };
+    FunctionalObjectProperty { 0 };
+    InverseFunctionalObjectProperty { 0 };
+    ReflexiveObjectProperty { 0 };
+    IrreflexiveObjectProperty { 0 };
+    SymmetricObjectProperty { 0 };
+    AsymmetricObjectProperty { 0 };
+    TransitiveObjectProperty { 0 };
+    SubDataPropertyOf { sup, sub };
+    EquivalentDataProperties { 0 };
+    DisjointDataProperties { 0 };
+    DataPropertyDomain { dp, ce };
+    DataPropertyRange { dp, dr };
+    FunctionalDataProperty { 0 };
+    DatatypeDefinition { kind, range };
+    HasKey { ce, vpe };
+    SameIndividual { 0 };
+    DifferentIndividuals { 0 };
+    ClassAssertion { ce, i };
+    ObjectPropertyAssertion { ope, from, to };
+    NegativeObjectPropertyAssertion { ope, from, to };
+    DataPropertyAssertion { dp, from, to };
+    NegativeDataPropertyAssertion { dp, from, to };
+    AnnotationAssertion { subject, ann };
+    SubAnnotationPropertyOf { sup, sub };
+    AnnotationPropertyDomain { ap, iri };
+    AnnotationPropertyRange { ap, iri };
+    Rule { head, body };
+}
+
+/**************** Enums ****************/
+
+/// Encodes enums whose variants wrap a single value, using the given tag per variant.
+macro_rules! enum_codec {
+    ($($name:ident { $($tag:literal => $variant:ident),* $(,)? };)*) => {$(
+        impl Encode for $name<ArcStr> {
+            fn encode(&self, e: &mut Encoder) {
+                match self {
+                    $($name::$variant(value) => {
+                        e.tag($tag);
+                        value.encode(e);
+                    })*
+                }
+            }
+        }
+
+        impl Decode for $name<ArcStr> {
+            fn decode(d: &mut Decoder) -> PyResult<Self> {
+                match d.tag()? {
+                    $($tag => Ok($name::$variant(d.decode()?)),)*
+                    tag => Err(d.invalid(format!(
+                        concat!("unknown ", stringify!($name), " tag {}"),
+                        tag
+                    ))),
+                }
+            }
+        }
+    )*};
+}
+
+enum_codec! {
+    Individual {
+        0 => Anonymous,
+        1 => Named,
+    };
+    ObjectPropertyExpression {
+        0 => ObjectProperty,
+        1 => InverseObjectProperty,
+    };
+    SubObjectPropertyExpression {
+        0 => ObjectPropertyChain,
+        1 => ObjectPropertyExpression,
+    };
+    PropertyExpression {
+        0 => ObjectPropertyExpression,
+        1 => DataProperty,
+        2 => AnnotationProperty,
+    };
+    AnnotationSubject {
+        0 => IRI,
+        1 => AnonymousIndividual,
+    };
+    AnnotationValue {
+        0 => Literal,
+        1 => IRI,
+        2 => AnonymousIndividual,
+    };
+    IArgument {
+        0 => Individual,
+        1 => Variable,
+    };
+    DArgument {
+        0 => Literal,
+        1 => Variable,
+    };
+    Component {
+        0 => OntologyID,
+        1 => DocIRI,
+        2 => OntologyAnnotation,
+        3 => Import,
+        4 => DeclareClass,
+        5 => DeclareObjectProperty,
+        6 => DeclareAnnotationProperty,
+        7 => DeclareDataProperty,
+        8 => DeclareNamedIndividual,
+        9 => DeclareDatatype,
+        10 => SubClassOf,
+        11 => EquivalentClasses,
+        12 => DisjointClasses,
+        13 => DisjointUnion,
+        14 => SubObjectPropertyOf,
+        15 => EquivalentObjectProperties,
+        16 => DisjointObjectProperties,
+        17 => InverseObjectProperties,
+        18 => ObjectPropertyDomain,
+        19 => ObjectPropertyRange,
+        20 => FunctionalObjectProperty,
+        21 => InverseFunctionalObjectProperty,
+        22 => ReflexiveObjectProperty,
+        23 => IrreflexiveObjectProperty,
+        24 => SymmetricObjectProperty,
+        25 => AsymmetricObjectProperty,
+        26 => TransitiveObjectProperty,
+        27 => SubDataPropertyOf,
+        28 => EquivalentDataProperties,
+        29 => DisjointDataProperties,
+        30 => DataPropertyDomain,
+        31 => DataPropertyRange,
+        32 => FunctionalDataProperty,
+        33 => DatatypeDefinition,
+        34 => HasKey,
+        35 => SameIndividual,
+        36 => DifferentIndividuals,
+        37 => ClassAssertion,
+        38 => ObjectPropertyAssertion,
+        39 => NegativeObjectPropertyAssertion,
+        40 => DataPropertyAssertion,
+        41 => NegativeDataPropertyAssertion,
+        42 => AnnotationAssertion,
+        43 => SubAnnotationPropertyOf,
+        44 => AnnotationPropertyDomain,
+        45 => AnnotationPropertyRange,
+        46 => Rule,
+    };
+}
+
+impl Encode for Literal<ArcStr> {
+    fn encode(&self, e: &mut Encoder) {
+        match self {
+            Literal::Simple { literal } => {
+                e.tag(0);
+                e.str(literal);
+            }
+            Literal::Language { literal, lang } => {
+                e.tag(1);
+                e.str(literal);
+                e.str(lang);
+            }
+            Literal::Datatype {
+                literal,
+                datatype_iri,
+            } => {
+                e.tag(2);
+                e.str(literal);
+                e.encode(datatype_iri);
+            }
+        }
+    }
+}
+
+impl Decode for Literal<ArcStr> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        match d.tag()? {
+            0 => Ok(Literal::Simple {
+                literal: d.decode()?,
+            }),
+            1 => Ok(Literal::Language {
+                literal: d.decode()?,
+                lang: d.decode()?,
+            }),
+            2 => Ok(Literal::Datatype {
+                literal: d.decode()?,
+                datatype_iri: d.decode()?,
+            }),
+            tag => Err(d.invalid(format!("unknown Literal tag {tag}"))),
+        }
+    }
+}
+
+impl Encode for DataRange<ArcStr> {
+    fn encode(&self, e: &mut Encoder) {
+        match self {
+            DataRange::Datatype(dt) => {
+                e.tag(0);
+                e.encode(dt);
+            }
+            DataRange::DataIntersectionOf(drs) => {
+                e.tag(1);
+                e.encode(drs);
+            }
+            DataRange::DataUnionOf(drs) => {
+                e.tag(2);
+                e.encode(drs);
+            }
+            DataRange::DataComplementOf(dr) => {
+                e.tag(3);
+                e.encode(dr);
+            }
+            DataRange::DataOneOf(literals) => {
+                e.tag(4);
+                e.encode(literals);
+            }
+            DataRange::DatatypeRestriction(dt, restrictions) => {
+                e.tag(5);
+                e.encode(dt);
+                e.encode(restrictions);
+            }
+        }
+    }
+}
+
+impl Decode for DataRange<ArcStr> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        match d.tag()? {
+            0 => Ok(DataRange::Datatype(d.decode()?)),
+            1 => Ok(DataRange::DataIntersectionOf(d.decode()?)),
+            2 => Ok(DataRange::DataUnionOf(d.decode()?)),
+            3 => Ok(DataRange::DataComplementOf(d.decode()?)),
+            4 => Ok(DataRange::DataOneOf(d.decode()?)),
+            5 => Ok(DataRange::DatatypeRestriction(d.decode()?, d.decode()?)),
+            tag => Err(d.invalid(format!("unknown DataRange tag {tag}"))),
+        }
+    }
+}
+
+impl Encode for ClassExpression<ArcStr> {
+    fn encode(&self, e: &mut Encoder) {
+        use ClassExpression::*;
+
+        match self {
+            Class(c) => {
+                e.tag(0);
+                e.encode(c);
+            }
+            ObjectIntersectionOf(ces) => {
+                e.tag(1);
+                e.encode(ces);
+            }
+            ObjectUnionOf(ces) => {
+                e.tag(2);
+                e.encode(ces);
+            }
+            ObjectComplementOf(ce) => {
+                e.tag(3);
+                e.encode(ce);
+            }
+            ObjectOneOf(individuals) => {
+                e.tag(4);
+                e.encode(individuals);
+            }
+            ObjectSomeValuesFrom { ope, bce } => {
+                e.tag(5);
+                e.encode(ope);
+                e.encode(bce);
+            }
+            ObjectAllValuesFrom { ope, bce } => {
+                e.tag(6);
+                e.encode(ope);
+                e.encode(bce);
+            }
+            ObjectHasValue { ope, i } => {
+                e.tag(7);
+                e.encode(ope);
+                e.encode(i);
+            }
+            ObjectHasSelf(ope) => {
+                e.tag(8);
+                e.encode(ope);
+            }
+            ObjectMinCardinality { n, ope, bce } => {
+                e.tag(9);
+                e.encode(n);
+                e.encode(ope);
+                e.encode(bce);
+            }
+            ObjectMaxCardinality { n, ope, bce } => {
+                e.tag(10);
+                e.encode(n);
+                e.encode(ope);
+                e.encode(bce);
+            }
+            ObjectExactCardinality { n, ope, bce } => {
+                e.tag(11);
+                e.encode(n);
+                e.encode(ope);
+                e.encode(bce);
+            }
+            DataSomeValuesFrom { dp, dr } => {
+                e.tag(12);
+                e.encode(dp);
+                e.encode(dr);
+            }
+            DataAllValuesFrom { dp, dr } => {
+                e.tag(13);
+                e.encode(dp);
+                e.encode(dr);
+            }
+            DataHasValue { dp, l } => {
+                e.tag(14);
+                e.encode(dp);
+                e.encode(l);
+            }
+            DataMinCardinality { n, dp, dr } => {
+                e.tag(15);
+                e.encode(n);
+                e.encode(dp);
+                e.encode(dr);
+            }
+            DataMaxCardinality { n, dp, dr } => {
+                e.tag(16);
+                e.encode(n);
+                e.encode(dp);
+                e.encode(dr);
+            }
+            DataExactCardinality { n, dp, dr } => {
+                e.tag(17);
+                e.encode(n);
+                e.encode(dp);
+                e.encode(dr);
+            }
+        }
+    }
+}
+
+impl Decode for ClassExpression<ArcStr> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        use ClassExpression::*;
+
+        match d.tag()? {
+            0 => Ok(Class(d.decode()?)),
+            1 => Ok(ObjectIntersectionOf(d.decode()?)),
+            2 => Ok(ObjectUnionOf(d.decode()?)),
+            3 => Ok(ObjectComplementOf(d.decode()?)),
+            4 => Ok(ObjectOneOf(d.decode()?)),
+            5 => Ok(ObjectSomeValuesFrom {
+                ope: d.decode()?,
+                bce: d.decode()?,
+            }),
+            6 => Ok(ObjectAllValuesFrom {
+                ope: d.decode()?,
+                bce: d.decode()?,
+            }),
+            7 => Ok(ObjectHasValue {
+                ope: d.decode()?,
+                i: d.decode()?,
+            }),
+            8 => Ok(ObjectHasSelf(d.decode()?)),
+            9 => Ok(ObjectMinCardinality {
+                n: d.decode()?,
+                ope: d.decode()?,
+                bce: d.decode()?,
+            }),
+            10 => Ok(ObjectMaxCardinality {
+                n: d.decode()?,
+                ope: d.decode()?,
+                bce: d.decode()?,
+            }),
+            11 => Ok(ObjectExactCardinality {
+                n: d.decode()?,
+                ope: d.decode()?,
+                bce: d.decode()?,
+            }),
+            12 => Ok(DataSomeValuesFrom {
+                dp: d.decode()?,
+                dr: d.decode()?,
+            }),
+            13 => Ok(DataAllValuesFrom {
+                dp: d.decode()?,
+                dr: d.decode()?,
+            }),
+            14 => Ok(DataHasValue {
+                dp: d.decode()?,
+                l: d.decode()?,
+            }),
+            15 => Ok(DataMinCardinality {
+                n: d.decode()?,
+                dp: d.decode()?,
+                dr: d.decode()?,
+            }),
+            16 => Ok(DataMaxCardinality {
+                n: d.decode()?,
+                dp: d.decode()?,
+                dr: d.decode()?,
+            }),
+            17 => Ok(DataExactCardinality {
+                n: d.decode()?,
+                dp: d.decode()?,
+                dr: d.decode()?,
+            }),
+            tag => Err(d.invalid(format!("unknown ClassExpression tag {tag}"))),
+        }
+    }
+}
+
+impl Encode for Atom<ArcStr> {
+    fn encode(&self, e: &mut Encoder) {
+        use Atom::*;
+
+        match self {
+            BuiltInAtom { pred, args } => {
+                e.tag(0);
+                e.encode(pred);
+                e.encode(args);
+            }
+            ClassAtom { pred, arg } => {
+                e.tag(1);
+                e.encode(pred);
+                e.encode(arg);
+            }
+            DataPropertyAtom { pred, args } => {
+                e.tag(2);
+                e.encode(pred);
+                e.encode(args);
+            }
+            DataRangeAtom { pred, arg } => {
+                e.tag(3);
+                e.encode(pred);
+                e.encode(arg);
+            }
+            DifferentIndividualsAtom(first, second) => {
+                e.tag(4);
+                e.encode(first);
+                e.encode(second);
+            }
+            ObjectPropertyAtom { pred, args } => {
+                e.tag(5);
+                e.encode(pred);
+                e.encode(args);
+            }
+            SameIndividualAtom(first, second) => {
+                e.tag(6);
+                e.encode(first);
+                e.encode(second);
+            }
+        }
+    }
+}
+
+impl Decode for Atom<ArcStr> {
+    fn decode(d: &mut Decoder) -> PyResult<Self> {
+        use Atom::*;
+
+        match d.tag()? {
+            0 => Ok(BuiltInAtom {
+                pred: d.decode()?,
+                args: d.decode()?,
+            }),
+            1 => Ok(ClassAtom {
+                pred: d.decode()?,
+                arg: d.decode()?,
+            }),
+            2 => Ok(DataPropertyAtom {
+                pred: d.decode()?,
+                args: d.decode()?,
+            }),
+            3 => Ok(DataRangeAtom {
+                pred: d.decode()?,
+                arg: d.decode()?,
+            }),
+            4 => Ok(DifferentIndividualsAtom(d.decode()?, d.decode()?)),
+            5 => Ok(ObjectPropertyAtom {
+                pred: d.decode()?,
+                args: d.decode()?,
+            }),
+            6 => Ok(SameIndividualAtom(d.decode()?, d.decode()?)),
+            tag => Err(d.invalid(format!("unknown Atom tag {tag}"))),
+        }
+    }
+}
@@ -0,0 +1,729 @@
+//! Folding (rewriting) of the OWL model
+//!
+//! `Fold` rebuilds a model value bottom-up. Every method defaults to the function of the same
+//! name in this module, which folds the children of the value and reassembles it. Implementations
+//! override the methods for the node types they want to rewrite and call the default function to
+//! continue the recursion.
+//!
+//! `PyFold` implements `Fold` by calling a Python function for every node, which backs
+//! `pyhornedowl.transform`.
+
+use std::collections::BTreeSet;
+
+use horned_owl::model::*;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyNone;
+use pyo3::IntoPyObjectExt;
+
+use crate::model;
+
+/// A bottom-up rewrite of the OWL model.
+pub trait Fold {
+    type Error;
+
+    fn fold_iri(&mut self, e: IRI<ArcStr>) -> Result<IRI<ArcStr>, Self::Error> {
+        Ok(e)
+    }
+
+    fn fold_class(&mut self, e: Class<ArcStr>) -> Result<Class<ArcStr>, Self::Error> {
+        Ok(Class(self.fold_iri(e.0)?))
+    }
+
+    fn fold_object_property(
+        &mut self,
+        e: ObjectProperty<ArcStr>,
+    ) -> Result<ObjectProperty<ArcStr>, Self::Error> {
+        Ok(ObjectProperty(self.fold_iri(e.0)?))
+    }
+
+    fn fold_data_property(
+        &mut self,
+        e: DataProperty<ArcStr>,
+    ) -> Result<DataProperty<ArcStr>, Self::Error> {
+        Ok(DataProperty(self.fold_iri(e.0)?))
+    }
+
+    fn fold_annotation_property(
+        &mut self,
+        e: AnnotationProperty<ArcStr>,
+    ) -> Result<AnnotationProperty<ArcStr>, Self::Error> {
+        Ok(AnnotationProperty(self.fold_iri(e.0)?))
+    }
+
+    fn fold_named_individual(
+        &mut self,
+        e: NamedIndividual<ArcStr>,
+    ) -> Result<NamedIndividual<ArcStr>, Self::Error> {
+        Ok(NamedIndividual(self.fold_iri(e.0)?))
+    }
+
+    fn fold_datatype(&mut self, e: Datatype<ArcStr>) -> Result<Datatype<ArcStr>, Self::Error> {
+        Ok(Datatype(self.fold_iri(e.0)?))
+    }
+
+    fn fold_individual(
+        &mut self,
+        e: Individual<ArcStr>,
+    ) -> Result<Individual<ArcStr>, Self::Error> {
+        fold_individual(self, e)
+    }
+
+    fn fold_literal(&mut self, e: Literal<ArcStr>) -> Result<Literal<ArcStr>, Self::Error> {
+        fold_literal(self, e)
+    }
+
+    fn fold_object_property_expression(
+        &mut self,
+        e: ObjectPropertyExpression<ArcStr>,
+    ) -> Result<ObjectPropertyExpression<ArcStr>, Self::Error> {
+        fold_object_property_expression(self, e)
+    }
+
+    fn fold_data_range(&mut self, e: DataRange<ArcStr>) -> Result<DataRange<ArcStr>, Self::Error> {
+        fold_data_range(self, e)
+    }
+
+    fn fold_class_expression(
+        &mut self,
+        e: ClassExpression<ArcStr>,
+    ) -> Result<ClassExpression<ArcStr>, Self::Error> {
+        fold_class_expression(self, e)
+    }
+
+    fn fold_annotation(
+        &mut self,
+        e: Annotation<ArcStr>,
+    ) -> Result<Annotation<ArcStr>, Self::Error> {
+        fold_annotation(self, e)
+    }
+
+    fn fold_atom(&mut self, e: Atom<ArcStr>) -> Result<Atom<ArcStr>, Self::Error> {
+        fold_atom(self, e)
+    }
+
+    fn fold_component(&mut self, e: Component<ArcStr>) -> Result<Component<ArcStr>, Self::Error> {
+        fold_component(self, e)
+    }
+
+    fn fold_annotated_component(
+        &mut self,
+        e: AnnotatedComponent<ArcStr>,
+    ) -> Result<AnnotatedComponent<ArcStr>, Self::Error> {
+        fold_annotated_component(self, e)
+    }
+}
+
+/// Folds each element of a collection.
+fn fold_all<T, C, F, E>(items: impl IntoIterator<Item = T>, mut f: F) -> Result<C, E>
+where
+    C: FromIterator<T>,
+    F: FnMut(T) -> Result<T, E>,
+{
+    items.into_iter().map(&mut f).collect()
+}
+
+pub fn fold_individual<F: Fold + ?Sized>(
+    f: &mut F,
+    e: Individual<ArcStr>,
+) -> Result<Individual<ArcStr>, F::Error> {
+    Ok(match e {
+        Individual::Named(i) => Individual::Named(f.fold_named_individual(i)?),
+        Individual::Anonymous(i) => Individual::Anonymous(i),
+    })
+}
+
+pub fn fold_literal<F: Fold + ?Sized>(
+    f: &mut F,
+    e: Literal<ArcStr>,
+) -> Result<Literal<ArcStr>, F::Error> {
+    Ok(match e {
+        Literal::Datatype {
+            literal,
+            datatype_iri,
+        } => Literal::Datatype {
+            literal,
+            datatype_iri: f.fold_iri(datatype_iri)?,
+        },
+        e => e,
+    })
+}
+
+pub fn fold_object_property_expression<F: Fold + ?Sized>(
+    f: &mut F,
+    e: ObjectPropertyExpression<ArcStr>,
+) -> Result<ObjectPropertyExpression<ArcStr>, F::Error> {
+    Ok(match e {
+        ObjectPropertyExpression::ObjectProperty(op) => {
+            ObjectPropertyExpression::ObjectProperty(f.fold_object_property(op)?)
+        }
+        ObjectPropertyExpression::InverseObjectProperty(op) => {
+            ObjectPropertyExpression::InverseObjectProperty(f.fold_object_property(op)?)
+        }
+    })
+}
+
+pub fn fold_data_range<F: Fold + ?Sized>(
+    f: &mut F,
+    e: DataRange<ArcStr>,
+) -> Result<DataRange<ArcStr>, F::Error> {
+    Ok(match e {
+        DataRange::Datatype(dt) => DataRange::Datatype(f.fold_datatype(dt)?),
+        DataRange::DataIntersectionOf(v) => {
+            DataRange::DataIntersectionOf(fold_all(v, |dr| f.fold_data_range(dr))?)
+        }
+        DataRange::DataUnionOf(v) => {
+            DataRange::DataUnionOf(fold_all(v, |dr| f.fold_data_range(dr))?)
+        }
+        DataRange::DataComplementOf(dr) => {
+            DataRange::DataComplementOf(Box::new(f.fold_data_range(*dr)?))
+        }
+        DataRange::DataOneOf(v) => DataRange::DataOneOf(fold_all(v, |l| f.fold_literal(l))?),
+        DataRange::DatatypeRestriction(dt, v) => DataRange::DatatypeRestriction(
+            f.fold_datatype(dt)?,
+            fold_all(v, |fr: FacetRestriction<ArcStr>| {
+                Ok(FacetRestriction {
+                    f: fr.f,
+                    l: f.fold_literal(fr.l)?,
+                })
+            })?,
+        ),
+    })
+}
+
+pub fn fold_class_expression<F: Fold + ?Sized>(
+    f: &mut F,
+    e: ClassExpression<ArcStr>,
+) -> Result<ClassExpression<ArcStr>, F::Error> {
+    use ClassExpression::*;
+
+    Ok(match e {
+        Class(c) => Class(f.fold_class(c)?),
+        ObjectIntersectionOf(v) => ObjectIntersectionOf(fold_all(v, |ce| f.fold_class_expression(ce))?),
+        ObjectUnionOf(v) => ObjectUnionOf(fold_all(v, |ce| f.fold_class_expression(ce))?),
+        ObjectComplementOf(ce) => ObjectComplementOf(Box::new(f.fold_class_expression(*ce)?)),
+        ObjectOneOf(v) => ObjectOneOf(fold_all(v, |i| f.fold_individual(i))?),
+        ObjectSomeValuesFrom { ope, bce } => ObjectSomeValuesFrom {
+            ope: f.fold_object_property_expression(ope)?,
+            bce: Box::new(f.fold_class_expression(*bce)?),
+        },
+        ObjectAllValuesFrom { ope, bce } => ObjectAllValuesFrom {
+            ope: f.fold_object_property_expression(ope)?,
+            bce: Box::new(f.fold_class_expression(*bce)?),
+        },
+        ObjectHasValue { ope, i } => ObjectHasValue {
+            ope: f.fold_object_property_expression(ope)?,
+            i: f.fold_individual(i)?,
+        },
+        ObjectHasSelf(ope) => ObjectHasSelf(f.fold_object_property_expression(ope)?),
+        ObjectMinCardinality { n, ope, bce } => ObjectMinCardinality {
+            n,
+            ope: f.fold_object_property_expression(ope)?,
+            bce: Box::new(f.fold_class_expression(*bce)?),
+        },
+        ObjectMaxCardinality { n, ope, bce } => ObjectMaxCardinality {
+            n,
+            ope: f.fold_object_property_expression(ope)?,
+            bce: Box::new(f.fold_class_expression(*bce)?),
+        },
+        ObjectExactCardinality { n, ope, bce } => ObjectExactCardinality {
+            n,
+            ope: f.fold_object_property_expression(ope)?,
+            bce: Box::new(f.fold_class_expression(*bce)?),
+        },
+        DataSomeValuesFrom { dp, dr } => DataSomeValuesFrom {
+            dp: f.fold_data_property(dp)?,
+            dr: f.fold_data_range(dr)?,
+        },
+        DataAllValuesFrom { dp, dr } => DataAllValuesFrom {
+            dp: f.fold_data_property(dp)?,
+            dr: f.fold_data_range(dr)?,
+        },
+        DataHasValue { dp, l } => DataHasValue {
+            dp: f.fold_data_property(dp)?,
+            l: f.fold_literal(l)?,
+        },
+        DataMinCardinality { n, dp, dr } => DataMinCardinality {
+            n,
+            dp: f.fold_data_property(dp)?,
+            dr: f.fold_data_range(dr)?,
+        },
+        DataMaxCardinality { n, dp, dr } => DataMaxCardinality {
+            n,
+            dp: f.fold_data_property(dp)?,
+            dr: f.fold_data_range(dr)?,
+        },
+        DataExactCardinality { n, dp, dr } => DataExactCardinality {
+            n,
+            dp: f.fold_data_property(dp)?,
+            dr: f.fold_data_range(dr)?,
+        },
+    })
+}
+
+pub fn fold_annotation<F: Fold + ?Sized>(
+    f: &mut F,
+    e: Annotation<ArcStr>,
+) -> Result<Annotation<ArcStr>, F::Error> {
+    Ok(Annotation {
+        ap: f.fold_annotation_property(e.ap)?,
+        av: match e.av {
+            AnnotationValue::Literal(l) => AnnotationValue::Literal(f.fold_literal(l)?),
+            AnnotationValue::IRI(iri) => AnnotationValue::IRI(f.fold_iri(iri)?),
+            av => av,
+        },
+    })
+}
+
+fn fold_property_expression<F: Fold + ?Sized>(
+    f: &mut F,
+    e: PropertyExpression<ArcStr>,
+) -> Result<PropertyExpression<ArcStr>, F::Error> {
+    Ok(match e {
+        PropertyExpression::ObjectPropertyExpression(ope) => {
+            PropertyExpression::ObjectPropertyExpression(f.fold_object_property_expression(ope)?)
+        }
+        PropertyExpression::DataProperty(dp) => {
+            PropertyExpression::DataProperty(f.fold_data_property(dp)?)
+        }
+        PropertyExpression::AnnotationProperty(ap) => {
+            PropertyExpression::AnnotationProperty(f.fold_annotation_property(ap)?)
+        }
+    })
+}
+
+fn fold_iargument<F: Fold + ?Sized>(
+    f: &mut F,
+    e: IArgument<ArcStr>,
+) -> Result<IArgument<ArcStr>, F::Error> {
+    Ok(match e {
+        IArgument::Individual(i) => IArgument::Individual(f.fold_individual(i)?),
+        IArgument::Variable(v) => IArgument::Variable(Variable(f.fold_iri(v.0)?)),
+    })
+}
+
+fn fold_dargument<F: Fold + ?Sized>(
+    f: &mut F,
+    e: DArgument<ArcStr>,
+) -> Result<DArgument<ArcStr>, F::Error> {
+    Ok(match e {
+        DArgument::Literal(l) => DArgument::Literal(f.fold_literal(l)?),
+        DArgument::Variable(v) => DArgument::Variable(Variable(f.fold_iri(v.0)?)),
+    })
+}
+
+pub fn fold_atom<F: Fold + ?Sized>(f: &mut F, e: Atom<ArcStr>) -> Result<Atom<ArcStr>, F::Error> {
+    Ok(match e {
+        Atom::BuiltInAtom { pred, args } => Atom::BuiltInAtom {
+            pred: f.fold_iri(pred)?,
+            args: fold_all(args, |a| fold_dargument(f, a))?,
+        },
+        Atom::ClassAtom { pred, arg } => Atom::ClassAtom {
+            pred: f.fold_class_expression(pred)?,
+            arg: fold_iargument(f, arg)?,
+        },
+        Atom::DataPropertyAtom { pred, args } => Atom::DataPropertyAtom {
+            pred: f.fold_data_property(pred)?,
+            args: (fold_dargument(f, args.0)?, fold_dargument(f, args.1)?),
+        },
+        Atom::DataRangeAtom { pred, arg } => Atom::DataRangeAtom {
+            pred: f.fold_data_range(pred)?,
+            arg: fold_dargument(f, arg)?,
+        },
+        Atom::DifferentIndividualsAtom(a, b) => {
+            Atom::DifferentIndividualsAtom(fold_iargument(f, a)?, fold_iargument(f, b)?)
+        }
+        Atom::ObjectPropertyAtom { pred, args } => Atom::ObjectPropertyAtom {
+            pred: f.fold_object_property_expression(pred)?,
+            args: (fold_iargument(f, args.0)?, fold_iargument(f, args.1)?),
+        },
+        Atom::SameIndividualAtom(a, b) => {
+            Atom::SameIndividualAtom(fold_iargument(f, a)?, fold_iargument(f, b)?)
+        }
+    })
+}
+
+pub fn fold_component<F: Fold + ?Sized>(
+    f: &mut F,
+    e: Component<ArcStr>,
+) -> Result<Component<ArcStr>, F::Error> {
+    use Component::*;
+
+    Ok(match e {
+        OntologyID(id) => OntologyID(horned_owl::model::OntologyID {
+            iri: id.iri.map(|iri| f.fold_iri(iri)).transpose()?,
+            viri: id.viri.map(|iri| f.fold_iri(iri)).transpose()?,
+        }),
+        DocIRI(d) => DocIRI(horned_owl::model::DocIRI(f.fold_iri(d.0)?)),
+        Import(i) => Import(horned_owl::model::Import(f.fold_iri(i.0)?)),
+        OntologyAnnotation(a) => {
+            OntologyAnnotation(horned_owl::model::OntologyAnnotation(f.fold_annotation(a.0)?))
+        }
+        DeclareClass(d) => DeclareClass(horned_owl::model::DeclareClass(f.fold_class(d.0)?)),
+        DeclareObjectProperty(d) => DeclareObjectProperty(
+            horned_owl::model::DeclareObjectProperty(f.fold_object_property(d.0)?),
+        ),
+        DeclareAnnotationProperty(d) => DeclareAnnotationProperty(
+            horned_owl::model::DeclareAnnotationProperty(f.fold_annotation_property(d.0)?),
+        ),
+        DeclareDataProperty(d) => DeclareDataProperty(horned_owl::model::DeclareDataProperty(
+            f.fold_data_property(d.0)?,
+        )),
+        DeclareNamedIndividual(d) => DeclareNamedIndividual(
+            horned_owl::model::DeclareNamedIndividual(f.fold_named_individual(d.0)?),
+        ),
+        DeclareDatatype(d) => {
+            DeclareDatatype(horned_owl::model::DeclareDatatype(f.fold_datatype(d.0)?))
+        }
+        SubClassOf(ax) => SubClassOf(horned_owl::model::SubClassOf {
+            sub: f.fold_class_expression(ax.sub)?,
+            sup: f.fold_class_expression(ax.sup)?,
+        }),
+        EquivalentClasses(ax) => EquivalentClasses(horned_owl::model::EquivalentClasses(
+            fold_all(ax.0, |ce| f.fold_class_expression(ce))?,
+        )),
+        DisjointClasses(ax) => DisjointClasses(horned_owl::model::DisjointClasses(fold_all(
+            ax.0,
+            |ce| f.fold_class_expression(ce),
+        )?)),
+        DisjointUnion(ax) => DisjointUnion(horned_owl::model::DisjointUnion(
+            f.fold_class(ax.0)?,
+            fold_all(ax.1, |ce| f.fold_class_expression(ce))?,
+        )),
+        SubObjectPropertyOf(ax) => SubObjectPropertyOf(horned_owl::model::SubObjectPropertyOf {
+            sub: match ax.sub {
+                SubObjectPropertyExpression::ObjectPropertyChain(v) => {
+                    SubObjectPropertyExpression::ObjectPropertyChain(fold_all(v, |ope| {
+                        f.fold_object_property_expression(ope)
+                    })?)
+                }
+                SubObjectPropertyExpression::ObjectPropertyExpression(ope) => {
+                    SubObjectPropertyExpression::ObjectPropertyExpression(
+                        f.fold_object_property_expression(ope)?,
+                    )
+                }
+            },
+            sup: f.fold_object_property_expression(ax.sup)?,
+        }),
+        EquivalentObjectProperties(ax) => EquivalentObjectProperties(
+            horned_owl::model::EquivalentObjectProperties(fold_all(ax.0, |ope| {
+                f.fold_object_property_expression(ope)
+            })?),
+        ),
+        DisjointObjectProperties(ax) => DisjointObjectProperties(
+            horned_owl::model::DisjointObjectProperties(fold_all(ax.0, |ope| {
+                f.fold_object_property_expression(ope)
+            })?),
+        ),
+        InverseObjectProperties(ax) => {
+            InverseObjectProperties(horned_owl::model::InverseObjectProperties(
+                f.fold_object_property(ax.0)?,
+                f.fold_object_property(ax.1)?,
+            ))
+        }
+        ObjectPropertyDomain(ax) => ObjectPropertyDomain(horned_owl::model::ObjectPropertyDomain {
+            ope: f.fold_object_property_expression(ax.ope)?,
+            ce: f.fold_class_expression(ax.ce)?,
+        }),
+        ObjectPropertyRange(ax) => ObjectPropertyRange(horned_owl::model::ObjectPropertyRange {
+            ope: f.fold_object_property_expression(ax.ope)?,
+            ce: f.fold_class_expression(ax.ce)?,
+        }),
+        FunctionalObjectProperty(ax) => FunctionalObjectProperty(
+            horned_owl::model::FunctionalObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        InverseFunctionalObjectProperty(ax) => InverseFunctionalObjectProperty(
+            horned_owl::model::InverseFunctionalObjectProperty(
+                f.fold_object_property_expression(ax.0)?,
+            ),
+        ),
+        ReflexiveObjectProperty(ax) => ReflexiveObjectProperty(
+            horned_owl::model::ReflexiveObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        IrreflexiveObjectProperty(ax) => IrreflexiveObjectProperty(
+            horned_owl::model::IrreflexiveObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        SymmetricObjectProperty(ax) => SymmetricObjectProperty(
+            horned_owl::model::SymmetricObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        AsymmetricObjectProperty(ax) => AsymmetricObjectProperty(
+            horned_owl::model::AsymmetricObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        TransitiveObjectProperty(ax) => TransitiveObjectProperty(
+            horned_owl::model::TransitiveObjectProperty(f.fold_object_property_expression(ax.0)?),
+        ),
+        SubDataPropertyOf(ax) => SubDataPropertyOf(horned_owl::model::SubDataPropertyOf {
+            sub: f.fold_data_property(ax.sub)?,
+            sup: f.fold_data_property(ax.sup)?,
+        }),
+        EquivalentDataProperties(ax) => EquivalentDataProperties(
+            horned_owl::model::EquivalentDataProperties(fold_all(ax.0, |dp| {
+                f.fold_data_property(dp)
+            })?),
+        ),
+        DisjointDataProperties(ax) => DisjointDataProperties(
+            horned_owl::model::DisjointDataProperties(fold_all(ax.0, |dp| {
+                f.fold_data_property(dp)
+            })?),
+        ),
+        DataPropertyDomain(ax) => DataPropertyDomain(horned_owl::model::DataPropertyDomain {
+            dp: f.fold_data_property(ax.dp)?,
+            ce: f.fold_class_expression(ax.ce)?,
+        }),
+        DataPropertyRange(ax) => DataPropertyRange(horned_owl::model::DataPropertyRange {
+            dp: f.fold_data_property(ax.dp)?,
+            dr: f.fold_data_range(ax.dr)?,
+        }),
+        FunctionalDataProperty(ax) => FunctionalDataProperty(
+            horned_owl::model::FunctionalDataProperty(f.fold_data_property(ax.0)?),
+        ),
+        DatatypeDefinition(ax) => DatatypeDefinition(horned_owl::model::DatatypeDefinition {
+            kind: f.fold_datatype(ax.kind)?,
+            range: f.fold_data_range(ax.range)?,
+        }),
+        HasKey(ax) => HasKey(horned_owl::model::HasKey {
+            ce: f.fold_class_expression(ax.ce)?,
+            vpe: fold_all(ax.vpe, |pe| fold_property_expression(f, pe))?,
+        }),
+        SameIndividual(ax) => SameIndividual(horned_owl::model::SameIndividual(fold_all(
+            ax.0,
+            |i| f.fold_individual(i),
+        )?)),
+        DifferentIndividuals(ax) => DifferentIndividuals(horned_owl::model::DifferentIndividuals(
+            fold_all(ax.0, |i| f.fold_individual(i))?,
+        )),
+        ClassAssertion(ax) => ClassAssertion(horned_owl::model::ClassAssertion {
+            ce: f.fold_class_expression(ax.ce)?,
+            i: f.fold_individual(ax.i)?,
+        }),
+        ObjectPropertyAssertion(ax) => {
+            ObjectPropertyAssertion(horned_owl::model::ObjectPropertyAssertion {
+                ope: f.fold_object_property_expression(ax.ope)?,
+                from: f.fold_individual(ax.from)?,
+                to: f.fold_individual(ax.to)?,
+            })
+        }
+        NegativeObjectPropertyAssertion(ax) => {
+            NegativeObjectPropertyAssertion(horned_owl::model::NegativeObjectPropertyAssertion {
+                ope: f.fold_object_property_expression(ax.ope)?,
+                from: f.fold_individual(ax.from)?,
+                to: f.fold_individual(ax.to)?,
+            })
+        }
+        DataPropertyAssertion(ax) => {
+            DataPropertyAssertion(horned_owl::model::DataPropertyAssertion {
+                dp: f.fold_data_property(ax.dp)?,
+                from: f.fold_individual(ax.from)?,
+                to: f.fold_literal(ax.to)?,
+            })
+        }
+        NegativeDataPropertyAssertion(ax) => {
+            NegativeDataPropertyAssertion(horned_owl::model::NegativeDataPropertyAssertion {
+                dp: f.fold_data_property(ax.dp)?,
+                from: f.fold_individual(ax.from)?,
+                to: f.fold_literal(ax.to)?,
+            })
+        }
+        AnnotationAssertion(ax) => AnnotationAssertion(horned_owl::model::AnnotationAssertion {
+            subject: match ax.subject {
+                AnnotationSubject::IRI(iri) => AnnotationSubject::IRI(f.fold_iri(iri)?),
+                subject => subject,
+            },
+            ann: f.fold_annotation(ax.ann)?,
+        }),
+        SubAnnotationPropertyOf(ax) => {
+            SubAnnotationPropertyOf(horned_owl::model::SubAnnotationPropertyOf {
+                sub: f.fold_annotation_property(ax.sub)?,
+                sup: f.fold_annotation_property(ax.sup)?,
+            })
+        }
+        AnnotationPropertyDomain(ax) => {
+            AnnotationPropertyDomain(horned_owl::model::AnnotationPropertyDomain {
+                ap: f.fold_annotation_property(ax.ap)?,
+                iri: f.fold_iri(ax.iri)?,
+            })
+        }
+        AnnotationPropertyRange(ax) => {
+            AnnotationPropertyRange(horned_owl::model::AnnotationPropertyRange {
+                ap: f.fold_annotation_property(ax.ap)?,
+                iri: f.fold_iri(ax.iri)?,
+            })
+        }
+        Rule(r) => Rule(horned_owl::model::Rule {
+            head: fold_all(r.head, |a| f.fold_atom(a))?,
+            body: fold_all(r.body, |a| f.fold_atom(a))?,
+        }),
+    })
+}
+
+pub fn fold_annotated_component<F: Fold + ?Sized>(
+    f: &mut F,
+    e: AnnotatedComponent<ArcStr>,
+) -> Result<AnnotatedComponent<ArcStr>, F::Error> {
+    Ok(AnnotatedComponent {
+        component: f.fold_component(e.component)?,
+        ann: fold_all::<_, BTreeSet<_>, _, _>(e.ann, |a| f.fold_annotation(a))?,
+    })
+}
+
+/// A `Fold` calling a Python function for every class expression, object property expression,
+/// data range, individual, literal, entity, annotation and component, children first.
+///
+/// The function receives the node as Python model object and returns either a replacement of
+/// a compatible type or `None` to keep the node. Named classes, object properties, datatypes and
+/// named individuals are passed once, as expression where an expression is allowed.
+pub struct PyFold<'py> {
+    pub function: Bound<'py, PyAny>,
+}
+
+impl<'py> PyFold<'py> {
+    /// Calls the function on `value` and extracts the result as `R`.
+    fn call<P, R>(&self, value: P) -> PyResult<R>
+    where
+        P: IntoPyObject<'py>,
+        R: for<'a> FromPyObject<'a>,
+    {
+        let py = self.function.py();
+        let node = value.into_bound_py_any(py)?;
+        let result = self.function.call1((node.clone(),))?;
+        let result = if result.is_instance_of::<PyNone>() {
+            node.clone()
+        } else {
+            result
+        };
+
+        result.extract::<R>().map_err(|_| {
+            PyTypeError::new_err(format!(
+                "transform function returned {} for {}",
+                result
+                    .get_type()
+                    .name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+                node.get_type()
+                    .name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+            ))
+        })
+    }
+}
+
+macro_rules! py_fold {
+    ($method:ident, $horned:ident, $py:ident) => {
+        fn $method(&mut self, e: $horned<ArcStr>) -> PyResult<$horned<ArcStr>> {
+            let e = self.call::<model::$py, model::$py>(model::$py::from(&e))?;
+            Ok((&e).into())
+        }
+    };
+}
+
+impl Fold for PyFold<'_> {
+    type Error = PyErr;
+
+    py_fold!(fold_class, Class, Class);
+    py_fold!(fold_object_property, ObjectProperty, ObjectProperty);
+    py_fold!(fold_data_property, DataProperty, DataProperty);
+    py_fold!(fold_annotation_property, AnnotationProperty, AnnotationProperty);
+    py_fold!(fold_named_individual, NamedIndividual, NamedIndividual);
+    py_fold!(fold_datatype, Datatype, Datatype);
+
+    fn fold_individual(&mut self, e: Individual<ArcStr>) -> PyResult<Individual<ArcStr>> {
+        let e: model::Individual = (&e).into();
+        Ok((&self.call::<_, model::Individual>(e)?).into())
+    }
+
+    fn fold_literal(&mut self, e: Literal<ArcStr>) -> PyResult<Literal<ArcStr>> {
+        let e: model::Literal = (&e).into();
+        Ok((&self.call::<_, model::Literal>(e)?).into())
+    }
+
+    fn fold_object_property_expression(
+        &mut self,
+        e: ObjectPropertyExpression<ArcStr>,
+    ) -> PyResult<ObjectPropertyExpression<ArcStr>> {
+        let e = match e {
+            ObjectPropertyExpression::ObjectProperty(op) => op.into(),
+            e => fold_object_property_expression(self, e)?,
+        };
+        let e: model::ObjectPropertyExpression = (&e).into();
+        Ok((&self.call::<_, model::ObjectPropertyExpression>(e)?).into())
+    }
+
+    fn fold_data_range(&mut self, e: DataRange<ArcStr>) -> PyResult<DataRange<ArcStr>> {
+        let e = match e {
+            DataRange::Datatype(dt) => dt.into(),
+            e => fold_data_range(self, e)?,
+        };
+        let e: model::DataRange = (&e).into();
+        Ok((&self.call::<_, model::DataRange>(e)?).into())
+    }
+
+    fn fold_class_expression(
+        &mut self,
+        e: ClassExpression<ArcStr>,
+    ) -> PyResult<ClassExpression<ArcStr>> {
+        let e = match e {
+            ClassExpression::Class(c) => c.into(),
+            e => fold_class_expression(self, e)?,
+        };
+        let e: model::ClassExpression = (&e).into();
+        Ok((&self.call::<_, model::ClassExpression>(e)?).into())
+    }
+
+    fn fold_annotation(&mut self, e: Annotation<ArcStr>) -> PyResult<Annotation<ArcStr>> {
+        let e = fold_annotation(self, e)?;
+        let e: model::Annotation = (&e).into();
+        Ok((&self.call::<_, model::Annotation>(e)?).into())
+    }
+
+    fn fold_component(&mut self, e: Component<ArcStr>) -> PyResult<Component<ArcStr>> {
+        let e = fold_component(self, e)?;
+        let e: model::Component = (&e).into();
+        Ok((&self.call::<_, model::Component>(e)?).into())
+    }
+}
+
+/// The model objects accepted by `pyhornedowl.transform`.
+#[derive(FromPyObject, IntoPyObject)]
+pub enum Transformable {
+    AnnotatedComponent(model::AnnotatedComponent),
+    Component(model::Component),
+    ClassExpression(model::ClassExpression),
+    ObjectPropertyExpression(model::ObjectPropertyExpression),
+    DataRange(model::DataRange),
+    Individual(model::Individual),
+    Literal(model::Literal),
+    Annotation(model::Annotation),
+}
+
+impl Transformable {
+    pub fn fold<F: Fold>(self, f: &mut F) -> Result<Self, F::Error> {
+        Ok(match self {
+            Transformable::AnnotatedComponent(e) => Transformable::AnnotatedComponent(
+                (&f.fold_annotated_component((&e).into())?).into(),
+            ),
+            Transformable::Component(e) => {
+                Transformable::Component((&f.fold_component((&e).into())?).into())
+            }
+            Transformable::ClassExpression(e) => {
+                Transformable::ClassExpression((&f.fold_class_expression((&e).into())?).into())
+            }
+            Transformable::ObjectPropertyExpression(e) => Transformable::ObjectPropertyExpression(
+                (&f.fold_object_property_expression((&e).into())?).into(),
+            ),
+            Transformable::DataRange(e) => {
+                Transformable::DataRange((&f.fold_data_range((&e).into())?).into())
+            }
+            Transformable::Individual(e) => {
+                Transformable::Individual((&f.fold_individual((&e).into())?).into())
+            }
+            Transformable::Literal(e) => {
+                Transformable::Literal((&f.fold_literal((&e).into())?).into())
+            }
+            Transformable::Annotation(e) => {
+                Transformable::Annotation((&f.fold_annotation((&e).into())?).into())
+            }
+        })
+    }
+}
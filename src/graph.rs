@@ -0,0 +1,266 @@
+//! Graph export of an ontology
+//!
+//! Derives a directed graph of the named classes from the `SubClassOf` and `EquivalentClasses`
+//! components of an ontology, either as node and edge lists for networkx or as Graphviz DOT.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use horned_owl::model::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::signature::Signature;
+use crate::tables::existentials;
+
+/// The kind of an edge between two named classes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EdgeKind {
+    SubClass,
+    SomeValuesFrom,
+    Equivalent,
+}
+
+impl EdgeKind {
+    pub const ALL: [EdgeKind; 3] = [
+        EdgeKind::SubClass,
+        EdgeKind::SomeValuesFrom,
+        EdgeKind::Equivalent,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            EdgeKind::SubClass => "subclass",
+            EdgeKind::SomeValuesFrom => "some_values_from",
+            EdgeKind::Equivalent => "equivalent",
+        }
+    }
+
+    /// The Graphviz attributes of edges of this kind, apart from the label.
+    fn dot_attributes(&self) -> &'static [&'static str] {
+        match self {
+            EdgeKind::SubClass => &["arrowhead=empty"],
+            EdgeKind::SomeValuesFrom => &[],
+            EdgeKind::Equivalent => &["dir=none", "style=dashed"],
+        }
+    }
+}
+
+impl TryFrom<&str> for EdgeKind {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> Result<Self, PyErr> {
+        EdgeKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == value)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Unknown edge type '{value}', expected one of {}",
+                    EdgeKind::ALL
+                        .map(|kind| format!("'{}'", kind.name()))
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+/// Returns the edge kinds selected by name, or all edge kinds.
+pub fn edge_kinds(edges: Option<Vec<String>>) -> PyResult<BTreeSet<EdgeKind>> {
+    match edges {
+        Some(edges) => edges
+            .iter()
+            .map(|e| EdgeKind::try_from(e.as_str()))
+            .collect(),
+        None => Ok(EdgeKind::ALL.into_iter().collect()),
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Edge<'a> {
+    kind: EdgeKind,
+    source: &'a IRI<ArcStr>,
+    target: &'a IRI<ArcStr>,
+    property: Option<&'a IRI<ArcStr>>,
+}
+
+/// A graph of named classes with their display labels.
+pub struct Graph<'a> {
+    nodes: Vec<IRI<ArcStr>>,
+    edges: BTreeSet<Edge<'a>>,
+    labels: HashMap<&'a IRI<ArcStr>, &'a Literal<ArcStr>>,
+}
+
+impl<'a> Graph<'a> {
+    /// Builds the graph of the classes in `components` with edges of the given kinds, as described
+    /// in `PyIndexedOntology.to_graph`. Nodes and properties without a label in `labels` are
+    /// labelled with their IRI.
+    pub fn new(
+        components: &[&'a AnnotatedComponent<ArcStr>],
+        kinds: &BTreeSet<EdgeKind>,
+        labels: HashMap<&'a IRI<ArcStr>, &'a Literal<ArcStr>>,
+    ) -> Self {
+        let mut edges = BTreeSet::new();
+        for c in components {
+            match &c.component {
+                Component::SubClassOf(SubClassOf {
+                    sub: ClassExpression::Class(sub),
+                    sup,
+                }) => add_superclass(&mut edges, &sub.0, sup),
+                Component::EquivalentClasses(EquivalentClasses(ces)) => {
+                    let (named, anonymous): (Vec<_>, Vec<_>) = ces
+                        .iter()
+                        .partition(|ce| matches!(ce, ClassExpression::Class(_)));
+                    let named: Vec<_> = named
+                        .into_iter()
+                        .filter_map(|ce| match ce {
+                            ClassExpression::Class(c) => Some(&c.0),
+                            _ => None,
+                        })
+                        .collect();
+
+                    for (i, a) in named.iter().enumerate() {
+                        for b in &named[i + 1..] {
+                            let (source, target) = if a <= b { (*a, *b) } else { (*b, *a) };
+                            if source != target {
+                                edges.insert(Edge {
+                                    kind: EdgeKind::Equivalent,
+                                    source,
+                                    target,
+                                    property: None,
+                                });
+                            }
+                        }
+                        for ce in &anonymous {
+                            add_superclass(&mut edges, a, ce);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        edges.retain(|e| kinds.contains(&e.kind));
+
+        let signature = Signature::of_components(components.iter().copied());
+        let mut nodes: Vec<_> = signature
+            .entities()
+            .iter()
+            .filter_map(|e| match e {
+                NamedOWLEntity::Class(c) => Some(c.0.clone()),
+                _ => None,
+            })
+            .collect();
+        nodes.sort();
+
+        Graph {
+            nodes,
+            edges,
+            labels,
+        }
+    }
+
+    fn label(&self, iri: &IRI<ArcStr>) -> String {
+        self.labels
+            .get(iri)
+            .map(|l| l.literal().to_string())
+            .unwrap_or_else(|| iri.to_string())
+    }
+
+    /// Returns the nodes as list of `(iri, {"label": label})` and the edges as list of
+    /// `(source, target, {"type": kind})`, where `some_values_from` edges also have a `property`
+    /// and a `label`.
+    pub fn into_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        let nodes = PyList::empty(py);
+        for iri in &self.nodes {
+            let attributes = PyDict::new(py);
+            attributes.set_item("label", self.label(iri))?;
+            nodes.append((iri.to_string(), attributes))?;
+        }
+
+        let edges = PyList::empty(py);
+        for edge in &self.edges {
+            let attributes = PyDict::new(py);
+            attributes.set_item("type", edge.kind.name())?;
+            if let Some(property) = edge.property {
+                attributes.set_item("property", property.to_string())?;
+                attributes.set_item("label", self.label(property))?;
+            }
+            edges.append((edge.source.to_string(), edge.target.to_string(), attributes))?;
+        }
+
+        PyTuple::new(py, [nodes.into_any(), edges.into_any()])
+    }
+
+    /// Returns the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for iri in &self.nodes {
+            writeln!(
+                dot,
+                "    {} [label={}];",
+                quote(iri.as_ref()),
+                quote(&self.label(iri))
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let mut attributes: Vec<String> = edge
+                .kind
+                .dot_attributes()
+                .iter()
+                .map(|a| a.to_string())
+                .collect();
+            if let Some(property) = edge.property {
+                attributes.push(format!("label={}", quote(&self.label(property))));
+            }
+            write!(
+                dot,
+                "    {} -> {}",
+                quote(edge.source.as_ref()),
+                quote(edge.target.as_ref())
+            )
+            .unwrap();
+            if !attributes.is_empty() {
+                write!(dot, " [{}]", attributes.join(", ")).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Adds the `subclass` and `some_values_from` edges of `SubClassOf(sub sup)`.
+fn add_superclass<'a>(
+    edges: &mut BTreeSet<Edge<'a>>,
+    sub: &'a IRI<ArcStr>,
+    sup: &'a ClassExpression<ArcStr>,
+) {
+    let operands = match sup {
+        ClassExpression::ObjectIntersectionOf(operands) => operands.iter().collect(),
+        ce => vec![ce],
+    };
+    for operand in operands {
+        if let ClassExpression::Class(target) = operand {
+            edges.insert(Edge {
+                kind: EdgeKind::SubClass,
+                source: sub,
+                target: &target.0,
+                property: None,
+            });
+        }
+    }
+    for (property, target) in existentials(sup) {
+        edges.insert(Edge {
+            kind: EdgeKind::SomeValuesFrom,
+            source: sub,
+            target,
+            property: Some(property),
+        });
+    }
+}
+
+/// Quotes a DOT identifier.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,140 @@
+//! Lazy iterators over the components of a `PyIndexedOntology`.
+//!
+//! The iterators hold a snapshot of (reference counted) pointers to the components and convert
+//! them to Python objects one at a time. Modifying the ontology while iterating invalidates the
+//! iterator.
+
+use horned_owl::model::{ArcAnnotatedComponent, Component, ComponentKind};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::model;
+use crate::ontology::PyIndexedOntology;
+
+/// Snapshot of components together with the modification count of the ontology at creation time.
+struct Snapshot {
+    ontology: Py<PyIndexedOntology>,
+    modification_count: u64,
+    components: std::vec::IntoIter<ArcAnnotatedComponent>,
+}
+
+impl Snapshot {
+    fn new(ontology: &Bound<'_, PyIndexedOntology>, components: Vec<ArcAnnotatedComponent>) -> Self {
+        Snapshot {
+            ontology: ontology.clone().unbind(),
+            modification_count: ontology.borrow().modification_count,
+            components: components.into_iter(),
+        }
+    }
+
+    fn next(&mut self, py: Python<'_>) -> PyResult<Option<ArcAnnotatedComponent>> {
+        if self.ontology.borrow(py).modification_count != self.modification_count {
+            return Err(PyRuntimeError::new_err(
+                "Ontology changed during iteration",
+            ));
+        }
+
+        Ok(self.components.next())
+    }
+}
+
+/// Iterator over the components of an ontology.
+#[pyclass]
+pub struct ComponentIter(Snapshot);
+
+impl ComponentIter {
+    pub fn new(
+        ontology: &Bound<'_, PyIndexedOntology>,
+        components: Vec<ArcAnnotatedComponent>,
+    ) -> Self {
+        ComponentIter(Snapshot::new(ontology, components))
+    }
+}
+
+#[pymethods]
+impl ComponentIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<model::AnnotatedComponent>> {
+        Ok(self.0.next(py)?.map(|c| model::AnnotatedComponent::from(c.as_ref())))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.0.components.len()
+    }
+}
+
+/// Iterator over the IRIs of the entities declared in an ontology.
+#[pyclass]
+pub struct EntityIter(Snapshot);
+
+impl EntityIter {
+    pub fn new(
+        ontology: &Bound<'_, PyIndexedOntology>,
+        declarations: Vec<ArcAnnotatedComponent>,
+    ) -> Self {
+        EntityIter(Snapshot::new(ontology, declarations))
+    }
+}
+
+#[pymethods]
+impl EntityIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<String>> {
+        while let Some(c) = self.0.next(py)? {
+            let iri = match &c.component {
+                Component::DeclareClass(d) => &d.0 .0,
+                Component::DeclareObjectProperty(d) => &d.0 .0,
+                Component::DeclareAnnotationProperty(d) => &d.0 .0,
+                Component::DeclareDataProperty(d) => &d.0 .0,
+                Component::DeclareNamedIndividual(d) => &d.0 .0,
+                Component::DeclareDatatype(d) => &d.0 .0,
+                _ => continue,
+            };
+            return Ok(Some(iri.to_string()));
+        }
+
+        Ok(None)
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.0.components.len()
+    }
+}
+
+/// Converts a `model.ComponentKind` or a model class such as `model.SubClassOf` into the
+/// corresponding component kind.
+pub fn component_kind(kind: &Bound<'_, PyAny>) -> PyResult<ComponentKind> {
+    if let Ok(kind) = kind.extract::<model::ComponentKind>() {
+        return Ok(kind.into());
+    }
+
+    let kind = kind.downcast::<PyType>().map_err(|_| {
+        PyTypeError::new_err(format!("Expected a component class or ComponentKind, got {}", kind))
+    })?;
+    let name = kind.name()?.to_string();
+
+    ComponentKind::all_kinds()
+        .into_iter()
+        .find(|k| format!("{:?}", k).trim_start_matches("ComponentKind::") == name)
+        .ok_or_else(|| PyTypeError::new_err(format!("{} is not a component class", name)))
+}
+
+/// Converts a single or a list of `model.ComponentKind`s or model classes into component kinds.
+pub fn component_kinds(kinds: &Bound<'_, PyAny>) -> PyResult<Vec<ComponentKind>> {
+    if let Ok(kind) = component_kind(kinds) {
+        return Ok(vec![kind]);
+    }
+
+    let mut result = vec![];
+    for kind in kinds.try_iter()? {
+        result.push(component_kind(&kind?)?);
+    }
+    Ok(result)
+}
@@ -0,0 +1,359 @@
+//! Conversion of model objects from and to JSON compatible Python values
+//!
+//! Every model object is converted to a dictionary with a `"type"` key holding its Python class
+//! name, e.g. `"SubClassOf"` or `"SimpleLiteral"`, and one key per field named like the Python
+//! attribute, e.g. `"sub"` and `"sup"` or `"first"` and `"second"` for classes with unnamed
+//! fields. Field values are converted as follows:
+//!
+//! - model objects are nested dictionaries,
+//! - IRIs and strings are strings, integers are numbers,
+//! - facets are their name, e.g. `"MinInclusive"`,
+//! - lists, sets and tuples are lists,
+//! - missing optional values are `None`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple};
+
+use crate::model::{Facet, IRI};
+use crate::wrappers::{BTreeSetWrap, BoxWrap, StringWrapper, VecWrap};
+
+/// Key of the type tag of a node.
+pub const TYPE_KEY: &str = "type";
+
+pub trait ToDict {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>>;
+}
+
+pub trait FromDict: Sized {
+    /// Returns true if `value` has the shape of `Self`, e.g. if it is a node with the type tag of
+    /// `Self`. Used to choose the variant of a union type.
+    fn accepts(value: &Bound<'_, PyAny>) -> bool;
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self>;
+}
+
+/// Creates a node with the type tag `type_name`.
+pub fn node<'py>(py: Python<'py>, type_name: &str) -> PyResult<Bound<'py, PyDict>> {
+    let node = PyDict::new(py);
+    node.set_item(TYPE_KEY, type_name)?;
+    Ok(node)
+}
+
+/// Returns true if `value` is a node with the type tag `type_name`.
+pub fn is_node(value: &Bound<'_, PyAny>, type_name: &str) -> bool {
+    value
+        .downcast::<PyDict>()
+        .ok()
+        .and_then(|node| node.get_item(TYPE_KEY).ok().flatten())
+        .is_some_and(|tag| tag.extract::<String>().is_ok_and(|tag| tag == type_name))
+}
+
+/// Returns `value` as node if it has the type tag `type_name`.
+pub fn expect_node<'py>(
+    value: &Bound<'py, PyAny>,
+    type_name: &str,
+) -> PyResult<Bound<'py, PyDict>> {
+    if !is_node(value, type_name) {
+        return Err(PyValueError::new_err(format!(
+            "Expected a '{}' node but got {}",
+            type_name,
+            value.repr()?
+        )));
+    }
+    Ok(value.downcast::<PyDict>()?.clone())
+}
+
+/// Reads the field `key` of a node with type tag `type_name`.
+pub fn field<T: FromDict>(node: &Bound<'_, PyDict>, type_name: &str, key: &str) -> PyResult<T> {
+    match node.get_item(key)? {
+        Some(value) => T::from_dict(&value),
+        None => Err(PyValueError::new_err(format!(
+            "Missing field '{}' in '{}' node",
+            key, type_name
+        ))),
+    }
+}
+
+/// Raises a ValueError for a value that cannot be converted to `type_name`.
+pub fn invalid<T>(value: &Bound<'_, PyAny>, type_name: &str) -> PyResult<T> {
+    Err(PyValueError::new_err(format!(
+        "Cannot convert {} to {}",
+        value.repr()?,
+        type_name
+    )))
+}
+
+/// Converts `value` to a JSON string.
+pub fn dumps(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    value
+        .py()
+        .import("json")?
+        .call_method1("dumps", (value,))?
+        .extract()
+}
+
+/// Parses the JSON string `value`.
+pub fn loads<'py>(py: Python<'py>, value: &str) -> PyResult<Bound<'py, PyAny>> {
+    py.import("json")?.call_method1("loads", (value,))
+}
+
+/// Adds `to_dict`, `to_json`, `from_dict` and `from_json` methods to a pyclass implementing
+/// `ToDict` and `FromDict`.
+#[macro_export]
+macro_rules! json_methods {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// to_dict(self) -> typing.Dict[str, typing.Any]
+            ///
+            /// Returns the object as JSON compatible dictionary with a "type" key per node.
+            pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                $crate::json::ToDict::to_dict(self, py)
+            }
+
+            /// to_json(self) -> str
+            ///
+            /// Returns the object as JSON string, see `to_dict`.
+            pub fn to_json(&self, py: Python<'_>) -> PyResult<String> {
+                $crate::json::dumps(&$crate::json::ToDict::to_dict(self, py)?)
+            }
+
+            /// from_dict(cls, value: typing.Dict[str, typing.Any]) -> typing.Self
+            ///
+            /// Creates the object from a dictionary as returned by `to_dict`. Raises a ValueError
+            /// if the dictionary is not a valid representation.
+            #[classmethod]
+            pub fn from_dict(
+                _: &Bound<'_, pyo3::types::PyType>,
+                value: &Bound<'_, PyAny>,
+            ) -> PyResult<Self> {
+                <Self as $crate::json::FromDict>::from_dict(value)
+            }
+
+            /// from_json(cls, value: str) -> typing.Self
+            ///
+            /// Creates the object from a JSON string as returned by `to_json`.
+            #[classmethod]
+            pub fn from_json(
+                _: &Bound<'_, pyo3::types::PyType>,
+                py: Python<'_>,
+                value: &str,
+            ) -> PyResult<Self> {
+                <Self as $crate::json::FromDict>::from_dict(&$crate::json::loads(py, value)?)
+            }
+        }
+    };
+}
+
+impl ToDict for String {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyString::new(py, self).into_any())
+    }
+}
+
+impl FromDict for String {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        value.is_instance_of::<PyString>()
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        value.extract()
+    }
+}
+
+impl ToDict for StringWrapper {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.to_dict(py)
+    }
+}
+
+impl FromDict for StringWrapper {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        String::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        String::from_dict(value).map(StringWrapper)
+    }
+}
+
+impl ToDict for u32 {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(self.into_pyobject(py)?.into_any())
+    }
+}
+
+impl FromDict for u32 {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        value.extract::<u32>().is_ok()
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        value.extract()
+    }
+}
+
+impl ToDict for IRI {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyString::new(py, &self.__str__()).into_any())
+    }
+}
+
+impl FromDict for IRI {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        value.is_instance_of::<PyString>()
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let iri: String = value.extract()?;
+        Ok(IRI::new(iri, &horned_owl::model::Build::new_arc()))
+    }
+}
+
+impl ToDict for Facet {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyString::new(py, &format!("{:?}", self)).into_any())
+    }
+}
+
+impl FromDict for Facet {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        value.is_instance_of::<PyString>()
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match value.extract::<String>()?.as_str() {
+            "Length" => Ok(Facet::Length),
+            "MinLength" => Ok(Facet::MinLength),
+            "MaxLength" => Ok(Facet::MaxLength),
+            "Pattern" => Ok(Facet::Pattern),
+            "MinInclusive" => Ok(Facet::MinInclusive),
+            "MinExclusive" => Ok(Facet::MinExclusive),
+            "MaxInclusive" => Ok(Facet::MaxInclusive),
+            "MaxExclusive" => Ok(Facet::MaxExclusive),
+            "TotalDigits" => Ok(Facet::TotalDigits),
+            "FractionDigits" => Ok(Facet::FractionDigits),
+            "LangRange" => Ok(Facet::LangRange),
+            _ => invalid(value, "Facet"),
+        }
+    }
+}
+
+impl<T: ToDict> ToDict for Option<T> {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Some(value) => value.to_dict(py),
+            None => Ok(py.None().into_bound(py)),
+        }
+    }
+}
+
+impl<T: FromDict> FromDict for Option<T> {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        value.is_none() || T::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if value.is_none() {
+            Ok(None)
+        } else {
+            T::from_dict(value).map(Some)
+        }
+    }
+}
+
+impl<T: ToDict> ToDict for BoxWrap<T> {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.0.to_dict(py)
+    }
+}
+
+impl<T: FromDict> FromDict for BoxWrap<T> {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        T::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        T::from_dict(value).map(Box::new).map(BoxWrap)
+    }
+}
+
+fn list_to_dict<'a, 'py, T: ToDict + 'a>(
+    py: Python<'py>,
+    values: impl IntoIterator<Item = &'a T>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let list = PyList::empty(py);
+    for value in values {
+        list.append(value.to_dict(py)?)?;
+    }
+    Ok(list.into_any())
+}
+
+fn is_list(value: &Bound<'_, PyAny>) -> bool {
+    value.is_instance_of::<PyList>()
+        || value.is_instance_of::<PyTuple>()
+        || value.is_instance_of::<PySet>()
+}
+
+fn list_from_dict<T: FromDict>(value: &Bound<'_, PyAny>, type_name: &str) -> PyResult<Vec<T>> {
+    if !is_list(value) {
+        return invalid(value, type_name);
+    }
+    value.try_iter()?.map(|v| T::from_dict(&v?)).collect()
+}
+
+impl<T: ToDict> ToDict for VecWrap<T> {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        list_to_dict(py, &self.0)
+    }
+}
+
+impl<T: FromDict> FromDict for VecWrap<T> {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        is_list(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        list_from_dict(value, "list").map(VecWrap)
+    }
+}
+
+impl<T: ToDict> ToDict for BTreeSetWrap<T> {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        list_to_dict(py, &self.0)
+    }
+}
+
+impl<T: FromDict + Ord> FromDict for BTreeSetWrap<T> {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        is_list(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        list_from_dict::<T>(value, "set").map(|values| BTreeSetWrap(values.into_iter().collect()))
+    }
+}
+
+impl<S: ToDict, T: ToDict> ToDict for (S, T) {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(PyList::new(py, [self.0.to_dict(py)?, self.1.to_dict(py)?])?.into_any())
+    }
+}
+
+impl<S: FromDict, T: FromDict> FromDict for (S, T) {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        (value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>())
+            && value.len().is_ok_and(|len| len == 2)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if !Self::accepts(value) {
+            return invalid(value, "pair");
+        }
+        Ok((
+            S::from_dict(&value.get_item(0)?)?,
+            T::from_dict(&value.get_item(1)?)?,
+        ))
+    }
+}
@@ -0,0 +1,154 @@
+//! Label index for py-horned-owl
+//!
+//! Maps labels and synonyms to the annotation assertions defining them, so that terms can be
+//! looked up by name without scanning all annotation assertions.
+
+use std::collections::{HashMap, HashSet};
+
+use horned_owl::model::{
+    AnnotatedComponent, Annotation, AnnotationAssertion, AnnotationSubject, AnnotationValue,
+    ArcAnnotatedComponent, ArcStr, Build, Component, Literal, IRI,
+};
+use horned_owl::ontology::indexed::OntologyIndex;
+use horned_owl::vocab::AnnotationBuiltIn;
+
+/// Synonym properties indexed in addition to `rdfs:label` by default.
+pub const DEFAULT_SYNONYM_PROPERTIES: [&str; 6] = [
+    "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym",
+    "http://www.geneontology.org/formats/oboInOwl#hasRelatedSynonym",
+    "http://www.geneontology.org/formats/oboInOwl#hasNarrowSynonym",
+    "http://www.geneontology.org/formats/oboInOwl#hasBroadSynonym",
+    "http://www.w3.org/2004/02/skos/core#prefLabel",
+    "http://www.w3.org/2004/02/skos/core#altLabel",
+];
+
+/// An index of `AnnotationAssertion`s with a literal value for `rdfs:label` and a configurable set
+/// of synonym properties.
+///
+/// Assertions are keyed by their case folded value with normalized whitespace, so that exact,
+/// case-insensitive and whitespace-insensitive lookups only need to inspect a single bucket.
+#[derive(Debug)]
+pub struct LabelIndex {
+    properties: HashSet<IRI<ArcStr>>,
+    labels: HashMap<String, HashSet<ArcAnnotatedComponent>>,
+}
+
+/// A single label or synonym found in a `LabelIndex`.
+#[derive(Debug, Clone, Copy)]
+pub struct LabelEntry<'a> {
+    pub subject: &'a IRI<ArcStr>,
+    pub property: &'a IRI<ArcStr>,
+    pub literal: &'a Literal<ArcStr>,
+}
+
+impl LabelEntry<'_> {
+    /// Whether the entry is an `rdfs:label` rather than a synonym.
+    pub fn is_label(&self) -> bool {
+        AnnotationBuiltIn::Label.underlying().eq(&self.property.to_string())
+    }
+}
+
+impl LabelIndex {
+    pub fn new(build: &Build<ArcStr>, synonym_properties: &[IRI<ArcStr>]) -> Self {
+        let mut properties: HashSet<IRI<ArcStr>> = synonym_properties.iter().cloned().collect();
+        properties.insert(build.iri(AnnotationBuiltIn::Label));
+
+        LabelIndex {
+            properties,
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Returns all entries whose value equals `label` after case folding and normalizing
+    /// whitespace. Use `label_matches` to narrow down the result.
+    pub fn candidates<'a>(&'a self, label: &str) -> impl Iterator<Item = LabelEntry<'a>> + 'a {
+        self.labels
+            .get(&normalize(label, true, true))
+            .into_iter()
+            .flatten()
+            .filter_map(|c| label_entry(c))
+    }
+
+    /// Returns the key of `component` if it is covered by this index.
+    fn key(&self, component: &AnnotatedComponent<ArcStr>) -> Option<String> {
+        label_entry(component)
+            .filter(|e| self.properties.contains(e.property))
+            .map(|e| normalize(e.literal.literal(), true, true))
+    }
+}
+
+impl OntologyIndex<ArcStr, ArcAnnotatedComponent> for LabelIndex {
+    fn index_insert(&mut self, cmp: ArcAnnotatedComponent) -> bool {
+        match self.key(&cmp) {
+            Some(key) => self.labels.entry(key).or_default().insert(cmp),
+            None => false,
+        }
+    }
+
+    fn index_remove(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> bool {
+        let Some(key) = self.key(cmp) else {
+            return false;
+        };
+
+        let Some(bucket) = self.labels.get_mut(&key) else {
+            return false;
+        };
+
+        let removed = bucket.remove(cmp);
+        if bucket.is_empty() {
+            self.labels.remove(&key);
+        }
+        removed
+    }
+}
+
+/// Returns subject, property and literal if `component` is an annotation assertion on an IRI with
+/// a literal value.
+pub fn label_entry(component: &AnnotatedComponent<ArcStr>) -> Option<LabelEntry<'_>> {
+    match &component.component {
+        Component::AnnotationAssertion(AnnotationAssertion {
+            subject: AnnotationSubject::IRI(subject),
+            ann:
+                Annotation {
+                    ap,
+                    av: AnnotationValue::Literal(literal),
+                },
+        }) => Some(LabelEntry {
+            subject,
+            property: &ap.0,
+            literal,
+        }),
+        _ => None,
+    }
+}
+
+/// Normalizes a label for comparison by optionally case folding it and collapsing all runs of
+/// whitespace into single spaces.
+pub fn normalize(label: &str, ignore_case: bool, normalize_whitespace: bool) -> String {
+    let label = if normalize_whitespace {
+        label.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        label.to_string()
+    };
+
+    if ignore_case {
+        label.to_lowercase()
+    } else {
+        label
+    }
+}
+
+/// Returns whether the label `candidate` matches `query`.
+pub fn label_matches(
+    candidate: &str,
+    query: &str,
+    ignore_case: bool,
+    normalize_whitespace: bool,
+) -> bool {
+    if !ignore_case && !normalize_whitespace {
+        return candidate == query;
+    }
+
+    normalize(candidate, ignore_case, normalize_whitespace)
+        == normalize(query, ignore_case, normalize_whitespace)
+}
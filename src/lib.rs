@@ -16,12 +16,28 @@ use pyhornedowlreasoner::PyReasoner;
 
 #[macro_use]
 mod doc;
+pub mod codec;
+pub mod fold;
+pub mod graph;
+pub mod iterators;
+pub mod json;
+pub mod label_index;
+pub mod lint;
+pub mod metrics;
 pub mod model;
 pub mod model_generated;
 pub mod ontology;
+pub mod parse;
+pub mod pattern;
 pub mod prefix_mapping;
+pub mod profile;
+pub mod rdf;
 pub mod reasoning;
+pub mod render;
+pub mod search_index;
+pub mod signature;
 pub mod structural_reasoner;
+pub mod tables;
 mod wrappers;
 
 pub use reasoning::create_reasoner;
@@ -195,6 +211,36 @@ fn open_ontology(
     }
 }
 
+/// open_ontology_from_rdflib(graph: typing.Any, index_strategy = IndexCreationStrategy.OnQuery) -> PyIndexedOntology
+///
+/// Opens an ontology from an `rdflib.Graph` using the same RDF to OWL mapping as the RDF/XML parser.
+/// The parser only accepts blank nodes it has read itself, so each triple of the graph is written
+/// as an N-Triples line when the parser asks for more input. The graph is not serialized as a
+/// whole.
+///
+/// The namespaces bound in the graph are added to the prefix mapping of the ontology.
+#[pyfunction(signature = (graph, index_strategy = IndexCreationStrategy::OnQuery))]
+fn open_ontology_from_rdflib(
+    py: Python<'_>,
+    graph: &Bound<'_, PyAny>,
+    index_strategy: IndexCreationStrategy,
+) -> PyResult<PyIndexedOntology> {
+    let rdflib = rdf::Rdflib::import(py)?;
+
+    let mut reader = rdf::NTriplesReader::rdflib(rdflib, graph)?;
+    let pio = PyIndexedOntology::from_ntriples(&mut reader, index_strategy);
+    let mut pio = reader.finish().and(pio)?;
+
+    let mut mapping = prefix_mapping::PrefixMapping::from(PrefixMapping::default());
+    for namespace in graph.call_method0("namespaces")?.try_iter()? {
+        let (prefix, iri): (String, String) = namespace?.extract()?;
+        mapping.add_prefix(prefix, iri)?;
+    }
+
+    pio.mapping = Py::new(py, mapping)?;
+    Ok(pio)
+}
+
 /// create_structural_reasoner(ontology: PyIndexedOntology) -> PyReasoner
 ///
 /// Creates a structural reasoner for the given ontology. The structural reasoner only uses the asserted named subclass and sub-property hierarchies to answer queries.
@@ -210,15 +256,36 @@ fn create_structural_reasoner(ontology: PyIndexedOntology) -> reasoning::PyReaso
     )))
 }
 
+/// transform(obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.Annotation], fn: typing.Callable[[typing.Any], typing.Any]) -> typing.Any
+///
+/// Rewrites a model object bottom-up by calling `fn` on each of its nodes.
+///
+/// `fn` is called with every entity, individual, literal, class expression, object property expression,
+/// data range, annotation and component, after their children have been transformed. It returns either
+/// a replacement or `None` to keep the node. A named class (object property, datatype) in a position
+/// that accepts a class expression (object property expression, data range) is passed only once and may
+/// be replaced by an expression. Raises a `TypeError` if a replacement does not fit its position.
+#[pyfunction]
+fn transform(obj: fold::Transformable, r#fn: Bound<'_, PyAny>) -> PyResult<fold::Transformable> {
+    obj.fold(&mut fold::PyFold { function: r#fn })
+}
+
 #[pymodule]
 fn pyhornedowl(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyIndexedOntology>()?;
     m.add_class::<IndexCreationStrategy>()?;
     m.add_class::<prefix_mapping::PrefixMapping>()?;
+    m.add_class::<search_index::SearchHit>()?;
+    m.add_class::<profile::ProfileReport>()?;
+    m.add_class::<profile::ProfileViolation>()?;
+    m.add_class::<metrics::OntologyMetrics>()?;
+    m.add_class::<lint::LintFinding>()?;
 
     m.add_function(wrap_pyfunction!(open_ontology, m)?)?;
     m.add_function(wrap_pyfunction!(open_ontology_from_file, m)?)?;
     m.add_function(wrap_pyfunction!(open_ontology_from_string, m)?)?;
+    m.add_function(wrap_pyfunction!(open_ontology_from_rdflib, m)?)?;
+    m.add_function(wrap_pyfunction!(transform, m)?)?;
     
     let model_sub_module = model::py_module(py)?;
     m.add_submodule(&model_sub_module)?;
@@ -0,0 +1,332 @@
+//! Ontology quality checks
+//!
+//! Rules in the style of the ROBOT report (<https://robot.obolibrary.org/report>), each reporting
+//! findings with a configurable severity.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use horned_owl::model::*;
+use horned_owl::vocab::AnnotationBuiltIn;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::label_index::label_entry;
+use crate::model;
+use crate::ontology::is_logical_axiom;
+use crate::search_index::DEFAULT_DEFINITION_PROPERTIES;
+use crate::signature::{declared_entity, entity_iri, entity_kind, is_builtin, Signature};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warn,
+    Info,
+}
+
+impl TryFrom<&str> for Severity {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> Result<Self, PyErr> {
+        match value.to_ascii_uppercase().as_str() {
+            "ERROR" => Ok(Severity::Error),
+            "WARN" => Ok(Severity::Warn),
+            "INFO" => Ok(Severity::Info),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown severity '{value}', expected one of 'ERROR', 'WARN' or 'INFO'"
+            ))),
+        }
+    }
+}
+
+impl Severity {
+    fn name(&self) -> &'static str {
+        match self {
+            Severity::Error => "ERROR",
+            Severity::Warn => "WARN",
+            Severity::Info => "INFO",
+        }
+    }
+}
+
+/// A lint rule. Findings are reported in the order of the variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rule {
+    MissingLabel,
+    MultipleLabels,
+    DuplicateLabel,
+    MissingDefinition,
+    UndeclaredEntity,
+    PunnedEntity,
+    DeprecatedEntityReference,
+    SelfReferentialSubclass,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 8] = [
+        Rule::MissingLabel,
+        Rule::MultipleLabels,
+        Rule::DuplicateLabel,
+        Rule::MissingDefinition,
+        Rule::UndeclaredEntity,
+        Rule::PunnedEntity,
+        Rule::DeprecatedEntityReference,
+        Rule::SelfReferentialSubclass,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rule::MissingLabel => "missing_label",
+            Rule::MultipleLabels => "multiple_labels",
+            Rule::DuplicateLabel => "duplicate_label",
+            Rule::MissingDefinition => "missing_definition",
+            Rule::UndeclaredEntity => "undeclared_entity",
+            Rule::PunnedEntity => "punned_entity",
+            Rule::DeprecatedEntityReference => "deprecated_entity_reference",
+            Rule::SelfReferentialSubclass => "self_referential_subclass",
+        }
+    }
+
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            Rule::MissingLabel | Rule::MultipleLabels | Rule::DuplicateLabel => Severity::Error,
+            _ => Severity::Warn,
+        }
+    }
+}
+
+impl TryFrom<&str> for Rule {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> Result<Self, PyErr> {
+        Rule::ALL
+            .into_iter()
+            .find(|rule| rule.id() == value)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Unknown lint rule '{value}', expected one of {}",
+                    Rule::ALL.map(|rule| format!("'{}'", rule.id())).join(", ")
+                ))
+            })
+    }
+}
+
+/// The rules to check, either as list of rule ids or as dictionary from rule id to severity.
+#[derive(FromPyObject)]
+pub enum RuleSelection {
+    Severities(HashMap<String, String>),
+    Rules(Vec<String>),
+}
+
+/// Returns the severity of each rule to check. All rules are checked with their default severity
+/// if there is no selection.
+pub fn rule_severities(selection: Option<RuleSelection>) -> PyResult<BTreeMap<Rule, Severity>> {
+    match selection {
+        None => Ok(Rule::ALL
+            .into_iter()
+            .map(|rule| (rule, rule.default_severity()))
+            .collect()),
+        Some(RuleSelection::Rules(rules)) => rules
+            .iter()
+            .map(|rule| {
+                let rule = Rule::try_from(rule.as_str())?;
+                Ok((rule, rule.default_severity()))
+            })
+            .collect(),
+        Some(RuleSelection::Severities(rules)) => rules
+            .iter()
+            .map(|(rule, severity)| {
+                Ok((
+                    Rule::try_from(rule.as_str())?,
+                    Severity::try_from(severity.as_str())?,
+                ))
+            })
+            .collect(),
+    }
+}
+
+/// A problem found by a lint rule.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct LintFinding {
+    /// Id of the rule, e.g. "missing_label".
+    #[pyo3(get)]
+    pub rule: String,
+    /// Severity of the finding, one of "ERROR", "WARN" or "INFO".
+    #[pyo3(get)]
+    pub severity: String,
+    /// IRI of the entity the finding is about.
+    #[pyo3(get)]
+    pub subject: String,
+    /// The offending component, if any.
+    #[pyo3(get)]
+    pub component: Option<model::AnnotatedComponent>,
+}
+
+#[pymethods]
+impl LintFinding {
+    fn __repr__(&self) -> String {
+        format!(
+            "LintFinding(rule={:?}, severity={:?}, subject={:?})",
+            self.rule, self.severity, self.subject
+        )
+    }
+}
+
+/// Checks `components` with the rules that have a severity and returns the findings ordered by
+/// rule, subject and component.
+pub fn lint(
+    components: &[&AnnotatedComponent<ArcStr>],
+    severities: &BTreeMap<Rule, Severity>,
+) -> Vec<LintFinding> {
+    let mut declarations: HashMap<_, Vec<_>> = HashMap::new();
+    let mut labels: HashMap<_, Vec<_>> = HashMap::new();
+    let mut defined = HashSet::new();
+    let mut deprecated = HashSet::new();
+
+    for &c in components {
+        if let Some((iri, kind)) = declared_entity(&c.component) {
+            declarations.entry(iri).or_default().push((kind, c));
+        }
+        if let Some(entry) = label_entry(c) {
+            if entry.is_label() {
+                labels
+                    .entry(entry.subject)
+                    .or_default()
+                    .push((entry.literal, c));
+            } else if DEFAULT_DEFINITION_PROPERTIES.contains(&entry.property.as_ref()) {
+                defined.insert(entry.subject);
+            } else if AnnotationBuiltIn::Deprecated
+                .underlying()
+                .eq(&entry.property.to_string())
+                && entry.literal.literal() == "true"
+            {
+                deprecated.insert(entry.subject);
+            }
+        }
+    }
+
+    let mut findings: Vec<(Rule, String, Option<&AnnotatedComponent<ArcStr>>)> = vec![];
+    let enabled = |rule: Rule| severities.contains_key(&rule);
+
+    for (&iri, decls) in declarations.iter().filter(|(iri, _)| !is_builtin(iri)) {
+        let first = decls.iter().map(|(_, c)| *c).min();
+
+        if enabled(Rule::MissingLabel) && !labels.contains_key(iri) {
+            findings.push((Rule::MissingLabel, iri.to_string(), first));
+        }
+
+        let needs_definition = decls.iter().any(|(kind, _)| {
+            matches!(
+                kind,
+                NamedOWLEntityKind::Class
+                    | NamedOWLEntityKind::ObjectProperty
+                    | NamedOWLEntityKind::DataProperty
+            )
+        });
+        if enabled(Rule::MissingDefinition)
+            && needs_definition
+            && !defined.contains(iri)
+            && !deprecated.contains(iri)
+        {
+            findings.push((Rule::MissingDefinition, iri.to_string(), first));
+        }
+
+        let kinds: HashSet<_> = decls.iter().map(|(kind, _)| kind).collect();
+        if enabled(Rule::PunnedEntity) && kinds.len() > 1 {
+            findings.extend(
+                decls
+                    .iter()
+                    .map(|(_, c)| (Rule::PunnedEntity, iri.to_string(), Some(*c))),
+            );
+        }
+    }
+
+    if enabled(Rule::MultipleLabels) {
+        for (&iri, literals) in &labels {
+            let mut by_lang: HashMap<Option<&str>, Vec<&AnnotatedComponent<ArcStr>>> =
+                HashMap::new();
+            for (literal, c) in literals {
+                let lang = match literal {
+                    Literal::Language { lang, .. } => Some(lang.as_str()),
+                    _ => None,
+                };
+                by_lang.entry(lang).or_default().push(c);
+            }
+            for cs in by_lang.values().filter(|cs| cs.len() > 1) {
+                findings.extend(
+                    cs.iter()
+                        .map(|c| (Rule::MultipleLabels, iri.to_string(), Some(*c))),
+                );
+            }
+        }
+    }
+
+    if enabled(Rule::DuplicateLabel) {
+        let mut by_text: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (&iri, literals) in &labels {
+            for (literal, c) in literals {
+                by_text
+                    .entry(literal.literal())
+                    .or_default()
+                    .push((iri, *c));
+            }
+        }
+        for entries in by_text.values() {
+            let subjects: HashSet<_> = entries.iter().map(|(iri, _)| iri).collect();
+            if subjects.len() > 1 {
+                findings.extend(
+                    entries
+                        .iter()
+                        .map(|(iri, c)| (Rule::DuplicateLabel, iri.to_string(), Some(*c))),
+                );
+            }
+        }
+    }
+
+    for &c in components {
+        let signature = (enabled(Rule::UndeclaredEntity)
+            || enabled(Rule::DeprecatedEntityReference))
+        .then(|| Signature::of(&c.component));
+        let entities = signature.iter().flat_map(|s| s.entities());
+
+        for entity in entities {
+            let iri = entity_iri(entity);
+
+            let declared = declarations
+                .get(iri)
+                .is_some_and(|decls| decls.iter().any(|(kind, _)| *kind == entity_kind(entity)));
+            if enabled(Rule::UndeclaredEntity) && !declared && !is_builtin(iri) {
+                findings.push((Rule::UndeclaredEntity, iri.to_string(), Some(c)));
+            }
+
+            if enabled(Rule::DeprecatedEntityReference)
+                && deprecated.contains(iri)
+                && is_logical_axiom(&c.component)
+            {
+                findings.push((Rule::DeprecatedEntityReference, iri.to_string(), Some(c)));
+            }
+        }
+
+        if let Component::SubClassOf(SubClassOf {
+            sub: ClassExpression::Class(sub),
+            sup: ClassExpression::Class(sup),
+        }) = &c.component
+        {
+            if enabled(Rule::SelfReferentialSubclass) && sub == sup {
+                findings.push((Rule::SelfReferentialSubclass, sub.0.to_string(), Some(c)));
+            }
+        }
+    }
+
+    findings.sort();
+    findings.dedup();
+    findings
+        .into_iter()
+        .map(|(rule, iri, c)| LintFinding {
+            rule: rule.id().to_string(),
+            severity: severities[&rule].name().to_string(),
+            subject: iri,
+            component: c.map(model::AnnotatedComponent::from),
+        })
+        .collect()
+}
@@ -0,0 +1,372 @@
+//! Ontology metrics
+//!
+//! Counts components and entities, measures the asserted class hierarchy and determines the DL
+//! expressivity of an ontology in a single pass over its components.
+
+use std::collections::{HashMap, HashSet};
+
+use horned_owl::model::*;
+use horned_owl::visitor::immutable::{Visit, Walk};
+use horned_owl::vocab;
+use pyo3::prelude::*;
+
+use crate::model;
+use crate::signature::{entity_kind, Signature};
+
+/// Statistics of an ontology.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct OntologyMetrics {
+    /// Number of components per component kind.
+    #[pyo3(get)]
+    pub component_counts: HashMap<model::ComponentKind, usize>,
+    /// Number of entities in the signature per entity type, e.g. "Class" or "ObjectProperty".
+    #[pyo3(get)]
+    pub entity_counts: HashMap<String, usize>,
+    /// Number of axioms, including declarations and annotation axioms.
+    #[pyo3(get)]
+    pub axiom_count: usize,
+    /// Number of logical axioms, i.e. axioms other than declarations and annotation axioms.
+    #[pyo3(get)]
+    pub logical_axiom_count: usize,
+    /// Number of annotation axioms.
+    #[pyo3(get)]
+    pub annotation_axiom_count: usize,
+    /// Number of classes on the longest path of asserted named superclasses.
+    #[pyo3(get)]
+    pub max_depth: usize,
+    /// Number of classes without an asserted named superclass other than owl:Thing.
+    #[pyo3(get)]
+    pub root_class_count: usize,
+    /// Number of entities per IRI namespace.
+    #[pyo3(get)]
+    pub namespaces: HashMap<String, usize>,
+    /// DL expressivity of the logical axioms, e.g. "SROIQ(D)".
+    #[pyo3(get)]
+    pub expressivity: String,
+}
+
+#[pymethods]
+impl OntologyMetrics {
+    fn __repr__(&self) -> String {
+        format!(
+            "OntologyMetrics(axiom_count={}, logical_axiom_count={}, max_depth={}, expressivity={:?})",
+            self.axiom_count, self.logical_axiom_count, self.max_depth, self.expressivity
+        )
+    }
+}
+
+fn is_annotation_axiom(component: &Component<ArcStr>) -> bool {
+    matches!(
+        component,
+        Component::AnnotationAssertion(_)
+            | Component::SubAnnotationPropertyOf(_)
+            | Component::AnnotationPropertyDomain(_)
+            | Component::AnnotationPropertyRange(_)
+    )
+}
+
+fn is_declaration(component: &Component<ArcStr>) -> bool {
+    matches!(
+        component,
+        Component::DeclareClass(_)
+            | Component::DeclareObjectProperty(_)
+            | Component::DeclareAnnotationProperty(_)
+            | Component::DeclareDataProperty(_)
+            | Component::DeclareNamedIndividual(_)
+            | Component::DeclareDatatype(_)
+    )
+}
+
+/// Returns the namespace of an IRI, i.e. the IRI up to and including the last '#' or '/'.
+fn namespace(iri: &str) -> &str {
+    match iri.rfind(['#', '/']) {
+        Some(i) => &iri[..=i],
+        None => iri,
+    }
+}
+
+fn is_thing(ce: &ClassExpression<ArcStr>) -> bool {
+    matches!(ce, ClassExpression::Class(c) if c.is(&vocab::OWL::Thing))
+}
+
+/// The DL constructors used in an ontology.
+#[derive(Debug, Default)]
+struct Expressivity {
+    existential: bool,
+    universal: bool,
+    union: bool,
+    atomic_negation: bool,
+    complement: bool,
+    nominals: bool,
+    inverse: bool,
+    role_hierarchy: bool,
+    complex_roles: bool,
+    transitive: bool,
+    functional: bool,
+    unqualified: bool,
+    qualified: bool,
+    datatypes: bool,
+}
+
+impl Visit<ArcStr> for Expressivity {
+    fn visit_class_expression(&mut self, ce: &ClassExpression<ArcStr>) {
+        use ClassExpression::*;
+
+        match ce {
+            ObjectUnionOf(_) => self.union = true,
+            ObjectComplementOf(ce) => match **ce {
+                Class(_) => self.atomic_negation = true,
+                _ => self.complement = true,
+            },
+            ObjectOneOf(_) => self.nominals = true,
+            ObjectSomeValuesFrom { bce, .. } => self.existential |= !is_thing(bce),
+            ObjectAllValuesFrom { .. } => self.universal = true,
+            ObjectHasValue { .. } => {
+                self.existential = true;
+                self.nominals = true;
+            }
+            ObjectHasSelf(_) => self.complex_roles = true,
+            ObjectMinCardinality { bce, .. }
+            | ObjectMaxCardinality { bce, .. }
+            | ObjectExactCardinality { bce, .. } => {
+                if is_thing(bce) {
+                    self.unqualified = true;
+                } else {
+                    self.qualified = true;
+                }
+            }
+            DataMinCardinality { dr, .. }
+            | DataMaxCardinality { dr, .. }
+            | DataExactCardinality { dr, .. } => match dr {
+                DataRange::Datatype(dt) if dt.is(&vocab::OWL2Datatype::Literal) => {
+                    self.unqualified = true
+                }
+                _ => self.qualified = true,
+            },
+            _ => {}
+        }
+    }
+
+    fn visit_object_property_expression(&mut self, ope: &ObjectPropertyExpression<ArcStr>) {
+        if let ObjectPropertyExpression::InverseObjectProperty(_) = ope {
+            self.inverse = true;
+        }
+    }
+
+    fn visit_data_property(&mut self, _: &DataProperty<ArcStr>) {
+        self.datatypes = true;
+    }
+
+    fn visit_data_range(&mut self, _: &DataRange<ArcStr>) {
+        self.datatypes = true;
+    }
+
+    fn visit_component(&mut self, component: &Component<ArcStr>) {
+        use Component::*;
+
+        match component {
+            DisjointClasses(_) => self.atomic_negation = true,
+            DisjointUnion(_) => {
+                self.union = true;
+                self.atomic_negation = true;
+            }
+            SubObjectPropertyOf(horned_owl::model::SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyChain(_),
+                ..
+            }) => self.complex_roles = true,
+            SubObjectPropertyOf(_)
+            | EquivalentObjectProperties(_)
+            | SubDataPropertyOf(_)
+            | EquivalentDataProperties(_) => self.role_hierarchy = true,
+            InverseObjectProperties(_) | SymmetricObjectProperty(_) => self.inverse = true,
+            ObjectPropertyRange(_) => self.universal = true,
+            FunctionalObjectProperty(_) | FunctionalDataProperty(_) => self.functional = true,
+            InverseFunctionalObjectProperty(_) => {
+                self.functional = true;
+                self.inverse = true;
+            }
+            TransitiveObjectProperty(_) => self.transitive = true,
+            ReflexiveObjectProperty(_)
+            | IrreflexiveObjectProperty(_)
+            | AsymmetricObjectProperty(_)
+            | DisjointObjectProperties(_) => self.complex_roles = true,
+            NegativeObjectPropertyAssertion(_) => {
+                self.complement = true;
+                self.nominals = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Expressivity {
+    /// Returns the name of the description logic, e.g. "ALC" or "SHOIN(D)".
+    fn name(&self) -> String {
+        let mut name = if self.complement || (self.union && self.existential) {
+            if self.transitive || self.complex_roles {
+                "S".to_string()
+            } else {
+                "ALC".to_string()
+            }
+        } else if self.existential && !(self.universal || self.atomic_negation || self.union) {
+            "EL".to_string()
+        } else {
+            let mut name = "AL".to_string();
+            if self.existential {
+                name.push('E');
+            }
+            if self.union {
+                name.push('U');
+            }
+            name
+        };
+
+        if self.transitive && !name.starts_with('S') {
+            name.push('+');
+        }
+        if self.complex_roles {
+            name.push('R');
+        } else if self.role_hierarchy {
+            name.push('H');
+        }
+        if self.nominals {
+            name.push('O');
+        }
+        if self.inverse {
+            name.push('I');
+        }
+        if self.qualified {
+            name.push('Q');
+        } else if self.unqualified {
+            name.push('N');
+        } else if self.functional {
+            name.push('F');
+        }
+        if self.datatypes {
+            name.push_str("(D)");
+        }
+        name
+    }
+}
+
+/// Returns the length of the longest chain of named superclasses starting at `class`. Classes on
+/// a cycle are counted once.
+///
+/// Walks the hierarchy with an explicit stack, as it can be deeper than the call stack allows. A
+/// class is pushed a second time to compute its depth once all its superclasses are done.
+fn depth<'a>(
+    class: &'a Class<ArcStr>,
+    superclasses: &HashMap<&'a Class<ArcStr>, Vec<&'a Class<ArcStr>>>,
+    depths: &mut HashMap<&'a Class<ArcStr>, usize>,
+) -> usize {
+    let mut visiting = HashSet::new();
+    let mut stack = vec![(class, false)];
+    while let Some((c, done)) = stack.pop() {
+        let sups = superclasses.get(c).into_iter().flatten();
+        if done {
+            let d = 1 + sups.filter_map(|sup| depths.get(sup)).max().unwrap_or(&0);
+            visiting.remove(c);
+            depths.insert(c, d);
+        } else if !depths.contains_key(c) && visiting.insert(c) {
+            stack.push((c, true));
+            stack.extend(sups.rev().map(|sup| (*sup, false)));
+        }
+    }
+    depths[class]
+}
+
+/// Computes the metrics of an ontology from its components and its `SubClassOf` axioms.
+pub fn metrics<'a, I, S>(components: I, subclass_axioms: S) -> OntologyMetrics
+where
+    I: IntoIterator<Item = &'a AnnotatedComponent<ArcStr>>,
+    S: IntoIterator<Item = &'a AnnotatedComponent<ArcStr>>,
+{
+    let mut component_counts = HashMap::new();
+    let mut axiom_count = 0;
+    let mut logical_axiom_count = 0;
+    let mut annotation_axiom_count = 0;
+    let mut superclasses: HashMap<&Class<ArcStr>, Vec<&Class<ArcStr>>> = HashMap::new();
+    let mut signature = Walk::new(Signature::default());
+    let mut expressivity = Walk::new(Expressivity::default());
+
+    for c in components {
+        *component_counts
+            .entry(model::ComponentKind::from(&c.component.kind()))
+            .or_insert(0) += 1;
+        signature.annotated_component(c);
+
+        if !c.is_axiom() {
+            continue;
+        }
+        axiom_count += 1;
+        if is_annotation_axiom(&c.component) {
+            annotation_axiom_count += 1;
+        } else if !is_declaration(&c.component) {
+            logical_axiom_count += 1;
+            expressivity.component(&c.component);
+        }
+    }
+
+    for c in subclass_axioms {
+        if let Component::SubClassOf(SubClassOf {
+            sub: ClassExpression::Class(sub),
+            sup: sup @ ClassExpression::Class(sup_class),
+        }) = &c.component
+        {
+            if !is_thing(sup) {
+                superclasses.entry(sub).or_default().push(sup_class);
+            }
+        }
+    }
+
+    let signature = signature.into_visit();
+    let mut entity_counts = HashMap::new();
+    let mut namespaces = HashMap::new();
+    let mut classes = Vec::new();
+    for entity in signature.entities() {
+        *entity_counts
+            .entry(format!("{:?}", entity_kind(entity)))
+            .or_insert(0) += 1;
+
+        let iri = match entity {
+            NamedOWLEntity::Class(c) => {
+                if !c.is(&vocab::OWL::Thing) && !c.is(&vocab::OWL::Nothing) {
+                    classes.push(c);
+                }
+                &c.0
+            }
+            NamedOWLEntity::Datatype(e) => &e.0,
+            NamedOWLEntity::ObjectProperty(e) => &e.0,
+            NamedOWLEntity::DataProperty(e) => &e.0,
+            NamedOWLEntity::AnnotationProperty(e) => &e.0,
+            NamedOWLEntity::NamedIndividual(e) => &e.0,
+        };
+        *namespaces
+            .entry(namespace(iri.as_ref()).to_string())
+            .or_insert(0) += 1;
+    }
+
+    let mut depths = HashMap::new();
+    let max_depth = classes
+        .iter()
+        .map(|c| depth(c, &superclasses, &mut depths))
+        .max()
+        .unwrap_or(0);
+    let root_class_count = classes
+        .iter()
+        .filter(|c| !superclasses.contains_key(*c))
+        .count();
+
+    OntologyMetrics {
+        component_counts,
+        entity_counts,
+        axiom_count,
+        logical_axiom_count,
+        annotation_axiom_count,
+        max_depth,
+        root_class_count,
+        namespaces,
+        expressivity: expressivity.into_visit().name(),
+    }
+}
@@ -218,6 +218,8 @@ pub fn py_module<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyModule>> {
     module.add_class::<SameIndividualAtom>()?;
 
     module.add_class::<Facet>()?;
+    module.add_class::<ComponentKind>()?;
+    module.add_class::<Var>()?;
 
     add_type_alias!(
         py,
@@ -7,8 +7,9 @@ use std::hash::{Hash, Hasher};
 use horned_owl::io::ofn::writer::AsFunctional;
 
 use horned_owl::model::ArcStr;
-use pyo3::{exceptions::PyKeyError, prelude::*, PyAny, types::PyType};
+use pyo3::{exceptions::PyKeyError, prelude::*, PyAny, types::{PyTuple, PyType}};
 
+use crate::json::{FromDict, ToDict};
 use crate::wrappers::*;
 
 /****************** IRI **********************/
@@ -39,7 +40,7 @@ impl FromCompatible<IRI> for horned_owl::model::IRI<Arc<str>> {
 
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[pyclass(module = "pyhornedowl.model")]
+#[pyclass(module = "pyhornedowl.model", subclass)]
 pub struct IRI(horned_owl::model::IRI<ArcStr>);
 
 impl From<IRI> for horned_owl::model::IRI<ArcStr> {
@@ -86,6 +87,18 @@ impl IRI {
         let builder = horned_owl::model::Build::new_arc();
         IRI(builder.iri(value))
     }
+
+    fn __getstate__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __setstate__(&mut self, state: String) {
+        self.0 = horned_owl::model::Build::new_arc().iri(state);
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (String,))> {
+        Ok((slf.get_type().getattr("parse")?, (slf.borrow().__getstate__(),)))
+    }
 }
 
 impl IRI {
@@ -94,6 +107,97 @@ impl IRI {
     }
 }
 
+/********************* VAR ***********************/
+
+/// Prefix of the IRIs standing in for a `Var` inside the model.
+pub const VAR_PREFIX: &str = "urn:pyhornedowl:var:";
+
+/// Returns the name of the variable encoded in `value`, if any.
+pub fn var_name(value: &str) -> Option<&str> {
+    value.strip_prefix(VAR_PREFIX)
+}
+
+/// Var(name: str)
+///
+/// A named placeholder in a pattern. A `Var` can be used wherever an IRI, a class expression, an
+/// object property expression, a data range, an individual or a literal is expected.
+#[pyclass(module = "pyhornedowl.model", extends = IRI)]
+#[derive(Debug, Clone)]
+pub struct Var {
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl Var {
+    #[new]
+    fn new(name: String) -> (Self, IRI) {
+        let iri = IRI::new(format!("{}{}", VAR_PREFIX, name), &horned_owl::model::Build::new_arc());
+        (Var { name }, iri)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Var({:?})", self.name)
+    }
+
+    fn __getstate__(&self) -> String {
+        self.name.clone()
+    }
+
+    fn __setstate__(mut slf: PyRefMut<'_, Self>, state: String) {
+        slf.name = state;
+        let iri = slf.iri();
+        slf.into_super().0 = iri.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (String,)) {
+        (slf.get_type(), (slf.borrow().name.clone(),))
+    }
+}
+
+impl Var {
+    pub fn iri(&self) -> IRI {
+        IRI::new(format!("{}{}", VAR_PREFIX, self.name), &horned_owl::model::Build::new_arc())
+    }
+}
+
+/// Model types that represent a `Var` in positions typed by an enum such as `ClassExpression`.
+pub trait FromVar {
+    fn from_var(var: &Var) -> Self;
+}
+
+impl FromVar for Class {
+    fn from_var(var: &Var) -> Self {
+        Class(var.iri())
+    }
+}
+
+impl FromVar for ObjectProperty {
+    fn from_var(var: &Var) -> Self {
+        ObjectProperty(var.iri())
+    }
+}
+
+impl FromVar for Datatype {
+    fn from_var(var: &Var) -> Self {
+        Datatype(var.iri())
+    }
+}
+
+impl FromVar for NamedIndividual {
+    fn from_var(var: &Var) -> Self {
+        NamedIndividual(var.iri())
+    }
+}
+
+impl FromVar for SimpleLiteral {
+    fn from_var(var: &Var) -> Self {
+        SimpleLiteral {
+            literal: var.iri().__str__(),
+        }
+    }
+}
+
 /********************* FACET ***********************/
 
 impl FromCompatible<&horned_owl::vocab::Facet> for Facet {
@@ -168,6 +272,11 @@ impl Facet {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyType>, String))> {
+        let getattr = slf.py().import("builtins")?.getattr("getattr")?;
+        Ok((getattr, (slf.py().get_type::<Self>(), format!("{:?}", slf.borrow()))))
+    }
 }
 
 impl From<&Facet> for horned_owl::vocab::Facet {
@@ -219,6 +328,254 @@ impl From<horned_owl::vocab::Facet> for Facet {
 }
 
 
+#[doc = doc!(ComponentKind)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[pyclass(module = "pyhornedowl.model")]
+pub enum ComponentKind {
+    OntologyID,
+    DocIRI,
+    OntologyAnnotation,
+    Import,
+    DeclareClass,
+    DeclareObjectProperty,
+    DeclareAnnotationProperty,
+    DeclareDataProperty,
+    DeclareNamedIndividual,
+    DeclareDatatype,
+    SubClassOf,
+    EquivalentClasses,
+    DisjointClasses,
+    DisjointUnion,
+    SubObjectPropertyOf,
+    EquivalentObjectProperties,
+    DisjointObjectProperties,
+    InverseObjectProperties,
+    ObjectPropertyDomain,
+    ObjectPropertyRange,
+    FunctionalObjectProperty,
+    InverseFunctionalObjectProperty,
+    ReflexiveObjectProperty,
+    IrreflexiveObjectProperty,
+    SymmetricObjectProperty,
+    AsymmetricObjectProperty,
+    TransitiveObjectProperty,
+    SubDataPropertyOf,
+    EquivalentDataProperties,
+    DisjointDataProperties,
+    DataPropertyDomain,
+    DataPropertyRange,
+    FunctionalDataProperty,
+    DatatypeDefinition,
+    HasKey,
+    SameIndividual,
+    DifferentIndividuals,
+    ClassAssertion,
+    ObjectPropertyAssertion,
+    NegativeObjectPropertyAssertion,
+    DataPropertyAssertion,
+    NegativeDataPropertyAssertion,
+    AnnotationAssertion,
+    SubAnnotationPropertyOf,
+    AnnotationPropertyDomain,
+    AnnotationPropertyRange,
+    Rule,
+}
+
+#[pymethods]
+impl ComponentKind {
+    #[cfg(pyi)]
+    #[classmethod]
+    fn __pyi__(_: &Bound<'_, PyType>) -> String {
+        "class ComponentKind:
+    OntologyID: ComponentKind
+    DocIRI: ComponentKind
+    OntologyAnnotation: ComponentKind
+    Import: ComponentKind
+    DeclareClass: ComponentKind
+    DeclareObjectProperty: ComponentKind
+    DeclareAnnotationProperty: ComponentKind
+    DeclareDataProperty: ComponentKind
+    DeclareNamedIndividual: ComponentKind
+    DeclareDatatype: ComponentKind
+    SubClassOf: ComponentKind
+    EquivalentClasses: ComponentKind
+    DisjointClasses: ComponentKind
+    DisjointUnion: ComponentKind
+    SubObjectPropertyOf: ComponentKind
+    EquivalentObjectProperties: ComponentKind
+    DisjointObjectProperties: ComponentKind
+    InverseObjectProperties: ComponentKind
+    ObjectPropertyDomain: ComponentKind
+    ObjectPropertyRange: ComponentKind
+    FunctionalObjectProperty: ComponentKind
+    InverseFunctionalObjectProperty: ComponentKind
+    ReflexiveObjectProperty: ComponentKind
+    IrreflexiveObjectProperty: ComponentKind
+    SymmetricObjectProperty: ComponentKind
+    AsymmetricObjectProperty: ComponentKind
+    TransitiveObjectProperty: ComponentKind
+    SubDataPropertyOf: ComponentKind
+    EquivalentDataProperties: ComponentKind
+    DisjointDataProperties: ComponentKind
+    DataPropertyDomain: ComponentKind
+    DataPropertyRange: ComponentKind
+    FunctionalDataProperty: ComponentKind
+    DatatypeDefinition: ComponentKind
+    HasKey: ComponentKind
+    SameIndividual: ComponentKind
+    DifferentIndividuals: ComponentKind
+    ClassAssertion: ComponentKind
+    ObjectPropertyAssertion: ComponentKind
+    NegativeObjectPropertyAssertion: ComponentKind
+    DataPropertyAssertion: ComponentKind
+    NegativeDataPropertyAssertion: ComponentKind
+    AnnotationAssertion: ComponentKind
+    SubAnnotationPropertyOf: ComponentKind
+    AnnotationPropertyDomain: ComponentKind
+    AnnotationPropertyRange: ComponentKind
+    Rule: ComponentKind
+"
+            .to_owned()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        Hash::hash(&self, &mut s);
+        s.finish()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyType>, String))> {
+        let getattr = slf.py().import("builtins")?.getattr("getattr")?;
+        Ok((getattr, (slf.py().get_type::<Self>(), format!("{:?}", slf.borrow()))))
+    }
+}
+
+impl From<&ComponentKind> for horned_owl::model::ComponentKind {
+    fn from(value: &ComponentKind) -> Self {
+        match value {
+            ComponentKind::OntologyID => horned_owl::model::ComponentKind::OntologyID,
+            ComponentKind::DocIRI => horned_owl::model::ComponentKind::DocIRI,
+            ComponentKind::OntologyAnnotation => horned_owl::model::ComponentKind::OntologyAnnotation,
+            ComponentKind::Import => horned_owl::model::ComponentKind::Import,
+            ComponentKind::DeclareClass => horned_owl::model::ComponentKind::DeclareClass,
+            ComponentKind::DeclareObjectProperty => horned_owl::model::ComponentKind::DeclareObjectProperty,
+            ComponentKind::DeclareAnnotationProperty => horned_owl::model::ComponentKind::DeclareAnnotationProperty,
+            ComponentKind::DeclareDataProperty => horned_owl::model::ComponentKind::DeclareDataProperty,
+            ComponentKind::DeclareNamedIndividual => horned_owl::model::ComponentKind::DeclareNamedIndividual,
+            ComponentKind::DeclareDatatype => horned_owl::model::ComponentKind::DeclareDatatype,
+            ComponentKind::SubClassOf => horned_owl::model::ComponentKind::SubClassOf,
+            ComponentKind::EquivalentClasses => horned_owl::model::ComponentKind::EquivalentClasses,
+            ComponentKind::DisjointClasses => horned_owl::model::ComponentKind::DisjointClasses,
+            ComponentKind::DisjointUnion => horned_owl::model::ComponentKind::DisjointUnion,
+            ComponentKind::SubObjectPropertyOf => horned_owl::model::ComponentKind::SubObjectPropertyOf,
+            ComponentKind::EquivalentObjectProperties => horned_owl::model::ComponentKind::EquivalentObjectProperties,
+            ComponentKind::DisjointObjectProperties => horned_owl::model::ComponentKind::DisjointObjectProperties,
+            ComponentKind::InverseObjectProperties => horned_owl::model::ComponentKind::InverseObjectProperties,
+            ComponentKind::ObjectPropertyDomain => horned_owl::model::ComponentKind::ObjectPropertyDomain,
+            ComponentKind::ObjectPropertyRange => horned_owl::model::ComponentKind::ObjectPropertyRange,
+            ComponentKind::FunctionalObjectProperty => horned_owl::model::ComponentKind::FunctionalObjectProperty,
+            ComponentKind::InverseFunctionalObjectProperty => horned_owl::model::ComponentKind::InverseFunctionalObjectProperty,
+            ComponentKind::ReflexiveObjectProperty => horned_owl::model::ComponentKind::ReflexiveObjectProperty,
+            ComponentKind::IrreflexiveObjectProperty => horned_owl::model::ComponentKind::IrreflexiveObjectProperty,
+            ComponentKind::SymmetricObjectProperty => horned_owl::model::ComponentKind::SymmetricObjectProperty,
+            ComponentKind::AsymmetricObjectProperty => horned_owl::model::ComponentKind::AsymmetricObjectProperty,
+            ComponentKind::TransitiveObjectProperty => horned_owl::model::ComponentKind::TransitiveObjectProperty,
+            ComponentKind::SubDataPropertyOf => horned_owl::model::ComponentKind::SubDataPropertyOf,
+            ComponentKind::EquivalentDataProperties => horned_owl::model::ComponentKind::EquivalentDataProperties,
+            ComponentKind::DisjointDataProperties => horned_owl::model::ComponentKind::DisjointDataProperties,
+            ComponentKind::DataPropertyDomain => horned_owl::model::ComponentKind::DataPropertyDomain,
+            ComponentKind::DataPropertyRange => horned_owl::model::ComponentKind::DataPropertyRange,
+            ComponentKind::FunctionalDataProperty => horned_owl::model::ComponentKind::FunctionalDataProperty,
+            ComponentKind::DatatypeDefinition => horned_owl::model::ComponentKind::DatatypeDefinition,
+            ComponentKind::HasKey => horned_owl::model::ComponentKind::HasKey,
+            ComponentKind::SameIndividual => horned_owl::model::ComponentKind::SameIndividual,
+            ComponentKind::DifferentIndividuals => horned_owl::model::ComponentKind::DifferentIndividuals,
+            ComponentKind::ClassAssertion => horned_owl::model::ComponentKind::ClassAssertion,
+            ComponentKind::ObjectPropertyAssertion => horned_owl::model::ComponentKind::ObjectPropertyAssertion,
+            ComponentKind::NegativeObjectPropertyAssertion => horned_owl::model::ComponentKind::NegativeObjectPropertyAssertion,
+            ComponentKind::DataPropertyAssertion => horned_owl::model::ComponentKind::DataPropertyAssertion,
+            ComponentKind::NegativeDataPropertyAssertion => horned_owl::model::ComponentKind::NegativeDataPropertyAssertion,
+            ComponentKind::AnnotationAssertion => horned_owl::model::ComponentKind::AnnotationAssertion,
+            ComponentKind::SubAnnotationPropertyOf => horned_owl::model::ComponentKind::SubAnnotationPropertyOf,
+            ComponentKind::AnnotationPropertyDomain => horned_owl::model::ComponentKind::AnnotationPropertyDomain,
+            ComponentKind::AnnotationPropertyRange => horned_owl::model::ComponentKind::AnnotationPropertyRange,
+            ComponentKind::Rule => horned_owl::model::ComponentKind::Rule,
+        }
+    }
+}
+
+impl From<&horned_owl::model::ComponentKind> for ComponentKind {
+    fn from(value: &horned_owl::model::ComponentKind) -> Self {
+        match value {
+            horned_owl::model::ComponentKind::OntologyID => ComponentKind::OntologyID,
+            horned_owl::model::ComponentKind::DocIRI => ComponentKind::DocIRI,
+            horned_owl::model::ComponentKind::OntologyAnnotation => ComponentKind::OntologyAnnotation,
+            horned_owl::model::ComponentKind::Import => ComponentKind::Import,
+            horned_owl::model::ComponentKind::DeclareClass => ComponentKind::DeclareClass,
+            horned_owl::model::ComponentKind::DeclareObjectProperty => ComponentKind::DeclareObjectProperty,
+            horned_owl::model::ComponentKind::DeclareAnnotationProperty => ComponentKind::DeclareAnnotationProperty,
+            horned_owl::model::ComponentKind::DeclareDataProperty => ComponentKind::DeclareDataProperty,
+            horned_owl::model::ComponentKind::DeclareNamedIndividual => ComponentKind::DeclareNamedIndividual,
+            horned_owl::model::ComponentKind::DeclareDatatype => ComponentKind::DeclareDatatype,
+            horned_owl::model::ComponentKind::SubClassOf => ComponentKind::SubClassOf,
+            horned_owl::model::ComponentKind::EquivalentClasses => ComponentKind::EquivalentClasses,
+            horned_owl::model::ComponentKind::DisjointClasses => ComponentKind::DisjointClasses,
+            horned_owl::model::ComponentKind::DisjointUnion => ComponentKind::DisjointUnion,
+            horned_owl::model::ComponentKind::SubObjectPropertyOf => ComponentKind::SubObjectPropertyOf,
+            horned_owl::model::ComponentKind::EquivalentObjectProperties => ComponentKind::EquivalentObjectProperties,
+            horned_owl::model::ComponentKind::DisjointObjectProperties => ComponentKind::DisjointObjectProperties,
+            horned_owl::model::ComponentKind::InverseObjectProperties => ComponentKind::InverseObjectProperties,
+            horned_owl::model::ComponentKind::ObjectPropertyDomain => ComponentKind::ObjectPropertyDomain,
+            horned_owl::model::ComponentKind::ObjectPropertyRange => ComponentKind::ObjectPropertyRange,
+            horned_owl::model::ComponentKind::FunctionalObjectProperty => ComponentKind::FunctionalObjectProperty,
+            horned_owl::model::ComponentKind::InverseFunctionalObjectProperty => ComponentKind::InverseFunctionalObjectProperty,
+            horned_owl::model::ComponentKind::ReflexiveObjectProperty => ComponentKind::ReflexiveObjectProperty,
+            horned_owl::model::ComponentKind::IrreflexiveObjectProperty => ComponentKind::IrreflexiveObjectProperty,
+            horned_owl::model::ComponentKind::SymmetricObjectProperty => ComponentKind::SymmetricObjectProperty,
+            horned_owl::model::ComponentKind::AsymmetricObjectProperty => ComponentKind::AsymmetricObjectProperty,
+            horned_owl::model::ComponentKind::TransitiveObjectProperty => ComponentKind::TransitiveObjectProperty,
+            horned_owl::model::ComponentKind::SubDataPropertyOf => ComponentKind::SubDataPropertyOf,
+            horned_owl::model::ComponentKind::EquivalentDataProperties => ComponentKind::EquivalentDataProperties,
+            horned_owl::model::ComponentKind::DisjointDataProperties => ComponentKind::DisjointDataProperties,
+            horned_owl::model::ComponentKind::DataPropertyDomain => ComponentKind::DataPropertyDomain,
+            horned_owl::model::ComponentKind::DataPropertyRange => ComponentKind::DataPropertyRange,
+            horned_owl::model::ComponentKind::FunctionalDataProperty => ComponentKind::FunctionalDataProperty,
+            horned_owl::model::ComponentKind::DatatypeDefinition => ComponentKind::DatatypeDefinition,
+            horned_owl::model::ComponentKind::HasKey => ComponentKind::HasKey,
+            horned_owl::model::ComponentKind::SameIndividual => ComponentKind::SameIndividual,
+            horned_owl::model::ComponentKind::DifferentIndividuals => ComponentKind::DifferentIndividuals,
+            horned_owl::model::ComponentKind::ClassAssertion => ComponentKind::ClassAssertion,
+            horned_owl::model::ComponentKind::ObjectPropertyAssertion => ComponentKind::ObjectPropertyAssertion,
+            horned_owl::model::ComponentKind::NegativeObjectPropertyAssertion => ComponentKind::NegativeObjectPropertyAssertion,
+            horned_owl::model::ComponentKind::DataPropertyAssertion => ComponentKind::DataPropertyAssertion,
+            horned_owl::model::ComponentKind::NegativeDataPropertyAssertion => ComponentKind::NegativeDataPropertyAssertion,
+            horned_owl::model::ComponentKind::AnnotationAssertion => ComponentKind::AnnotationAssertion,
+            horned_owl::model::ComponentKind::SubAnnotationPropertyOf => ComponentKind::SubAnnotationPropertyOf,
+            horned_owl::model::ComponentKind::AnnotationPropertyDomain => ComponentKind::AnnotationPropertyDomain,
+            horned_owl::model::ComponentKind::AnnotationPropertyRange => ComponentKind::AnnotationPropertyRange,
+            horned_owl::model::ComponentKind::Rule => ComponentKind::Rule,
+        }
+    }
+}
+
+impl From<ComponentKind> for horned_owl::model::ComponentKind {
+    fn from(value: ComponentKind) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<horned_owl::model::ComponentKind> for ComponentKind {
+    fn from(value: horned_owl::model::ComponentKind) -> Self {
+        (&value).into()
+    }
+}
+
+
+
 /************ Annotations *******************/
 
 impl FromCompatible<&BTreeSet<horned_owl::model::Annotation<Arc<str>>>>
@@ -289,6 +646,20 @@ impl Class {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::Class<ArcStr>> for Class {
@@ -308,6 +679,31 @@ impl From<&Class> for horned_owl::model::Class<ArcStr> {
     }
 }
 
+crate::signature_methods!(Class, this => crate::signature::Signature::of(&horned_owl::model::Class::<ArcStr>::from(this)));
+
+impl ToDict for Class {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Class")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Class {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Class")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Class")?;
+        Ok(Class(
+            crate::json::field(&node, "Class", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(Class);
+
 
 
 /**************** Base implementations for Class ****************/
@@ -504,6 +900,20 @@ impl AnonymousIndividual {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (StringWrapper,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::AnonymousIndividual<ArcStr>> for AnonymousIndividual {
@@ -523,6 +933,31 @@ impl From<&AnonymousIndividual> for horned_owl::model::AnonymousIndividual<ArcSt
     }
 }
 
+crate::signature_methods!(AnonymousIndividual, this => crate::signature::Signature::of(&horned_owl::model::AnonymousIndividual::<ArcStr>::from(this)));
+
+impl ToDict for AnonymousIndividual {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnonymousIndividual")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnonymousIndividual {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnonymousIndividual")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnonymousIndividual")?;
+        Ok(AnonymousIndividual(
+            crate::json::field(&node, "AnonymousIndividual", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(AnonymousIndividual);
+
 
 
 /**************** Base implementations for AnonymousIndividual ****************/
@@ -702,6 +1137,20 @@ impl NamedIndividual {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::NamedIndividual<ArcStr>> for NamedIndividual {
@@ -721,6 +1170,31 @@ impl From<&NamedIndividual> for horned_owl::model::NamedIndividual<ArcStr> {
     }
 }
 
+crate::signature_methods!(NamedIndividual, this => crate::signature::Signature::of(&horned_owl::model::NamedIndividual::<ArcStr>::from(this)));
+
+impl ToDict for NamedIndividual {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "NamedIndividual")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for NamedIndividual {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "NamedIndividual")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "NamedIndividual")?;
+        Ok(NamedIndividual(
+            crate::json::field(&node, "NamedIndividual", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(NamedIndividual);
+
 
 
 /**************** Base implementations for NamedIndividual ****************/
@@ -900,6 +1374,20 @@ impl ObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::ObjectProperty<ArcStr>> for ObjectProperty {
@@ -919,6 +1407,31 @@ impl From<&ObjectProperty> for horned_owl::model::ObjectProperty<ArcStr> {
     }
 }
 
+crate::signature_methods!(ObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::ObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for ObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ObjectProperty")?;
+        Ok(ObjectProperty(
+            crate::json::field(&node, "ObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(ObjectProperty);
+
 
 
 /**************** Base implementations for ObjectProperty ****************/
@@ -1172,6 +1685,20 @@ impl Datatype {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::Datatype<ArcStr>> for Datatype {
@@ -1191,6 +1718,31 @@ impl From<&Datatype> for horned_owl::model::Datatype<ArcStr> {
     }
 }
 
+crate::signature_methods!(Datatype, this => crate::signature::Signature::of(&horned_owl::model::Datatype::<ArcStr>::from(this)));
+
+impl ToDict for Datatype {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Datatype")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Datatype {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Datatype")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Datatype")?;
+        Ok(Datatype(
+            crate::json::field(&node, "Datatype", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(Datatype);
+
 
 
 /**************** Base implementations for Datatype ****************/
@@ -1370,6 +1922,20 @@ impl DataProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
 }
 
 impl From<&horned_owl::model::DataProperty<ArcStr>> for DataProperty {
@@ -1389,6 +1955,31 @@ impl From<&DataProperty> for horned_owl::model::DataProperty<ArcStr> {
     }
 }
 
+crate::signature_methods!(DataProperty, this => crate::signature::Signature::of(&horned_owl::model::DataProperty::<ArcStr>::from(this)));
+
+impl ToDict for DataProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DataProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DataProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DataProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DataProperty")?;
+        Ok(DataProperty(
+            crate::json::field(&node, "DataProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DataProperty);
+
 
 
 /**************** Base implementations for DataProperty ****************/
@@ -1597,6 +2188,22 @@ impl FacetRestriction {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.f.clone().into_pyobject(py)?.into_any(),
+            self.l.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Facet,Literal,)) {
+        self.f = state.0;
+        self.l = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::FacetRestriction<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -1621,6 +2228,33 @@ impl From<&FacetRestriction> for horned_owl::model::FacetRestriction<ArcStr> {
     }
 }
 
+crate::signature_methods!(FacetRestriction, this => crate::signature::Signature::of(&horned_owl::model::FacetRestriction::<ArcStr>::from(this)));
+
+impl ToDict for FacetRestriction {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "FacetRestriction")?;
+        node.set_item("f", ToDict::to_dict(&self.f, py)?)?;
+        node.set_item("l", ToDict::to_dict(&self.l, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for FacetRestriction {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "FacetRestriction")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "FacetRestriction")?;
+        Ok(FacetRestriction {
+            f: crate::json::field(&node, "FacetRestriction", "f")?,
+            l: crate::json::field(&node, "FacetRestriction", "l")?,
+        })
+    }
+}
+
+crate::json_methods!(FacetRestriction);
+
 
 
 /**************** Base implementations for FacetRestriction ****************/
@@ -1751,17 +2385,34 @@ impl FromCompatible<&Vec<horned_owl::model::FacetRestriction<ArcStr>>> for VecWr
         VecWrap::<FacetRestriction>::from(value)
     }
 }
-#[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Individual {
     
-        #[pyo3(transparent)]
         Anonymous (AnonymousIndividual),
     
-        #[pyo3(transparent)]
         Named (NamedIndividual),
     
 }
 
+impl<'py> FromPyObject<'py> for Individual {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(var) = ob.downcast::<Var>() {
+            return Ok(Individual::Named(FromVar::from_var(&*var.borrow())));
+        }
+        
+        if let Ok(inner) = ob.extract::<AnonymousIndividual>() {
+            return Ok(Individual::Anonymous(inner));
+        }
+        
+        if let Ok(inner) = ob.extract::<NamedIndividual>() {
+            return Ok(Individual::Named(inner));
+        }
+        
+        Err(pyo3::PyErr::new::<pyo3::exceptions::PyTypeError, _>("Object cannot be converted to Individual"))
+    }
+}
+
 impl<'py> IntoPyObject<'py> for Individual {
     type Target = PyAny;
     type Output = Bound<'py, Self::Target>;
@@ -1804,6 +2455,33 @@ impl From<&horned_owl::model::Individual<ArcStr>> for Individual {
 }
 
 
+impl ToDict for Individual {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Individual::Anonymous(inner) => ToDict::to_dict(inner, py),
+            Individual::Named(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for Individual {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <AnonymousIndividual>::accepts(value) ||
+        <NamedIndividual>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <AnonymousIndividual>::accepts(value) {
+            return Ok(Individual::Anonymous(<AnonymousIndividual as FromDict>::from_dict(value)?));
+        }
+        if <NamedIndividual>::accepts(value) {
+            return Ok(Individual::Named(<NamedIndividual as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "Individual")
+    }
+}
+
 impl Individual {
     pub fn py_def() -> String {
         "typing.Union[m.AnonymousIndividual,m.NamedIndividual,]".into()
@@ -2016,6 +2694,20 @@ pub struct ObjectPropertyExpression(ObjectPropertyExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectProperty,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ObjectPropertyExpression<ArcStr>>::into(Into::<ObjectPropertyExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -2097,6 +2789,33 @@ pub struct ObjectPropertyExpression(ObjectPropertyExpression_Inner);
             }
         }
 
+    crate::signature_methods!(InverseObjectProperty, this => crate::signature::Signature::of(
+        &horned_owl::model::ObjectPropertyExpression::<ArcStr>::from(&ObjectPropertyExpression::from(this.clone()))
+    ));
+
+    impl ToDict for InverseObjectProperty {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "InverseObjectProperty")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for InverseObjectProperty {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "InverseObjectProperty")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "InverseObjectProperty")?;
+            Ok(InverseObjectProperty(
+                crate::json::field(&node, "InverseObjectProperty", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(InverseObjectProperty);
+
     // Transparent variant implementation
     impl From<ObjectProperty> for ObjectPropertyExpression {
         fn from(value: ObjectProperty) -> Self {
@@ -2152,6 +2871,10 @@ impl<'py> IntoPyObject<'py> for ObjectPropertyExpression {
 
 impl <'py> FromPyObject<'py> for ObjectPropertyExpression {
     fn extract_bound(ob: &Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(var) = ob.downcast::<Var>() {
+            let inner = ObjectPropertyExpression_Inner::ObjectProperty(FromVar::from_var(&*var.borrow()));
+            return Ok(ObjectPropertyExpression(inner));
+        }
             {
             	let r = ObjectProperty::extract_bound(ob);
                 if let Ok(local) = r {
@@ -2171,14 +2894,41 @@ impl <'py> FromPyObject<'py> for ObjectPropertyExpression {
     }
 }
 
-impl ObjectPropertyExpression {
-    pub fn py_def() -> String {
-        "typing.Union[m.ObjectProperty,m.InverseObjectProperty,]".into()
+impl ToDict for ObjectPropertyExpression {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.0 {
+            ObjectPropertyExpression_Inner::ObjectProperty(val) => ToDict::to_dict(val, py),
+            ObjectPropertyExpression_Inner::InverseObjectProperty(val) => ToDict::to_dict(val, py),
+        }
     }
 }
 
+impl FromDict for ObjectPropertyExpression {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        ObjectProperty::accepts(value) ||
+        InverseObjectProperty::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if ObjectProperty::accepts(value) {
+            return Ok(ObjectPropertyExpression(ObjectPropertyExpression_Inner::ObjectProperty(<ObjectProperty as FromDict>::from_dict(value)?)));
+        }
+        if InverseObjectProperty::accepts(value) {
+            return Ok(ObjectPropertyExpression(ObjectPropertyExpression_Inner::InverseObjectProperty(<InverseObjectProperty as FromDict>::from_dict(value)?)));
+        }
+
+        crate::json::invalid(value, "ObjectPropertyExpression")
+    }
+}
+
+impl ObjectPropertyExpression {
+    pub fn py_def() -> String {
+        "typing.Union[m.ObjectProperty,m.InverseObjectProperty,]".into()
+    }
+}
+
+
 
-
 /**************** Base implementations for ObjectPropertyExpression ****************/
 impl FromCompatible<horned_owl::model::ObjectPropertyExpression<ArcStr>> for ObjectPropertyExpression {
     fn from_c(value: horned_owl::model::ObjectPropertyExpression<ArcStr>) -> Self {
@@ -2383,6 +3133,20 @@ pub struct Literal(Literal_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.literal.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (String,)) {
+            self.literal = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Literal<ArcStr>>::into(Into::<Literal>::into(self.clone())).as_functional().to_string()
         }
@@ -2390,6 +3154,33 @@ pub struct Literal(Literal_Inner);
 
     
 
+    crate::signature_methods!(SimpleLiteral, this => crate::signature::Signature::of(
+        &horned_owl::model::Literal::<ArcStr>::from(&Literal::from(this.clone()))
+    ));
+
+    impl ToDict for SimpleLiteral {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "SimpleLiteral")?;
+            node.set_item("literal", ToDict::to_dict(&self.literal, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for SimpleLiteral {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "SimpleLiteral")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "SimpleLiteral")?;
+            Ok(SimpleLiteral{
+                literal: crate::json::field(&node, "SimpleLiteral", "literal")?,
+            })
+        }
+    }
+
+    crate::json_methods!(SimpleLiteral);
+
 
     /**************** ENUM VARIANT LanguageLiteral for Literal ****************/
     #[doc = concat!("LanguageLiteral(literal: strlang: str",
@@ -2461,6 +3252,22 @@ pub struct Literal(Literal_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.literal.clone().into_pyobject(py)?.into_any(),
+                self.lang.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (String,String,)) {
+            self.literal = state.0;
+            self.lang = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Literal<ArcStr>>::into(Into::<Literal>::into(self.clone())).as_functional().to_string()
         }
@@ -2468,6 +3275,35 @@ pub struct Literal(Literal_Inner);
 
     
 
+    crate::signature_methods!(LanguageLiteral, this => crate::signature::Signature::of(
+        &horned_owl::model::Literal::<ArcStr>::from(&Literal::from(this.clone()))
+    ));
+
+    impl ToDict for LanguageLiteral {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "LanguageLiteral")?;
+            node.set_item("literal", ToDict::to_dict(&self.literal, py)?)?;
+            node.set_item("lang", ToDict::to_dict(&self.lang, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for LanguageLiteral {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "LanguageLiteral")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "LanguageLiteral")?;
+            Ok(LanguageLiteral{
+                literal: crate::json::field(&node, "LanguageLiteral", "literal")?,
+                lang: crate::json::field(&node, "LanguageLiteral", "lang")?,
+            })
+        }
+    }
+
+    crate::json_methods!(LanguageLiteral);
+
 
     /**************** ENUM VARIANT DatatypeLiteral for Literal ****************/
     #[doc = concat!("DatatypeLiteral(literal: strdatatype_iri: IRI",
@@ -2539,6 +3375,22 @@ pub struct Literal(Literal_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.literal.clone().into_pyobject(py)?.into_any(),
+                self.datatype_iri.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (String,IRI,)) {
+            self.literal = state.0;
+            self.datatype_iri = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Literal<ArcStr>>::into(Into::<Literal>::into(self.clone())).as_functional().to_string()
         }
@@ -2546,6 +3398,35 @@ pub struct Literal(Literal_Inner);
 
     
 
+    crate::signature_methods!(DatatypeLiteral, this => crate::signature::Signature::of(
+        &horned_owl::model::Literal::<ArcStr>::from(&Literal::from(this.clone()))
+    ));
+
+    impl ToDict for DatatypeLiteral {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DatatypeLiteral")?;
+            node.set_item("literal", ToDict::to_dict(&self.literal, py)?)?;
+            node.set_item("datatype_iri", ToDict::to_dict(&self.datatype_iri, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DatatypeLiteral {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DatatypeLiteral")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DatatypeLiteral")?;
+            Ok(DatatypeLiteral{
+                literal: crate::json::field(&node, "DatatypeLiteral", "literal")?,
+                datatype_iri: crate::json::field(&node, "DatatypeLiteral", "datatype_iri")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DatatypeLiteral);
+
 
 
 impl From<&horned_owl::model::Literal<ArcStr>> for Literal {
@@ -2614,6 +3495,10 @@ impl<'py> IntoPyObject<'py> for Literal {
 
 impl <'py> FromPyObject<'py> for Literal {
     fn extract_bound(ob: &Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(var) = ob.downcast::<Var>() {
+            let inner = Literal_Inner::Simple(FromVar::from_var(&*var.borrow()));
+            return Ok(Literal(inner));
+        }
             {
                 let r = SimpleLiteral::extract_bound(ob);
                 if let Ok(local) = r {
@@ -2640,6 +3525,38 @@ impl <'py> FromPyObject<'py> for Literal {
     }
 }
 
+impl ToDict for Literal {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.0 {
+            Literal_Inner::Simple(val) => ToDict::to_dict(val, py),
+            Literal_Inner::Language(val) => ToDict::to_dict(val, py),
+            Literal_Inner::Datatype(val) => ToDict::to_dict(val, py),
+        }
+    }
+}
+
+impl FromDict for Literal {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        SimpleLiteral::accepts(value) ||
+        LanguageLiteral::accepts(value) ||
+        DatatypeLiteral::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if SimpleLiteral::accepts(value) {
+            return Ok(Literal(Literal_Inner::Simple(<SimpleLiteral as FromDict>::from_dict(value)?)));
+        }
+        if LanguageLiteral::accepts(value) {
+            return Ok(Literal(Literal_Inner::Language(<LanguageLiteral as FromDict>::from_dict(value)?)));
+        }
+        if DatatypeLiteral::accepts(value) {
+            return Ok(Literal(Literal_Inner::Datatype(<DatatypeLiteral as FromDict>::from_dict(value)?)));
+        }
+
+        crate::json::invalid(value, "Literal")
+    }
+}
+
 impl Literal {
     pub fn py_def() -> String {
         "typing.Union[m.SimpleLiteral,m.LanguageLiteral,m.DatatypeLiteral,]".into()
@@ -2856,6 +3773,20 @@ pub struct DataRange(DataRange_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<DataRange>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::DataRange<ArcStr>>::into(Into::<DataRange>::into(self.clone())).as_functional().to_string()
         }
@@ -2863,6 +3794,33 @@ pub struct DataRange(DataRange_Inner);
 
     
 
+    crate::signature_methods!(DataIntersectionOf, this => crate::signature::Signature::of(
+        &horned_owl::model::DataRange::<ArcStr>::from(&DataRange::from(this.clone()))
+    ));
+
+    impl ToDict for DataIntersectionOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataIntersectionOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataIntersectionOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataIntersectionOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataIntersectionOf")?;
+            Ok(DataIntersectionOf(
+                crate::json::field(&node, "DataIntersectionOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DataIntersectionOf);
+
 
     /**************** ENUM VARIANT DataUnionOf for DataRange ****************/
     #[doc = concat!("DataUnionOf(first: typing.List[DataRange]",
@@ -2925,6 +3883,20 @@ pub struct DataRange(DataRange_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<DataRange>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::DataRange<ArcStr>>::into(Into::<DataRange>::into(self.clone())).as_functional().to_string()
         }
@@ -2932,6 +3904,33 @@ pub struct DataRange(DataRange_Inner);
 
     
 
+    crate::signature_methods!(DataUnionOf, this => crate::signature::Signature::of(
+        &horned_owl::model::DataRange::<ArcStr>::from(&DataRange::from(this.clone()))
+    ));
+
+    impl ToDict for DataUnionOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataUnionOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataUnionOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataUnionOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataUnionOf")?;
+            Ok(DataUnionOf(
+                crate::json::field(&node, "DataUnionOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DataUnionOf);
+
 
     /**************** ENUM VARIANT DataComplementOf for DataRange ****************/
     #[doc = concat!("DataComplementOf(first: DataRange",
@@ -2994,6 +3993,20 @@ pub struct DataRange(DataRange_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (BoxWrap<DataRange>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::DataRange<ArcStr>>::into(Into::<DataRange>::into(self.clone())).as_functional().to_string()
         }
@@ -3001,6 +4014,33 @@ pub struct DataRange(DataRange_Inner);
 
     
 
+    crate::signature_methods!(DataComplementOf, this => crate::signature::Signature::of(
+        &horned_owl::model::DataRange::<ArcStr>::from(&DataRange::from(this.clone()))
+    ));
+
+    impl ToDict for DataComplementOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataComplementOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataComplementOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataComplementOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataComplementOf")?;
+            Ok(DataComplementOf(
+                crate::json::field(&node, "DataComplementOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DataComplementOf);
+
 
     /**************** ENUM VARIANT DataOneOf for DataRange ****************/
     #[doc = concat!("DataOneOf(first: typing.List[Literal]",
@@ -3063,6 +4103,20 @@ pub struct DataRange(DataRange_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<Literal>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::DataRange<ArcStr>>::into(Into::<DataRange>::into(self.clone())).as_functional().to_string()
         }
@@ -3070,6 +4124,33 @@ pub struct DataRange(DataRange_Inner);
 
     
 
+    crate::signature_methods!(DataOneOf, this => crate::signature::Signature::of(
+        &horned_owl::model::DataRange::<ArcStr>::from(&DataRange::from(this.clone()))
+    ));
+
+    impl ToDict for DataOneOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataOneOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataOneOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataOneOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataOneOf")?;
+            Ok(DataOneOf(
+                crate::json::field(&node, "DataOneOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DataOneOf);
+
 
     /**************** ENUM VARIANT DatatypeRestriction for DataRange ****************/
     #[doc = concat!("DatatypeRestriction(first: Datatypesecond: typing.List[FacetRestriction]",
@@ -3141,6 +4222,22 @@ pub struct DataRange(DataRange_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+                self.1.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (Datatype,VecWrap<FacetRestriction>,)) {
+            self.0 = state.0;
+            self.1 = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::DataRange<ArcStr>>::into(Into::<DataRange>::into(self.clone())).as_functional().to_string()
         }
@@ -3148,6 +4245,35 @@ pub struct DataRange(DataRange_Inner);
 
     
 
+    crate::signature_methods!(DatatypeRestriction, this => crate::signature::Signature::of(
+        &horned_owl::model::DataRange::<ArcStr>::from(&DataRange::from(this.clone()))
+    ));
+
+    impl ToDict for DatatypeRestriction {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DatatypeRestriction")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            node.set_item("second", ToDict::to_dict(&self.1, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DatatypeRestriction {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DatatypeRestriction")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DatatypeRestriction")?;
+            Ok(DatatypeRestriction(
+                crate::json::field(&node, "DatatypeRestriction", "first")?,
+                crate::json::field(&node, "DatatypeRestriction", "second")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DatatypeRestriction);
+
     // Transparent variant implementation
     impl From<Datatype> for DataRange {
         fn from(value: Datatype) -> Self {
@@ -3244,6 +4370,10 @@ impl<'py> IntoPyObject<'py> for DataRange {
 
 impl <'py> FromPyObject<'py> for DataRange {
     fn extract_bound(ob: &Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(var) = ob.downcast::<Var>() {
+            let inner = DataRange_Inner::Datatype(FromVar::from_var(&*var.borrow()));
+            return Ok(DataRange(inner));
+        }
             {
             	let r = Datatype::extract_bound(ob);
                 if let Ok(local) = r {
@@ -3291,6 +4421,53 @@ impl <'py> FromPyObject<'py> for DataRange {
     }
 }
 
+impl ToDict for DataRange {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.0 {
+            DataRange_Inner::Datatype(val) => ToDict::to_dict(val, py),
+            DataRange_Inner::DataIntersectionOf(val) => ToDict::to_dict(val, py),
+            DataRange_Inner::DataUnionOf(val) => ToDict::to_dict(val, py),
+            DataRange_Inner::DataComplementOf(val) => ToDict::to_dict(val, py),
+            DataRange_Inner::DataOneOf(val) => ToDict::to_dict(val, py),
+            DataRange_Inner::DatatypeRestriction(val) => ToDict::to_dict(val, py),
+        }
+    }
+}
+
+impl FromDict for DataRange {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        Datatype::accepts(value) ||
+        DataIntersectionOf::accepts(value) ||
+        DataUnionOf::accepts(value) ||
+        DataComplementOf::accepts(value) ||
+        DataOneOf::accepts(value) ||
+        DatatypeRestriction::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if Datatype::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::Datatype(<Datatype as FromDict>::from_dict(value)?)));
+        }
+        if DataIntersectionOf::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::DataIntersectionOf(<DataIntersectionOf as FromDict>::from_dict(value)?)));
+        }
+        if DataUnionOf::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::DataUnionOf(<DataUnionOf as FromDict>::from_dict(value)?)));
+        }
+        if DataComplementOf::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::DataComplementOf(<DataComplementOf as FromDict>::from_dict(value)?)));
+        }
+        if DataOneOf::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::DataOneOf(<DataOneOf as FromDict>::from_dict(value)?)));
+        }
+        if DatatypeRestriction::accepts(value) {
+            return Ok(DataRange(DataRange_Inner::DatatypeRestriction(<DatatypeRestriction as FromDict>::from_dict(value)?)));
+        }
+
+        crate::json::invalid(value, "DataRange")
+    }
+}
+
 impl DataRange {
     pub fn py_def() -> String {
         "typing.Union[m.Datatype,m.DataIntersectionOf,m.DataUnionOf,m.DataComplementOf,m.DataOneOf,m.DatatypeRestriction,]".into()
@@ -3519,6 +4696,20 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<ClassExpression>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3543,6 +4734,33 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectIntersectionOf, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectIntersectionOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectIntersectionOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectIntersectionOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectIntersectionOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectIntersectionOf")?;
+            Ok(ObjectIntersectionOf(
+                crate::json::field(&node, "ObjectIntersectionOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(ObjectIntersectionOf);
+
 
     /**************** ENUM VARIANT ObjectUnionOf for ClassExpression ****************/
     #[doc = concat!("ObjectUnionOf(first: typing.List[ClassExpression]",
@@ -3605,6 +4823,20 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<ClassExpression>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3629,6 +4861,33 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectUnionOf, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectUnionOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectUnionOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectUnionOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectUnionOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectUnionOf")?;
+            Ok(ObjectUnionOf(
+                crate::json::field(&node, "ObjectUnionOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(ObjectUnionOf);
+
 
     /**************** ENUM VARIANT ObjectComplementOf for ClassExpression ****************/
     #[doc = concat!("ObjectComplementOf(first: ClassExpression",
@@ -3691,6 +4950,20 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (BoxWrap<ClassExpression>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3715,6 +4988,33 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectComplementOf, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectComplementOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectComplementOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectComplementOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectComplementOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectComplementOf")?;
+            Ok(ObjectComplementOf(
+                crate::json::field(&node, "ObjectComplementOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(ObjectComplementOf);
+
 
     /**************** ENUM VARIANT ObjectOneOf for ClassExpression ****************/
     #[doc = concat!("ObjectOneOf(first: typing.List[Individual]",
@@ -3777,6 +5077,20 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (VecWrap<Individual>,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3801,6 +5115,33 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectOneOf, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectOneOf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectOneOf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectOneOf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectOneOf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectOneOf")?;
+            Ok(ObjectOneOf(
+                crate::json::field(&node, "ObjectOneOf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(ObjectOneOf);
+
 
     /**************** ENUM VARIANT ObjectSomeValuesFrom for ClassExpression ****************/
     #[doc = concat!("ObjectSomeValuesFrom(ope: ObjectPropertyExpressionbce: ClassExpression",
@@ -3872,6 +5213,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.bce.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectPropertyExpression,BoxWrap<ClassExpression>,)) {
+            self.ope = state.0;
+            self.bce = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3896,6 +5253,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectSomeValuesFrom, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectSomeValuesFrom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectSomeValuesFrom")?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("bce", ToDict::to_dict(&self.bce, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectSomeValuesFrom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectSomeValuesFrom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectSomeValuesFrom")?;
+            Ok(ObjectSomeValuesFrom{
+                ope: crate::json::field(&node, "ObjectSomeValuesFrom", "ope")?,
+                bce: crate::json::field(&node, "ObjectSomeValuesFrom", "bce")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectSomeValuesFrom);
+
 
     /**************** ENUM VARIANT ObjectAllValuesFrom for ClassExpression ****************/
     #[doc = concat!("ObjectAllValuesFrom(ope: ObjectPropertyExpressionbce: ClassExpression",
@@ -3967,6 +5353,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.bce.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectPropertyExpression,BoxWrap<ClassExpression>,)) {
+            self.ope = state.0;
+            self.bce = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -3991,6 +5393,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectAllValuesFrom, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectAllValuesFrom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectAllValuesFrom")?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("bce", ToDict::to_dict(&self.bce, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectAllValuesFrom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectAllValuesFrom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectAllValuesFrom")?;
+            Ok(ObjectAllValuesFrom{
+                ope: crate::json::field(&node, "ObjectAllValuesFrom", "ope")?,
+                bce: crate::json::field(&node, "ObjectAllValuesFrom", "bce")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectAllValuesFrom);
+
 
     /**************** ENUM VARIANT ObjectHasValue for ClassExpression ****************/
     #[doc = concat!("ObjectHasValue(ope: ObjectPropertyExpressioni: Individual",
@@ -4062,6 +5493,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.i.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectPropertyExpression,Individual,)) {
+            self.ope = state.0;
+            self.i = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4086,6 +5533,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectHasValue, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectHasValue {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectHasValue")?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("i", ToDict::to_dict(&self.i, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectHasValue {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectHasValue")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectHasValue")?;
+            Ok(ObjectHasValue{
+                ope: crate::json::field(&node, "ObjectHasValue", "ope")?,
+                i: crate::json::field(&node, "ObjectHasValue", "i")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectHasValue);
+
 
     /**************** ENUM VARIANT ObjectHasSelf for ClassExpression ****************/
     #[doc = concat!("ObjectHasSelf(first: ObjectPropertyExpression",
@@ -4148,6 +5624,20 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+            self.0 = state.0;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4172,6 +5662,33 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectHasSelf, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectHasSelf {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectHasSelf")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectHasSelf {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectHasSelf")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectHasSelf")?;
+            Ok(ObjectHasSelf(
+                crate::json::field(&node, "ObjectHasSelf", "first")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(ObjectHasSelf);
+
 
     /**************** ENUM VARIANT ObjectMinCardinality for ClassExpression ****************/
     #[doc = concat!("ObjectMinCardinality(n: intope: ObjectPropertyExpressionbce: ClassExpression",
@@ -4253,6 +5770,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.bce.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,ObjectPropertyExpression,BoxWrap<ClassExpression>,)) {
+            self.n = state.0;
+            self.ope = state.1;
+            self.bce = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4267,16 +5802,47 @@ pub struct ClassExpression(ClassExpression_Inner);
             Ok(ObjectIntersectionOf(vec![self.clone().into(), ce].into()))
         }
 
-        fn __or__(&self, obj: &Bound<'_, PyAny>) -> PyResult<ObjectUnionOf> {
-            let ce: ClassExpression = obj.extract()?;
-            Ok(ObjectUnionOf(vec![self.clone().into(), ce].into()))
+        fn __or__(&self, obj: &Bound<'_, PyAny>) -> PyResult<ObjectUnionOf> {
+            let ce: ClassExpression = obj.extract()?;
+            Ok(ObjectUnionOf(vec![self.clone().into(), ce].into()))
+        }
+
+        fn __invert__(&self) -> ObjectComplementOf {
+            ObjectComplementOf(Box::<ClassExpression>::new(self.clone().into()).into())
+        }
+    }
+
+    crate::signature_methods!(ObjectMinCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectMinCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectMinCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("bce", ToDict::to_dict(&self.bce, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectMinCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectMinCardinality")
         }
 
-        fn __invert__(&self) -> ObjectComplementOf {
-            ObjectComplementOf(Box::<ClassExpression>::new(self.clone().into()).into())
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectMinCardinality")?;
+            Ok(ObjectMinCardinality{
+                n: crate::json::field(&node, "ObjectMinCardinality", "n")?,
+                ope: crate::json::field(&node, "ObjectMinCardinality", "ope")?,
+                bce: crate::json::field(&node, "ObjectMinCardinality", "bce")?,
+            })
         }
     }
 
+    crate::json_methods!(ObjectMinCardinality);
+
 
     /**************** ENUM VARIANT ObjectMaxCardinality for ClassExpression ****************/
     #[doc = concat!("ObjectMaxCardinality(n: intope: ObjectPropertyExpressionbce: ClassExpression",
@@ -4358,6 +5924,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.bce.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,ObjectPropertyExpression,BoxWrap<ClassExpression>,)) {
+            self.n = state.0;
+            self.ope = state.1;
+            self.bce = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4382,6 +5966,37 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectMaxCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectMaxCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectMaxCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("bce", ToDict::to_dict(&self.bce, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectMaxCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectMaxCardinality")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectMaxCardinality")?;
+            Ok(ObjectMaxCardinality{
+                n: crate::json::field(&node, "ObjectMaxCardinality", "n")?,
+                ope: crate::json::field(&node, "ObjectMaxCardinality", "ope")?,
+                bce: crate::json::field(&node, "ObjectMaxCardinality", "bce")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectMaxCardinality);
+
 
     /**************** ENUM VARIANT ObjectExactCardinality for ClassExpression ****************/
     #[doc = concat!("ObjectExactCardinality(n: intope: ObjectPropertyExpressionbce: ClassExpression",
@@ -4463,6 +6078,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.ope.clone().into_pyobject(py)?.into_any(),
+                self.bce.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,ObjectPropertyExpression,BoxWrap<ClassExpression>,)) {
+            self.n = state.0;
+            self.ope = state.1;
+            self.bce = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4487,6 +6120,37 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(ObjectExactCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectExactCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectExactCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+            node.set_item("bce", ToDict::to_dict(&self.bce, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectExactCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectExactCardinality")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectExactCardinality")?;
+            Ok(ObjectExactCardinality{
+                n: crate::json::field(&node, "ObjectExactCardinality", "n")?,
+                ope: crate::json::field(&node, "ObjectExactCardinality", "ope")?,
+                bce: crate::json::field(&node, "ObjectExactCardinality", "bce")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectExactCardinality);
+
 
     /**************** ENUM VARIANT DataSomeValuesFrom for ClassExpression ****************/
     #[doc = concat!("DataSomeValuesFrom(dp: DataPropertydr: DataRange",
@@ -4558,6 +6222,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.dr.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (DataProperty,DataRange,)) {
+            self.dp = state.0;
+            self.dr = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4582,6 +6262,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataSomeValuesFrom, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataSomeValuesFrom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataSomeValuesFrom")?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataSomeValuesFrom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataSomeValuesFrom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataSomeValuesFrom")?;
+            Ok(DataSomeValuesFrom{
+                dp: crate::json::field(&node, "DataSomeValuesFrom", "dp")?,
+                dr: crate::json::field(&node, "DataSomeValuesFrom", "dr")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataSomeValuesFrom);
+
 
     /**************** ENUM VARIANT DataAllValuesFrom for ClassExpression ****************/
     #[doc = concat!("DataAllValuesFrom(dp: DataPropertydr: DataRange",
@@ -4653,6 +6362,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.dr.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (DataProperty,DataRange,)) {
+            self.dp = state.0;
+            self.dr = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4677,6 +6402,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataAllValuesFrom, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataAllValuesFrom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataAllValuesFrom")?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataAllValuesFrom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataAllValuesFrom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataAllValuesFrom")?;
+            Ok(DataAllValuesFrom{
+                dp: crate::json::field(&node, "DataAllValuesFrom", "dp")?,
+                dr: crate::json::field(&node, "DataAllValuesFrom", "dr")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataAllValuesFrom);
+
 
     /**************** ENUM VARIANT DataHasValue for ClassExpression ****************/
     #[doc = concat!("DataHasValue(dp: DataPropertyl: Literal",
@@ -4748,6 +6502,22 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.l.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (DataProperty,Literal,)) {
+            self.dp = state.0;
+            self.l = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4772,6 +6542,35 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataHasValue, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataHasValue {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataHasValue")?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("l", ToDict::to_dict(&self.l, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataHasValue {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataHasValue")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataHasValue")?;
+            Ok(DataHasValue{
+                dp: crate::json::field(&node, "DataHasValue", "dp")?,
+                l: crate::json::field(&node, "DataHasValue", "l")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataHasValue);
+
 
     /**************** ENUM VARIANT DataMinCardinality for ClassExpression ****************/
     #[doc = concat!("DataMinCardinality(n: intdp: DataPropertydr: DataRange",
@@ -4853,6 +6652,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.dr.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,DataProperty,DataRange,)) {
+            self.n = state.0;
+            self.dp = state.1;
+            self.dr = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4877,6 +6694,37 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataMinCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataMinCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataMinCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataMinCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataMinCardinality")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataMinCardinality")?;
+            Ok(DataMinCardinality{
+                n: crate::json::field(&node, "DataMinCardinality", "n")?,
+                dp: crate::json::field(&node, "DataMinCardinality", "dp")?,
+                dr: crate::json::field(&node, "DataMinCardinality", "dr")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataMinCardinality);
+
 
     /**************** ENUM VARIANT DataMaxCardinality for ClassExpression ****************/
     #[doc = concat!("DataMaxCardinality(n: intdp: DataPropertydr: DataRange",
@@ -4958,6 +6806,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.dr.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,DataProperty,DataRange,)) {
+            self.n = state.0;
+            self.dp = state.1;
+            self.dr = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -4982,6 +6848,37 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataMaxCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataMaxCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataMaxCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataMaxCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataMaxCardinality")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataMaxCardinality")?;
+            Ok(DataMaxCardinality{
+                n: crate::json::field(&node, "DataMaxCardinality", "n")?,
+                dp: crate::json::field(&node, "DataMaxCardinality", "dp")?,
+                dr: crate::json::field(&node, "DataMaxCardinality", "dr")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataMaxCardinality);
+
 
     /**************** ENUM VARIANT DataExactCardinality for ClassExpression ****************/
     #[doc = concat!("DataExactCardinality(n: intdp: DataPropertydr: DataRange",
@@ -5063,6 +6960,24 @@ pub struct ClassExpression(ClassExpression_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.n.clone().into_pyobject(py)?.into_any(),
+                self.dp.clone().into_pyobject(py)?.into_any(),
+                self.dr.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (u32,DataProperty,DataRange,)) {
+            self.n = state.0;
+            self.dp = state.1;
+            self.dr = state.2;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::ClassExpression<ArcStr>>::into(Into::<ClassExpression>::into(self.clone())).as_functional().to_string()
         }
@@ -5087,6 +7002,37 @@ pub struct ClassExpression(ClassExpression_Inner);
         }
     }
 
+    crate::signature_methods!(DataExactCardinality, this => crate::signature::Signature::of(
+        &horned_owl::model::ClassExpression::<ArcStr>::from(&ClassExpression::from(this.clone()))
+    ));
+
+    impl ToDict for DataExactCardinality {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataExactCardinality")?;
+            node.set_item("n", ToDict::to_dict(&self.n, py)?)?;
+            node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+            node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataExactCardinality {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataExactCardinality")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataExactCardinality")?;
+            Ok(DataExactCardinality{
+                n: crate::json::field(&node, "DataExactCardinality", "n")?,
+                dp: crate::json::field(&node, "DataExactCardinality", "dp")?,
+                dr: crate::json::field(&node, "DataExactCardinality", "dr")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataExactCardinality);
+
     // Transparent variant implementation
     impl From<Class> for ClassExpression {
         fn from(value: Class) -> Self {
@@ -5350,6 +7296,10 @@ impl<'py> IntoPyObject<'py> for ClassExpression {
 
 impl <'py> FromPyObject<'py> for ClassExpression {
     fn extract_bound(ob: &Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        if let Ok(var) = ob.downcast::<Var>() {
+            let inner = ClassExpression_Inner::Class(FromVar::from_var(&*var.borrow()));
+            return Ok(ClassExpression(inner));
+        }
             {
             	let r = Class::extract_bound(ob);
                 if let Ok(local) = r {
@@ -5481,6 +7431,113 @@ impl <'py> FromPyObject<'py> for ClassExpression {
     }
 }
 
+impl ToDict for ClassExpression {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.0 {
+            ClassExpression_Inner::Class(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectIntersectionOf(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectUnionOf(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectComplementOf(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectOneOf(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectSomeValuesFrom(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectAllValuesFrom(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectHasValue(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectHasSelf(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectMinCardinality(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectMaxCardinality(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::ObjectExactCardinality(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataSomeValuesFrom(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataAllValuesFrom(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataHasValue(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataMinCardinality(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataMaxCardinality(val) => ToDict::to_dict(val, py),
+            ClassExpression_Inner::DataExactCardinality(val) => ToDict::to_dict(val, py),
+        }
+    }
+}
+
+impl FromDict for ClassExpression {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        Class::accepts(value) ||
+        ObjectIntersectionOf::accepts(value) ||
+        ObjectUnionOf::accepts(value) ||
+        ObjectComplementOf::accepts(value) ||
+        ObjectOneOf::accepts(value) ||
+        ObjectSomeValuesFrom::accepts(value) ||
+        ObjectAllValuesFrom::accepts(value) ||
+        ObjectHasValue::accepts(value) ||
+        ObjectHasSelf::accepts(value) ||
+        ObjectMinCardinality::accepts(value) ||
+        ObjectMaxCardinality::accepts(value) ||
+        ObjectExactCardinality::accepts(value) ||
+        DataSomeValuesFrom::accepts(value) ||
+        DataAllValuesFrom::accepts(value) ||
+        DataHasValue::accepts(value) ||
+        DataMinCardinality::accepts(value) ||
+        DataMaxCardinality::accepts(value) ||
+        DataExactCardinality::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if Class::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::Class(<Class as FromDict>::from_dict(value)?)));
+        }
+        if ObjectIntersectionOf::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectIntersectionOf(<ObjectIntersectionOf as FromDict>::from_dict(value)?)));
+        }
+        if ObjectUnionOf::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectUnionOf(<ObjectUnionOf as FromDict>::from_dict(value)?)));
+        }
+        if ObjectComplementOf::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectComplementOf(<ObjectComplementOf as FromDict>::from_dict(value)?)));
+        }
+        if ObjectOneOf::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectOneOf(<ObjectOneOf as FromDict>::from_dict(value)?)));
+        }
+        if ObjectSomeValuesFrom::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectSomeValuesFrom(<ObjectSomeValuesFrom as FromDict>::from_dict(value)?)));
+        }
+        if ObjectAllValuesFrom::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectAllValuesFrom(<ObjectAllValuesFrom as FromDict>::from_dict(value)?)));
+        }
+        if ObjectHasValue::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectHasValue(<ObjectHasValue as FromDict>::from_dict(value)?)));
+        }
+        if ObjectHasSelf::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectHasSelf(<ObjectHasSelf as FromDict>::from_dict(value)?)));
+        }
+        if ObjectMinCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectMinCardinality(<ObjectMinCardinality as FromDict>::from_dict(value)?)));
+        }
+        if ObjectMaxCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectMaxCardinality(<ObjectMaxCardinality as FromDict>::from_dict(value)?)));
+        }
+        if ObjectExactCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::ObjectExactCardinality(<ObjectExactCardinality as FromDict>::from_dict(value)?)));
+        }
+        if DataSomeValuesFrom::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataSomeValuesFrom(<DataSomeValuesFrom as FromDict>::from_dict(value)?)));
+        }
+        if DataAllValuesFrom::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataAllValuesFrom(<DataAllValuesFrom as FromDict>::from_dict(value)?)));
+        }
+        if DataHasValue::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataHasValue(<DataHasValue as FromDict>::from_dict(value)?)));
+        }
+        if DataMinCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataMinCardinality(<DataMinCardinality as FromDict>::from_dict(value)?)));
+        }
+        if DataMaxCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataMaxCardinality(<DataMaxCardinality as FromDict>::from_dict(value)?)));
+        }
+        if DataExactCardinality::accepts(value) {
+            return Ok(ClassExpression(ClassExpression_Inner::DataExactCardinality(<DataExactCardinality as FromDict>::from_dict(value)?)));
+        }
+
+        crate::json::invalid(value, "ClassExpression")
+    }
+}
+
 impl ClassExpression {
     pub fn py_def() -> String {
         "typing.Union[m.Class,m.ObjectIntersectionOf,m.ObjectUnionOf,m.ObjectComplementOf,m.ObjectOneOf,m.ObjectSomeValuesFrom,m.ObjectAllValuesFrom,m.ObjectHasValue,m.ObjectHasSelf,m.ObjectMinCardinality,m.ObjectMaxCardinality,m.ObjectExactCardinality,m.DataSomeValuesFrom,m.DataAllValuesFrom,m.DataHasValue,m.DataMinCardinality,m.DataMaxCardinality,m.DataExactCardinality,]".into()
@@ -5617,6 +7674,7 @@ impl FromCompatible<&Vec<horned_owl::model::ClassExpression<ArcStr>>> for VecWra
         VecWrap::<ClassExpression>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PropertyExpression {
     
@@ -5679,6 +7737,38 @@ impl From<&horned_owl::model::PropertyExpression<ArcStr>> for PropertyExpression
 }
 
 
+impl ToDict for PropertyExpression {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            PropertyExpression::ObjectPropertyExpression(inner) => ToDict::to_dict(inner, py),
+            PropertyExpression::DataProperty(inner) => ToDict::to_dict(inner, py),
+            PropertyExpression::AnnotationProperty(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for PropertyExpression {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <ObjectPropertyExpression>::accepts(value) ||
+        <DataProperty>::accepts(value) ||
+        <AnnotationProperty>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <ObjectPropertyExpression>::accepts(value) {
+            return Ok(PropertyExpression::ObjectPropertyExpression(<ObjectPropertyExpression as FromDict>::from_dict(value)?));
+        }
+        if <DataProperty>::accepts(value) {
+            return Ok(PropertyExpression::DataProperty(<DataProperty as FromDict>::from_dict(value)?));
+        }
+        if <AnnotationProperty>::accepts(value) {
+            return Ok(PropertyExpression::AnnotationProperty(<AnnotationProperty as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "PropertyExpression")
+    }
+}
+
 impl PropertyExpression {
     pub fn py_def() -> String {
         "typing.Union[m.ObjectPropertyExpression,m.DataProperty,m.AnnotationProperty,]".into()
@@ -5815,6 +7905,7 @@ impl FromCompatible<&Vec<horned_owl::model::PropertyExpression<ArcStr>>> for Vec
         VecWrap::<PropertyExpression>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AnnotationSubject {
     
@@ -5868,6 +7959,33 @@ impl From<&horned_owl::model::AnnotationSubject<ArcStr>> for AnnotationSubject {
 }
 
 
+impl ToDict for AnnotationSubject {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            AnnotationSubject::IRI(inner) => ToDict::to_dict(inner, py),
+            AnnotationSubject::AnonymousIndividual(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for AnnotationSubject {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <IRI>::accepts(value) ||
+        <AnonymousIndividual>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <IRI>::accepts(value) {
+            return Ok(AnnotationSubject::IRI(<IRI as FromDict>::from_dict(value)?));
+        }
+        if <AnonymousIndividual>::accepts(value) {
+            return Ok(AnnotationSubject::AnonymousIndividual(<AnonymousIndividual as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "AnnotationSubject")
+    }
+}
+
 impl AnnotationSubject {
     pub fn py_def() -> String {
         "typing.Union[m.IRI,m.AnonymousIndividual,]".into()
@@ -6040,6 +8158,20 @@ impl AnnotationProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AnnotationProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -6062,6 +8194,31 @@ impl From<&AnnotationProperty> for horned_owl::model::AnnotationProperty<ArcStr>
     }
 }
 
+crate::signature_methods!(AnnotationProperty, this => crate::signature::Signature::of(&horned_owl::model::AnnotationProperty::<ArcStr>::from(this)));
+
+impl ToDict for AnnotationProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnnotationProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnnotationProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnnotationProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnnotationProperty")?;
+        Ok(AnnotationProperty(
+            crate::json::field(&node, "AnnotationProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(AnnotationProperty);
+
 
 
 /**************** Base implementations for AnnotationProperty ****************/
@@ -6192,6 +8349,7 @@ impl FromCompatible<&Vec<horned_owl::model::AnnotationProperty<ArcStr>>> for Vec
         VecWrap::<AnnotationProperty>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AnnotationValue {
     
@@ -6254,6 +8412,38 @@ impl From<&horned_owl::model::AnnotationValue<ArcStr>> for AnnotationValue {
 }
 
 
+impl ToDict for AnnotationValue {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            AnnotationValue::Literal(inner) => ToDict::to_dict(inner, py),
+            AnnotationValue::IRI(inner) => ToDict::to_dict(inner, py),
+            AnnotationValue::AnonymousIndividual(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for AnnotationValue {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <Literal>::accepts(value) ||
+        <IRI>::accepts(value) ||
+        <AnonymousIndividual>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <Literal>::accepts(value) {
+            return Ok(AnnotationValue::Literal(<Literal as FromDict>::from_dict(value)?));
+        }
+        if <IRI>::accepts(value) {
+            return Ok(AnnotationValue::IRI(<IRI as FromDict>::from_dict(value)?));
+        }
+        if <AnonymousIndividual>::accepts(value) {
+            return Ok(AnnotationValue::AnonymousIndividual(<AnonymousIndividual as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "AnnotationValue")
+    }
+}
+
 impl AnnotationValue {
     pub fn py_def() -> String {
         "typing.Union[m.Literal,m.IRI,m.AnonymousIndividual,]".into()
@@ -6455,6 +8645,22 @@ impl Annotation {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ap.clone().into_pyobject(py)?.into_any(),
+            self.av.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationProperty,AnnotationValue,)) {
+        self.ap = state.0;
+        self.av = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::Annotation<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -6479,6 +8685,33 @@ impl From<&Annotation> for horned_owl::model::Annotation<ArcStr> {
     }
 }
 
+crate::signature_methods!(Annotation, this => crate::signature::Signature::of(&horned_owl::model::Annotation::<ArcStr>::from(this)));
+
+impl ToDict for Annotation {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Annotation")?;
+        node.set_item("ap", ToDict::to_dict(&self.ap, py)?)?;
+        node.set_item("av", ToDict::to_dict(&self.av, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Annotation {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Annotation")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Annotation")?;
+        Ok(Annotation {
+            ap: crate::json::field(&node, "Annotation", "ap")?,
+            av: crate::json::field(&node, "Annotation", "av")?,
+        })
+    }
+}
+
+crate::json_methods!(Annotation);
+
 
 
 /**************** Base implementations for Annotation ****************/
@@ -6645,6 +8878,20 @@ impl OntologyAnnotation {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Annotation,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::OntologyAnnotation<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -6667,6 +8914,31 @@ impl From<&OntologyAnnotation> for horned_owl::model::OntologyAnnotation<ArcStr>
     }
 }
 
+crate::signature_methods!(OntologyAnnotation, this => crate::signature::Signature::of(&horned_owl::model::OntologyAnnotation::<ArcStr>::from(this)));
+
+impl ToDict for OntologyAnnotation {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "OntologyAnnotation")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for OntologyAnnotation {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "OntologyAnnotation")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "OntologyAnnotation")?;
+        Ok(OntologyAnnotation(
+            crate::json::field(&node, "OntologyAnnotation", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(OntologyAnnotation);
+
 
 
 /**************** Base implementations for OntologyAnnotation ****************/
@@ -6833,6 +9105,20 @@ impl Import {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::Import<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -6855,6 +9141,31 @@ impl From<&Import> for horned_owl::model::Import<ArcStr> {
     }
 }
 
+crate::signature_methods!(Import, this => crate::signature::Signature::of(&horned_owl::model::Import::<ArcStr>::from(this)));
+
+impl ToDict for Import {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Import")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Import {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Import")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Import")?;
+        Ok(Import(
+            crate::json::field(&node, "Import", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(Import);
+
 
 
 /**************** Base implementations for Import ****************/
@@ -7021,6 +9332,20 @@ impl DeclareClass {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Class,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareClass<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7043,6 +9368,31 @@ impl From<&DeclareClass> for horned_owl::model::DeclareClass<ArcStr> {
     }
 }
 
+crate::signature_methods!(DeclareClass, this => crate::signature::Signature::of(&horned_owl::model::DeclareClass::<ArcStr>::from(this)));
+
+impl ToDict for DeclareClass {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareClass")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareClass {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareClass")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareClass")?;
+        Ok(DeclareClass(
+            crate::json::field(&node, "DeclareClass", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareClass);
+
 
 
 /**************** Base implementations for DeclareClass ****************/
@@ -7209,6 +9559,20 @@ impl DeclareObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectProperty,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7231,6 +9595,31 @@ impl From<&DeclareObjectProperty> for horned_owl::model::DeclareObjectProperty<A
     }
 }
 
+crate::signature_methods!(DeclareObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::DeclareObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for DeclareObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareObjectProperty")?;
+        Ok(DeclareObjectProperty(
+            crate::json::field(&node, "DeclareObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareObjectProperty);
+
 
 
 /**************** Base implementations for DeclareObjectProperty ****************/
@@ -7397,6 +9786,20 @@ impl DeclareAnnotationProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationProperty,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareAnnotationProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7419,6 +9822,31 @@ impl From<&DeclareAnnotationProperty> for horned_owl::model::DeclareAnnotationPr
     }
 }
 
+crate::signature_methods!(DeclareAnnotationProperty, this => crate::signature::Signature::of(&horned_owl::model::DeclareAnnotationProperty::<ArcStr>::from(this)));
+
+impl ToDict for DeclareAnnotationProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareAnnotationProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareAnnotationProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareAnnotationProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareAnnotationProperty")?;
+        Ok(DeclareAnnotationProperty(
+            crate::json::field(&node, "DeclareAnnotationProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareAnnotationProperty);
+
 
 
 /**************** Base implementations for DeclareAnnotationProperty ****************/
@@ -7585,6 +10013,20 @@ impl DeclareDataProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareDataProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7607,6 +10049,31 @@ impl From<&DeclareDataProperty> for horned_owl::model::DeclareDataProperty<ArcSt
     }
 }
 
+crate::signature_methods!(DeclareDataProperty, this => crate::signature::Signature::of(&horned_owl::model::DeclareDataProperty::<ArcStr>::from(this)));
+
+impl ToDict for DeclareDataProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareDataProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareDataProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareDataProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareDataProperty")?;
+        Ok(DeclareDataProperty(
+            crate::json::field(&node, "DeclareDataProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareDataProperty);
+
 
 
 /**************** Base implementations for DeclareDataProperty ****************/
@@ -7773,6 +10240,20 @@ impl DeclareNamedIndividual {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (NamedIndividual,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareNamedIndividual<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7795,6 +10276,31 @@ impl From<&DeclareNamedIndividual> for horned_owl::model::DeclareNamedIndividual
     }
 }
 
+crate::signature_methods!(DeclareNamedIndividual, this => crate::signature::Signature::of(&horned_owl::model::DeclareNamedIndividual::<ArcStr>::from(this)));
+
+impl ToDict for DeclareNamedIndividual {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareNamedIndividual")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareNamedIndividual {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareNamedIndividual")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareNamedIndividual")?;
+        Ok(DeclareNamedIndividual(
+            crate::json::field(&node, "DeclareNamedIndividual", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareNamedIndividual);
+
 
 
 /**************** Base implementations for DeclareNamedIndividual ****************/
@@ -7961,6 +10467,20 @@ impl DeclareDatatype {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Datatype,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DeclareDatatype<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -7983,6 +10503,31 @@ impl From<&DeclareDatatype> for horned_owl::model::DeclareDatatype<ArcStr> {
     }
 }
 
+crate::signature_methods!(DeclareDatatype, this => crate::signature::Signature::of(&horned_owl::model::DeclareDatatype::<ArcStr>::from(this)));
+
+impl ToDict for DeclareDatatype {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DeclareDatatype")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DeclareDatatype {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DeclareDatatype")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DeclareDatatype")?;
+        Ok(DeclareDatatype(
+            crate::json::field(&node, "DeclareDatatype", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DeclareDatatype);
+
 
 
 /**************** Base implementations for DeclareDatatype ****************/
@@ -8178,6 +10723,22 @@ impl SubClassOf {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.sub.clone().into_pyobject(py)?.into_any(),
+            self.sup.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ClassExpression,ClassExpression,)) {
+        self.sub = state.0;
+        self.sup = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SubClassOf<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -8202,6 +10763,33 @@ impl From<&SubClassOf> for horned_owl::model::SubClassOf<ArcStr> {
     }
 }
 
+crate::signature_methods!(SubClassOf, this => crate::signature::Signature::of(&horned_owl::model::SubClassOf::<ArcStr>::from(this)));
+
+impl ToDict for SubClassOf {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SubClassOf")?;
+        node.set_item("sub", ToDict::to_dict(&self.sub, py)?)?;
+        node.set_item("sup", ToDict::to_dict(&self.sup, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SubClassOf {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SubClassOf")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SubClassOf")?;
+        Ok(SubClassOf {
+            sub: crate::json::field(&node, "SubClassOf", "sub")?,
+            sup: crate::json::field(&node, "SubClassOf", "sup")?,
+        })
+    }
+}
+
+crate::json_methods!(SubClassOf);
+
 
 
 /**************** Base implementations for SubClassOf ****************/
@@ -8368,6 +10956,20 @@ impl EquivalentClasses {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<ClassExpression>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::EquivalentClasses<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -8390,6 +10992,31 @@ impl From<&EquivalentClasses> for horned_owl::model::EquivalentClasses<ArcStr> {
     }
 }
 
+crate::signature_methods!(EquivalentClasses, this => crate::signature::Signature::of(&horned_owl::model::EquivalentClasses::<ArcStr>::from(this)));
+
+impl ToDict for EquivalentClasses {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "EquivalentClasses")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for EquivalentClasses {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "EquivalentClasses")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "EquivalentClasses")?;
+        Ok(EquivalentClasses(
+            crate::json::field(&node, "EquivalentClasses", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(EquivalentClasses);
+
 
 
 /**************** Base implementations for EquivalentClasses ****************/
@@ -8556,6 +11183,20 @@ impl DisjointClasses {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<ClassExpression>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DisjointClasses<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -8578,6 +11219,31 @@ impl From<&DisjointClasses> for horned_owl::model::DisjointClasses<ArcStr> {
     }
 }
 
+crate::signature_methods!(DisjointClasses, this => crate::signature::Signature::of(&horned_owl::model::DisjointClasses::<ArcStr>::from(this)));
+
+impl ToDict for DisjointClasses {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DisjointClasses")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DisjointClasses {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DisjointClasses")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DisjointClasses")?;
+        Ok(DisjointClasses(
+            crate::json::field(&node, "DisjointClasses", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DisjointClasses);
+
 
 
 /**************** Base implementations for DisjointClasses ****************/
@@ -8748,6 +11414,22 @@ impl DisjointUnion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+            self.1.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Class,VecWrap<ClassExpression>,)) {
+        self.0 = state.0;
+        self.1 = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DisjointUnion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -8772,6 +11454,33 @@ impl From<&DisjointUnion> for horned_owl::model::DisjointUnion<ArcStr> {
     }
 }
 
+crate::signature_methods!(DisjointUnion, this => crate::signature::Signature::of(&horned_owl::model::DisjointUnion::<ArcStr>::from(this)));
+
+impl ToDict for DisjointUnion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DisjointUnion")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        node.set_item("second", ToDict::to_dict(&self.1, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DisjointUnion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DisjointUnion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DisjointUnion")?;
+        Ok(DisjointUnion(
+            crate::json::field(&node, "DisjointUnion", "first")?,
+            crate::json::field(&node, "DisjointUnion", "second")?,
+        ))
+    }
+}
+
+crate::json_methods!(DisjointUnion);
+
 
 
 /**************** Base implementations for DisjointUnion ****************/
@@ -8902,6 +11611,7 @@ impl FromCompatible<&Vec<horned_owl::model::DisjointUnion<ArcStr>>> for VecWrap<
         VecWrap::<DisjointUnion>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SubObjectPropertyExpression {
     
@@ -8955,6 +11665,33 @@ impl From<&horned_owl::model::SubObjectPropertyExpression<ArcStr>> for SubObject
 }
 
 
+impl ToDict for SubObjectPropertyExpression {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            SubObjectPropertyExpression::ObjectPropertyChain(inner) => ToDict::to_dict(inner, py),
+            SubObjectPropertyExpression::ObjectPropertyExpression(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for SubObjectPropertyExpression {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <VecWrap<ObjectPropertyExpression>>::accepts(value) ||
+        <ObjectPropertyExpression>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <VecWrap<ObjectPropertyExpression>>::accepts(value) {
+            return Ok(SubObjectPropertyExpression::ObjectPropertyChain(<VecWrap<ObjectPropertyExpression> as FromDict>::from_dict(value)?));
+        }
+        if <ObjectPropertyExpression>::accepts(value) {
+            return Ok(SubObjectPropertyExpression::ObjectPropertyExpression(<ObjectPropertyExpression as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "SubObjectPropertyExpression")
+    }
+}
+
 impl SubObjectPropertyExpression {
     pub fn py_def() -> String {
         "typing.Union[typing.List[ObjectPropertyExpression],m.ObjectPropertyExpression,]".into()
@@ -9156,6 +11893,22 @@ impl SubObjectPropertyOf {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.sub.clone().into_pyobject(py)?.into_any(),
+            self.sup.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (SubObjectPropertyExpression,ObjectPropertyExpression,)) {
+        self.sub = state.0;
+        self.sup = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SubObjectPropertyOf<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -9180,6 +11933,33 @@ impl From<&SubObjectPropertyOf> for horned_owl::model::SubObjectPropertyOf<ArcSt
     }
 }
 
+crate::signature_methods!(SubObjectPropertyOf, this => crate::signature::Signature::of(&horned_owl::model::SubObjectPropertyOf::<ArcStr>::from(this)));
+
+impl ToDict for SubObjectPropertyOf {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SubObjectPropertyOf")?;
+        node.set_item("sub", ToDict::to_dict(&self.sub, py)?)?;
+        node.set_item("sup", ToDict::to_dict(&self.sup, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SubObjectPropertyOf {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SubObjectPropertyOf")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SubObjectPropertyOf")?;
+        Ok(SubObjectPropertyOf {
+            sub: crate::json::field(&node, "SubObjectPropertyOf", "sub")?,
+            sup: crate::json::field(&node, "SubObjectPropertyOf", "sup")?,
+        })
+    }
+}
+
+crate::json_methods!(SubObjectPropertyOf);
+
 
 
 /**************** Base implementations for SubObjectPropertyOf ****************/
@@ -9346,6 +12126,20 @@ impl EquivalentObjectProperties {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<ObjectPropertyExpression>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::EquivalentObjectProperties<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -9368,6 +12162,31 @@ impl From<&EquivalentObjectProperties> for horned_owl::model::EquivalentObjectPr
     }
 }
 
+crate::signature_methods!(EquivalentObjectProperties, this => crate::signature::Signature::of(&horned_owl::model::EquivalentObjectProperties::<ArcStr>::from(this)));
+
+impl ToDict for EquivalentObjectProperties {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "EquivalentObjectProperties")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for EquivalentObjectProperties {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "EquivalentObjectProperties")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "EquivalentObjectProperties")?;
+        Ok(EquivalentObjectProperties(
+            crate::json::field(&node, "EquivalentObjectProperties", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(EquivalentObjectProperties);
+
 
 
 /**************** Base implementations for EquivalentObjectProperties ****************/
@@ -9534,6 +12353,20 @@ impl DisjointObjectProperties {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<ObjectPropertyExpression>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DisjointObjectProperties<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -9548,14 +12381,39 @@ impl From<&horned_owl::model::DisjointObjectProperties<ArcStr>> for DisjointObje
     }
 }
 
-impl From<&DisjointObjectProperties> for horned_owl::model::DisjointObjectProperties<ArcStr> {
-    fn from(value: &DisjointObjectProperties) -> Self {
-        horned_owl::model::DisjointObjectProperties::<ArcStr> (
-    IntoCompatible::into_c(&value.0),
-        )
+impl From<&DisjointObjectProperties> for horned_owl::model::DisjointObjectProperties<ArcStr> {
+    fn from(value: &DisjointObjectProperties) -> Self {
+        horned_owl::model::DisjointObjectProperties::<ArcStr> (
+    IntoCompatible::into_c(&value.0),
+        )
+    }
+}
+
+crate::signature_methods!(DisjointObjectProperties, this => crate::signature::Signature::of(&horned_owl::model::DisjointObjectProperties::<ArcStr>::from(this)));
+
+impl ToDict for DisjointObjectProperties {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DisjointObjectProperties")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DisjointObjectProperties {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DisjointObjectProperties")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DisjointObjectProperties")?;
+        Ok(DisjointObjectProperties(
+            crate::json::field(&node, "DisjointObjectProperties", "first")?,
+        ))
     }
 }
 
+crate::json_methods!(DisjointObjectProperties);
+
 
 
 /**************** Base implementations for DisjointObjectProperties ****************/
@@ -9726,6 +12584,22 @@ impl InverseObjectProperties {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+            self.1.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectProperty,ObjectProperty,)) {
+        self.0 = state.0;
+        self.1 = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::InverseObjectProperties<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -9750,6 +12624,33 @@ impl From<&InverseObjectProperties> for horned_owl::model::InverseObjectProperti
     }
 }
 
+crate::signature_methods!(InverseObjectProperties, this => crate::signature::Signature::of(&horned_owl::model::InverseObjectProperties::<ArcStr>::from(this)));
+
+impl ToDict for InverseObjectProperties {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "InverseObjectProperties")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        node.set_item("second", ToDict::to_dict(&self.1, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for InverseObjectProperties {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "InverseObjectProperties")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "InverseObjectProperties")?;
+        Ok(InverseObjectProperties(
+            crate::json::field(&node, "InverseObjectProperties", "first")?,
+            crate::json::field(&node, "InverseObjectProperties", "second")?,
+        ))
+    }
+}
+
+crate::json_methods!(InverseObjectProperties);
+
 
 
 /**************** Base implementations for InverseObjectProperties ****************/
@@ -9945,6 +12846,22 @@ impl ObjectPropertyDomain {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ope.clone().into_pyobject(py)?.into_any(),
+            self.ce.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,ClassExpression,)) {
+        self.ope = state.0;
+        self.ce = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::ObjectPropertyDomain<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -9969,6 +12886,33 @@ impl From<&ObjectPropertyDomain> for horned_owl::model::ObjectPropertyDomain<Arc
     }
 }
 
+crate::signature_methods!(ObjectPropertyDomain, this => crate::signature::Signature::of(&horned_owl::model::ObjectPropertyDomain::<ArcStr>::from(this)));
+
+impl ToDict for ObjectPropertyDomain {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ObjectPropertyDomain")?;
+        node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+        node.set_item("ce", ToDict::to_dict(&self.ce, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ObjectPropertyDomain {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ObjectPropertyDomain")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ObjectPropertyDomain")?;
+        Ok(ObjectPropertyDomain {
+            ope: crate::json::field(&node, "ObjectPropertyDomain", "ope")?,
+            ce: crate::json::field(&node, "ObjectPropertyDomain", "ce")?,
+        })
+    }
+}
+
+crate::json_methods!(ObjectPropertyDomain);
+
 
 
 /**************** Base implementations for ObjectPropertyDomain ****************/
@@ -10164,6 +13108,22 @@ impl ObjectPropertyRange {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ope.clone().into_pyobject(py)?.into_any(),
+            self.ce.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,ClassExpression,)) {
+        self.ope = state.0;
+        self.ce = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::ObjectPropertyRange<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -10188,6 +13148,33 @@ impl From<&ObjectPropertyRange> for horned_owl::model::ObjectPropertyRange<ArcSt
     }
 }
 
+crate::signature_methods!(ObjectPropertyRange, this => crate::signature::Signature::of(&horned_owl::model::ObjectPropertyRange::<ArcStr>::from(this)));
+
+impl ToDict for ObjectPropertyRange {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ObjectPropertyRange")?;
+        node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+        node.set_item("ce", ToDict::to_dict(&self.ce, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ObjectPropertyRange {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ObjectPropertyRange")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ObjectPropertyRange")?;
+        Ok(ObjectPropertyRange {
+            ope: crate::json::field(&node, "ObjectPropertyRange", "ope")?,
+            ce: crate::json::field(&node, "ObjectPropertyRange", "ce")?,
+        })
+    }
+}
+
+crate::json_methods!(ObjectPropertyRange);
+
 
 
 /**************** Base implementations for ObjectPropertyRange ****************/
@@ -10354,6 +13341,20 @@ impl FunctionalObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::FunctionalObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -10376,6 +13377,31 @@ impl From<&FunctionalObjectProperty> for horned_owl::model::FunctionalObjectProp
     }
 }
 
+crate::signature_methods!(FunctionalObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::FunctionalObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for FunctionalObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "FunctionalObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for FunctionalObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "FunctionalObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "FunctionalObjectProperty")?;
+        Ok(FunctionalObjectProperty(
+            crate::json::field(&node, "FunctionalObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(FunctionalObjectProperty);
+
 
 
 /**************** Base implementations for FunctionalObjectProperty ****************/
@@ -10542,6 +13568,20 @@ impl InverseFunctionalObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::InverseFunctionalObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -10564,6 +13604,31 @@ impl From<&InverseFunctionalObjectProperty> for horned_owl::model::InverseFuncti
     }
 }
 
+crate::signature_methods!(InverseFunctionalObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::InverseFunctionalObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for InverseFunctionalObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "InverseFunctionalObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for InverseFunctionalObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "InverseFunctionalObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "InverseFunctionalObjectProperty")?;
+        Ok(InverseFunctionalObjectProperty(
+            crate::json::field(&node, "InverseFunctionalObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(InverseFunctionalObjectProperty);
+
 
 
 /**************** Base implementations for InverseFunctionalObjectProperty ****************/
@@ -10730,6 +13795,20 @@ impl ReflexiveObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::ReflexiveObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -10752,6 +13831,31 @@ impl From<&ReflexiveObjectProperty> for horned_owl::model::ReflexiveObjectProper
     }
 }
 
+crate::signature_methods!(ReflexiveObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::ReflexiveObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for ReflexiveObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ReflexiveObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ReflexiveObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ReflexiveObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ReflexiveObjectProperty")?;
+        Ok(ReflexiveObjectProperty(
+            crate::json::field(&node, "ReflexiveObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(ReflexiveObjectProperty);
+
 
 
 /**************** Base implementations for ReflexiveObjectProperty ****************/
@@ -10918,6 +14022,20 @@ impl IrreflexiveObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::IrreflexiveObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -10940,6 +14058,31 @@ impl From<&IrreflexiveObjectProperty> for horned_owl::model::IrreflexiveObjectPr
     }
 }
 
+crate::signature_methods!(IrreflexiveObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::IrreflexiveObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for IrreflexiveObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "IrreflexiveObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for IrreflexiveObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "IrreflexiveObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "IrreflexiveObjectProperty")?;
+        Ok(IrreflexiveObjectProperty(
+            crate::json::field(&node, "IrreflexiveObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(IrreflexiveObjectProperty);
+
 
 
 /**************** Base implementations for IrreflexiveObjectProperty ****************/
@@ -11106,6 +14249,20 @@ impl SymmetricObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SymmetricObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -11128,6 +14285,31 @@ impl From<&SymmetricObjectProperty> for horned_owl::model::SymmetricObjectProper
     }
 }
 
+crate::signature_methods!(SymmetricObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::SymmetricObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for SymmetricObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SymmetricObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SymmetricObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SymmetricObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SymmetricObjectProperty")?;
+        Ok(SymmetricObjectProperty(
+            crate::json::field(&node, "SymmetricObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(SymmetricObjectProperty);
+
 
 
 /**************** Base implementations for SymmetricObjectProperty ****************/
@@ -11294,6 +14476,20 @@ impl AsymmetricObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AsymmetricObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -11316,6 +14512,31 @@ impl From<&AsymmetricObjectProperty> for horned_owl::model::AsymmetricObjectProp
     }
 }
 
+crate::signature_methods!(AsymmetricObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::AsymmetricObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for AsymmetricObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AsymmetricObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AsymmetricObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AsymmetricObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AsymmetricObjectProperty")?;
+        Ok(AsymmetricObjectProperty(
+            crate::json::field(&node, "AsymmetricObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(AsymmetricObjectProperty);
+
 
 
 /**************** Base implementations for AsymmetricObjectProperty ****************/
@@ -11482,6 +14703,20 @@ impl TransitiveObjectProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::TransitiveObjectProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -11504,6 +14739,31 @@ impl From<&TransitiveObjectProperty> for horned_owl::model::TransitiveObjectProp
     }
 }
 
+crate::signature_methods!(TransitiveObjectProperty, this => crate::signature::Signature::of(&horned_owl::model::TransitiveObjectProperty::<ArcStr>::from(this)));
+
+impl ToDict for TransitiveObjectProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "TransitiveObjectProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for TransitiveObjectProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "TransitiveObjectProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "TransitiveObjectProperty")?;
+        Ok(TransitiveObjectProperty(
+            crate::json::field(&node, "TransitiveObjectProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(TransitiveObjectProperty);
+
 
 
 /**************** Base implementations for TransitiveObjectProperty ****************/
@@ -11699,6 +14959,22 @@ impl SubDataPropertyOf {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.sub.clone().into_pyobject(py)?.into_any(),
+            self.sup.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,DataProperty,)) {
+        self.sub = state.0;
+        self.sup = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SubDataPropertyOf<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -11723,6 +14999,33 @@ impl From<&SubDataPropertyOf> for horned_owl::model::SubDataPropertyOf<ArcStr> {
     }
 }
 
+crate::signature_methods!(SubDataPropertyOf, this => crate::signature::Signature::of(&horned_owl::model::SubDataPropertyOf::<ArcStr>::from(this)));
+
+impl ToDict for SubDataPropertyOf {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SubDataPropertyOf")?;
+        node.set_item("sub", ToDict::to_dict(&self.sub, py)?)?;
+        node.set_item("sup", ToDict::to_dict(&self.sup, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SubDataPropertyOf {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SubDataPropertyOf")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SubDataPropertyOf")?;
+        Ok(SubDataPropertyOf {
+            sub: crate::json::field(&node, "SubDataPropertyOf", "sub")?,
+            sup: crate::json::field(&node, "SubDataPropertyOf", "sup")?,
+        })
+    }
+}
+
+crate::json_methods!(SubDataPropertyOf);
+
 
 
 /**************** Base implementations for SubDataPropertyOf ****************/
@@ -11889,6 +15192,20 @@ impl EquivalentDataProperties {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<DataProperty>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::EquivalentDataProperties<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -11911,6 +15228,31 @@ impl From<&EquivalentDataProperties> for horned_owl::model::EquivalentDataProper
     }
 }
 
+crate::signature_methods!(EquivalentDataProperties, this => crate::signature::Signature::of(&horned_owl::model::EquivalentDataProperties::<ArcStr>::from(this)));
+
+impl ToDict for EquivalentDataProperties {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "EquivalentDataProperties")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for EquivalentDataProperties {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "EquivalentDataProperties")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "EquivalentDataProperties")?;
+        Ok(EquivalentDataProperties(
+            crate::json::field(&node, "EquivalentDataProperties", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(EquivalentDataProperties);
+
 
 
 /**************** Base implementations for EquivalentDataProperties ****************/
@@ -12077,6 +15419,20 @@ impl DisjointDataProperties {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<DataProperty>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DisjointDataProperties<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -12099,6 +15455,31 @@ impl From<&DisjointDataProperties> for horned_owl::model::DisjointDataProperties
     }
 }
 
+crate::signature_methods!(DisjointDataProperties, this => crate::signature::Signature::of(&horned_owl::model::DisjointDataProperties::<ArcStr>::from(this)));
+
+impl ToDict for DisjointDataProperties {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DisjointDataProperties")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DisjointDataProperties {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DisjointDataProperties")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DisjointDataProperties")?;
+        Ok(DisjointDataProperties(
+            crate::json::field(&node, "DisjointDataProperties", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DisjointDataProperties);
+
 
 
 /**************** Base implementations for DisjointDataProperties ****************/
@@ -12294,6 +15675,22 @@ impl DataPropertyDomain {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.dp.clone().into_pyobject(py)?.into_any(),
+            self.ce.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,ClassExpression,)) {
+        self.dp = state.0;
+        self.ce = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DataPropertyDomain<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -12318,6 +15715,33 @@ impl From<&DataPropertyDomain> for horned_owl::model::DataPropertyDomain<ArcStr>
     }
 }
 
+crate::signature_methods!(DataPropertyDomain, this => crate::signature::Signature::of(&horned_owl::model::DataPropertyDomain::<ArcStr>::from(this)));
+
+impl ToDict for DataPropertyDomain {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DataPropertyDomain")?;
+        node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+        node.set_item("ce", ToDict::to_dict(&self.ce, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DataPropertyDomain {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DataPropertyDomain")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DataPropertyDomain")?;
+        Ok(DataPropertyDomain {
+            dp: crate::json::field(&node, "DataPropertyDomain", "dp")?,
+            ce: crate::json::field(&node, "DataPropertyDomain", "ce")?,
+        })
+    }
+}
+
+crate::json_methods!(DataPropertyDomain);
+
 
 
 /**************** Base implementations for DataPropertyDomain ****************/
@@ -12513,6 +15937,22 @@ impl DataPropertyRange {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.dp.clone().into_pyobject(py)?.into_any(),
+            self.dr.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,DataRange,)) {
+        self.dp = state.0;
+        self.dr = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DataPropertyRange<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -12537,6 +15977,33 @@ impl From<&DataPropertyRange> for horned_owl::model::DataPropertyRange<ArcStr> {
     }
 }
 
+crate::signature_methods!(DataPropertyRange, this => crate::signature::Signature::of(&horned_owl::model::DataPropertyRange::<ArcStr>::from(this)));
+
+impl ToDict for DataPropertyRange {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DataPropertyRange")?;
+        node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+        node.set_item("dr", ToDict::to_dict(&self.dr, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DataPropertyRange {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DataPropertyRange")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DataPropertyRange")?;
+        Ok(DataPropertyRange {
+            dp: crate::json::field(&node, "DataPropertyRange", "dp")?,
+            dr: crate::json::field(&node, "DataPropertyRange", "dr")?,
+        })
+    }
+}
+
+crate::json_methods!(DataPropertyRange);
+
 
 
 /**************** Base implementations for DataPropertyRange ****************/
@@ -12703,6 +16170,20 @@ impl FunctionalDataProperty {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::FunctionalDataProperty<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -12725,6 +16206,31 @@ impl From<&FunctionalDataProperty> for horned_owl::model::FunctionalDataProperty
     }
 }
 
+crate::signature_methods!(FunctionalDataProperty, this => crate::signature::Signature::of(&horned_owl::model::FunctionalDataProperty::<ArcStr>::from(this)));
+
+impl ToDict for FunctionalDataProperty {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "FunctionalDataProperty")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for FunctionalDataProperty {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "FunctionalDataProperty")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "FunctionalDataProperty")?;
+        Ok(FunctionalDataProperty(
+            crate::json::field(&node, "FunctionalDataProperty", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(FunctionalDataProperty);
+
 
 
 /**************** Base implementations for FunctionalDataProperty ****************/
@@ -12920,6 +16426,22 @@ impl DatatypeDefinition {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.kind.clone().into_pyobject(py)?.into_any(),
+            self.range.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Datatype,DataRange,)) {
+        self.kind = state.0;
+        self.range = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DatatypeDefinition<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -12944,6 +16466,33 @@ impl From<&DatatypeDefinition> for horned_owl::model::DatatypeDefinition<ArcStr>
     }
 }
 
+crate::signature_methods!(DatatypeDefinition, this => crate::signature::Signature::of(&horned_owl::model::DatatypeDefinition::<ArcStr>::from(this)));
+
+impl ToDict for DatatypeDefinition {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DatatypeDefinition")?;
+        node.set_item("kind", ToDict::to_dict(&self.kind, py)?)?;
+        node.set_item("range", ToDict::to_dict(&self.range, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DatatypeDefinition {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DatatypeDefinition")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DatatypeDefinition")?;
+        Ok(DatatypeDefinition {
+            kind: crate::json::field(&node, "DatatypeDefinition", "kind")?,
+            range: crate::json::field(&node, "DatatypeDefinition", "range")?,
+        })
+    }
+}
+
+crate::json_methods!(DatatypeDefinition);
+
 
 
 /**************** Base implementations for DatatypeDefinition ****************/
@@ -13139,6 +16688,22 @@ impl HasKey {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ce.clone().into_pyobject(py)?.into_any(),
+            self.vpe.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ClassExpression,VecWrap<PropertyExpression>,)) {
+        self.ce = state.0;
+        self.vpe = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::HasKey<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -13163,6 +16728,33 @@ impl From<&HasKey> for horned_owl::model::HasKey<ArcStr> {
     }
 }
 
+crate::signature_methods!(HasKey, this => crate::signature::Signature::of(&horned_owl::model::HasKey::<ArcStr>::from(this)));
+
+impl ToDict for HasKey {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "HasKey")?;
+        node.set_item("ce", ToDict::to_dict(&self.ce, py)?)?;
+        node.set_item("vpe", ToDict::to_dict(&self.vpe, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for HasKey {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "HasKey")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "HasKey")?;
+        Ok(HasKey {
+            ce: crate::json::field(&node, "HasKey", "ce")?,
+            vpe: crate::json::field(&node, "HasKey", "vpe")?,
+        })
+    }
+}
+
+crate::json_methods!(HasKey);
+
 
 
 /**************** Base implementations for HasKey ****************/
@@ -13329,6 +16921,20 @@ impl SameIndividual {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<Individual>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SameIndividual<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -13351,6 +16957,31 @@ impl From<&SameIndividual> for horned_owl::model::SameIndividual<ArcStr> {
     }
 }
 
+crate::signature_methods!(SameIndividual, this => crate::signature::Signature::of(&horned_owl::model::SameIndividual::<ArcStr>::from(this)));
+
+impl ToDict for SameIndividual {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SameIndividual")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SameIndividual {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SameIndividual")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SameIndividual")?;
+        Ok(SameIndividual(
+            crate::json::field(&node, "SameIndividual", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(SameIndividual);
+
 
 
 /**************** Base implementations for SameIndividual ****************/
@@ -13517,6 +17148,20 @@ impl DifferentIndividuals {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<Individual>,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DifferentIndividuals<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -13539,6 +17184,31 @@ impl From<&DifferentIndividuals> for horned_owl::model::DifferentIndividuals<Arc
     }
 }
 
+crate::signature_methods!(DifferentIndividuals, this => crate::signature::Signature::of(&horned_owl::model::DifferentIndividuals::<ArcStr>::from(this)));
+
+impl ToDict for DifferentIndividuals {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DifferentIndividuals")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DifferentIndividuals {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DifferentIndividuals")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DifferentIndividuals")?;
+        Ok(DifferentIndividuals(
+            crate::json::field(&node, "DifferentIndividuals", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DifferentIndividuals);
+
 
 
 /**************** Base implementations for DifferentIndividuals ****************/
@@ -13734,6 +17404,22 @@ impl ClassAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ce.clone().into_pyobject(py)?.into_any(),
+            self.i.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ClassExpression,Individual,)) {
+        self.ce = state.0;
+        self.i = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::ClassAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -13758,6 +17444,33 @@ impl From<&ClassAssertion> for horned_owl::model::ClassAssertion<ArcStr> {
     }
 }
 
+crate::signature_methods!(ClassAssertion, this => crate::signature::Signature::of(&horned_owl::model::ClassAssertion::<ArcStr>::from(this)));
+
+impl ToDict for ClassAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ClassAssertion")?;
+        node.set_item("ce", ToDict::to_dict(&self.ce, py)?)?;
+        node.set_item("i", ToDict::to_dict(&self.i, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ClassAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ClassAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ClassAssertion")?;
+        Ok(ClassAssertion {
+            ce: crate::json::field(&node, "ClassAssertion", "ce")?,
+            i: crate::json::field(&node, "ClassAssertion", "i")?,
+        })
+    }
+}
+
+crate::json_methods!(ClassAssertion);
+
 
 
 /**************** Base implementations for ClassAssertion ****************/
@@ -13964,6 +17677,24 @@ impl ObjectPropertyAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ope.clone().into_pyobject(py)?.into_any(),
+            self.source.clone().into_pyobject(py)?.into_any(),
+            self.target.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,Individual,Individual,)) {
+        self.ope = state.0;
+        self.source = state.1;
+        self.target = state.2;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::ObjectPropertyAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -13990,6 +17721,35 @@ impl From<&ObjectPropertyAssertion> for horned_owl::model::ObjectPropertyAsserti
     }
 }
 
+crate::signature_methods!(ObjectPropertyAssertion, this => crate::signature::Signature::of(&horned_owl::model::ObjectPropertyAssertion::<ArcStr>::from(this)));
+
+impl ToDict for ObjectPropertyAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "ObjectPropertyAssertion")?;
+        node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+        node.set_item("source", ToDict::to_dict(&self.source, py)?)?;
+        node.set_item("target", ToDict::to_dict(&self.target, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for ObjectPropertyAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "ObjectPropertyAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "ObjectPropertyAssertion")?;
+        Ok(ObjectPropertyAssertion {
+            ope: crate::json::field(&node, "ObjectPropertyAssertion", "ope")?,
+            source: crate::json::field(&node, "ObjectPropertyAssertion", "source")?,
+            target: crate::json::field(&node, "ObjectPropertyAssertion", "target")?,
+        })
+    }
+}
+
+crate::json_methods!(ObjectPropertyAssertion);
+
 
 
 /**************** Base implementations for ObjectPropertyAssertion ****************/
@@ -14196,6 +17956,24 @@ impl NegativeObjectPropertyAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ope.clone().into_pyobject(py)?.into_any(),
+            self.source.clone().into_pyobject(py)?.into_any(),
+            self.target.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (ObjectPropertyExpression,Individual,Individual,)) {
+        self.ope = state.0;
+        self.source = state.1;
+        self.target = state.2;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::NegativeObjectPropertyAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -14222,6 +18000,35 @@ impl From<&NegativeObjectPropertyAssertion> for horned_owl::model::NegativeObjec
     }
 }
 
+crate::signature_methods!(NegativeObjectPropertyAssertion, this => crate::signature::Signature::of(&horned_owl::model::NegativeObjectPropertyAssertion::<ArcStr>::from(this)));
+
+impl ToDict for NegativeObjectPropertyAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "NegativeObjectPropertyAssertion")?;
+        node.set_item("ope", ToDict::to_dict(&self.ope, py)?)?;
+        node.set_item("source", ToDict::to_dict(&self.source, py)?)?;
+        node.set_item("target", ToDict::to_dict(&self.target, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for NegativeObjectPropertyAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "NegativeObjectPropertyAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "NegativeObjectPropertyAssertion")?;
+        Ok(NegativeObjectPropertyAssertion {
+            ope: crate::json::field(&node, "NegativeObjectPropertyAssertion", "ope")?,
+            source: crate::json::field(&node, "NegativeObjectPropertyAssertion", "source")?,
+            target: crate::json::field(&node, "NegativeObjectPropertyAssertion", "target")?,
+        })
+    }
+}
+
+crate::json_methods!(NegativeObjectPropertyAssertion);
+
 
 
 /**************** Base implementations for NegativeObjectPropertyAssertion ****************/
@@ -14428,6 +18235,24 @@ impl DataPropertyAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.dp.clone().into_pyobject(py)?.into_any(),
+            self.source.clone().into_pyobject(py)?.into_any(),
+            self.target.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,Individual,Literal,)) {
+        self.dp = state.0;
+        self.source = state.1;
+        self.target = state.2;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DataPropertyAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -14454,6 +18279,35 @@ impl From<&DataPropertyAssertion> for horned_owl::model::DataPropertyAssertion<A
     }
 }
 
+crate::signature_methods!(DataPropertyAssertion, this => crate::signature::Signature::of(&horned_owl::model::DataPropertyAssertion::<ArcStr>::from(this)));
+
+impl ToDict for DataPropertyAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DataPropertyAssertion")?;
+        node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+        node.set_item("source", ToDict::to_dict(&self.source, py)?)?;
+        node.set_item("target", ToDict::to_dict(&self.target, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DataPropertyAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DataPropertyAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DataPropertyAssertion")?;
+        Ok(DataPropertyAssertion {
+            dp: crate::json::field(&node, "DataPropertyAssertion", "dp")?,
+            source: crate::json::field(&node, "DataPropertyAssertion", "source")?,
+            target: crate::json::field(&node, "DataPropertyAssertion", "target")?,
+        })
+    }
+}
+
+crate::json_methods!(DataPropertyAssertion);
+
 
 
 /**************** Base implementations for DataPropertyAssertion ****************/
@@ -14660,6 +18514,24 @@ impl NegativeDataPropertyAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.dp.clone().into_pyobject(py)?.into_any(),
+            self.source.clone().into_pyobject(py)?.into_any(),
+            self.target.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (DataProperty,Individual,Literal,)) {
+        self.dp = state.0;
+        self.source = state.1;
+        self.target = state.2;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::NegativeDataPropertyAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -14686,6 +18558,35 @@ impl From<&NegativeDataPropertyAssertion> for horned_owl::model::NegativeDataPro
     }
 }
 
+crate::signature_methods!(NegativeDataPropertyAssertion, this => crate::signature::Signature::of(&horned_owl::model::NegativeDataPropertyAssertion::<ArcStr>::from(this)));
+
+impl ToDict for NegativeDataPropertyAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "NegativeDataPropertyAssertion")?;
+        node.set_item("dp", ToDict::to_dict(&self.dp, py)?)?;
+        node.set_item("source", ToDict::to_dict(&self.source, py)?)?;
+        node.set_item("target", ToDict::to_dict(&self.target, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for NegativeDataPropertyAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "NegativeDataPropertyAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "NegativeDataPropertyAssertion")?;
+        Ok(NegativeDataPropertyAssertion {
+            dp: crate::json::field(&node, "NegativeDataPropertyAssertion", "dp")?,
+            source: crate::json::field(&node, "NegativeDataPropertyAssertion", "source")?,
+            target: crate::json::field(&node, "NegativeDataPropertyAssertion", "target")?,
+        })
+    }
+}
+
+crate::json_methods!(NegativeDataPropertyAssertion);
+
 
 
 /**************** Base implementations for NegativeDataPropertyAssertion ****************/
@@ -14881,6 +18782,22 @@ impl AnnotationAssertion {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.subject.clone().into_pyobject(py)?.into_any(),
+            self.ann.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationSubject,Annotation,)) {
+        self.subject = state.0;
+        self.ann = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AnnotationAssertion<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -14905,6 +18822,33 @@ impl From<&AnnotationAssertion> for horned_owl::model::AnnotationAssertion<ArcSt
     }
 }
 
+crate::signature_methods!(AnnotationAssertion, this => crate::signature::Signature::of(&horned_owl::model::AnnotationAssertion::<ArcStr>::from(this)));
+
+impl ToDict for AnnotationAssertion {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnnotationAssertion")?;
+        node.set_item("subject", ToDict::to_dict(&self.subject, py)?)?;
+        node.set_item("ann", ToDict::to_dict(&self.ann, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnnotationAssertion {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnnotationAssertion")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnnotationAssertion")?;
+        Ok(AnnotationAssertion {
+            subject: crate::json::field(&node, "AnnotationAssertion", "subject")?,
+            ann: crate::json::field(&node, "AnnotationAssertion", "ann")?,
+        })
+    }
+}
+
+crate::json_methods!(AnnotationAssertion);
+
 
 
 /**************** Base implementations for AnnotationAssertion ****************/
@@ -15100,6 +19044,22 @@ impl SubAnnotationPropertyOf {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.sub.clone().into_pyobject(py)?.into_any(),
+            self.sup.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationProperty,AnnotationProperty,)) {
+        self.sub = state.0;
+        self.sup = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::SubAnnotationPropertyOf<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -15124,6 +19084,33 @@ impl From<&SubAnnotationPropertyOf> for horned_owl::model::SubAnnotationProperty
     }
 }
 
+crate::signature_methods!(SubAnnotationPropertyOf, this => crate::signature::Signature::of(&horned_owl::model::SubAnnotationPropertyOf::<ArcStr>::from(this)));
+
+impl ToDict for SubAnnotationPropertyOf {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "SubAnnotationPropertyOf")?;
+        node.set_item("sub", ToDict::to_dict(&self.sub, py)?)?;
+        node.set_item("sup", ToDict::to_dict(&self.sup, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for SubAnnotationPropertyOf {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "SubAnnotationPropertyOf")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "SubAnnotationPropertyOf")?;
+        Ok(SubAnnotationPropertyOf {
+            sub: crate::json::field(&node, "SubAnnotationPropertyOf", "sub")?,
+            sup: crate::json::field(&node, "SubAnnotationPropertyOf", "sup")?,
+        })
+    }
+}
+
+crate::json_methods!(SubAnnotationPropertyOf);
+
 
 
 /**************** Base implementations for SubAnnotationPropertyOf ****************/
@@ -15319,6 +19306,22 @@ impl AnnotationPropertyDomain {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ap.clone().into_pyobject(py)?.into_any(),
+            self.iri.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationProperty,IRI,)) {
+        self.ap = state.0;
+        self.iri = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AnnotationPropertyDomain<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -15343,6 +19346,33 @@ impl From<&AnnotationPropertyDomain> for horned_owl::model::AnnotationPropertyDo
     }
 }
 
+crate::signature_methods!(AnnotationPropertyDomain, this => crate::signature::Signature::of(&horned_owl::model::AnnotationPropertyDomain::<ArcStr>::from(this)));
+
+impl ToDict for AnnotationPropertyDomain {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnnotationPropertyDomain")?;
+        node.set_item("ap", ToDict::to_dict(&self.ap, py)?)?;
+        node.set_item("iri", ToDict::to_dict(&self.iri, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnnotationPropertyDomain {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnnotationPropertyDomain")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnnotationPropertyDomain")?;
+        Ok(AnnotationPropertyDomain {
+            ap: crate::json::field(&node, "AnnotationPropertyDomain", "ap")?,
+            iri: crate::json::field(&node, "AnnotationPropertyDomain", "iri")?,
+        })
+    }
+}
+
+crate::json_methods!(AnnotationPropertyDomain);
+
 
 
 /**************** Base implementations for AnnotationPropertyDomain ****************/
@@ -15538,6 +19568,22 @@ impl AnnotationPropertyRange {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.ap.clone().into_pyobject(py)?.into_any(),
+            self.iri.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (AnnotationProperty,IRI,)) {
+        self.ap = state.0;
+        self.iri = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AnnotationPropertyRange<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -15562,6 +19608,33 @@ impl From<&AnnotationPropertyRange> for horned_owl::model::AnnotationPropertyRan
     }
 }
 
+crate::signature_methods!(AnnotationPropertyRange, this => crate::signature::Signature::of(&horned_owl::model::AnnotationPropertyRange::<ArcStr>::from(this)));
+
+impl ToDict for AnnotationPropertyRange {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnnotationPropertyRange")?;
+        node.set_item("ap", ToDict::to_dict(&self.ap, py)?)?;
+        node.set_item("iri", ToDict::to_dict(&self.iri, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnnotationPropertyRange {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnnotationPropertyRange")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnnotationPropertyRange")?;
+        Ok(AnnotationPropertyRange {
+            ap: crate::json::field(&node, "AnnotationPropertyRange", "ap")?,
+            iri: crate::json::field(&node, "AnnotationPropertyRange", "iri")?,
+        })
+    }
+}
+
+crate::json_methods!(AnnotationPropertyRange);
+
 
 
 /**************** Base implementations for AnnotationPropertyRange ****************/
@@ -15728,6 +19801,20 @@ impl DocIRI {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::DocIRI<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -15750,6 +19837,31 @@ impl From<&DocIRI> for horned_owl::model::DocIRI<ArcStr> {
     }
 }
 
+crate::signature_methods!(DocIRI, this => crate::signature::Signature::of(&horned_owl::model::DocIRI::<ArcStr>::from(this)));
+
+impl ToDict for DocIRI {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "DocIRI")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for DocIRI {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "DocIRI")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "DocIRI")?;
+        Ok(DocIRI(
+            crate::json::field(&node, "DocIRI", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(DocIRI);
+
 
 
 /**************** Base implementations for DocIRI ****************/
@@ -15945,6 +20057,22 @@ impl OntologyID {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.iri.clone().into_pyobject(py)?.into_any(),
+            self.viri.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Option<IRI>,Option<IRI>,)) {
+        self.iri = state.0;
+        self.viri = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::OntologyID<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -15969,6 +20097,33 @@ impl From<&OntologyID> for horned_owl::model::OntologyID<ArcStr> {
     }
 }
 
+crate::signature_methods!(OntologyID, this => crate::signature::Signature::of(&horned_owl::model::OntologyID::<ArcStr>::from(this)));
+
+impl ToDict for OntologyID {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "OntologyID")?;
+        node.set_item("iri", ToDict::to_dict(&self.iri, py)?)?;
+        node.set_item("viri", ToDict::to_dict(&self.viri, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for OntologyID {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "OntologyID")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "OntologyID")?;
+        Ok(OntologyID {
+            iri: crate::json::field(&node, "OntologyID", "iri")?,
+            viri: crate::json::field(&node, "OntologyID", "viri")?,
+        })
+    }
+}
+
+crate::json_methods!(OntologyID);
+
 
 
 /**************** Base implementations for OntologyID ****************/
@@ -16135,6 +20290,20 @@ impl Variable {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.0.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (IRI,)) {
+        self.0 = state.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::Variable<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -16157,6 +20326,31 @@ impl From<&Variable> for horned_owl::model::Variable<ArcStr> {
     }
 }
 
+crate::signature_methods!(Variable, this => crate::signature::Signature::of(&horned_owl::model::Variable::<ArcStr>::from(this)));
+
+impl ToDict for Variable {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Variable")?;
+        node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Variable {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Variable")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Variable")?;
+        Ok(Variable(
+            crate::json::field(&node, "Variable", "first")?,
+        ))
+    }
+}
+
+crate::json_methods!(Variable);
+
 
 
 /**************** Base implementations for Variable ****************/
@@ -16287,6 +20481,7 @@ impl FromCompatible<&Vec<horned_owl::model::Variable<ArcStr>>> for VecWrap<Varia
         VecWrap::<Variable>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DArgument {
     
@@ -16340,6 +20535,33 @@ impl From<&horned_owl::model::DArgument<ArcStr>> for DArgument {
 }
 
 
+impl ToDict for DArgument {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            DArgument::Literal(inner) => ToDict::to_dict(inner, py),
+            DArgument::Variable(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for DArgument {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <Literal>::accepts(value) ||
+        <Variable>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <Literal>::accepts(value) {
+            return Ok(DArgument::Literal(<Literal as FromDict>::from_dict(value)?));
+        }
+        if <Variable>::accepts(value) {
+            return Ok(DArgument::Variable(<Variable as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "DArgument")
+    }
+}
+
 impl DArgument {
     pub fn py_def() -> String {
         "typing.Union[m.Literal,m.Variable,]".into()
@@ -16476,6 +20698,7 @@ impl FromCompatible<&Vec<horned_owl::model::DArgument<ArcStr>>> for VecWrap<DArg
         VecWrap::<DArgument>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IArgument {
     
@@ -16529,6 +20752,33 @@ impl From<&horned_owl::model::IArgument<ArcStr>> for IArgument {
 }
 
 
+impl ToDict for IArgument {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            IArgument::Individual(inner) => ToDict::to_dict(inner, py),
+            IArgument::Variable(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for IArgument {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <Individual>::accepts(value) ||
+        <Variable>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <Individual>::accepts(value) {
+            return Ok(IArgument::Individual(<Individual as FromDict>::from_dict(value)?));
+        }
+        if <Variable>::accepts(value) {
+            return Ok(IArgument::Variable(<Variable as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "IArgument")
+    }
+}
+
 impl IArgument {
     pub fn py_def() -> String {
         "typing.Union[m.Individual,m.Variable,]".into()
@@ -16755,6 +21005,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.pred.clone().into_pyobject(py)?.into_any(),
+                self.args.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (IRI,VecWrap<DArgument>,)) {
+            self.pred = state.0;
+            self.args = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -16762,6 +21028,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(BuiltInAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for BuiltInAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "BuiltInAtom")?;
+            node.set_item("pred", ToDict::to_dict(&self.pred, py)?)?;
+            node.set_item("args", ToDict::to_dict(&self.args, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for BuiltInAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "BuiltInAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "BuiltInAtom")?;
+            Ok(BuiltInAtom{
+                pred: crate::json::field(&node, "BuiltInAtom", "pred")?,
+                args: crate::json::field(&node, "BuiltInAtom", "args")?,
+            })
+        }
+    }
+
+    crate::json_methods!(BuiltInAtom);
+
 
     /**************** ENUM VARIANT ClassAtom for Atom ****************/
     #[doc = concat!("ClassAtom(pred: ClassExpressionarg: IArgument",
@@ -16833,6 +21128,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.pred.clone().into_pyobject(py)?.into_any(),
+                self.arg.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ClassExpression,IArgument,)) {
+            self.pred = state.0;
+            self.arg = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -16840,6 +21151,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(ClassAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for ClassAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ClassAtom")?;
+            node.set_item("pred", ToDict::to_dict(&self.pred, py)?)?;
+            node.set_item("arg", ToDict::to_dict(&self.arg, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ClassAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ClassAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ClassAtom")?;
+            Ok(ClassAtom{
+                pred: crate::json::field(&node, "ClassAtom", "pred")?,
+                arg: crate::json::field(&node, "ClassAtom", "arg")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ClassAtom);
+
 
     /**************** ENUM VARIANT DataPropertyAtom for Atom ****************/
     #[doc = concat!("DataPropertyAtom(pred: DataPropertyargs: typing.Tuple[DArgument,DArgument]",
@@ -16911,6 +21251,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.pred.clone().into_pyobject(py)?.into_any(),
+                self.args.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (DataProperty,(DArgument,DArgument),)) {
+            self.pred = state.0;
+            self.args = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -16918,6 +21274,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(DataPropertyAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for DataPropertyAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataPropertyAtom")?;
+            node.set_item("pred", ToDict::to_dict(&self.pred, py)?)?;
+            node.set_item("args", ToDict::to_dict(&self.args, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataPropertyAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataPropertyAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataPropertyAtom")?;
+            Ok(DataPropertyAtom{
+                pred: crate::json::field(&node, "DataPropertyAtom", "pred")?,
+                args: crate::json::field(&node, "DataPropertyAtom", "args")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataPropertyAtom);
+
 
     /**************** ENUM VARIANT DataRangeAtom for Atom ****************/
     #[doc = concat!("DataRangeAtom(pred: DataRangearg: DArgument",
@@ -16989,6 +21374,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.pred.clone().into_pyobject(py)?.into_any(),
+                self.arg.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (DataRange,DArgument,)) {
+            self.pred = state.0;
+            self.arg = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -16996,6 +21397,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(DataRangeAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for DataRangeAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DataRangeAtom")?;
+            node.set_item("pred", ToDict::to_dict(&self.pred, py)?)?;
+            node.set_item("arg", ToDict::to_dict(&self.arg, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DataRangeAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DataRangeAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DataRangeAtom")?;
+            Ok(DataRangeAtom{
+                pred: crate::json::field(&node, "DataRangeAtom", "pred")?,
+                arg: crate::json::field(&node, "DataRangeAtom", "arg")?,
+            })
+        }
+    }
+
+    crate::json_methods!(DataRangeAtom);
+
 
     /**************** ENUM VARIANT DifferentIndividualsAtom for Atom ****************/
     #[doc = concat!("DifferentIndividualsAtom(first: IArgumentsecond: IArgument",
@@ -17067,6 +21497,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+                self.1.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (IArgument,IArgument,)) {
+            self.0 = state.0;
+            self.1 = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -17074,6 +21520,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(DifferentIndividualsAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for DifferentIndividualsAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "DifferentIndividualsAtom")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            node.set_item("second", ToDict::to_dict(&self.1, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for DifferentIndividualsAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "DifferentIndividualsAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "DifferentIndividualsAtom")?;
+            Ok(DifferentIndividualsAtom(
+                crate::json::field(&node, "DifferentIndividualsAtom", "first")?,
+                crate::json::field(&node, "DifferentIndividualsAtom", "second")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(DifferentIndividualsAtom);
+
 
     /**************** ENUM VARIANT ObjectPropertyAtom for Atom ****************/
     #[doc = concat!("ObjectPropertyAtom(pred: ObjectPropertyExpressionargs: typing.Tuple[IArgument,IArgument]",
@@ -17145,6 +21620,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.pred.clone().into_pyobject(py)?.into_any(),
+                self.args.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (ObjectPropertyExpression,(IArgument,IArgument),)) {
+            self.pred = state.0;
+            self.args = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -17152,6 +21643,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(ObjectPropertyAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for ObjectPropertyAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "ObjectPropertyAtom")?;
+            node.set_item("pred", ToDict::to_dict(&self.pred, py)?)?;
+            node.set_item("args", ToDict::to_dict(&self.args, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for ObjectPropertyAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "ObjectPropertyAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "ObjectPropertyAtom")?;
+            Ok(ObjectPropertyAtom{
+                pred: crate::json::field(&node, "ObjectPropertyAtom", "pred")?,
+                args: crate::json::field(&node, "ObjectPropertyAtom", "args")?,
+            })
+        }
+    }
+
+    crate::json_methods!(ObjectPropertyAtom);
+
 
     /**************** ENUM VARIANT SameIndividualAtom for Atom ****************/
     #[doc = concat!("SameIndividualAtom(first: IArgumentsecond: IArgument",
@@ -17223,6 +21743,22 @@ pub struct Atom(Atom_Inner);
             self == other
         }
 
+        fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+            PyTuple::new(py, [
+                self.0.clone().into_pyobject(py)?.into_any(),
+                self.1.clone().into_pyobject(py)?.into_any(),
+            ])
+        }
+
+        fn __setstate__(&mut self, state: (IArgument,IArgument,)) {
+            self.0 = state.0;
+            self.1 = state.1;
+        }
+
+        fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+            Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+        }
+
         fn __str__(&self) -> String {
             Into::<horned_owl::model::Atom<ArcStr>>::into(Into::<Atom>::into(self.clone())).as_functional().to_string()
         }
@@ -17230,6 +21766,35 @@ pub struct Atom(Atom_Inner);
 
     
 
+    crate::signature_methods!(SameIndividualAtom, this => crate::signature::Signature::of(
+        &horned_owl::model::Atom::<ArcStr>::from(&Atom::from(this.clone()))
+    ));
+
+    impl ToDict for SameIndividualAtom {
+        fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let node = crate::json::node(py, "SameIndividualAtom")?;
+            node.set_item("first", ToDict::to_dict(&self.0, py)?)?;
+            node.set_item("second", ToDict::to_dict(&self.1, py)?)?;
+            Ok(node.into_any())
+        }
+    }
+
+    impl FromDict for SameIndividualAtom {
+        fn accepts(value: &Bound<'_, PyAny>) -> bool {
+            crate::json::is_node(value, "SameIndividualAtom")
+        }
+
+        fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+            let node = crate::json::expect_node(value, "SameIndividualAtom")?;
+            Ok(SameIndividualAtom(
+                crate::json::field(&node, "SameIndividualAtom", "first")?,
+                crate::json::field(&node, "SameIndividualAtom", "second")?,
+            ))
+        }
+    }
+
+    crate::json_methods!(SameIndividualAtom);
+
 
 
 impl From<&horned_owl::model::Atom<ArcStr>> for Atom {
@@ -17402,6 +21967,58 @@ impl <'py> FromPyObject<'py> for Atom {
     }
 }
 
+impl ToDict for Atom {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.0 {
+            Atom_Inner::BuiltInAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::ClassAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::DataPropertyAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::DataRangeAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::DifferentIndividualsAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::ObjectPropertyAtom(val) => ToDict::to_dict(val, py),
+            Atom_Inner::SameIndividualAtom(val) => ToDict::to_dict(val, py),
+        }
+    }
+}
+
+impl FromDict for Atom {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        BuiltInAtom::accepts(value) ||
+        ClassAtom::accepts(value) ||
+        DataPropertyAtom::accepts(value) ||
+        DataRangeAtom::accepts(value) ||
+        DifferentIndividualsAtom::accepts(value) ||
+        ObjectPropertyAtom::accepts(value) ||
+        SameIndividualAtom::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if BuiltInAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::BuiltInAtom(<BuiltInAtom as FromDict>::from_dict(value)?)));
+        }
+        if ClassAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::ClassAtom(<ClassAtom as FromDict>::from_dict(value)?)));
+        }
+        if DataPropertyAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::DataPropertyAtom(<DataPropertyAtom as FromDict>::from_dict(value)?)));
+        }
+        if DataRangeAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::DataRangeAtom(<DataRangeAtom as FromDict>::from_dict(value)?)));
+        }
+        if DifferentIndividualsAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::DifferentIndividualsAtom(<DifferentIndividualsAtom as FromDict>::from_dict(value)?)));
+        }
+        if ObjectPropertyAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::ObjectPropertyAtom(<ObjectPropertyAtom as FromDict>::from_dict(value)?)));
+        }
+        if SameIndividualAtom::accepts(value) {
+            return Ok(Atom(Atom_Inner::SameIndividualAtom(<SameIndividualAtom as FromDict>::from_dict(value)?)));
+        }
+
+        crate::json::invalid(value, "Atom")
+    }
+}
+
 impl Atom {
     pub fn py_def() -> String {
         "typing.Union[m.BuiltInAtom,m.ClassAtom,m.DataPropertyAtom,m.DataRangeAtom,m.DifferentIndividualsAtom,m.ObjectPropertyAtom,m.SameIndividualAtom,]".into()
@@ -17603,6 +22220,22 @@ impl Rule {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.head.clone().into_pyobject(py)?.into_any(),
+            self.body.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (VecWrap<Atom>,VecWrap<Atom>,)) {
+        self.head = state.0;
+        self.body = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::Rule<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -17627,6 +22260,33 @@ impl From<&Rule> for horned_owl::model::Rule<ArcStr> {
     }
 }
 
+crate::signature_methods!(Rule, this => crate::signature::Signature::of(&horned_owl::model::Rule::<ArcStr>::from(this)));
+
+impl ToDict for Rule {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "Rule")?;
+        node.set_item("head", ToDict::to_dict(&self.head, py)?)?;
+        node.set_item("body", ToDict::to_dict(&self.body, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for Rule {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "Rule")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "Rule")?;
+        Ok(Rule {
+            head: crate::json::field(&node, "Rule", "head")?,
+            body: crate::json::field(&node, "Rule", "body")?,
+        })
+    }
+}
+
+crate::json_methods!(Rule);
+
 
 
 /**************** Base implementations for Rule ****************/
@@ -17757,6 +22417,7 @@ impl FromCompatible<&Vec<horned_owl::model::Rule<ArcStr>>> for VecWrap<Rule> {
         VecWrap::<Rule>::from(value)
     }
 }
+
 #[derive(Debug, FromPyObject, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Component {
     
@@ -18215,6 +22876,258 @@ impl From<&horned_owl::model::Component<ArcStr>> for Component {
 }
 
 
+impl ToDict for Component {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Component::OntologyID(inner) => ToDict::to_dict(inner, py),
+            Component::DocIRI(inner) => ToDict::to_dict(inner, py),
+            Component::OntologyAnnotation(inner) => ToDict::to_dict(inner, py),
+            Component::Import(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareClass(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareAnnotationProperty(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareDataProperty(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareNamedIndividual(inner) => ToDict::to_dict(inner, py),
+            Component::DeclareDatatype(inner) => ToDict::to_dict(inner, py),
+            Component::SubClassOf(inner) => ToDict::to_dict(inner, py),
+            Component::EquivalentClasses(inner) => ToDict::to_dict(inner, py),
+            Component::DisjointClasses(inner) => ToDict::to_dict(inner, py),
+            Component::DisjointUnion(inner) => ToDict::to_dict(inner, py),
+            Component::SubObjectPropertyOf(inner) => ToDict::to_dict(inner, py),
+            Component::EquivalentObjectProperties(inner) => ToDict::to_dict(inner, py),
+            Component::DisjointObjectProperties(inner) => ToDict::to_dict(inner, py),
+            Component::InverseObjectProperties(inner) => ToDict::to_dict(inner, py),
+            Component::ObjectPropertyDomain(inner) => ToDict::to_dict(inner, py),
+            Component::ObjectPropertyRange(inner) => ToDict::to_dict(inner, py),
+            Component::FunctionalObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::InverseFunctionalObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::ReflexiveObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::IrreflexiveObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::SymmetricObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::AsymmetricObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::TransitiveObjectProperty(inner) => ToDict::to_dict(inner, py),
+            Component::SubDataPropertyOf(inner) => ToDict::to_dict(inner, py),
+            Component::EquivalentDataProperties(inner) => ToDict::to_dict(inner, py),
+            Component::DisjointDataProperties(inner) => ToDict::to_dict(inner, py),
+            Component::DataPropertyDomain(inner) => ToDict::to_dict(inner, py),
+            Component::DataPropertyRange(inner) => ToDict::to_dict(inner, py),
+            Component::FunctionalDataProperty(inner) => ToDict::to_dict(inner, py),
+            Component::DatatypeDefinition(inner) => ToDict::to_dict(inner, py),
+            Component::HasKey(inner) => ToDict::to_dict(inner, py),
+            Component::SameIndividual(inner) => ToDict::to_dict(inner, py),
+            Component::DifferentIndividuals(inner) => ToDict::to_dict(inner, py),
+            Component::ClassAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::ObjectPropertyAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::NegativeObjectPropertyAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::DataPropertyAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::NegativeDataPropertyAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::AnnotationAssertion(inner) => ToDict::to_dict(inner, py),
+            Component::SubAnnotationPropertyOf(inner) => ToDict::to_dict(inner, py),
+            Component::AnnotationPropertyDomain(inner) => ToDict::to_dict(inner, py),
+            Component::AnnotationPropertyRange(inner) => ToDict::to_dict(inner, py),
+            Component::Rule(inner) => ToDict::to_dict(inner, py),
+        }
+    }
+}
+
+impl FromDict for Component {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        <OntologyID>::accepts(value) ||
+        <DocIRI>::accepts(value) ||
+        <OntologyAnnotation>::accepts(value) ||
+        <Import>::accepts(value) ||
+        <DeclareClass>::accepts(value) ||
+        <DeclareObjectProperty>::accepts(value) ||
+        <DeclareAnnotationProperty>::accepts(value) ||
+        <DeclareDataProperty>::accepts(value) ||
+        <DeclareNamedIndividual>::accepts(value) ||
+        <DeclareDatatype>::accepts(value) ||
+        <SubClassOf>::accepts(value) ||
+        <EquivalentClasses>::accepts(value) ||
+        <DisjointClasses>::accepts(value) ||
+        <DisjointUnion>::accepts(value) ||
+        <SubObjectPropertyOf>::accepts(value) ||
+        <EquivalentObjectProperties>::accepts(value) ||
+        <DisjointObjectProperties>::accepts(value) ||
+        <InverseObjectProperties>::accepts(value) ||
+        <ObjectPropertyDomain>::accepts(value) ||
+        <ObjectPropertyRange>::accepts(value) ||
+        <FunctionalObjectProperty>::accepts(value) ||
+        <InverseFunctionalObjectProperty>::accepts(value) ||
+        <ReflexiveObjectProperty>::accepts(value) ||
+        <IrreflexiveObjectProperty>::accepts(value) ||
+        <SymmetricObjectProperty>::accepts(value) ||
+        <AsymmetricObjectProperty>::accepts(value) ||
+        <TransitiveObjectProperty>::accepts(value) ||
+        <SubDataPropertyOf>::accepts(value) ||
+        <EquivalentDataProperties>::accepts(value) ||
+        <DisjointDataProperties>::accepts(value) ||
+        <DataPropertyDomain>::accepts(value) ||
+        <DataPropertyRange>::accepts(value) ||
+        <FunctionalDataProperty>::accepts(value) ||
+        <DatatypeDefinition>::accepts(value) ||
+        <HasKey>::accepts(value) ||
+        <SameIndividual>::accepts(value) ||
+        <DifferentIndividuals>::accepts(value) ||
+        <ClassAssertion>::accepts(value) ||
+        <ObjectPropertyAssertion>::accepts(value) ||
+        <NegativeObjectPropertyAssertion>::accepts(value) ||
+        <DataPropertyAssertion>::accepts(value) ||
+        <NegativeDataPropertyAssertion>::accepts(value) ||
+        <AnnotationAssertion>::accepts(value) ||
+        <SubAnnotationPropertyOf>::accepts(value) ||
+        <AnnotationPropertyDomain>::accepts(value) ||
+        <AnnotationPropertyRange>::accepts(value) ||
+        <Rule>::accepts(value)
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if <OntologyID>::accepts(value) {
+            return Ok(Component::OntologyID(<OntologyID as FromDict>::from_dict(value)?));
+        }
+        if <DocIRI>::accepts(value) {
+            return Ok(Component::DocIRI(<DocIRI as FromDict>::from_dict(value)?));
+        }
+        if <OntologyAnnotation>::accepts(value) {
+            return Ok(Component::OntologyAnnotation(<OntologyAnnotation as FromDict>::from_dict(value)?));
+        }
+        if <Import>::accepts(value) {
+            return Ok(Component::Import(<Import as FromDict>::from_dict(value)?));
+        }
+        if <DeclareClass>::accepts(value) {
+            return Ok(Component::DeclareClass(<DeclareClass as FromDict>::from_dict(value)?));
+        }
+        if <DeclareObjectProperty>::accepts(value) {
+            return Ok(Component::DeclareObjectProperty(<DeclareObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <DeclareAnnotationProperty>::accepts(value) {
+            return Ok(Component::DeclareAnnotationProperty(<DeclareAnnotationProperty as FromDict>::from_dict(value)?));
+        }
+        if <DeclareDataProperty>::accepts(value) {
+            return Ok(Component::DeclareDataProperty(<DeclareDataProperty as FromDict>::from_dict(value)?));
+        }
+        if <DeclareNamedIndividual>::accepts(value) {
+            return Ok(Component::DeclareNamedIndividual(<DeclareNamedIndividual as FromDict>::from_dict(value)?));
+        }
+        if <DeclareDatatype>::accepts(value) {
+            return Ok(Component::DeclareDatatype(<DeclareDatatype as FromDict>::from_dict(value)?));
+        }
+        if <SubClassOf>::accepts(value) {
+            return Ok(Component::SubClassOf(<SubClassOf as FromDict>::from_dict(value)?));
+        }
+        if <EquivalentClasses>::accepts(value) {
+            return Ok(Component::EquivalentClasses(<EquivalentClasses as FromDict>::from_dict(value)?));
+        }
+        if <DisjointClasses>::accepts(value) {
+            return Ok(Component::DisjointClasses(<DisjointClasses as FromDict>::from_dict(value)?));
+        }
+        if <DisjointUnion>::accepts(value) {
+            return Ok(Component::DisjointUnion(<DisjointUnion as FromDict>::from_dict(value)?));
+        }
+        if <SubObjectPropertyOf>::accepts(value) {
+            return Ok(Component::SubObjectPropertyOf(<SubObjectPropertyOf as FromDict>::from_dict(value)?));
+        }
+        if <EquivalentObjectProperties>::accepts(value) {
+            return Ok(Component::EquivalentObjectProperties(<EquivalentObjectProperties as FromDict>::from_dict(value)?));
+        }
+        if <DisjointObjectProperties>::accepts(value) {
+            return Ok(Component::DisjointObjectProperties(<DisjointObjectProperties as FromDict>::from_dict(value)?));
+        }
+        if <InverseObjectProperties>::accepts(value) {
+            return Ok(Component::InverseObjectProperties(<InverseObjectProperties as FromDict>::from_dict(value)?));
+        }
+        if <ObjectPropertyDomain>::accepts(value) {
+            return Ok(Component::ObjectPropertyDomain(<ObjectPropertyDomain as FromDict>::from_dict(value)?));
+        }
+        if <ObjectPropertyRange>::accepts(value) {
+            return Ok(Component::ObjectPropertyRange(<ObjectPropertyRange as FromDict>::from_dict(value)?));
+        }
+        if <FunctionalObjectProperty>::accepts(value) {
+            return Ok(Component::FunctionalObjectProperty(<FunctionalObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <InverseFunctionalObjectProperty>::accepts(value) {
+            return Ok(Component::InverseFunctionalObjectProperty(<InverseFunctionalObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <ReflexiveObjectProperty>::accepts(value) {
+            return Ok(Component::ReflexiveObjectProperty(<ReflexiveObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <IrreflexiveObjectProperty>::accepts(value) {
+            return Ok(Component::IrreflexiveObjectProperty(<IrreflexiveObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <SymmetricObjectProperty>::accepts(value) {
+            return Ok(Component::SymmetricObjectProperty(<SymmetricObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <AsymmetricObjectProperty>::accepts(value) {
+            return Ok(Component::AsymmetricObjectProperty(<AsymmetricObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <TransitiveObjectProperty>::accepts(value) {
+            return Ok(Component::TransitiveObjectProperty(<TransitiveObjectProperty as FromDict>::from_dict(value)?));
+        }
+        if <SubDataPropertyOf>::accepts(value) {
+            return Ok(Component::SubDataPropertyOf(<SubDataPropertyOf as FromDict>::from_dict(value)?));
+        }
+        if <EquivalentDataProperties>::accepts(value) {
+            return Ok(Component::EquivalentDataProperties(<EquivalentDataProperties as FromDict>::from_dict(value)?));
+        }
+        if <DisjointDataProperties>::accepts(value) {
+            return Ok(Component::DisjointDataProperties(<DisjointDataProperties as FromDict>::from_dict(value)?));
+        }
+        if <DataPropertyDomain>::accepts(value) {
+            return Ok(Component::DataPropertyDomain(<DataPropertyDomain as FromDict>::from_dict(value)?));
+        }
+        if <DataPropertyRange>::accepts(value) {
+            return Ok(Component::DataPropertyRange(<DataPropertyRange as FromDict>::from_dict(value)?));
+        }
+        if <FunctionalDataProperty>::accepts(value) {
+            return Ok(Component::FunctionalDataProperty(<FunctionalDataProperty as FromDict>::from_dict(value)?));
+        }
+        if <DatatypeDefinition>::accepts(value) {
+            return Ok(Component::DatatypeDefinition(<DatatypeDefinition as FromDict>::from_dict(value)?));
+        }
+        if <HasKey>::accepts(value) {
+            return Ok(Component::HasKey(<HasKey as FromDict>::from_dict(value)?));
+        }
+        if <SameIndividual>::accepts(value) {
+            return Ok(Component::SameIndividual(<SameIndividual as FromDict>::from_dict(value)?));
+        }
+        if <DifferentIndividuals>::accepts(value) {
+            return Ok(Component::DifferentIndividuals(<DifferentIndividuals as FromDict>::from_dict(value)?));
+        }
+        if <ClassAssertion>::accepts(value) {
+            return Ok(Component::ClassAssertion(<ClassAssertion as FromDict>::from_dict(value)?));
+        }
+        if <ObjectPropertyAssertion>::accepts(value) {
+            return Ok(Component::ObjectPropertyAssertion(<ObjectPropertyAssertion as FromDict>::from_dict(value)?));
+        }
+        if <NegativeObjectPropertyAssertion>::accepts(value) {
+            return Ok(Component::NegativeObjectPropertyAssertion(<NegativeObjectPropertyAssertion as FromDict>::from_dict(value)?));
+        }
+        if <DataPropertyAssertion>::accepts(value) {
+            return Ok(Component::DataPropertyAssertion(<DataPropertyAssertion as FromDict>::from_dict(value)?));
+        }
+        if <NegativeDataPropertyAssertion>::accepts(value) {
+            return Ok(Component::NegativeDataPropertyAssertion(<NegativeDataPropertyAssertion as FromDict>::from_dict(value)?));
+        }
+        if <AnnotationAssertion>::accepts(value) {
+            return Ok(Component::AnnotationAssertion(<AnnotationAssertion as FromDict>::from_dict(value)?));
+        }
+        if <SubAnnotationPropertyOf>::accepts(value) {
+            return Ok(Component::SubAnnotationPropertyOf(<SubAnnotationPropertyOf as FromDict>::from_dict(value)?));
+        }
+        if <AnnotationPropertyDomain>::accepts(value) {
+            return Ok(Component::AnnotationPropertyDomain(<AnnotationPropertyDomain as FromDict>::from_dict(value)?));
+        }
+        if <AnnotationPropertyRange>::accepts(value) {
+            return Ok(Component::AnnotationPropertyRange(<AnnotationPropertyRange as FromDict>::from_dict(value)?));
+        }
+        if <Rule>::accepts(value) {
+            return Ok(Component::Rule(<Rule as FromDict>::from_dict(value)?));
+        }
+
+        crate::json::invalid(value, "Component")
+    }
+}
+
 impl Component {
     pub fn py_def() -> String {
         "typing.Union[m.OntologyID,m.DocIRI,m.OntologyAnnotation,m.Import,m.DeclareClass,m.DeclareObjectProperty,m.DeclareAnnotationProperty,m.DeclareDataProperty,m.DeclareNamedIndividual,m.DeclareDatatype,m.SubClassOf,m.EquivalentClasses,m.DisjointClasses,m.DisjointUnion,m.SubObjectPropertyOf,m.EquivalentObjectProperties,m.DisjointObjectProperties,m.InverseObjectProperties,m.ObjectPropertyDomain,m.ObjectPropertyRange,m.FunctionalObjectProperty,m.InverseFunctionalObjectProperty,m.ReflexiveObjectProperty,m.IrreflexiveObjectProperty,m.SymmetricObjectProperty,m.AsymmetricObjectProperty,m.TransitiveObjectProperty,m.SubDataPropertyOf,m.EquivalentDataProperties,m.DisjointDataProperties,m.DataPropertyDomain,m.DataPropertyRange,m.FunctionalDataProperty,m.DatatypeDefinition,m.HasKey,m.SameIndividual,m.DifferentIndividuals,m.ClassAssertion,m.ObjectPropertyAssertion,m.NegativeObjectPropertyAssertion,m.DataPropertyAssertion,m.NegativeDataPropertyAssertion,m.AnnotationAssertion,m.SubAnnotationPropertyOf,m.AnnotationPropertyDomain,m.AnnotationPropertyRange,m.Rule,]".into()
@@ -18416,6 +23329,22 @@ impl AnnotatedComponent {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [
+            self.component.clone().into_pyobject(py)?.into_any(),
+            self.ann.clone().into_pyobject(py)?.into_any(),
+        ])
+    }
+
+    fn __setstate__(&mut self, state: (Component,BTreeSetWrap<Annotation>,)) {
+        self.component = state.0;
+        self.ann = state.1;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
+        Ok((slf.get_type(), slf.borrow().__getstate__(slf.py())?))
+    }
     fn __str__(&self) -> String {
         Into::<horned_owl::model::AnnotatedComponent<ArcStr>>::into(self.clone()).as_functional().to_string()
     }
@@ -18440,6 +23369,33 @@ impl From<&AnnotatedComponent> for horned_owl::model::AnnotatedComponent<ArcStr>
     }
 }
 
+crate::signature_methods!(AnnotatedComponent, this => crate::signature::Signature::of(&horned_owl::model::AnnotatedComponent::<ArcStr>::from(this)));
+
+impl ToDict for AnnotatedComponent {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let node = crate::json::node(py, "AnnotatedComponent")?;
+        node.set_item("component", ToDict::to_dict(&self.component, py)?)?;
+        node.set_item("ann", ToDict::to_dict(&self.ann, py)?)?;
+        Ok(node.into_any())
+    }
+}
+
+impl FromDict for AnnotatedComponent {
+    fn accepts(value: &Bound<'_, PyAny>) -> bool {
+        crate::json::is_node(value, "AnnotatedComponent")
+    }
+
+    fn from_dict(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let node = crate::json::expect_node(value, "AnnotatedComponent")?;
+        Ok(AnnotatedComponent {
+            component: crate::json::field(&node, "AnnotatedComponent", "component")?,
+            ann: crate::json::field(&node, "AnnotatedComponent", "ann")?,
+        })
+    }
+}
+
+crate::json_methods!(AnnotatedComponent);
+
 
 
 /**************** Base implementations for AnnotatedComponent ****************/
@@ -1,14 +1,34 @@
+use crate::codec::{Decoder, Encoder};
+use crate::iterators::{component_kind, component_kinds, ComponentIter, EntityIter};
+use crate::label_index::{
+    label_entry, label_matches, LabelEntry, LabelIndex, DEFAULT_SYNONYM_PROPERTIES,
+};
+use crate::fold::Transformable;
+use crate::graph::{self, Graph};
+use crate::lint::{self, LintFinding, RuleSelection};
+use crate::metrics::{self, OntologyMetrics};
+use crate::parse::{self, Resolver};
+use crate::pattern::unify;
 use crate::prefix_mapping::PrefixMapping;
+use crate::profile::{self, Profile, ProfileReport};
+use crate::rdf;
 use crate::reasoning::DynamicLoadedReasoner;
+use crate::render::{Renderer, Syntax};
+use crate::search_index::{SearchHit, SearchIndex, DEFAULT_DEFINITION_PROPERTIES};
+use crate::signature::{
+    declaration, defines, entity_into_py, entity_iri, entity_kind, undeclared_entities,
+    Signature, SignatureIndex,
+};
 use crate::structural_reasoner::StructuralReasoner;
+use crate::tables;
 use crate::wrappers::BTreeSetWrap;
 use crate::{guess_serialization, model, parse_serialization, to_py_err};
 use curie::Curie;
 use horned_owl::io::rdf::reader::ConcreteRDFOntology;
-use horned_owl::io::ResourceType;
+use horned_owl::io::{ParserConfiguration, RDFParserConfiguration, ResourceType};
 use horned_owl::model::{
     AnnotatedComponent, Annotation, AnnotationAssertion, AnnotationSubject, AnnotationValue,
-    ArcAnnotatedComponent, ArcStr, Build, Class, ClassExpression, Component, ComponentKind, ForIRI, HigherKinded, Kinded, Literal, MutableOntology, Ontology, OntologyID,
+    ArcAnnotatedComponent, ArcStr, Build, Class, ClassExpression, Component, ComponentKind, ForIRI, HigherKinded, Import, Kinded, Literal, MutableOntology, NamedOWLEntityKind, Ontology, OntologyAnnotation, OntologyID,
     SubClassOf, IRI,
 };
 use horned_owl::ontology::component_mapped::{
@@ -17,17 +37,29 @@ use horned_owl::ontology::component_mapped::{
 use horned_owl::ontology::indexed::OntologyIndex;
 use horned_owl::ontology::iri_mapped::IRIMappedIndex;
 use horned_owl::ontology::set::{SetIndex, SetOntology};
-use horned_owl::vocab::{AnnotationBuiltIn, OWL};
+use horned_owl::visitor::immutable::entity::IRIExtract;
+use horned_owl::visitor::immutable::Walk;
+use horned_owl::vocab::{AnnotationBuiltIn, OWL, XSD};
 use pyhornedowlreasoner::{PyReasoner, Reasoner};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyNone;
+use pyo3::types::{PyBytes, PyDict, PyList, PyNone, PySet, PyTuple, PyType};
 use pyo3::{pyclass, pymethods, Bound, Py, PyAny, PyResult, Python};
+use std::borrow::Borrow;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufRead, Write};
 use std::sync::{Arc, Mutex, RwLock};
 
+/// IAO "term replaced by"
+pub(crate) const IAO_TERM_REPLACED_BY: &str = "http://purl.obolibrary.org/obo/IAO_0100001";
+/// IAO "has obsolescence reason"
+pub(crate) const IAO_OBSOLESCENCE_REASON: &str = "http://purl.obolibrary.org/obo/IAO_0000231";
+/// oboInOwl "consider"
+pub(crate) const OBO_IN_OWL_CONSIDER: &str = "http://www.geneontology.org/formats/oboInOwl#consider";
+/// Prefix of the labels of obsolete terms
+const OBSOLETE_PREFIX: &str = "obsolete ";
+
 macro_rules! into_iri {
     ($s:ident, $py:ident, $iri:ident) => {
         $iri.into_iri(&$s.mapping.borrow($py).0, &$s.build.read().unwrap())?
@@ -35,7 +67,7 @@ macro_rules! into_iri {
 }
 
 macro_rules! entity_query {
-    ($s:ident, $kind:expr, $comp:pat => $comp_var:expr) => {{
+    ($s:ident, $include_undeclared:expr, $entity_kind:expr, $kind:expr, $comp:pat => $comp_var:expr) => {{
         //Get the declaration axioms
         let entities = if let Some(ref mut component_index) = &mut $s.component_index {
             Box::new(component_index.component_for_kind($kind))
@@ -44,13 +76,35 @@ macro_rules! entity_query {
             Box::new((&$s.set_index).into_iter())
         };
 
-        let entities = entities
+        let mut entities: HashSet<String> = entities
             .filter_map(|aax| match &aax.component {
                 $comp => Some($comp_var.0 .0.to_string()),
                 _ => None,
             })
             .collect();
 
+        // Add the entities of the signature, which include undeclared ones
+        if $include_undeclared {
+            match $s.get_signature_index() {
+                Some(signature_index) => entities.extend(
+                    signature_index
+                        .entities($entity_kind)
+                        .map(|e| entity_iri(e).to_string()),
+                ),
+                None => {
+                    let signature =
+                        Signature::of_components($s.set_index.iter().map(AsRef::as_ref));
+                    entities.extend(
+                        signature
+                            .entities()
+                            .iter()
+                            .filter(|e| entity_kind(e) == $entity_kind)
+                            .map(|e| entity_iri(e).to_string()),
+                    );
+                }
+            }
+        }
+
         Ok(entities)
     }};
 }
@@ -75,8 +129,70 @@ pub enum IndexCreationStrategy {
     Explicit,
 }
 
+/// The set of all components of an ontology.
+///
+/// In contrast to the `SetIndex` of horned owl, it gives access to the shared pointers of the
+/// components, so components found through the other indexes can be handed out without cloning.
+#[derive(Default)]
+pub struct ComponentSet(HashSet<ArcAnnotatedComponent>);
+
+impl ComponentSet {
+    pub fn iter(&self) -> impl Iterator<Item = &ArcAnnotatedComponent> + '_ {
+        self.0.iter()
+    }
+
+    /// Returns the shared pointer to `cmp` if it is part of the set.
+    pub fn get(&self, cmp: &AnnotatedComponent<ArcStr>) -> Option<&ArcAnnotatedComponent> {
+        self.0.get(cmp)
+    }
+}
+
+impl OntologyIndex<ArcStr, ArcAnnotatedComponent> for ComponentSet {
+    fn index_insert(&mut self, cmp: ArcAnnotatedComponent) -> bool {
+        self.0.insert(cmp)
+    }
+
+    fn index_remove(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> bool {
+        self.0.remove(cmp)
+    }
+
+    fn index_take(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> Option<AnnotatedComponent<ArcStr>> {
+        self.0.take(cmp).map(Arc::unwrap_or_clone)
+    }
+}
+
+impl From<SetIndex<ArcStr, ArcAnnotatedComponent>> for ComponentSet {
+    fn from(value: SetIndex<ArcStr, ArcAnnotatedComponent>) -> Self {
+        ComponentSet(value.iter().cloned().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a ComponentSet {
+    type Item = &'a AnnotatedComponent<ArcStr>;
+    type IntoIter = std::iter::Map<
+        std::collections::hash_set::Iter<'a, ArcAnnotatedComponent>,
+        fn(&'a ArcAnnotatedComponent) -> &'a AnnotatedComponent<ArcStr>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().map(|c| c.as_ref())
+    }
+}
+
+impl IntoIterator for ComponentSet {
+    type Item = AnnotatedComponent<ArcStr>;
+    type IntoIter = std::iter::Map<
+        std::collections::hash_set::IntoIter<ArcAnnotatedComponent>,
+        fn(ArcAnnotatedComponent) -> AnnotatedComponent<ArcStr>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(Arc::unwrap_or_clone)
+    }
+}
+
 /// Represents a loaded ontology.
-#[pyclass]
+#[pyclass(module = "pyhornedowl")]
 pub struct PyIndexedOntology {
     //State variables private to Rust, exposed through methods to Python
     pub labels_to_iris: HashMap<String, IRI<ArcStr>>,
@@ -88,13 +204,22 @@ pub struct PyIndexedOntology {
     //The primary store of the axioms is a Horned OWL indexed ontology
     pub iri_index: Option<IRIMappedIndex<ArcStr, ArcAnnotatedComponent>>,
     pub component_index: Option<ComponentMappedIndex<ArcStr, ArcAnnotatedComponent>>,
-    pub set_index: SetIndex<ArcStr, ArcAnnotatedComponent>,
+    pub label_index: Option<LabelIndex>,
+    pub search_index: Option<SearchIndex>,
+    pub signature_index: Option<SignatureIndex>,
+    pub set_index: ComponentSet,
     //Need this for converting IRIs to IDs and for saving again afterwards
     pub mapping: Py<PrefixMapping>,
     pub build: RwLock<Build<ArcStr>>,
 
+    //Annotation properties indexed by the label index in addition to rdfs:label
+    pub synonym_properties: Vec<IRI<ArcStr>>,
+
     pub index_strategy: IndexCreationStrategy,
 
+    //Incremented on every change to detect modifications during iteration
+    pub modification_count: u64,
+
     pub reasoners: Vec<crate::reasoning::PyReasoner>,
 }
 
@@ -103,17 +228,28 @@ unsafe impl Send for PyIndexedOntology {}
 
 impl Default for PyIndexedOntology {
     fn default() -> Self {
+        let build = Build::new_arc();
+        let synonym_properties = DEFAULT_SYNONYM_PROPERTIES
+            .iter()
+            .map(|p| build.iri(*p))
+            .collect();
+
         Python::attach(|py| PyIndexedOntology {
             labels_to_iris: Default::default(),
             classes_to_subclasses: Default::default(),
             classes_to_superclasses: Default::default(),
             iri_index: None,
             component_index: None,
+            label_index: None,
+            search_index: None,
+            signature_index: None,
             set_index: Default::default(),
             mapping: Py::new(py, PrefixMapping::default())
                 .expect("Unable to create default prefix mapping"),
-            build: RwLock::new(Build::new_arc()),
+            build: RwLock::new(build),
+            synonym_properties,
             index_strategy: IndexCreationStrategy::OnQuery,
+            modification_count: 0,
             reasoners: vec![],
         })
     }
@@ -134,8 +270,18 @@ where
 
 impl Clone for PyIndexedOntology {
     fn clone(&self) -> Self {
-        let set = SetOntology::from(self);
-        Self::from_set_ontology(set, self.index_strategy)
+        let mut clone = Self::new(self.index_strategy);
+        clone.synonym_properties = self.synonym_properties.clone();
+
+        for cmp in self.set_index.iter() {
+            clone.insert_arc(cmp.clone());
+        }
+
+        if let IndexCreationStrategy::OnLoad = clone.index_strategy {
+            clone.build_indexes();
+        }
+
+        clone
     }
 }
 
@@ -145,18 +291,7 @@ impl MutableOntology<ArcStr> for PyIndexedOntology {
     where
         AA: Into<AnnotatedComponent<ArcStr>>,
     {
-        let ax: ArcAnnotatedComponent = ax.into().into();
-        if let Some(ref mut iri_index) = &mut self.iri_index {
-            iri_index.index_insert(ax.clone());
-        }
-        if let Some(ref mut component_index) = &mut self.component_index {
-            component_index.index_insert(ax.clone());
-        }
-        for reasoner in &mut self.reasoners {
-            reasoner.0.lock().unwrap().0.index_insert(ax.clone());
-        }
-
-        self.set_index.index_insert(ax)
+        self.insert_arc(ax.into().into())
     }
 
     fn take(&mut self, ax: &AnnotatedComponent<ArcStr>) -> Option<AnnotatedComponent<ArcStr>> {
@@ -166,11 +301,24 @@ impl MutableOntology<ArcStr> for PyIndexedOntology {
         if let Some(ref mut component_index) = &mut self.component_index {
             component_index.index_take(ax);
         }
+        if let Some(ref mut label_index) = &mut self.label_index {
+            label_index.index_take(ax);
+        }
+        if let Some(ref mut search_index) = &mut self.search_index {
+            search_index.index_take(ax);
+        }
         for reasoner in &self.reasoners {
             reasoner.0.lock().unwrap().0.index_take(ax);
         }
 
-        self.set_index.index_take(ax)
+        let taken = self.set_index.index_take(ax);
+        if taken.is_some() {
+            if let Some(ref mut signature_index) = &mut self.signature_index {
+                signature_index.index_remove(ax);
+            }
+            self.modification_count += 1;
+        }
+        taken
     }
 
     fn remove(&mut self, ax: &AnnotatedComponent<ArcStr>) -> bool {
@@ -180,11 +328,24 @@ impl MutableOntology<ArcStr> for PyIndexedOntology {
         if let Some(ref mut component_index) = &mut self.component_index {
             component_index.index_remove(ax);
         }
+        if let Some(ref mut label_index) = &mut self.label_index {
+            label_index.index_remove(ax);
+        }
+        if let Some(ref mut search_index) = &mut self.search_index {
+            search_index.index_remove(ax);
+        }
         for reasoner in &mut self.reasoners {
             reasoner.0.lock().unwrap().0.index_remove(ax);
         }
 
-        self.set_index.index_remove(ax)
+        let removed = self.set_index.index_remove(ax);
+        if removed {
+            if let Some(ref mut signature_index) = &mut self.signature_index {
+                signature_index.index_remove(ax);
+            }
+            self.modification_count += 1;
+        }
+        removed
     }
 }
 
@@ -205,6 +366,82 @@ impl PyIndexedOntology {
         s
     }
 
+    /// __getstate__(self) -> bytes
+    ///
+    /// Returns the components, prefix mapping, synonym properties and index creation strategy of
+    /// the ontology in a compact binary encoding. Used by `pickle`.
+    pub fn __getstate__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut e = Encoder::new();
+        e.tag(self.index_strategy as u8);
+        e.encode(&self.mapping.borrow(py).__getstate__());
+        e.encode(&self.synonym_properties);
+
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+        e.usize(components.len());
+        for c in components {
+            e.encode(c);
+        }
+
+        PyBytes::new(py, &e.finish())
+    }
+
+    /// __setstate__(self, state: bytes) -> None
+    ///
+    /// Replaces the ontology with one encoded by `PyIndexedOntology.__getstate__`. Raises a
+    /// ValueError if `state` is not a valid encoding.
+    pub fn __setstate__(&mut self, py: Python<'_>, state: &[u8]) -> PyResult<()> {
+        let build = Build::new_arc();
+        let mut d = Decoder::new(state, &build)?;
+        let index_strategy = match d.tag()? {
+            0 => IndexCreationStrategy::OnLoad,
+            1 => IndexCreationStrategy::OnQuery,
+            2 => IndexCreationStrategy::Explicit,
+            tag => return Err(d.invalid(format!("unknown index creation strategy {tag}"))),
+        };
+        let prefixes: Vec<(String, String)> = d.decode()?;
+        let synonym_properties = d.decode()?;
+        let components: Vec<AnnotatedComponent<ArcStr>> = d.decode()?;
+        d.finish()?;
+
+        let mut ontology = Self::new(index_strategy);
+        ontology.build = RwLock::new(build);
+        ontology.synonym_properties = synonym_properties;
+        ontology.mapping.borrow_mut(py).__setstate__(prefixes)?;
+        for c in components {
+            ontology.insert(c);
+        }
+
+        if let IndexCreationStrategy::OnLoad = index_strategy {
+            ontology.build_indexes();
+        }
+
+        *self = ontology;
+        Ok(())
+    }
+
+    pub fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> (Bound<'py, PyType>, (), Bound<'py, PyBytes>) {
+        (slf.get_type(), (), slf.borrow().__getstate__(slf.py()))
+    }
+
+    /// __copy__(self) -> PyIndexedOntology
+    ///
+    /// Returns a copy of the ontology with its own prefix mapping. Components are immutable and
+    /// shared between the copies.
+    pub fn __copy__(&self, py: Python<'_>) -> PyResult<Self> {
+        let mut copy = self.clone();
+        copy.mapping = Py::new(py, PrefixMapping::from(self.mapping.borrow(py).0.clone()))?;
+        Ok(copy)
+    }
+
+    /// __deepcopy__(self, memo: dict) -> PyIndexedOntology
+    ///
+    /// Same as `PyIndexedOntology.__copy__`.
+    pub fn __deepcopy__(&self, py: Python<'_>, _memo: &Bound<'_, PyAny>) -> PyResult<Self> {
+        self.__copy__(py)
+    }
+
     /// get_id_for_iri(self, iri: model.IRIParam) -> Optional[str]
     ///
     /// Gets the ID of term by it IRI.
@@ -283,17 +520,19 @@ impl PyIndexedOntology {
         self.mapping.borrow_mut(py).add_prefix(iriprefix, mappedid)
     }
 
-    /// set_label(self, iri: model.IRIParam, label: str) -> None
+    /// set_label(self, iri: model.IRIParam, label: str, lang: Optional[str]=None) -> None
     ///
     /// Sets the label of a term by iri.
     ///
-    /// Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`.
-    #[pyo3[signature = (iri, label)]]
+    /// Adds an or updates the `AnnotationAssertion` axiom for `rdfs:label`. If `lang` is given,
+    /// a language tagged literal is written and only the label with the same language is replaced.
+    #[pyo3[signature = (iri, label, lang = None)]]
     pub fn set_label(
         &mut self,
         py: Python<'_>,
         iri: model::IRIParam,
         label: String,
+        lang: Option<String>,
     ) -> PyResult<()> {
         let iri: IRI<ArcStr> = into_iri!(self, py, iri);
 
@@ -304,60 +543,77 @@ impl PyIndexedOntology {
                 .read()
                 .map_err(to_py_err!("Failed get build instance!"))?;
 
+            let literal = match &lang {
+                Some(lang) => Literal::Language {
+                    literal: label.clone(),
+                    lang: lang.clone(),
+                },
+                None => Literal::Simple {
+                    literal: label.clone(),
+                },
+            };
+
             ax1 = Component::AnnotationAssertion(AnnotationAssertion {
                 subject: iri.clone().into(),
                 ann: Annotation {
                     ap: build.annotation_property(AnnotationBuiltIn::Label),
-                    av: AnnotationValue::Literal(Literal::Simple {
-                        literal: label.clone(),
-                    }),
+                    av: AnnotationValue::Literal(literal),
                 },
             })
             .into();
         }
 
-        let components = if let Some(ref mut iri_index) = &mut self.iri_index {
-            Box::new(iri_index.component_for_iri(&iri))
-                as Box<dyn Iterator<Item = &AnnotatedComponent<ArcStr>>>
-        } else {
-            Box::new((&self.set_index).into_iter())
-        };
-
-        //If we already have a label, update it:
-        let old_ax = &components
-            .filter_map(|aax: &AnnotatedComponent<ArcStr>| match &aax.component {
-                Component::AnnotationAssertion(AnnotationAssertion {
-                    subject: AnnotationSubject::IRI(subj),
-                    ann:
-                        Annotation {
-                            ap,
-                            av: AnnotationValue::Literal(Literal::Simple { literal: _old }),
-                        },
-                }) if subj == &iri => {
-                    if AnnotationBuiltIn::Label.to_string().eq(&ap.0.to_string()) {
-                        Some(aax.clone())
-                    } else {
-                        None
+        //If we already have a label in this language, update it:
+        let old_axs: Vec<AnnotatedComponent<ArcStr>> = self
+            .components_for_iri(&iri)
+            .into_iter()
+            .filter(|aax| match label_of(aax) {
+                Some((subj, literal)) if subj == &iri => {
+                    match (literal_lang(literal), &lang) {
+                        (Some(old), Some(new)) => old.eq_ignore_ascii_case(new),
+                        (None, None) => true,
+                        _ => false,
                     }
                 }
-                _ => None,
+                _ => false,
             })
-            .next();
+            .collect();
 
-        if let Some(old_ax) = old_ax {
-            self.take(old_ax);
+        for old_ax in old_axs {
+            self.take(&old_ax);
         }
 
         self.insert(ax1);
         Ok(())
     }
 
-    /// get_iri_for_label(self, label: str) -> Optional[str]
+    /// get_iri_for_label(self, label: str, lang: Optional[str]=None) -> Optional[str]
     ///
     /// Returns the IRI of a term by its label if it exists.
     ///
+    /// Labels are matched regardless of their language tag unless `lang` is given. In that case only
+    /// labels whose language tag matches `lang` are considered (`"en"` matches `"en"` and `"en-GB"`,
+    /// `""` matches labels without language tag).
+    ///
     /// If the term does not have a label, `None` is returned.
-    pub fn get_iri_for_label(&mut self, label: String) -> PyResult<Option<String>> {
+    #[pyo3[signature = (label, lang = None)]]
+    pub fn get_iri_for_label(
+        &mut self,
+        label: String,
+        lang: Option<String>,
+    ) -> PyResult<Option<String>> {
+        let matches_lang = |literal: &Literal<ArcStr>| {
+            lang.as_deref()
+                .is_none_or(|lang| lang_matches(literal_lang(literal), lang))
+        };
+
+        if let Some(label_index) = self.get_label_index() {
+            return Ok(label_index
+                .candidates(&label)
+                .find(|e| e.is_label() && e.literal.literal() == &label && matches_lang(e.literal))
+                .map(|e| e.subject.to_string()));
+        }
+
         let components = if let Some(ref mut component_index) = &mut self.component_index {
             Box::new(component_index.annotation_assertion())
                 as Box<dyn Iterator<Item = &AnnotationAssertion<ArcStr>>>
@@ -377,9 +633,10 @@ impl PyIndexedOntology {
                 ann:
                     Annotation {
                         ap,
-                        av: AnnotationValue::Literal(Literal::Simple { literal }),
+                        av: AnnotationValue::Literal(literal),
                     },
-            } if *literal == label
+            } if literal.literal() == &label
+                && matches_lang(literal)
                 && AnnotationBuiltIn::Label.underlying().eq(&ap.0.to_string()) =>
             {
                 Some(subj)
@@ -390,6 +647,296 @@ impl PyIndexedOntology {
         Ok(labels.next().map(|i| i.to_string()))
     }
 
+    /// get_label(self, iri: model.IRIParam, lang: Optional[str|List[str]]=None) -> Optional[model.Literal]
+    ///
+    /// Returns the label of a term as literal including its language tag or datatype.
+    ///
+    /// `lang` is a preferred language or a list of preferred languages in descending priority. The
+    /// first label matching a language in the list is returned (`"en"` matches `"en"` and `"en-GB"`,
+    /// `""` matches labels without language tag and `"*"` matches any label). If no `lang` is given,
+    /// labels without language tag are preferred over tagged ones.
+    ///
+    /// If the term does not have a matching label, `None` is returned.
+    #[pyo3[signature = (iri, lang = None)]]
+    pub fn get_label(
+        &mut self,
+        py: Python<'_>,
+        iri: model::IRIParam,
+        lang: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<model::Literal>> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+        let langs: Vec<String> = match lang {
+            Some(l) if l.is_instance_of::<pyo3::types::PyString>() => vec![l.extract()?],
+            Some(l) => l.extract()?,
+            None => DEFAULT_LABEL_LANGS.map(String::from).to_vec(),
+        };
+
+        let labels = self.labels(&iri);
+        Ok(select_label(&labels, &langs).map(model::Literal::from))
+    }
+
+    /// get_labels(self, iri: model.IRIParam) -> List[model.Literal]
+    ///
+    /// Returns all labels of a term as literals including their language tags or datatypes.
+    #[pyo3[signature = (iri)]]
+    pub fn get_labels(
+        &mut self,
+        py: Python<'_>,
+        iri: model::IRIParam,
+    ) -> PyResult<Vec<model::Literal>> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+        Ok(self.labels(&iri).iter().map(model::Literal::from).collect())
+    }
+
+    /// get_iris_for_label(self, label: str, synonyms: bool=False, ignore_case: bool=False, normalize_whitespace: bool=False) -> List[str]
+    ///
+    /// Returns the IRIs of all terms with the label `label`.
+    ///
+    /// If `synonyms` is true, the synonym properties (see `PyIndexedOntology.get_synonym_properties`)
+    /// are searched as well. With `ignore_case` labels are compared case-insensitively and with
+    /// `normalize_whitespace` leading, trailing and repeated whitespace is ignored.
+    #[pyo3[signature = (label, synonyms = false, ignore_case = false, normalize_whitespace = false)]]
+    pub fn get_iris_for_label(
+        &mut self,
+        label: String,
+        synonyms: bool,
+        ignore_case: bool,
+        normalize_whitespace: bool,
+    ) -> PyResult<Vec<String>> {
+        let synonym_properties: HashSet<IRI<ArcStr>> =
+            self.synonym_properties.iter().cloned().collect();
+        let matches = |e: &LabelEntry| {
+            (e.is_label() || (synonyms && synonym_properties.contains(e.property)))
+                && label_matches(e.literal.literal(), &label, ignore_case, normalize_whitespace)
+        };
+
+        let iris: BTreeSet<String> = if let Some(label_index) = self.get_label_index() {
+            label_index
+                .candidates(&label)
+                .filter(matches)
+                .map(|e| e.subject.to_string())
+                .collect()
+        } else {
+            (&self.set_index)
+                .into_iter()
+                .filter_map(label_entry)
+                .filter(matches)
+                .map(|e| e.subject.to_string())
+                .collect()
+        };
+
+        Ok(iris.into_iter().collect())
+    }
+
+    /// search(self, query: str, properties: Optional[List[model.IRIParam]]=None, limit: int=20) -> List[SearchHit]
+    ///
+    /// Searches terms by the words in their annotations.
+    ///
+    /// Words of the query match words in the annotations exactly, as prefix, or with a few typos.
+    /// Terms are ranked by their best matching annotation: exact matches of the whole annotation
+    /// rank highest, and labels rank higher than synonyms (see
+    /// `PyIndexedOntology.get_synonym_properties`) which rank higher than definitions.
+    ///
+    /// By default labels, synonyms and definitions are searched. Pass `properties` to search other
+    /// annotation properties.
+    #[pyo3[signature = (query, properties = None, limit = 20)]]
+    pub fn search(
+        &mut self,
+        py: Python<'_>,
+        query: String,
+        properties: Option<Vec<model::IRIParam>>,
+        limit: usize,
+    ) -> PyResult<Vec<SearchHit>> {
+        let properties: Option<HashSet<IRI<ArcStr>>> = match properties {
+            Some(properties) => {
+                let mut iris = HashSet::new();
+                for p in properties {
+                    iris.insert(into_iri!(self, py, p));
+                }
+                Some(iris)
+            }
+            None => None,
+        };
+
+        let label: IRI<ArcStr> = self.build.read().unwrap().iri(AnnotationBuiltIn::Label);
+        let synonyms: HashSet<IRI<ArcStr>> = self.synonym_properties.iter().cloned().collect();
+        let weight = |p: &IRI<ArcStr>| {
+            let weight = if p == &label {
+                3.0
+            } else if synonyms.contains(p) {
+                2.0
+            } else if DEFAULT_DEFINITION_PROPERTIES.contains(&p.as_ref()) || properties.is_some() {
+                1.0
+            } else {
+                return None;
+            };
+
+            match &properties {
+                Some(properties) if !properties.contains(p) => None,
+                _ => Some(weight),
+            }
+        };
+
+        if self.search_index.is_none() && self.index_strategy != IndexCreationStrategy::Explicit {
+            self.build_search_index();
+        }
+
+        match &self.search_index {
+            Some(search_index) => Ok(search_index.search(&query, weight, limit)),
+            None => Err(PyValueError::new_err("Search index not yet build!")),
+        }
+    }
+
+    /// get_synonym_properties(self) -> List[model.IRI]
+    ///
+    /// Returns the annotation properties treated as synonyms by `PyIndexedOntology.get_iris_for_label`.
+    ///
+    /// Defaults to the oboInOwl synonym properties, `skos:prefLabel` and `skos:altLabel`.
+    pub fn get_synonym_properties(&self) -> Vec<model::IRI> {
+        self.synonym_properties
+            .iter()
+            .map(model::IRI::from)
+            .collect()
+    }
+
+    /// set_synonym_properties(self, properties: List[model.IRIParam]) -> None
+    ///
+    /// Sets the annotation properties treated as synonyms by `PyIndexedOntology.get_iris_for_label`.
+    #[pyo3[signature = (properties)]]
+    pub fn set_synonym_properties(
+        &mut self,
+        py: Python<'_>,
+        properties: Vec<model::IRIParam>,
+    ) -> PyResult<()> {
+        let mut synonym_properties = vec![];
+        for p in properties {
+            synonym_properties.push(into_iri!(self, py, p));
+        }
+        self.synonym_properties = synonym_properties;
+
+        if self.label_index.take().is_some() {
+            self.build_label_index();
+        }
+
+        Ok(())
+    }
+
+    /// deprecate_entity(self, iri: model.IRIParam, replaced_by: Optional[model.IRIParam]=None, consider: List[model.IRIParam]=[], reason: Optional[model.IRIParam]=None, remove_references: bool=False) -> List[model.AnnotatedComponent]
+    ///
+    /// Marks a term as obsolete following the OBO obsoletion workflow.
+    ///
+    /// Adds `owl:deprecated true`, prefixes all labels with "obsolete " unless they already start
+    /// with it in any case, adds `IAO:0100001` (term replaced by) and `oboInOwl:consider`
+    /// annotations, and `IAO:0000231` (has obsolescence reason) if a reason such as `IAO:0000227`
+    /// is given. The logical axioms defining the term, e.g. `SubClassOf` axioms with the term as
+    /// sub class, are removed and returned. With `remove_references` the logical axioms of other
+    /// terms referencing it are removed as well.
+    #[pyo3(signature = (iri, replaced_by = None, consider = vec![], reason = None, remove_references = false))]
+    pub fn deprecate_entity(
+        &mut self,
+        py: Python<'_>,
+        iri: model::IRIParam,
+        replaced_by: Option<model::IRIParam>,
+        consider: Vec<model::IRIParam>,
+        reason: Option<model::IRIParam>,
+        remove_references: bool,
+    ) -> PyResult<Vec<model::AnnotatedComponent>> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+
+        let mut annotations: Vec<(IRI<ArcStr>, AnnotationValue<ArcStr>)> = vec![];
+        {
+            let build = self
+                .build
+                .read()
+                .map_err(to_py_err!("Failed get build instance!"))?;
+
+            annotations.push((
+                build.iri(AnnotationBuiltIn::Deprecated),
+                AnnotationValue::Literal(Literal::Datatype {
+                    literal: "true".to_string(),
+                    datatype_iri: build.iri(XSD::Boolean),
+                }),
+            ));
+            if let Some(replaced_by) = replaced_by {
+                annotations.push((
+                    build.iri(IAO_TERM_REPLACED_BY),
+                    into_iri!(self, py, replaced_by).into(),
+                ));
+            }
+            for c in consider {
+                annotations.push((build.iri(OBO_IN_OWL_CONSIDER), into_iri!(self, py, c).into()));
+            }
+            if let Some(reason) = reason {
+                annotations.push((
+                    build.iri(IAO_OBSOLESCENCE_REASON),
+                    into_iri!(self, py, reason).into(),
+                ));
+            }
+        }
+
+        let referencing = self.components_for_iri(&iri);
+
+        let mut removed = vec![];
+        for aax in referencing {
+            match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(subj),
+                    ann: Annotation {
+                        ap,
+                        av: AnnotationValue::Literal(literal),
+                    },
+                }) if subj == &iri && AnnotationBuiltIn::Label.underlying().eq(&ap.0.to_string()) => {
+                    if literal
+                        .literal()
+                        .get(..OBSOLETE_PREFIX.len())
+                        .is_some_and(|p| p.eq_ignore_ascii_case(OBSOLETE_PREFIX))
+                    {
+                        continue;
+                    }
+                    let literal = match literal {
+                        Literal::Simple { literal } => Literal::Simple {
+                            literal: format!("{}{}", OBSOLETE_PREFIX, literal),
+                        },
+                        Literal::Language { literal, lang } => Literal::Language {
+                            literal: format!("{}{}", OBSOLETE_PREFIX, literal),
+                            lang: lang.clone(),
+                        },
+                        Literal::Datatype { literal, datatype_iri } => Literal::Datatype {
+                            literal: format!("{}{}", OBSOLETE_PREFIX, literal),
+                            datatype_iri: datatype_iri.clone(),
+                        },
+                    };
+                    let relabelled = AnnotatedComponent {
+                        component: Component::AnnotationAssertion(AnnotationAssertion {
+                            subject: subj.clone().into(),
+                            ann: Annotation {
+                                ap: ap.clone(),
+                                av: AnnotationValue::Literal(literal),
+                            },
+                        }),
+                        ann: aax.ann.clone(),
+                    };
+                    self.take(&aax);
+                    self.insert(relabelled);
+                }
+                c if is_logical_axiom(c) && (remove_references || defines(c, &iri)) => {
+                    self.take(&aax);
+                    removed.push(model::AnnotatedComponent::from(aax));
+                }
+                _ => {}
+            }
+        }
+
+        for (ap, av) in annotations {
+            self.insert(Component::AnnotationAssertion(AnnotationAssertion {
+                subject: iri.clone().into(),
+                ann: Annotation { ap: ap.into(), av },
+            }));
+        }
+
+        Ok(removed)
+    }
+
     /// get_iri(self) -> Optional[str]
     ///
     /// Returns the ontology iri, if it exists.
@@ -410,6 +957,188 @@ impl PyIndexedOntology {
             .map(model::IRI::from))
     }
 
+    /// set_iri(self, iri: Optional[model.IRIParam]) -> None
+    ///
+    /// Sets the ontology iri. If `None` is passed, the iri is removed.
+    #[pyo3(signature = (iri))]
+    pub fn set_iri(&mut self, py: Python<'_>, iri: Option<model::IRIParam>) -> PyResult<()> {
+        let iri = match iri {
+            Some(iri) => Some(into_iri!(self, py, iri)),
+            None => None,
+        };
+        self.update_id(|id| id.iri = iri);
+        Ok(())
+    }
+
+    /// set_version_iri(self, iri: Optional[model.IRIParam]) -> None
+    ///
+    /// Sets the ontology version iri. If `None` is passed, the version iri is removed.
+    #[pyo3(signature = (iri))]
+    pub fn set_version_iri(
+        &mut self,
+        py: Python<'_>,
+        iri: Option<model::IRIParam>,
+    ) -> PyResult<()> {
+        let iri = match iri {
+            Some(iri) => Some(into_iri!(self, py, iri)),
+            None => None,
+        };
+        self.update_id(|id| id.viri = iri);
+        Ok(())
+    }
+
+    /// get_imports(self) -> List[model.IRI]
+    ///
+    /// Returns the IRIs of all ontologies imported by this ontology.
+    pub fn get_imports(&mut self) -> PyResult<Vec<model::IRI>> {
+        Ok(self
+            .components_for_kind(ComponentKind::Import)
+            .into_iter()
+            .filter_map(|c| match c.component {
+                Component::Import(Import(iri)) => Some(model::IRI::from(iri)),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// add_import(self, iri: model.IRIParam) -> bool
+    ///
+    /// Adds an import of the ontology identified by `iri`. Returns `False` if it was already imported.
+    #[pyo3(signature = (iri))]
+    pub fn add_import(&mut self, py: Python<'_>, iri: model::IRIParam) -> PyResult<bool> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+        let exists = self
+            .components_for_kind(ComponentKind::Import)
+            .iter()
+            .any(|c| matches!(&c.component, Component::Import(Import(i)) if i == &iri));
+
+        if exists {
+            return Ok(false);
+        }
+
+        Ok(self.insert(Component::Import(Import(iri))))
+    }
+
+    /// remove_import(self, iri: model.IRIParam) -> bool
+    ///
+    /// Removes the import of the ontology identified by `iri`. Returns `False` if it was not imported.
+    #[pyo3(signature = (iri))]
+    pub fn remove_import(&mut self, py: Python<'_>, iri: model::IRIParam) -> PyResult<bool> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+        let imports: Vec<_> = self
+            .components_for_kind(ComponentKind::Import)
+            .into_iter()
+            .filter(|c| matches!(&c.component, Component::Import(Import(i)) if i == &iri))
+            .collect();
+
+        let mut removed = false;
+        for import in imports {
+            removed |= self.remove(&import);
+        }
+        Ok(removed)
+    }
+
+    /// get_ontology_annotations(self, ann_iri: Optional[model.IRIParam]=None) -> List[model.Annotation]
+    ///
+    /// Returns the annotations of the ontology itself, optionally restricted to the annotation property `ann_iri`.
+    #[pyo3(signature = (ann_iri = None))]
+    pub fn get_ontology_annotations(
+        &mut self,
+        py: Python<'_>,
+        ann_iri: Option<model::IRIParam>,
+    ) -> PyResult<Vec<model::Annotation>> {
+        let ann_iri = match ann_iri {
+            Some(iri) => Some(into_iri!(self, py, iri)),
+            None => None,
+        };
+
+        Ok(self
+            .ontology_annotations(ann_iri.as_ref())
+            .into_iter()
+            .filter_map(|c| match c.component {
+                Component::OntologyAnnotation(OntologyAnnotation(ann)) => {
+                    Some(model::Annotation::from(ann))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// add_ontology_annotation(self, ann_iri: model.IRIParam, value: model.AnnotationValue | str) -> bool
+    ///
+    /// Adds an annotation to the ontology itself. Strings are added as simple literals.
+    #[pyo3(signature = (ann_iri, value))]
+    pub fn add_ontology_annotation(
+        &mut self,
+        py: Python<'_>,
+        ann_iri: model::IRIParam,
+        value: &Bound<'_, PyAny>,
+    ) -> PyResult<bool> {
+        let ann_iri: IRI<ArcStr> = into_iri!(self, py, ann_iri);
+        let av = annotation_value(value)?;
+
+        Ok(self.insert(Component::OntologyAnnotation(OntologyAnnotation(
+            Annotation {
+                ap: ann_iri.into(),
+                av,
+            },
+        ))))
+    }
+
+    /// set_ontology_annotation(self, ann_iri: model.IRIParam, value: model.AnnotationValue | str) -> None
+    ///
+    /// Sets an annotation of the ontology itself, replacing all existing values for `ann_iri`.
+    /// Strings are added as simple literals.
+    #[pyo3(signature = (ann_iri, value))]
+    pub fn set_ontology_annotation(
+        &mut self,
+        py: Python<'_>,
+        ann_iri: model::IRIParam,
+        value: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let ann_iri: IRI<ArcStr> = into_iri!(self, py, ann_iri);
+        let av = annotation_value(value)?;
+
+        for c in self.ontology_annotations(Some(&ann_iri)) {
+            self.remove(&c);
+        }
+
+        self.insert(Component::OntologyAnnotation(OntologyAnnotation(
+            Annotation {
+                ap: ann_iri.into(),
+                av,
+            },
+        )));
+        Ok(())
+    }
+
+    /// remove_ontology_annotation(self, ann_iri: model.IRIParam, value: Optional[model.AnnotationValue | str]=None) -> bool
+    ///
+    /// Removes annotations of the ontology itself with the annotation property `ann_iri`. If
+    /// `value` is given, only annotations with that value are removed. Returns `False` if nothing was removed.
+    #[pyo3(signature = (ann_iri, value = None))]
+    pub fn remove_ontology_annotation(
+        &mut self,
+        py: Python<'_>,
+        ann_iri: model::IRIParam,
+        value: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        let ann_iri: IRI<ArcStr> = into_iri!(self, py, ann_iri);
+        let av = value.map(annotation_value).transpose()?;
+
+        let mut removed = false;
+        for c in self.ontology_annotations(Some(&ann_iri)) {
+            let matches = match (&c.component, &av) {
+                (Component::OntologyAnnotation(OntologyAnnotation(ann)), Some(av)) => &ann.av == av,
+                _ => true,
+            };
+            if matches {
+                removed |= self.remove(&c);
+            }
+        }
+        Ok(removed)
+    }
+
     /// get_subclasses(self, iri: model.IRIParam) -> Set[str]
     ///
     /// Gets all asserted (named) direct subclasses of a class.
@@ -483,46 +1212,154 @@ impl PyIndexedOntology {
         )
     }
 
-    /// get_classes(self) -> Set[str]
+    /// get_classes(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared classes in the ontology. With `include_undeclared`, all
+    /// classes used anywhere in the ontology are returned. See `classes` for model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_classes(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::Class, ComponentKind::DeclareClass, Component::DeclareClass(x) => x)
+    }
+
+    /// get_datatypes(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared datatypes in the ontology. With `include_undeclared`, all
+    /// datatypes used anywhere in the ontology are returned. See `datatypes` for model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_datatypes(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::Datatype, ComponentKind::DeclareDatatype, Component::DeclareDatatype(x) => x)
+    }
+
+    /// get_object_properties(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared object properties in the ontology. With
+    /// `include_undeclared`, all object properties used anywhere in the ontology are returned. See
+    /// `object_properties` for model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_object_properties(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::ObjectProperty, ComponentKind::DeclareObjectProperty, Component::DeclareObjectProperty(x) => x)
+    }
+
+    /// get_annotation_properties(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared annotation properties in the ontology. With
+    /// `include_undeclared`, all annotation properties used anywhere in the ontology are returned.
+    /// See `annotation_properties` for model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_annotation_properties(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::AnnotationProperty, ComponentKind::DeclareAnnotationProperty, Component::DeclareAnnotationProperty(x) => x)
+    }
+
+    /// get_data_properties(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared data properties in the ontology. With `include_undeclared`,
+    /// all data properties used anywhere in the ontology are returned. See `data_properties` for
+    /// model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_data_properties(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::DataProperty, ComponentKind::DeclareDataProperty, Component::DeclareDataProperty(x) => x)
+    }
+
+    /// get_named_individuals(self, include_undeclared: bool = False) -> Set[str]
+    ///
+    /// Returns the IRIs of all declared named individuals in the ontology. With
+    /// `include_undeclared`, all named individuals used anywhere in the ontology are returned. See
+    /// `named_individuals` for model objects.
+    #[pyo3(signature = (include_undeclared = false))]
+    pub fn get_named_individuals(&mut self, include_undeclared: bool) -> PyResult<HashSet<String>> {
+        entity_query!(self, include_undeclared, NamedOWLEntityKind::NamedIndividual, ComponentKind::DeclareNamedIndividual, Component::DeclareNamedIndividual(x) => x)
+    }
+
+    /// get_undeclared_entities(self) -> Set[typing.Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]
     ///
-    /// Returns the IRIs of all declared classes in the ontology.
-    pub fn get_classes(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareClass, Component::DeclareClass(x) => x)
+    /// Returns all entities used in a component of the ontology without being declared as the
+    /// type they are used as. Entities of the OWL, RDF, RDFS and XSD vocabularies need no
+    /// declaration and are not returned.
+    pub fn get_undeclared_entities<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PySet>> {
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+        let set = PySet::empty(py)?;
+        for entity in undeclared_entities(&components) {
+            set.add(entity_into_py(py, &entity)?)?;
+        }
+        Ok(set)
+    }
+
+    /// add_missing_declarations(self) -> List[model.Component]
+    ///
+    /// Declares all entities returned by `get_undeclared_entities`. The type of each entity
+    /// follows from the position it is used in. Returns the added declarations.
+    pub fn add_missing_declarations(&mut self) -> Vec<model::Component> {
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+        let mut declarations: Vec<_> = undeclared_entities(&components)
+            .into_iter()
+            .map(declaration)
+            .collect();
+        declarations.sort();
+
+        for d in &declarations {
+            self.insert(d.clone());
+        }
+        declarations.iter().map(model::Component::from).collect()
+    }
+
+    /// iter_classes(self) -> Iterator[str]
+    ///
+    /// Returns a lazy iterator over the IRIs of all declared classes in the ontology.
+    pub fn iter_classes(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareClass), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
-    /// get_datatypes(self) -> Set[str]
+    /// iter_datatypes(self) -> Iterator[str]
     ///
-    /// Returns the IRIs of all declared datatypes in the ontology.
-    pub fn get_datatypes(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareDatatype, Component::DeclareDatatype(x) => x)
+    /// Returns a lazy iterator over the IRIs of all declared datatypes in the ontology.
+    pub fn iter_datatypes(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareDatatype), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
-    /// get_object_properties(self) -> Set[str]
+    /// iter_object_properties(self) -> Iterator[str]
     ///
-    /// Returns the IRIs of all declared object properties in the ontology.
-    pub fn get_object_properties(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareObjectProperty, Component::DeclareObjectProperty(x) => x)
+    /// Returns a lazy iterator over the IRIs of all declared object properties in the ontology.
+    pub fn iter_object_properties(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareObjectProperty), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
-    /// get_annotation_properties(self) -> Set[str]
+    /// iter_annotation_properties(self) -> Iterator[str]
     ///
-    /// Returns the IRIs of all declared annotation properties in the ontology.
-    pub fn get_annotation_properties(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareAnnotationProperty, Component::DeclareAnnotationProperty(x) => x)
+    /// Returns a lazy iterator over the IRIs of all declared annotation properties in the ontology.
+    pub fn iter_annotation_properties(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareAnnotationProperty), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
-    /// get_data_properties(self) -> Set[str]
+    /// iter_data_properties(self) -> Iterator[str]
     ///
-    /// Returns the IRIs of all declared data properties in the ontology.
-    pub fn get_data_properties(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareDataProperty, Component::DeclareDataProperty(x) => x)
+    /// Returns a lazy iterator over the IRIs of all declared data properties in the ontology.
+    pub fn iter_data_properties(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareDataProperty), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
-    /// get_named_individuals(self) -> Set[str]
+    /// iter_named_individuals(self) -> Iterator[str]
     ///
-    /// Returns the IRIs of all declared named individuals in the ontology.
-    pub fn get_named_individuals(&mut self) -> PyResult<HashSet<String>> {
-        entity_query!(self, ComponentKind::DeclareNamedIndividual, Component::DeclareNamedIndividual(x) => x)
+    /// Returns a lazy iterator over the IRIs of all declared named individuals in the ontology.
+    pub fn iter_named_individuals(slf: &Bound<'_, Self>) -> EntityIter {
+        let declarations = slf
+            .borrow()
+            .arc_components(Some(ComponentKind::DeclareNamedIndividual), |_| true);
+        EntityIter::new(slf, declarations)
     }
 
     /// get_annotation(self, entity_iri: model.IRIParam, ann_iri: model.IRIParam) -> Optional[str]
@@ -592,6 +1429,39 @@ impl PyIndexedOntology {
         Ok(literal_values)
     }
 
+    /// get_annotation_values(self, entity_iri: model.IRIParam, ann_iri: model.IRIParam) -> List[Tuple[model.AnnotationValue, Set[model.Annotation]]]
+    ///
+    /// Gets all annotation values for an entity and annotation property together with the
+    /// annotations on the respective annotation assertion.
+    ///
+    /// In contrast to `PyIndexedOntology.get_annotations` the values are returned as model objects,
+    /// i.e. literals keep their language tag or datatype and IRI and anonymous individual values
+    /// are included.
+    #[pyo3(signature = (entity_iri, ann_iri))]
+    pub fn get_annotation_values(
+        &mut self,
+        py: Python<'_>,
+        entity_iri: model::IRIParam,
+        ann_iri: model::IRIParam,
+    ) -> PyResult<Vec<(model::AnnotationValue, BTreeSetWrap<model::Annotation>)>> {
+        let entity_iri: IRI<ArcStr> = into_iri!(self, py, entity_iri);
+        let ann_iri: IRI<ArcStr> = into_iri!(self, py, ann_iri);
+
+        Ok(self
+            .components_for_iri(&entity_iri)
+            .iter()
+            .filter_map(|aax| match &aax.component {
+                Component::AnnotationAssertion(AnnotationAssertion {
+                    subject: AnnotationSubject::IRI(s),
+                    ann: Annotation { ap, av },
+                }) if s == &entity_iri && ap.0 == ann_iri => {
+                    Some((model::AnnotationValue::from(av), (&aax.ann).into()))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
     /// save_to_string(self, serialization: typing.Literal['owl', 'rdf','ofn', 'owx']) -> str
     ///
     /// Saves the ontology to a UTF8 string.
@@ -622,6 +1492,63 @@ impl PyIndexedOntology {
         self.save_to_buf(py, &mut file, serialization)
     }
 
+    /// to_triples(self) -> Iterator[typing.Tuple[typing.Union[model.IRI, model.AnonymousIndividual], model.IRI, typing.Union[model.IRI, model.AnonymousIndividual, model.Literal]]]
+    ///
+    /// Yields the ontology as RDF triples using the same OWL to RDF mapping as the RDF/XML writer.
+    ///
+    /// IRIs are returned as `model.IRI`, blank nodes as `model.AnonymousIndividual` and literals as
+    /// `model.Literal`.
+    pub fn to_triples(&self) -> PyResult<rdf::TripleIter> {
+        Ok(rdf::TripleIter::new(self.rdf_triples()?))
+    }
+
+    /// @staticmethod
+    /// from_triples(triples: typing.Iterable[typing.Tuple[typing.Union[model.IRI, model.AnonymousIndividual, str], typing.Union[model.IRI, str], typing.Union[model.IRI, model.AnonymousIndividual, model.Literal, str]]], index_strategy = IndexCreationStrategy.OnQuery) -> PyIndexedOntology
+    ///
+    /// Creates an ontology from RDF triples using the same RDF to OWL mapping as the RDF/XML parser.
+    ///
+    /// Terms are given as returned by `to_triples`. Strings are interpreted as absolute IRIs.
+    ///
+    /// The parser only accepts blank nodes it has read itself, so each triple is written as an
+    /// N-Triples line when the parser asks for more input. The triples are not collected first.
+    #[staticmethod]
+    #[pyo3(signature = (triples, index_strategy = IndexCreationStrategy::OnQuery))]
+    pub fn from_triples(
+        triples: &Bound<'_, PyAny>,
+        index_strategy: IndexCreationStrategy,
+    ) -> PyResult<Self> {
+        let mut reader = rdf::NTriplesReader::new(triples)?;
+        let pio = Self::from_ntriples(&mut reader, index_strategy);
+        reader.finish().and(pio)
+    }
+
+    /// to_rdflib(self) -> typing.Any
+    ///
+    /// Converts the ontology to an `rdflib.Graph` using the same OWL to RDF mapping as the RDF/XML
+    /// writer. The prefixes of the ontology are bound as namespaces of the graph.
+    ///
+    /// Requires rdflib to be installed.
+    pub fn to_rdflib<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let rdflib = rdf::Rdflib::import(py)?;
+        let graph = rdflib.graph()?;
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("replace", true)?;
+        for (prefix, namespace) in self.mapping.borrow(py).0.mappings() {
+            graph.call_method(
+                "bind",
+                (prefix, rdflib.uri_ref(namespace)?),
+                Some(&kwargs),
+            )?;
+        }
+
+        for triple in self.rdf_triples()? {
+            graph.call_method1("add", (rdflib.triple(&triple)?,))?;
+        }
+
+        Ok(graph)
+    }
+
     /// get_axioms_for_iri(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]
     ///
     /// Gets all axioms for an entity.
@@ -696,6 +1623,46 @@ impl PyIndexedOntology {
         }
     }
 
+    /// get_defining_axioms(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]
+    ///
+    /// Gets all components defining an entity: its declarations, the annotation assertions on it
+    /// and the axioms it is the subject of, e.g. `SubClassOf` axioms with the class as sub class
+    /// or property assertions with the individual as source.
+    #[pyo3[signature = (iri)]]
+    pub fn get_defining_axioms(
+        &mut self,
+        py: Python<'_>,
+        iri: model::IRIParam,
+    ) -> PyResult<Vec<model::AnnotatedComponent>> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+
+        Ok(self
+            .components_for_iri(&iri)
+            .iter()
+            .filter(|c| defines(&c.component, &iri))
+            .map(model::AnnotatedComponent::from)
+            .collect())
+    }
+
+    /// get_usages(self, iri: model.IRIParam) -> List[model.AnnotatedComponent]
+    ///
+    /// Gets all components referencing an entity without defining it. See `get_defining_axioms`.
+    #[pyo3[signature = (iri)]]
+    pub fn get_usages(
+        &mut self,
+        py: Python<'_>,
+        iri: model::IRIParam,
+    ) -> PyResult<Vec<model::AnnotatedComponent>> {
+        let iri: IRI<ArcStr> = into_iri!(self, py, iri);
+
+        Ok(self
+            .components_for_iri(&iri)
+            .iter()
+            .filter(|c| !defines(&c.component, &iri))
+            .map(model::AnnotatedComponent::from)
+            .collect())
+    }
+
     /// get_axioms(self) -> List[model.AnnotatedComponent]
     ///
     /// Returns all axioms of the ontology.
@@ -711,19 +1678,173 @@ impl PyIndexedOntology {
             })
             .collect();
 
-        Ok(r)
+        Ok(r)
+    }
+
+    /// get_components(self) -> List[model.AnnotatedComponent]
+    ///
+    /// Returns all axioms of the ontology.
+    pub fn get_components(&mut self) -> PyResult<Vec<model::AnnotatedComponent>> {
+        let r = (&self.set_index)
+            .into_iter()
+            .map(model::AnnotatedComponent::from)
+            .collect();
+
+        Ok(r)
+    }
+
+    /// get_components_by_kind(self, kinds: model.ComponentKind | type | List[model.ComponentKind | type]) -> List[model.AnnotatedComponent]
+    ///
+    /// Returns all components of one or more kinds. Kinds are given either as `model.ComponentKind`
+    /// or as model class such as `model.SubClassOf`.
+    #[pyo3(signature = (kinds))]
+    pub fn get_components_by_kind(
+        &mut self,
+        kinds: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<model::AnnotatedComponent>> {
+        let kinds = component_kinds(kinds)?;
+
+        if let Some(component_index) = self.get_component_index() {
+            return Ok(kinds
+                .into_iter()
+                .flat_map(|k| component_index.component_for_kind(k))
+                .map(model::AnnotatedComponent::from)
+                .collect());
+        }
+
+        Ok((&self.set_index)
+            .into_iter()
+            .filter(|c| kinds.contains(&c.kind()))
+            .map(model::AnnotatedComponent::from)
+            .collect())
+    }
+
+    /// count_by_kind(self) -> Dict[model.ComponentKind, int]
+    ///
+    /// Returns the number of components for each kind occurring in the ontology.
+    pub fn count_by_kind(&self) -> HashMap<model::ComponentKind, usize> {
+        let mut counts = HashMap::new();
+        for c in self.set_index.iter() {
+            *counts.entry(model::ComponentKind::from(c.kind())).or_default() += 1;
+        }
+        counts
+    }
+
+    /// iter_components(self, kind: Optional[model.ComponentKind | type]=None) -> Iterator[model.AnnotatedComponent]
+    ///
+    /// Returns a lazy iterator over all components of the ontology, optionally restricted to a
+    /// `model.ComponentKind` or the components of a model class such as `model.SubClassOf`.
+    ///
+    /// Components are converted to Python objects one at a time. Modifying the ontology while
+    /// iterating raises a `RuntimeError` on the next iteration step.
+    #[pyo3(signature = (kind = None))]
+    pub fn iter_components(
+        slf: &Bound<'_, Self>,
+        kind: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<ComponentIter> {
+        let kind = kind.map(component_kind).transpose()?;
+        let components = slf.borrow().arc_components(kind, |_| true);
+        Ok(ComponentIter::new(slf, components))
+    }
+
+    /// iter_axioms(self, kind: Optional[model.ComponentKind | type]=None) -> Iterator[model.AnnotatedComponent]
+    ///
+    /// Returns a lazy iterator over all axioms of the ontology, optionally restricted to a
+    /// `model.ComponentKind` or the axioms of a model class such as `model.SubClassOf`.
+    ///
+    /// Axioms are converted to Python objects one at a time. Modifying the ontology while
+    /// iterating raises a `RuntimeError` on the next iteration step.
+    #[pyo3(signature = (kind = None))]
+    pub fn iter_axioms(
+        slf: &Bound<'_, Self>,
+        kind: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<ComponentIter> {
+        let kind = kind.map(component_kind).transpose()?;
+        let components = slf.borrow().arc_components(kind, |c| c.is_axiom());
+        Ok(ComponentIter::new(slf, components))
+    }
+
+    /// iter_components_for_iri(self, iri: model.IRIParam) -> Iterator[model.AnnotatedComponent]
+    ///
+    /// Returns a lazy iterator over all components (axiom, swrl, and meta component) for an entity.
+    ///
+    /// Modifying the ontology while iterating raises a `RuntimeError` on the next iteration step.
+    #[pyo3[signature = (iri)]]
+    pub fn iter_components_for_iri(
+        slf: &Bound<'_, Self>,
+        iri: model::IRIParam,
+    ) -> PyResult<ComponentIter> {
+        let components = {
+            let mut o = slf.borrow_mut();
+            let py = slf.py();
+            let iri: IRI<ArcStr> = into_iri!(o, py, iri);
+
+            if o.iri_index.is_none() && o.index_strategy == IndexCreationStrategy::OnQuery {
+                o.build_iri_index();
+            }
+
+            match &o.iri_index {
+                Some(iri_index) => iri_index
+                    .component_for_iri(&iri)
+                    .filter_map(|c| o.set_index.get(c))
+                    .cloned()
+                    .collect(),
+                None => return Err(PyValueError::new_err("IRI index not yet build!")),
+            }
+        };
+
+        Ok(ComponentIter::new(slf, components))
     }
 
-    /// get_components(self) -> List[model.AnnotatedComponent]
+    /// match_pattern(self, pattern: model.Component) -> List[Dict[str, typing.Any]]
     ///
-    /// Returns all axioms of the ontology.
-    pub fn get_components(&mut self) -> PyResult<Vec<model::AnnotatedComponent>> {
-        let r = (&self.set_index)
+    /// Matches a component containing `model.Var` placeholders against the components of the
+    /// ontology and returns the variable bindings of every match, e.g.
+    /// `SubClassOf(Var("x"), ObjectSomeValuesFrom(part_of, Var("y")))`.
+    ///
+    /// An entity wrapping a variable such as `AnnotationProperty(Var("p"))` matches any entity of
+    /// that type. All occurrences of a variable have to match equal nodes. Lists are matched in
+    /// order. Candidates are narrowed down by the kind of the pattern and by the IRIs occurring in
+    /// it.
+    #[pyo3[signature = (pattern)]]
+    pub fn match_pattern<'py>(
+        &mut self,
+        py: Python<'py>,
+        pattern: model::Component,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let component: Component<ArcStr> = (&pattern).into();
+        let kind = component.kind();
+
+        let mut walk = Walk::new(IRIExtract::default());
+        walk.component(&component);
+        let fixed_iris: Vec<IRI<ArcStr>> = walk
+            .into_visit()
+            .into_vec()
             .into_iter()
-            .map(model::AnnotatedComponent::from)
+            .filter(|iri| model::var_name(iri).is_none())
             .collect();
 
-        Ok(r)
+        let candidates = fixed_iris
+            .iter()
+            .map(|iri| self.components_for_iri(iri))
+            .min_by_key(Vec::len)
+            .unwrap_or_else(|| self.components_for_kind(kind));
+
+        let pattern = pattern.into_pyobject(py)?;
+        let mut results = vec![];
+        for candidate in candidates.iter().filter(|c| c.kind() == kind) {
+            let candidate = model::Component::from(&candidate.component).into_pyobject(py)?;
+            let mut bindings = vec![];
+            if unify(&pattern, &candidate, &mut bindings)? {
+                let result = PyDict::new(py);
+                for (name, value) in bindings {
+                    result.set_item(name, value)?;
+                }
+                results.push(result);
+            }
+        }
+
+        Ok(results)
     }
 
     /// add_component(self, component: model.Component, annotations: Optional[List[model.Annotation]|Set[model.Annotation]]=None) -> None
@@ -812,6 +1933,248 @@ impl PyIndexedOntology {
         Ok(model::IRI::new(iri, &build))
     }
 
+    /// parse_class_expression(self, expression: str) -> model.ClassExpression
+    ///
+    /// Parses a class expression in Manchester syntax, e.g. `"part_of some (nucleus and not cytoplasm)"`.
+    ///
+    /// Names are written as full IRIs (`<https://example.com/A>`), prefixed names (`ex:A`, `:A`),
+    /// quoted labels (`'cell nucleus'`) or bare labels. Bare names without prefix are looked up as label
+    /// first and then in the default prefix. A property is read as data property if it is declared as one
+    /// or its filler is a literal or a datatype.
+    ///
+    /// Raises a `ValueError` pointing at the offending part of `expression` if it cannot be parsed.
+    pub fn parse_class_expression(
+        &mut self,
+        py: Python<'_>,
+        expression: &str,
+    ) -> PyResult<model::ClassExpression> {
+        let mut resolver = Resolver::new(py, self)?;
+        let ce = parse::parse_class_expression(expression, &mut resolver)
+            .map_err(|e| e.into_py_err(expression))?;
+        Ok((&ce).into())
+    }
+
+    /// parse_axiom(self, axiom: str) -> model.AnnotatedComponent
+    ///
+    /// Parses a single axiom in functional syntax, e.g. `"SubClassOf(:A ObjectSomeValuesFrom(:part_of :B))"`.
+    ///
+    /// Prefixed names are expanded with the prefix mapping of the ontology, which is extended by the
+    /// `owl`, `rdf`, `rdfs` and `xsd` prefixes. Entities can also be referenced by their quoted label
+    /// (`'cell nucleus'`). Axiom annotations are returned with the component.
+    ///
+    /// Raises a `ValueError` pointing at the offending part of `axiom` if it cannot be parsed.
+    pub fn parse_axiom(
+        &mut self,
+        py: Python<'_>,
+        axiom: &str,
+    ) -> PyResult<model::AnnotatedComponent> {
+        let mut resolver = Resolver::new(py, self)?;
+        let component =
+            parse::parse_axiom(axiom, &mut resolver).map_err(|e| e.into_py_err(axiom))?;
+        Ok((&component).into())
+    }
+
+    /// render(self, obj: typing.Union[model.Component, model.AnnotatedComponent, model.ClassExpression, model.ObjectPropertyExpression, model.DataRange, model.Individual, model.Literal, model.Annotation], syntax: typing.Literal["manchester", "functional"] = "manchester", use_labels: bool = True, use_curies: bool = True) -> str
+    ///
+    /// Renders a component or class expression in Manchester or functional syntax.
+    ///
+    /// With `use_labels`, entities are written as their `rdfs:label`, quoted if it contains spaces or
+    /// other characters not allowed in names. Otherwise, or for entities without label, they are
+    /// written as prefixed names if `use_curies` is set and the prefix mapping allows it, and as full
+    /// IRIs else. Components are written in Manchester syntax as in Protégé, e.g. `A SubClassOf B`.
+    #[pyo3(signature = (obj, syntax = "manchester", use_labels = true, use_curies = true))]
+    pub fn render(
+        &mut self,
+        py: Python<'_>,
+        obj: Transformable,
+        syntax: &str,
+        use_labels: bool,
+        use_curies: bool,
+    ) -> PyResult<String> {
+        let syntax = match syntax {
+            "manchester" => Syntax::Manchester,
+            "functional" => Syntax::Functional,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown syntax '{syntax}', expected 'manchester' or 'functional'"
+                )))
+            }
+        };
+        let mapping = self.mapping.borrow(py).0.clone();
+
+        Ok(Renderer::new(self, &mapping, syntax, use_labels, use_curies).render(&obj))
+    }
+
+    /// check_profile(self, profile: typing.Literal["EL", "QL", "RL", "DL"]) -> ProfileReport
+    ///
+    /// Checks the ontology against an OWL 2 profile.
+    ///
+    /// Returns a report listing each violating component with the reason, e.g. a disallowed
+    /// constructor, a non-simple property in a cardinality restriction or an undeclared or punned
+    /// entity. A component is reported once per reason. The global restrictions of OWL 2 DL are
+    /// checked for every profile, except for the regularity of property hierarchies.
+    #[pyo3(signature = (profile))]
+    pub fn check_profile(&self, profile: &str) -> PyResult<ProfileReport> {
+        let profile = Profile::try_from(profile)?;
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+
+        Ok(profile::check_profile(&components, profile))
+    }
+
+    /// metrics(self) -> OntologyMetrics
+    ///
+    /// Computes statistics of the ontology: the number of components per kind and of entities per
+    /// type, the number of logical and annotation axioms, the depth of the asserted class hierarchy,
+    /// the number of root classes, the namespaces of the entities and the DL expressivity.
+    pub fn metrics(&mut self) -> OntologyMetrics {
+        // Builds the component index if the strategy allows it
+        self.get_component_index();
+        let subclass_axioms = self.arc_components(Some(ComponentKind::SubClassOf), |_| true);
+
+        metrics::metrics(
+            self.set_index.iter().map(|c| c.as_ref()),
+            subclass_axioms.iter().map(|c| c.as_ref()),
+        )
+    }
+
+    /// lint(self, rules: Optional[Union[List[str], Dict[str, typing.Literal["ERROR", "WARN", "INFO"]]]] = None) -> List[LintFinding]
+    ///
+    /// Checks the ontology for common quality problems, similar to the ROBOT report.
+    ///
+    /// The available rules are `missing_label`, `multiple_labels` (per language), `duplicate_label`
+    /// (across entities), `missing_definition` (for classes and properties that are not deprecated),
+    /// `undeclared_entity`, `punned_entity`, `deprecated_entity_reference` (in logical axioms) and
+    /// `self_referential_subclass`. By default all rules are checked with their default severity.
+    /// `rules` selects the rules to check, either as list of rule ids or as dictionary from rule id
+    /// to severity.
+    ///
+    /// Findings are ordered by rule, subject and component.
+    #[pyo3(signature = (rules = None))]
+    pub fn lint(&self, rules: Option<RuleSelection>) -> PyResult<Vec<LintFinding>> {
+        let severities = lint::rule_severities(rules)?;
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+
+        Ok(lint::lint(&components, &severities))
+    }
+
+    /// to_tables(self) -> Dict[str, Dict[str, List[Optional[str]]]]
+    ///
+    /// Flattens the ontology into normalized tables, each given as dictionary from column name to
+    /// a list of strings or `None`:
+    ///
+    /// - `entities`: `iri`, `type` (e.g. "Class") and `label` of each entity in the signature. The
+    ///   label is the one returned by `get_label`.
+    /// - `annotation_assertions`: `subject`, `property`, `value`, `value_type` ("literal", "iri" or
+    ///   "anonymous"), `lang` and `datatype`.
+    /// - `subclass_edges`: `subclass` and `superclass` of each `SubClassOf` between named classes.
+    /// - `existential_edges`: `subclass`, `property` and `filler` of each `SubClassOf` of a named
+    ///   class and an `ObjectSomeValuesFrom` on a named property and class, also as operand of an
+    ///   `ObjectIntersectionOf`.
+    /// - `components`: `kind` and functional syntax `text` of each component.
+    pub fn to_tables<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        tables::tables(
+            self.set_index.iter().map(|c| c.as_ref()),
+            &self.preferred_labels(),
+        )
+        .into_py(
+            py,
+            |py, values| Ok(PyList::new(py, values)?.into_any()),
+            |columns| Ok(columns.into_any()),
+        )
+    }
+
+    /// to_arrow(self) -> Dict[str, typing.Any]
+    ///
+    /// Returns the tables of `to_tables` as `pyarrow.Table` with string columns.
+    ///
+    /// Requires pyarrow to be installed.
+    pub fn to_arrow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let pyarrow = py.import("pyarrow")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("type", pyarrow.call_method0("string")?)?;
+
+        tables::tables(
+            self.set_index.iter().map(|c| c.as_ref()),
+            &self.preferred_labels(),
+        )
+        .into_py(
+            py,
+            |_, values| pyarrow.call_method("array", (values,), Some(&kwargs)),
+            |columns| pyarrow.call_method1("table", (columns,)),
+        )
+    }
+
+    /// to_pandas(self) -> Dict[str, typing.Any]
+    ///
+    /// Returns the tables of `to_tables` as `pandas.DataFrame`.
+    ///
+    /// Requires pandas to be installed.
+    pub fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let pandas = py.import("pandas")?;
+
+        tables::tables(
+            self.set_index.iter().map(|c| c.as_ref()),
+            &self.preferred_labels(),
+        )
+        .into_py(
+            py,
+            |py, values| Ok(PyList::new(py, values)?.into_any()),
+            |columns| pandas.call_method1("DataFrame", (columns,)),
+        )
+    }
+
+    /// to_graph(self, edges: Optional[List[typing.Literal["subclass", "some_values_from", "equivalent"]]] = None, use_labels: bool = True) -> Tuple[List[Tuple[str, Dict[str, str]]], List[Tuple[str, str, Dict[str, str]]]]
+    ///
+    /// Returns the graph of the named classes as node and edge lists, which can be passed to
+    /// `networkx.DiGraph.add_nodes_from` and `networkx.DiGraph.add_edges_from`.
+    ///
+    /// Nodes are `(iri, {"label": label})` for each class. Edges are `(source, target, {"type": type})`
+    /// of the types given in `edges`, by default all of them:
+    ///
+    /// - `subclass`: from `A` to `B` for `SubClassOf(A B)`, also if `B` is an operand of an
+    ///   `ObjectIntersectionOf`.
+    /// - `some_values_from`: from `A` to `B` for `SubClassOf(A ObjectSomeValuesFrom(p B))`, also
+    ///   as operand of an `ObjectIntersectionOf`. These edges also have the `property` `p` and its
+    ///   `label`.
+    /// - `equivalent`: between each pair of named classes in `EquivalentClasses`.
+    ///
+    /// `EquivalentClasses(A C)` of a named class `A` and a class expression `C` also gives the
+    /// edges of `SubClassOf(A C)`. With `use_labels` the labels are the ones returned by
+    /// `get_label`, otherwise they are IRIs.
+    #[pyo3(signature = (edges = None, use_labels = true))]
+    pub fn to_graph<'py>(
+        &self,
+        py: Python<'py>,
+        edges: Option<Vec<String>>,
+        use_labels: bool,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let kinds = graph::edge_kinds(edges)?;
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+        let labels = if use_labels {
+            self.preferred_labels()
+        } else {
+            HashMap::new()
+        };
+        Graph::new(&components, &kinds, labels).into_py(py)
+    }
+
+    /// to_dot(self, edges: Optional[List[typing.Literal["subclass", "some_values_from", "equivalent"]]] = None, use_labels: bool = True) -> str
+    ///
+    /// Returns the graph of `to_graph` in the Graphviz DOT language. `subclass` edges have an empty
+    /// arrowhead, `some_values_from` edges are labelled with the property and `equivalent` edges
+    /// are dashed and undirected.
+    #[pyo3(signature = (edges = None, use_labels = true))]
+    pub fn to_dot(&self, edges: Option<Vec<String>>, use_labels: bool) -> PyResult<String> {
+        let kinds = graph::edge_kinds(edges)?;
+        let components: Vec<_> = self.set_index.iter().map(|c| c.as_ref()).collect();
+        let labels = if use_labels {
+            self.preferred_labels()
+        } else {
+            HashMap::new()
+        };
+        Ok(Graph::new(&components, &kinds, labels).to_dot())
+    }
+
     /// clazz(self, iri: model.IRIParam) -> model.Class
     ///
     /// Convenience method to create a Class from an IRI.
@@ -1060,10 +2423,51 @@ impl PyIndexedOntology {
         self.component_index = Some(component_index);
     }
 
+    /// build_label_index(self) -> None
+    ///
+    /// Builds an index by label and synonym.
+    pub fn build_label_index(&mut self) {
+        if self.label_index.is_some() {
+            return;
+        }
+
+        let mut label_index = LabelIndex::new(
+            &self.build.read().unwrap(),
+            &self.synonym_properties,
+        );
+
+        for c in self.set_index.iter() {
+            label_index.index_insert(c.clone());
+        }
+
+        self.label_index = Some(label_index);
+    }
+
+    /// build_search_index(self) -> None
+    ///
+    /// Builds the full text index used by `PyIndexedOntology.search`.
+    pub fn build_search_index(&mut self) {
+        if self.search_index.is_some() {
+            return;
+        }
+
+        let mut search_index = SearchIndex::new();
+
+        for c in self.set_index.iter() {
+            search_index.index_insert(c.clone());
+        }
+
+        self.search_index = Some(search_index);
+    }
+
     /// build_indexes(self) -> None
     ///
     /// Builds indexes to allow (a quicker) access to axioms and entities.
     pub fn build_indexes(&mut self) {
+        self.build_label_index();
+        self.build_search_index();
+        self.build_signature_index();
+
         match (&self.iri_index, &self.component_index) {
             (Some(_), Some(_)) => return,
             (Some(_), None) => return self.build_component_index(),
@@ -1084,7 +2488,43 @@ impl PyIndexedOntology {
     }
 }
 
+// Answered from the signature index if it has been built. The methods take `&self`, so they cannot
+// build it themselves.
+crate::signature_methods!(PyIndexedOntology, this => match &this.signature_index {
+    Some(index) => index.signature(),
+    None => Signature::of_components(this.set_index.iter().map(AsRef::as_ref)),
+});
+
 impl PyIndexedOntology {
+    /// Inserts a component without copying it, sharing it with the other holders of `ax`.
+    fn insert_arc(&mut self, ax: ArcAnnotatedComponent) -> bool {
+        if let Some(ref mut iri_index) = &mut self.iri_index {
+            iri_index.index_insert(ax.clone());
+        }
+        if let Some(ref mut component_index) = &mut self.component_index {
+            component_index.index_insert(ax.clone());
+        }
+        if let Some(ref mut label_index) = &mut self.label_index {
+            label_index.index_insert(ax.clone());
+        }
+        if let Some(ref mut search_index) = &mut self.search_index {
+            search_index.index_insert(ax.clone());
+        }
+        for reasoner in &mut self.reasoners {
+            reasoner.0.lock().unwrap().0.index_insert(ax.clone());
+        }
+
+        // The signature index counts usages, so it only sees components new to the ontology
+        let inserted = self.set_index.index_insert(ax.clone());
+        if inserted {
+            if let Some(ref mut signature_index) = &mut self.signature_index {
+                signature_index.index_insert(ax);
+            }
+            self.modification_count += 1;
+        }
+        inserted
+    }
+
     fn create_structural_reasoner(&self) -> StructuralReasoner {
         StructuralReasoner::create_reasoner(self.into())
     }
@@ -1099,6 +2539,36 @@ impl PyIndexedOntology {
         self.component_index.as_ref()
     }
 
+    fn build_signature_index(&mut self) {
+        if self.signature_index.is_some() {
+            return;
+        }
+
+        let mut signature_index = SignatureIndex::default();
+
+        for c in self.set_index.iter() {
+            signature_index.index_insert(c.clone());
+        }
+
+        self.signature_index = Some(signature_index);
+    }
+
+    fn get_signature_index(&mut self) -> Option<&SignatureIndex> {
+        if self.signature_index.is_none() && self.index_strategy == IndexCreationStrategy::OnQuery {
+            self.build_signature_index();
+        }
+
+        self.signature_index.as_ref()
+    }
+
+    fn get_label_index(&mut self) -> Option<&LabelIndex> {
+        if self.label_index.is_none() && self.index_strategy == IndexCreationStrategy::OnQuery {
+            self.build_label_index();
+        }
+
+        self.label_index.as_ref()
+    }
+
     fn get_iri_index(&mut self) -> Option<&IRIMappedIndex<ArcStr, ArcAnnotatedComponent>> {
         if self.iri_index.is_none() && self.index_strategy == IndexCreationStrategy::OnQuery {
             self.build_iri_index();
@@ -1107,6 +2577,141 @@ impl PyIndexedOntology {
         self.iri_index.as_ref()
     }
 
+    /// Returns all components referencing `iri`. Uses the IRI index if available and falls back
+    /// to scanning all components otherwise.
+    pub(crate) fn components_for_iri(
+        &mut self,
+        iri: &IRI<ArcStr>,
+    ) -> Vec<AnnotatedComponent<ArcStr>> {
+        if let Some(iri_index) = self.get_iri_index() {
+            return iri_index.component_for_iri(iri).cloned().collect();
+        }
+
+        (&self.set_index)
+            .into_iter()
+            .filter(|aax| {
+                let mut walk = Walk::new(IRIExtract::default());
+                walk.annotated_component(aax);
+                walk.into_visit().into_vec().contains(iri)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns pointers to all components of `kind` (or of any kind) satisfying `filter` without
+    /// cloning the components. Uses the component index if available.
+    pub(crate) fn arc_components<F: Fn(&AnnotatedComponent<ArcStr>) -> bool>(
+        &self,
+        kind: Option<ComponentKind>,
+        filter: F,
+    ) -> Vec<ArcAnnotatedComponent> {
+        match (kind, &self.component_index) {
+            (Some(kind), Some(component_index)) => component_index
+                .component_for_kind(kind)
+                .filter(|c| filter(c))
+                .filter_map(|c| self.set_index.get(c))
+                .cloned()
+                .collect(),
+            _ => self
+                .set_index
+                .iter()
+                .filter(|c| kind.is_none_or(|k| c.kind() == k) && filter(c))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns all components of the given kind. Uses the component index if available and falls
+    /// back to scanning all components otherwise.
+    pub(crate) fn components_for_kind(
+        &mut self,
+        kind: ComponentKind,
+    ) -> Vec<AnnotatedComponent<ArcStr>> {
+        if let Some(component_index) = self.get_component_index() {
+            return component_index.component_for_kind(kind).cloned().collect();
+        }
+
+        (&self.set_index)
+            .into_iter()
+            .filter(|aax| aax.kind() == kind)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns all `rdfs:label` literals of `iri`.
+    pub(crate) fn labels(&mut self, iri: &IRI<ArcStr>) -> Vec<Literal<ArcStr>> {
+        let mut labels: Vec<Literal<ArcStr>> = self
+            .components_for_iri(iri)
+            .iter()
+            .filter_map(|aax| match label_of(aax) {
+                Some((subj, literal)) if subj == iri => Some(literal.clone()),
+                _ => None,
+            })
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    /// Returns the label `get_label` returns without `lang` for each IRI with an `rdfs:label`.
+    fn preferred_labels(&self) -> HashMap<&IRI<ArcStr>, &Literal<ArcStr>> {
+        let mut labels: HashMap<_, Vec<_>> = HashMap::new();
+        for c in self.set_index.iter() {
+            if let Some((subject, literal)) = label_of(c) {
+                labels.entry(subject).or_default().push(literal);
+            }
+        }
+
+        labels
+            .into_iter()
+            .filter_map(|(iri, mut literals)| {
+                literals.sort();
+                select_label(&literals, &DEFAULT_LABEL_LANGS).map(|l| (iri, *l))
+            })
+            .collect()
+    }
+
+    /// Returns the ontology annotations, optionally restricted to a single annotation property.
+    fn ontology_annotations(
+        &mut self,
+        ann_iri: Option<&IRI<ArcStr>>,
+    ) -> Vec<AnnotatedComponent<ArcStr>> {
+        self.components_for_kind(ComponentKind::OntologyAnnotation)
+            .into_iter()
+            .filter(|c| match (&c.component, ann_iri) {
+                (Component::OntologyAnnotation(OntologyAnnotation(ann)), Some(iri)) => {
+                    &ann.ap.0 == iri
+                }
+                (Component::OntologyAnnotation(_), None) => true,
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Replaces all `OntologyID` components by a single one, modified by `f`. Iri and version iri
+    /// are taken from the first component defining them, annotations of all components are kept.
+    fn update_id<F: FnOnce(&mut OntologyID<ArcStr>)>(&mut self, f: F) {
+        let mut id = OntologyID::default();
+        let mut ann = BTreeSet::new();
+
+        for c in self.components_for_kind(ComponentKind::OntologyID) {
+            self.remove(&c);
+            if let Component::OntologyID(old) = c.component {
+                id.iri = id.iri.or(old.iri);
+                id.viri = id.viri.or(old.viri);
+            }
+            ann.extend(c.ann);
+        }
+
+        f(&mut id);
+
+        if id.iri.is_some() || id.viri.is_some() || !ann.is_empty() {
+            self.insert(AnnotatedComponent {
+                component: Component::OntologyID(id),
+                ann,
+            });
+        }
+    }
+
     pub fn get_subclasses(&mut self, iri: &IRI<ArcStr>) -> HashSet<ClassExpression<ArcStr>> {
         let subclassof_axioms: Box<dyn std::iter::Iterator<Item = &AnnotatedComponent<ArcStr>>> =
             if let Some(iri_index) = self.get_iri_index() {
@@ -1188,6 +2793,10 @@ impl PyIndexedOntology {
             pio.insert(cmp);
         }
 
+        if let IndexCreationStrategy::OnLoad = index_strategy {
+            pio.build_indexes();
+        }
+
         pio
     }
 
@@ -1198,11 +2807,71 @@ impl PyIndexedOntology {
         let mut pio = Self::new(index_strategy);
         let (set_index, _, _) = value.index();
 
-        pio.set_index = set_index;
+        pio.set_index = set_index.into();
+
+        if let IndexCreationStrategy::OnLoad = index_strategy {
+            pio.build_indexes();
+        }
 
         pio
     }
 
+    /// Copies the components into a `ComponentMappedOntology` as that is what horned owl writes.
+    fn component_mapped_ontology(&self) -> ArcComponentMappedOntology {
+        let mut amo: ArcComponentMappedOntology = ComponentMappedOntology::new_arc();
+
+        for component in (&self.set_index).into_iter() {
+            amo.insert(component.clone());
+        }
+
+        amo
+    }
+
+    /// Returns the ontology as RDF triples.
+    fn rdf_triples(&self) -> PyResult<Vec<[rdf::RdfTerm; 3]>> {
+        let mut triples = Vec::new();
+        horned_owl::io::rdf::writer::write_to_rdf_formatter(
+            &self.component_mapped_ontology(),
+            rdf::TripleCollector(&mut triples),
+        )
+        .map_err(to_py_err!("Failed to convert the ontology to RDF"))?;
+
+        let build = self.build.read().unwrap();
+        Ok(triples
+            .iter()
+            .map(|t| rdf::RdfTerm::triple(&build, t))
+            .collect())
+    }
+
+    /// Reads an ontology from N-Triples.
+    pub fn from_ntriples<R: BufRead>(
+        ntriples: &mut R,
+        index_strategy: IndexCreationStrategy,
+    ) -> PyResult<Self> {
+        let b = Build::new_arc();
+        let (o, _) = horned_owl::io::rdf::reader::read_with_build::<
+            ArcStr,
+            ArcAnnotatedComponent,
+            _,
+        >(
+            ntriples,
+            &b,
+            ParserConfiguration {
+                rdf: RDFParserConfiguration {
+                    lax: true,
+                    format: Some(oxrdfio::RdfFormat::NTriples),
+                },
+                ..Default::default()
+            },
+        )
+        .map_err(to_py_err!("Failed to read the triples"))?;
+
+        let mut pio = Self::from_rdf_ontology(o, index_strategy);
+        pio.build = RwLock::new(b);
+
+        Ok(pio)
+    }
+
     fn save_to_buf<W: Write>(
         &mut self,
         py: Python<'_>,
@@ -1210,12 +2879,7 @@ impl PyIndexedOntology {
         serialization: ResourceType,
     ) -> PyResult<()> {
         let mut file = w;
-        let mut amo: ArcComponentMappedOntology = ComponentMappedOntology::new_arc();
-
-        //Copy the components into an ComponentMappedOntology as that is what horned owl writes
-        for component in (&self.set_index).into_iter() {
-            amo.insert(component.clone());
-        }
+        let amo = self.component_mapped_ontology();
 
         let mapping = self.mapping.borrow(py);
 
@@ -1253,3 +2917,82 @@ impl From<PyIndexedOntology> for SetOntology<ArcStr> {
         o
     }
 }
+
+/// Returns subject and literal if `aax` is an `rdfs:label` assertion on an IRI.
+fn label_of(aax: &AnnotatedComponent<ArcStr>) -> Option<(&IRI<ArcStr>, &Literal<ArcStr>)> {
+    label_entry(aax)
+        .filter(|e| e.is_label())
+        .map(|e| (e.subject, e.literal))
+}
+
+/// The language ranges of `get_label` without `lang`: labels without language tag are preferred over
+/// tagged ones.
+const DEFAULT_LABEL_LANGS: [&str; 2] = ["", "*"];
+
+/// Returns the first of the sorted `labels` matching a language range in `langs`, trying the ranges
+/// in order.
+fn select_label<'a, L, S>(labels: &'a [L], langs: &[S]) -> Option<&'a L>
+where
+    L: Borrow<Literal<ArcStr>>,
+    S: AsRef<str>,
+{
+    langs.iter().find_map(|lang| {
+        let lang = lang.as_ref();
+        labels
+            .iter()
+            .find(|&l| lang == "*" || lang_matches(literal_lang(l.borrow()), lang))
+    })
+}
+
+/// Returns the language tag of a literal, if it has one.
+fn literal_lang(literal: &Literal<ArcStr>) -> Option<&str> {
+    match literal {
+        Literal::Language { lang, .. } => Some(lang),
+        _ => None,
+    }
+}
+
+/// Basic filtering of a language tag against a language range (RFC 4647). The empty range only
+/// matches literals without language tag.
+fn lang_matches(lang: Option<&str>, range: &str) -> bool {
+    match lang {
+        None => range.is_empty(),
+        Some(lang) => {
+            !range.is_empty()
+                && (lang.eq_ignore_ascii_case(range)
+                    || (lang.len() > range.len()
+                        && lang.as_bytes()[range.len()] == b'-'
+                        && lang[..range.len()].eq_ignore_ascii_case(range)))
+        }
+    }
+}
+
+/// Extracts an annotation value, treating strings as simple literals.
+fn annotation_value(value: &Bound<'_, PyAny>) -> PyResult<AnnotationValue<ArcStr>> {
+    if let Ok(literal) = value.extract::<String>() {
+        return Ok(AnnotationValue::Literal(Literal::Simple { literal }));
+    }
+
+    Ok(value.extract::<model::AnnotationValue>()?.into())
+}
+
+/// Whether a component is a logical axiom, i.e. an axiom that is neither a declaration nor an
+/// annotation axiom.
+pub(crate) fn is_logical_axiom(component: &Component<ArcStr>) -> bool {
+    component.is_axiom()
+        && !matches!(
+            component.kind(),
+            ComponentKind::OntologyAnnotation
+                | ComponentKind::Import
+                | ComponentKind::DeclareClass
+                | ComponentKind::DeclareObjectProperty
+                | ComponentKind::DeclareAnnotationProperty
+                | ComponentKind::DeclareDataProperty
+                | ComponentKind::DeclareNamedIndividual
+                | ComponentKind::DeclareDatatype
+                | ComponentKind::AnnotationAssertion
+                | ComponentKind::SubAnnotationPropertyOf
+                | ComponentKind::AnnotationPropertyDomain
+                | ComponentKind::AnnotationPropertyRange
+        )
+}
@@ -0,0 +1,940 @@
+//! Parsing of single class expressions and axioms
+//!
+//! Class expressions are read in Manchester syntax by the small recursive descent parser in this
+//! module. Axioms are read in functional syntax by wrapping them in an ontology document for the
+//! horned-owl OFN reader. In both, names are resolved through the prefix mapping and the labels of
+//! an ontology.
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Arc;
+
+use horned_owl::error::{HornedError, Location};
+use horned_owl::io::ofn::reader::read_with_build;
+use horned_owl::model::*;
+use horned_owl::ontology::set::SetOntology;
+use horned_owl::vocab::Facet;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::ontology::PyIndexedOntology;
+
+pub(crate) const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const RDFS_LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+const OWL_THING: &str = "http://www.w3.org/2002/07/owl#Thing";
+
+const STANDARD_PREFIXES: [(&str, &str); 4] = [
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("xsd", XSD),
+];
+
+/// Returns a copy of `mapping` extended by the `owl`, `rdf`, `rdfs` and `xsd` prefixes unless they
+/// are already mapped.
+pub fn with_standard_prefixes(mapping: &curie::PrefixMapping) -> curie::PrefixMapping {
+    let mut mapping = mapping.clone();
+    for (prefix, iri) in STANDARD_PREFIXES {
+        if mapping.expand_curie_string(&format!("{prefix}:")).is_err() {
+            mapping.add_prefix(prefix, iri).ok();
+        }
+    }
+    mapping
+}
+
+/// Datatypes of the OWL 2 datatype map that may be written without prefix in data ranges.
+const XSD_DATATYPES: [&str; 29] = [
+    "anyURI",
+    "base64Binary",
+    "boolean",
+    "byte",
+    "dateTime",
+    "dateTimeStamp",
+    "decimal",
+    "double",
+    "float",
+    "hexBinary",
+    "int",
+    "integer",
+    "language",
+    "long",
+    "Name",
+    "NCName",
+    "negativeInteger",
+    "NMTOKEN",
+    "nonNegativeInteger",
+    "nonPositiveInteger",
+    "normalizedString",
+    "positiveInteger",
+    "short",
+    "string",
+    "token",
+    "unsignedByte",
+    "unsignedInt",
+    "unsignedLong",
+    "unsignedShort",
+];
+
+pub(crate) const KEYWORDS: [&str; 11] = [
+    "and", "or", "not", "that", "some", "only", "value", "min", "max", "exactly", "inverse",
+];
+
+/// Keywords following the property of a restriction.
+const RESTRICTION_KEYWORDS: [&str; 7] = ["some", "only", "value", "min", "max", "exactly", "Self"];
+
+/// A syntax or resolution error at a byte range of the input.
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Range<usize>,
+}
+
+impl ParseError {
+    fn new<S: Into<String>>(message: S, span: Range<usize>) -> Self {
+        ParseError {
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// Converts the error into a `ValueError` pointing at the offending part of `input`.
+    pub fn into_py_err(self, input: &str) -> PyErr {
+        let start = input.floor_char_boundary(self.span.start);
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let line = &input[line_start..line_end];
+        let line_no = input[..line_start].matches('\n').count() + 1;
+        let column = input[line_start..start].chars().count();
+        let width = input[start..input.ceil_char_boundary(self.span.end.clamp(start, line_end))]
+            .chars()
+            .count()
+            .max(1);
+
+        let location = if line_no > 1 {
+            format!("line {}, column {}", line_no, column + 1)
+        } else {
+            format!("column {}", column + 1)
+        };
+
+        PyValueError::new_err(format!(
+            "{} at {}\n    {}\n    {}{}",
+            self.message,
+            location,
+            line,
+            " ".repeat(column),
+            "^".repeat(width)
+        ))
+    }
+}
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// Resolves names against an ontology.
+pub struct Resolver<'a> {
+    ontology: &'a mut PyIndexedOntology,
+    mapping: curie::PrefixMapping,
+    data_properties: HashSet<String>,
+    datatypes: HashSet<String>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(py: Python<'_>, ontology: &'a mut PyIndexedOntology) -> PyResult<Self> {
+        let mapping = with_standard_prefixes(&ontology.mapping.borrow(py).0);
+
+        Ok(Resolver {
+            data_properties: ontology.get_data_properties(false)?,
+            datatypes: ontology.get_datatypes(false)?,
+            ontology,
+            mapping,
+        })
+    }
+
+    fn iri(&self, iri: &str) -> IRI<ArcStr> {
+        self.ontology.build.read().unwrap().iri(iri)
+    }
+
+    fn prefixed(&self, prefix: &str, local: &str) -> Option<IRI<ArcStr>> {
+        self.mapping
+            .expand_curie(&curie::Curie::new(Some(prefix), local))
+            .ok()
+            .or_else(|| {
+                prefix
+                    .is_empty()
+                    .then(|| self.mapping.expand_curie(&curie::Curie::new(None, local)).ok())
+                    .flatten()
+            })
+            .map(|iri| self.iri(&iri))
+    }
+
+    fn label(&mut self, label: &str) -> Option<IRI<ArcStr>> {
+        let iri = self
+            .ontology
+            .get_iri_for_label(label.to_string(), None)
+            .ok()
+            .flatten()?;
+        Some(self.iri(&iri))
+    }
+
+    fn is_data_property(&self, iri: &IRI<ArcStr>) -> bool {
+        self.data_properties.contains(iri.as_ref())
+    }
+
+    fn is_datatype(&self, iri: &IRI<ArcStr>) -> bool {
+        self.datatypes.contains(iri.as_ref())
+            || iri.starts_with(XSD)
+            || [RDFS_LITERAL, "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral"]
+                .contains(&iri.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Word(String),
+    FullIRI(String),
+    Label(String),
+    String(String, Option<String>),
+    Integer(String),
+    Decimal(String),
+    Punct(&'static str),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    span: Range<usize>,
+}
+
+const PUNCTUATION: [&str; 12] = [
+    "^^", "<=", ">=", "(", ")", "{", "}", "[", "]", ",", "<", ">",
+];
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !"(){}[],'\"<>^".contains(c)
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        let rest = &input[start..];
+        let (tok, len) = if c.is_whitespace() {
+            chars.next();
+            continue;
+        } else if c == '<' && !rest.starts_with("<=") {
+            match rest[1..].find(|c: char| c == '>' || c.is_whitespace()) {
+                Some(end) if rest.as_bytes()[end + 1] == b'>' => {
+                    (Tok::FullIRI(rest[1..end + 1].to_string()), end + 2)
+                }
+                _ => (Tok::Punct("<"), 1),
+            }
+        } else if let Some(p) = PUNCTUATION.iter().find(|p| rest.starts_with(**p)) {
+            (Tok::Punct(p), p.len())
+        } else if c == '\'' {
+            let end = rest[1..].find('\'').ok_or_else(|| {
+                ParseError::new("unterminated quoted name", start..input.len())
+            })?;
+            (Tok::Label(rest[1..end + 1].to_string()), end + 2)
+        } else if c == '"' {
+            let mut value = String::new();
+            let mut escaped = false;
+            let mut end = None;
+            for (i, c) in rest.char_indices().skip(1) {
+                match c {
+                    _ if escaped => {
+                        value.push(c);
+                        escaped = false;
+                    }
+                    '\\' => escaped = true,
+                    '"' => {
+                        end = Some(i + 1);
+                        break;
+                    }
+                    _ => value.push(c),
+                }
+            }
+            let mut end = end.ok_or_else(|| {
+                ParseError::new("unterminated string literal", start..input.len())
+            })?;
+            let lang = if rest[end..].starts_with('@') {
+                let len = rest[end + 1..]
+                    .find(|c: char| !(c.is_alphanumeric() || c == '-'))
+                    .unwrap_or(rest.len() - end - 1);
+                if len == 0 {
+                    return Err(ParseError::new(
+                        "expected a language tag",
+                        start + end..start + end + 1,
+                    ));
+                }
+                let lang = rest[end + 1..end + 1 + len].to_string();
+                end += len + 1;
+                Some(lang)
+            } else {
+                None
+            };
+            (Tok::String(value, lang), end)
+        } else {
+            let len = rest.find(|c| !is_word_char(c)).unwrap_or(rest.len());
+            let word = &rest[..len];
+            let number = word.strip_prefix(['-', '+']).unwrap_or(word);
+            let tok = if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                Tok::Integer(word.to_string())
+            } else if number
+                .split_once('.')
+                .is_some_and(|(i, f)| {
+                    !i.is_empty()
+                        && !f.is_empty()
+                        && i.chars().all(|c| c.is_ascii_digit())
+                        && f.chars().all(|c| c.is_ascii_digit())
+                })
+            {
+                Tok::Decimal(word.to_string())
+            } else {
+                Tok::Word(word.to_string())
+            };
+            (tok, len)
+        };
+
+        tokens.push(Token {
+            tok,
+            span: start..start + len,
+        });
+        while chars.peek().is_some_and(|(i, _)| *i < start + len) {
+            chars.next();
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Parses a class expression in Manchester syntax.
+pub fn parse_class_expression(
+    input: &str,
+    resolver: &mut Resolver<'_>,
+) -> Result<ClassExpression<ArcStr>> {
+    let mut parser = Manchester {
+        tokens: tokenize(input)?,
+        pos: 0,
+        end: input.len(),
+        input,
+        resolver,
+    };
+
+    let ce = parser.description()?;
+    if let Some(token) = parser.peek() {
+        return Err(ParseError::new(
+            format!("unexpected '{}'", parser.text(token)),
+            token.span.clone(),
+        ));
+    }
+    Ok(ce)
+}
+
+struct Manchester<'i, 'r, 'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    end: usize,
+    input: &'i str,
+    resolver: &'r mut Resolver<'a>,
+}
+
+impl Manchester<'_, '_, '_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn text(&self, token: &Token) -> &str {
+        &self.input[token.span.clone()]
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token { tok: Tok::Word(w), .. }) if w == keyword)
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token { tok: Tok::Punct(p), .. }) if *p == punct)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let found = self.is_punct(punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn error<T>(&self, expected: &str) -> Result<T> {
+        Err(match self.peek() {
+            Some(token) => ParseError::new(
+                format!("expected {}, found '{}'", expected, self.text(token)),
+                token.span.clone(),
+            ),
+            None => ParseError::new(
+                format!("expected {expected}, found end of input"),
+                self.end..self.end,
+            ),
+        })
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<()> {
+        if self.eat_punct(punct) {
+            Ok(())
+        } else {
+            self.error(&format!("'{punct}'"))
+        }
+    }
+
+    /// Returns whether the next token can start a name.
+    fn at_name(&self) -> bool {
+        match self.peek() {
+            Some(Token {
+                tok: Tok::Word(w), ..
+            }) => !KEYWORDS.contains(&w.as_str()) && w != "Self",
+            Some(Token {
+                tok: Tok::FullIRI(_) | Tok::Label(_),
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    fn resolve(&mut self, token: &Token) -> Result<IRI<ArcStr>> {
+        let unresolved = |message: String| Err(ParseError::new(message, token.span.clone()));
+
+        match &token.tok {
+            Tok::FullIRI(iri) => Ok(self.resolver.iri(iri)),
+            Tok::Label(label) => match self.resolver.label(label) {
+                Some(iri) => Ok(iri),
+                None => unresolved(format!("no entity with label '{label}'")),
+            },
+            Tok::Word(word) => {
+                if let Some((prefix, local)) = word.split_once(':') {
+                    if let Some(iri) = self.resolver.prefixed(prefix, local) {
+                        return Ok(iri);
+                    }
+                    if let Some(iri) = self.resolver.label(word) {
+                        return Ok(iri);
+                    }
+                    unresolved(format!("undefined prefix '{prefix}' in '{word}'"))
+                } else {
+                    if let Some(iri) = self.resolver.label(word) {
+                        return Ok(iri);
+                    }
+                    if let Some(iri) = self.resolver.prefixed("", word) {
+                        return Ok(iri);
+                    }
+                    unresolved(format!(
+                        "cannot resolve '{word}' as label or name in the default prefix"
+                    ))
+                }
+            }
+            _ => unresolved(format!("expected a name, found '{}'", self.text(token))),
+        }
+    }
+
+    fn name(&mut self, expected: &str) -> Result<IRI<ArcStr>> {
+        if !self.at_name() {
+            return self.error(expected);
+        }
+        let token = self.next().unwrap();
+        self.resolve(&token)
+    }
+
+    fn description(&mut self) -> Result<ClassExpression<ArcStr>> {
+        let mut operands = vec![self.conjunction()?];
+        while self.eat_keyword("or") {
+            operands.push(self.conjunction()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            ClassExpression::ObjectUnionOf(operands)
+        })
+    }
+
+    fn conjunction(&mut self) -> Result<ClassExpression<ArcStr>> {
+        let mut operands = vec![self.primary()?];
+        while self.eat_keyword("and") || self.eat_keyword("that") {
+            operands.push(self.primary()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            ClassExpression::ObjectIntersectionOf(operands)
+        })
+    }
+
+    fn primary(&mut self) -> Result<ClassExpression<ArcStr>> {
+        if self.eat_keyword("not") {
+            return Ok(ClassExpression::ObjectComplementOf(Box::new(
+                self.primary()?,
+            )));
+        }
+        if self.eat_punct("(") {
+            let ce = self.description()?;
+            self.expect_punct(")")?;
+            return Ok(ce);
+        }
+        if self.eat_punct("{") {
+            let mut individuals = vec![self.individual()?];
+            while self.eat_punct(",") {
+                individuals.push(self.individual()?);
+            }
+            self.expect_punct("}")?;
+            return Ok(ClassExpression::ObjectOneOf(individuals));
+        }
+        if self.eat_keyword("inverse") {
+            let parenthesized = self.eat_punct("(");
+            let op = ObjectProperty(self.name("object property")?);
+            if parenthesized {
+                self.expect_punct(")")?;
+            }
+            return self.restriction(op.0, true);
+        }
+        if !self.at_name() {
+            return self.error("class expression");
+        }
+
+        let iri = self.name("class expression")?;
+        if RESTRICTION_KEYWORDS.iter().any(|k| self.is_keyword(k)) {
+            self.restriction(iri, false)
+        } else {
+            Ok(ClassExpression::Class(Class(iri)))
+        }
+    }
+
+    fn restriction(&mut self, iri: IRI<ArcStr>, inverse: bool) -> Result<ClassExpression<ArcStr>> {
+        let ope = || {
+            if inverse {
+                ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(iri.clone()))
+            } else {
+                ObjectPropertyExpression::ObjectProperty(ObjectProperty(iri.clone()))
+            }
+        };
+        let is_data = !inverse && self.resolver.is_data_property(&iri);
+
+        let keyword = match self.peek() {
+            Some(Token {
+                tok: Tok::Word(w), ..
+            }) if RESTRICTION_KEYWORDS.contains(&w.as_str()) => w.clone(),
+            _ => return self.error("a restriction keyword"),
+        };
+        self.next();
+
+        Ok(match keyword.as_str() {
+            "some" | "only" if is_data || (!inverse && self.at_data_range()) => {
+                let dp = DataProperty(iri.clone());
+                let dr = self.data_primary()?;
+                if keyword == "some" {
+                    ClassExpression::DataSomeValuesFrom { dp, dr }
+                } else {
+                    ClassExpression::DataAllValuesFrom { dp, dr }
+                }
+            }
+            "some" => ClassExpression::ObjectSomeValuesFrom {
+                ope: ope(),
+                bce: Box::new(self.primary()?),
+            },
+            "only" => ClassExpression::ObjectAllValuesFrom {
+                ope: ope(),
+                bce: Box::new(self.primary()?),
+            },
+            "value" if is_data || (!inverse && self.at_literal()) => ClassExpression::DataHasValue {
+                dp: DataProperty(iri.clone()),
+                l: self.literal()?,
+            },
+            "value" => ClassExpression::ObjectHasValue {
+                ope: ope(),
+                i: self.individual()?,
+            },
+            "Self" => ClassExpression::ObjectHasSelf(ope()),
+            _ => {
+                let n = match self.next() {
+                    Some(Token {
+                        tok: Tok::Integer(n),
+                        span,
+                    }) => n
+                        .parse::<u32>()
+                        .map_err(|_| ParseError::new("expected a non-negative integer", span))?,
+                    _ => {
+                        self.pos -= 1;
+                        return self.error("a non-negative integer");
+                    }
+                };
+                let has_filler = self.at_name()
+                    || self.is_keyword("not")
+                    || self.is_keyword("inverse")
+                    || self.is_punct("(")
+                    || self.is_punct("{");
+
+                if is_data || (!inverse && has_filler && self.at_data_range()) {
+                    let dp = DataProperty(iri.clone());
+                    let dr = if has_filler {
+                        self.data_primary()?
+                    } else {
+                        DataRange::Datatype(Datatype(self.resolver.iri(RDFS_LITERAL)))
+                    };
+                    match keyword.as_str() {
+                        "min" => ClassExpression::DataMinCardinality { n, dp, dr },
+                        "max" => ClassExpression::DataMaxCardinality { n, dp, dr },
+                        _ => ClassExpression::DataExactCardinality { n, dp, dr },
+                    }
+                } else {
+                    let bce = Box::new(if has_filler {
+                        self.primary()?
+                    } else {
+                        ClassExpression::Class(Class(self.resolver.iri(OWL_THING)))
+                    });
+                    match keyword.as_str() {
+                        "min" => ClassExpression::ObjectMinCardinality { n, ope: ope(), bce },
+                        "max" => ClassExpression::ObjectMaxCardinality { n, ope: ope(), bce },
+                        _ => ClassExpression::ObjectExactCardinality { n, ope: ope(), bce },
+                    }
+                }
+            }
+        })
+    }
+
+    fn at_literal(&self) -> bool {
+        match self.peek() {
+            Some(Token {
+                tok: Tok::String(..) | Tok::Integer(_) | Tok::Decimal(_),
+                ..
+            }) => true,
+            Some(Token {
+                tok: Tok::Word(w), ..
+            }) => w == "true" || w == "false",
+            _ => false,
+        }
+    }
+
+    /// Returns whether the upcoming filler is recognisably a data range.
+    fn at_data_range(&mut self) -> bool {
+        let mut offset = 0;
+        while matches!(self.peek_at(offset), Some(Token { tok: Tok::Word(w), .. }) if w == "not")
+            || matches!(self.peek_at(offset), Some(Token { tok: Tok::Punct("("), .. }))
+        {
+            offset += 1;
+        }
+
+        match self.peek_at(offset).cloned() {
+            Some(Token {
+                tok: Tok::Punct("{"),
+                ..
+            }) => {
+                let pos = self.pos;
+                self.pos += offset + 1;
+                let literal = self.at_literal();
+                self.pos = pos;
+                literal
+            }
+            Some(
+                token @ Token {
+                    tok: Tok::Word(_) | Tok::FullIRI(_) | Tok::Label(_),
+                    ..
+                },
+            ) => {
+                if matches!(self.peek_at(offset + 1), Some(Token { tok: Tok::Punct("["), .. })) {
+                    return true;
+                }
+                self.datatype_iri(&token)
+                    .is_ok_and(|iri| self.resolver.is_datatype(&iri))
+            }
+            _ => false,
+        }
+    }
+
+    fn datatype_iri(&mut self, token: &Token) -> Result<IRI<ArcStr>> {
+        if let Tok::Word(word) = &token.tok {
+            if self.resolver.label(word).is_none() {
+                if XSD_DATATYPES.contains(&word.as_str()) {
+                    return Ok(self.resolver.iri(&format!("{XSD}{word}")));
+                }
+                if word == "Literal" {
+                    return Ok(self.resolver.iri(RDFS_LITERAL));
+                }
+            }
+        }
+        self.resolve(token)
+    }
+
+    fn data_range(&mut self) -> Result<DataRange<ArcStr>> {
+        let mut operands = vec![self.data_conjunction()?];
+        while self.eat_keyword("or") {
+            operands.push(self.data_conjunction()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            DataRange::DataUnionOf(operands)
+        })
+    }
+
+    fn data_conjunction(&mut self) -> Result<DataRange<ArcStr>> {
+        let mut operands = vec![self.data_primary()?];
+        while self.eat_keyword("and") {
+            operands.push(self.data_primary()?);
+        }
+        Ok(if operands.len() == 1 {
+            operands.pop().unwrap()
+        } else {
+            DataRange::DataIntersectionOf(operands)
+        })
+    }
+
+    fn data_primary(&mut self) -> Result<DataRange<ArcStr>> {
+        if self.eat_keyword("not") {
+            return Ok(DataRange::DataComplementOf(Box::new(self.data_primary()?)));
+        }
+        if self.eat_punct("(") {
+            let dr = self.data_range()?;
+            self.expect_punct(")")?;
+            return Ok(dr);
+        }
+        if self.eat_punct("{") {
+            let mut literals = vec![self.literal()?];
+            while self.eat_punct(",") {
+                literals.push(self.literal()?);
+            }
+            self.expect_punct("}")?;
+            return Ok(DataRange::DataOneOf(literals));
+        }
+        if !self.at_name() {
+            return self.error("data range");
+        }
+
+        let token = self.next().unwrap();
+        let datatype = Datatype(self.datatype_iri(&token)?);
+        if !self.eat_punct("[") {
+            return Ok(DataRange::Datatype(datatype));
+        }
+
+        let mut restrictions = vec![self.facet_restriction()?];
+        while self.eat_punct(",") {
+            restrictions.push(self.facet_restriction()?);
+        }
+        self.expect_punct("]")?;
+        Ok(DataRange::DatatypeRestriction(datatype, restrictions))
+    }
+
+    fn facet_restriction(&mut self) -> Result<FacetRestriction<ArcStr>> {
+        let f = match self.peek().map(|t| t.tok.clone()) {
+            Some(Tok::Punct("<=")) => Facet::MaxInclusive,
+            Some(Tok::Punct("<")) => Facet::MaxExclusive,
+            Some(Tok::Punct(">=")) => Facet::MinInclusive,
+            Some(Tok::Punct(">")) => Facet::MinExclusive,
+            Some(Tok::Word(w)) => match Facet::try_from(format!("{XSD}{w}").as_str()) {
+                Ok(f) => f,
+                Err(_) => return self.error("facet"),
+            },
+            _ => return self.error("facet"),
+        };
+        self.pos += 1;
+        Ok(FacetRestriction {
+            f,
+            l: self.literal()?,
+        })
+    }
+
+    fn literal(&mut self) -> Result<Literal<ArcStr>> {
+        let typed = |resolver: &Resolver<'_>, literal: &str, datatype: &str| Literal::Datatype {
+            literal: literal.to_string(),
+            datatype_iri: resolver.iri(&format!("{XSD}{datatype}")),
+        };
+
+        match self.peek().map(|t| t.tok.clone()) {
+            Some(Tok::String(literal, lang)) => {
+                self.pos += 1;
+                if let Some(lang) = lang {
+                    return Ok(Literal::Language { literal, lang });
+                }
+                if !self.eat_punct("^^") {
+                    return Ok(Literal::Simple { literal });
+                }
+                if !self.at_name() {
+                    return self.error("datatype");
+                }
+                let token = self.next().unwrap();
+                Ok(Literal::Datatype {
+                    literal,
+                    datatype_iri: self.datatype_iri(&token)?,
+                })
+            }
+            Some(Tok::Integer(n)) => {
+                self.pos += 1;
+                Ok(typed(self.resolver, &n, "integer"))
+            }
+            Some(Tok::Decimal(n)) => {
+                self.pos += 1;
+                Ok(typed(self.resolver, &n, "decimal"))
+            }
+            Some(Tok::Word(w)) if w == "true" || w == "false" => {
+                self.pos += 1;
+                Ok(typed(self.resolver, &w, "boolean"))
+            }
+            _ => self.error("literal"),
+        }
+    }
+
+    fn individual(&mut self) -> Result<Individual<ArcStr>> {
+        if let Some(Token {
+            tok: Tok::Word(w), ..
+        }) = self.peek()
+        {
+            if w.starts_with("_:") {
+                let anon = AnonymousIndividual(Arc::from(w.as_str()));
+                self.pos += 1;
+                return Ok(Individual::Anonymous(anon));
+            }
+        }
+        Ok(Individual::Named(NamedIndividual(self.name("individual")?)))
+    }
+}
+
+/// A quoted label replaced by its IRI, with its byte ranges in the rewritten and original input.
+struct Replacement {
+    output: Range<usize>,
+    input: Range<usize>,
+}
+
+/// Replaces quoted labels outside of string literals with the full IRIs they resolve to.
+fn substitute_labels(
+    input: &str,
+    resolver: &mut Resolver<'_>,
+) -> Result<(String, Vec<Replacement>)> {
+    let mut output = String::with_capacity(input.len());
+    let mut replacements = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = input.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '\'' {
+            let end = input[i + 1..]
+                .find('\'')
+                .map(|e| i + 1 + e)
+                .ok_or_else(|| ParseError::new("unterminated quoted name", i..input.len()))?;
+            let label = &input[i + 1..end];
+            let iri = resolver
+                .label(label)
+                .ok_or_else(|| ParseError::new(format!("no entity with label '{label}'"), i..end + 1))?;
+            let replacement = format!("<{}>", iri.as_ref());
+            replacements.push(Replacement {
+                output: output.len()..output.len() + replacement.len(),
+                input: i..end + 1,
+            });
+            output.push_str(&replacement);
+            for _ in input[i + 1..=end].chars() {
+                chars.next();
+            }
+            continue;
+        }
+        output.push(c);
+    }
+
+    Ok((output, replacements))
+}
+
+/// Parses a single axiom in functional syntax.
+pub fn parse_axiom(input: &str, resolver: &mut Resolver<'_>) -> Result<AnnotatedComponent<ArcStr>> {
+    let (axiom, replacements) = substitute_labels(input, resolver)?;
+
+    let mut header = String::new();
+    for (prefix, iri) in resolver.mapping.mappings() {
+        header.push_str(&format!("Prefix({prefix}:=<{iri}>)\n"));
+    }
+    if resolver.prefixed("", "").is_some() && resolver.mapping.mappings().all(|(p, _)| !p.is_empty()) {
+        let default = resolver.prefixed("", "").unwrap();
+        header.push_str(&format!("Prefix(:=<{}>)\n", default.as_ref()));
+    }
+    header.push_str("Ontology(\n");
+    let document = format!("{header}{axiom}\n)");
+
+    // Maps a byte offset in the document back to the input.
+    let to_input = |offset: u64| {
+        let mut offset = (offset as usize).saturating_sub(header.len()).min(axiom.len());
+        let mut shift = 0isize;
+        for r in &replacements {
+            if offset >= r.output.end {
+                shift += r.input.len() as isize - r.output.len() as isize;
+            } else if offset >= r.output.start {
+                return r.input.clone();
+            }
+        }
+        offset = input.floor_char_boundary((offset as isize + shift).max(0) as usize);
+        offset..input.ceil_char_boundary(offset + 1)
+    };
+    let span = |location: &Location| match location {
+        Location::BytePosition(p) => to_input(*p),
+        Location::ByteSpan(r) => {
+            let start = to_input(r.start);
+            start.start..to_input(r.end).start.max(start.end)
+        }
+        Location::Unknown => 0..input.len(),
+    };
+
+    let build = resolver.ontology.build.read().unwrap();
+    let (ontology, _) = read_with_build::<_, SetOntology<ArcStr>, _>(document.as_bytes(), &build)
+        .map_err(|e| match e {
+            HornedError::ParserError(e, location) => {
+                let message = e.to_string();
+                let message = message
+                    .lines()
+                    .rev()
+                    .find_map(|l| l.trim_start().strip_prefix("= "))
+                    .unwrap_or(&message)
+                    .to_string();
+                ParseError::new(message, span(&location))
+            }
+            HornedError::ValidityError(message, location) => {
+                ParseError::new(message, span(&location))
+            }
+            e => ParseError::new(e.to_string(), 0..input.len()),
+        })?;
+
+    let mut components: Vec<_> = ontology
+        .into_iter()
+        .filter(|c| {
+            !matches!(
+                c.component,
+                Component::OntologyID(_) | Component::DocIRI(_)
+            )
+        })
+        .collect();
+
+    match components.len() {
+        1 => Ok(components.pop().unwrap()),
+        0 => Err(ParseError::new("expected an axiom", 0..input.len())),
+        n => Err(ParseError::new(
+            format!("expected a single axiom, found {n}"),
+            0..input.len(),
+        )),
+    }
+}
@@ -0,0 +1,146 @@
+//! Pattern queries for py-horned-owl
+//!
+//! A pattern is a model component in which some nodes are replaced by `model.Var`s. Patterns are
+//! matched structurally against the Python representation of the components of an ontology. Each
+//! variable matches any node in its position, but all occurrences of the same variable have to
+//! match equal nodes.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyList, PySet, PyTuple};
+
+use crate::model;
+
+/// Variable bindings in the order the variables were first encountered.
+pub type Bindings<'py> = Vec<(String, Bound<'py, PyAny>)>;
+
+/// Matches `pattern` against `candidate`, extending `bindings`. On failure `bindings` is left
+/// unchanged.
+pub fn unify<'py>(
+    pattern: &Bound<'py, PyAny>,
+    candidate: &Bound<'py, PyAny>,
+    bindings: &mut Bindings<'py>,
+) -> PyResult<bool> {
+    let bound = bindings.len();
+    let matched = unify_inner(pattern, candidate, bindings)?;
+    if !matched {
+        bindings.truncate(bound);
+    }
+    Ok(matched)
+}
+
+fn unify_inner<'py>(
+    pattern: &Bound<'py, PyAny>,
+    candidate: &Bound<'py, PyAny>,
+    bindings: &mut Bindings<'py>,
+) -> PyResult<bool> {
+    if let Some(name) = variable(pattern) {
+        if let Some((_, value)) = bindings.iter().find(|(n, _)| *n == name) {
+            return same(value, candidate);
+        }
+        bindings.push((name, candidate.clone()));
+        return Ok(true);
+    }
+
+    if !pattern.get_type().is(candidate.get_type()) {
+        return Ok(false);
+    }
+
+    if let Ok(fields) = pattern.getattr("__match_args__") {
+        for field in fields.try_iter()? {
+            let field = field?;
+            if !unify(
+                &pattern.getattr(field.downcast()?)?,
+                &candidate.getattr(field.downcast()?)?,
+                bindings,
+            )? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    if pattern.is_instance_of::<PyList>() || pattern.is_instance_of::<PyTuple>() {
+        if pattern.len()? != candidate.len()? {
+            return Ok(false);
+        }
+        for (p, c) in pattern.try_iter()?.zip(candidate.try_iter()?) {
+            if !unify(&p?, &c?, bindings)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    if pattern.is_instance_of::<PySet>() || pattern.is_instance_of::<PyFrozenSet>() {
+        if pattern.len()? != candidate.len()? {
+            return Ok(false);
+        }
+        let patterns = pattern.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        let candidates = candidate.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        return unify_unordered(&patterns, candidates, bindings);
+    }
+
+    same(pattern, candidate)
+}
+
+/// Matches each of `patterns` against a distinct element of `candidates`, backtracking over the
+/// possible assignments.
+fn unify_unordered<'py>(
+    patterns: &[Bound<'py, PyAny>],
+    candidates: Vec<Bound<'py, PyAny>>,
+    bindings: &mut Bindings<'py>,
+) -> PyResult<bool> {
+    let Some((pattern, patterns)) = patterns.split_first() else {
+        return Ok(true);
+    };
+
+    for i in 0..candidates.len() {
+        let bound = bindings.len();
+        if unify(pattern, &candidates[i], bindings)? {
+            let mut rest = candidates.clone();
+            rest.remove(i);
+            if unify_unordered(patterns, rest, bindings)? {
+                return Ok(true);
+            }
+            bindings.truncate(bound);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns the name of the variable if `pattern` is a `Var`, an entity wrapping a `Var` or the
+/// node a `Var` is converted to when passed in place of a class expression, object property
+/// expression, data range, individual or literal.
+fn variable(pattern: &Bound<'_, PyAny>) -> Option<String> {
+    let value = if let Ok(iri) = pattern.downcast::<model::IRI>() {
+        iri.borrow().__str__()
+    } else if let Ok(c) = pattern.downcast::<model::Class>() {
+        c.borrow().0.__str__()
+    } else if let Ok(op) = pattern.downcast::<model::ObjectProperty>() {
+        op.borrow().0.__str__()
+    } else if let Ok(dt) = pattern.downcast::<model::Datatype>() {
+        dt.borrow().0.__str__()
+    } else if let Ok(i) = pattern.downcast::<model::NamedIndividual>() {
+        i.borrow().0.__str__()
+    } else if let Ok(dp) = pattern.downcast::<model::DataProperty>() {
+        dp.borrow().0.__str__()
+    } else if let Ok(ap) = pattern.downcast::<model::AnnotationProperty>() {
+        ap.borrow().0.__str__()
+    } else if let Ok(l) = pattern.downcast::<model::SimpleLiteral>() {
+        l.borrow().literal.clone()
+    } else {
+        return None;
+    };
+
+    model::var_name(&value).map(str::to_string)
+}
+
+/// Compares two nodes. IRIs are compared by value.
+fn same(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if let (Ok(a), Ok(b)) = (a.downcast::<model::IRI>(), b.downcast::<model::IRI>()) {
+        return Ok(a.borrow().__str__() == b.borrow().__str__());
+    }
+
+    a.eq(b)
+}
@@ -1,11 +1,12 @@
 use pyo3::{
     exceptions::{PyKeyError, PyValueError},
     prelude::*,
+    types::PyType,
 };
 
 use crate::to_py_err;
 
-#[pyclass(mapping)]
+#[pyclass(module = "pyhornedowl", mapping)]
 #[derive(Default)]
 pub struct PrefixMapping(pub(crate) curie::PrefixMapping);
 
@@ -40,6 +41,31 @@ impl PyIter {
 
 #[pymethods]
 impl PrefixMapping {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn __getstate__(&self) -> Vec<(String, String)> {
+        self.0
+            .mappings()
+            .map(|(p, v)| (p.clone(), v.clone()))
+            .collect()
+    }
+
+    pub fn __setstate__(&mut self, state: Vec<(String, String)>) -> PyResult<()> {
+        self.0 = curie::PrefixMapping::default();
+        for (p, v) in state {
+            self.add_prefix(p, v)?;
+        }
+        Ok(())
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> (Bound<'py, PyType>, (), Vec<(String, String)>) {
+        (slf.get_type(), (), slf.borrow().__getstate__())
+    }
 
     /// __iter__(self) -> typing.Iterable[typing.Tuple[str, str]]
     ///
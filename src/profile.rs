@@ -0,0 +1,762 @@
+//! OWL 2 profile validation
+//!
+//! Checks the components of an ontology against the grammars of the OWL 2 EL, QL and RL profiles
+//! (<https://www.w3.org/TR/owl2-profiles/>) and a subset of the global restrictions of OWL 2 DL,
+//! which every profile inherits:
+//!
+//! - non-simple object properties in cardinality restrictions, `ObjectHasSelf`, disjoint
+//!   properties and functional, inverse functional, irreflexive or asymmetric properties,
+//! - IRIs declared as more than one kind of property or as class and datatype,
+//! - entities used without declaration,
+//! - datatypes outside of the OWL 2 datatype map and SWRL rules.
+//!
+//! The regularity of property hierarchies and the acyclicity of datatype definitions are not
+//! checked.
+
+use std::collections::{HashMap, HashSet};
+
+use horned_owl::model::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::model;
+use crate::signature::{declared_entity, entity_iri, entity_kind, is_builtin, Signature};
+
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Datatypes supported by OWL 2 EL and QL, by local name in their namespace.
+const EL_DATATYPES: [&str; 19] = [
+    "PlainLiteral",
+    "XMLLiteral",
+    "Literal",
+    "real",
+    "rational",
+    "decimal",
+    "integer",
+    "nonNegativeInteger",
+    "string",
+    "normalizedString",
+    "token",
+    "Name",
+    "NCName",
+    "NMTOKEN",
+    "hexBinary",
+    "base64Binary",
+    "anyURI",
+    "dateTime",
+    "dateTimeStamp",
+];
+
+/// Datatypes of the OWL 2 datatype map that are supported by OWL 2 RL in addition to the EL
+/// datatypes (except `owl:real` and `owl:rational`).
+const RL_DATATYPES: [&str; 15] = [
+    "nonPositiveInteger",
+    "positiveInteger",
+    "negativeInteger",
+    "long",
+    "int",
+    "short",
+    "byte",
+    "unsignedLong",
+    "unsignedInt",
+    "unsignedShort",
+    "unsignedByte",
+    "float",
+    "double",
+    "language",
+    "boolean",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    EL,
+    QL,
+    RL,
+    DL,
+}
+
+impl TryFrom<&str> for Profile {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase().as_str() {
+            "EL" => Ok(Profile::EL),
+            "QL" => Ok(Profile::QL),
+            "RL" => Ok(Profile::RL),
+            "DL" => Ok(Profile::DL),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown profile '{value}', expected one of 'EL', 'QL', 'RL' or 'DL'"
+            ))),
+        }
+    }
+}
+
+impl Profile {
+    fn name(&self) -> &'static str {
+        match self {
+            Profile::EL => "EL",
+            Profile::QL => "QL",
+            Profile::RL => "RL",
+            Profile::DL => "DL",
+        }
+    }
+}
+
+/// A component violating a profile.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct ProfileViolation {
+    /// The violating component.
+    #[pyo3(get)]
+    pub component: model::AnnotatedComponent,
+    /// Why the component violates the profile.
+    #[pyo3(get)]
+    pub reason: String,
+    kind: String,
+}
+
+#[pymethods]
+impl ProfileViolation {
+    fn __repr__(&self) -> String {
+        format!(
+            "ProfileViolation(component={}, reason={:?})",
+            self.kind, self.reason
+        )
+    }
+}
+
+/// Result of checking an ontology against an OWL 2 profile.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct ProfileReport {
+    /// The checked profile, one of "EL", "QL", "RL" or "DL".
+    #[pyo3(get)]
+    pub profile: String,
+    /// The violations, ordered by component.
+    #[pyo3(get)]
+    pub violations: Vec<ProfileViolation>,
+}
+
+#[pymethods]
+impl ProfileReport {
+    /// Whether the ontology is in the profile, i.e. there are no violations.
+    #[getter]
+    fn is_in_profile(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.is_in_profile()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ProfileReport(profile={:?}, violations={})",
+            self.profile,
+            self.violations.len()
+        )
+    }
+}
+
+/// The position of a class expression in an axiom, which restricts the allowed constructors in
+/// OWL 2 QL and RL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Sub,
+    Super,
+    Equivalent,
+}
+
+fn kind_name(kind: NamedOWLEntityKind) -> &'static str {
+    match kind {
+        NamedOWLEntityKind::Class => "class",
+        NamedOWLEntityKind::Datatype => "datatype",
+        NamedOWLEntityKind::ObjectProperty => "object property",
+        NamedOWLEntityKind::DataProperty => "data property",
+        NamedOWLEntityKind::AnnotationProperty => "annotation property",
+        NamedOWLEntityKind::NamedIndividual => "named individual",
+    }
+}
+
+fn opi(ope: &ObjectPropertyExpression<ArcStr>) -> &ObjectProperty<ArcStr> {
+    match ope {
+        ObjectPropertyExpression::ObjectProperty(op) => op,
+        ObjectPropertyExpression::InverseObjectProperty(op) => op,
+    }
+}
+
+/// Returns the object properties that are not simple: transitive properties, super properties of
+/// property chains and their super properties.
+fn non_simple_properties<'a>(
+    components: impl Iterator<Item = &'a AnnotatedComponent<ArcStr>>,
+) -> HashSet<ObjectProperty<ArcStr>> {
+    let mut non_simple = HashSet::new();
+    let mut supers: HashMap<ObjectProperty<ArcStr>, Vec<ObjectProperty<ArcStr>>> = HashMap::new();
+    let mut sub_super = |sub: &ObjectProperty<ArcStr>, sup: &ObjectProperty<ArcStr>| {
+        supers.entry(sub.clone()).or_default().push(sup.clone());
+    };
+
+    for c in components {
+        match &c.component {
+            Component::TransitiveObjectProperty(TransitiveObjectProperty(ope)) => {
+                non_simple.insert(opi(ope).clone());
+            }
+            Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyChain(_),
+                sup,
+            }) => {
+                non_simple.insert(opi(sup).clone());
+            }
+            Component::SubObjectPropertyOf(SubObjectPropertyOf {
+                sub: SubObjectPropertyExpression::ObjectPropertyExpression(sub),
+                sup,
+            }) => sub_super(opi(sub), opi(sup)),
+            Component::EquivalentObjectProperties(EquivalentObjectProperties(v)) => {
+                for a in v {
+                    for b in v {
+                        sub_super(opi(a), opi(b));
+                    }
+                }
+            }
+            Component::InverseObjectProperties(InverseObjectProperties(a, b)) => {
+                sub_super(a, b);
+                sub_super(b, a);
+            }
+            _ => {}
+        }
+    }
+
+    let mut todo: Vec<_> = non_simple.iter().cloned().collect();
+    while let Some(op) = todo.pop() {
+        for sup in supers.get(&op).into_iter().flatten() {
+            if non_simple.insert(sup.clone()) {
+                todo.push(sup.clone());
+            }
+        }
+    }
+    non_simple
+}
+
+struct Checker<'a> {
+    profile: Profile,
+    non_simple: &'a HashSet<ObjectProperty<ArcStr>>,
+    reasons: Vec<String>,
+}
+
+impl Checker<'_> {
+    fn disallow(&mut self, construct: &str) {
+        self.reasons.push(format!(
+            "{} is not allowed in OWL 2 {}",
+            construct,
+            self.profile.name()
+        ));
+    }
+
+    fn disallow_in(&mut self, construct: &str, position: Position) {
+        let position = match position {
+            Position::Sub => "subclass",
+            Position::Super => "superclass",
+            Position::Equivalent => "equivalent class",
+        };
+        self.reasons.push(format!(
+            "{} is not allowed as {} expression in OWL 2 {}",
+            construct,
+            position,
+            self.profile.name()
+        ));
+    }
+
+    fn simple(&mut self, ope: &ObjectPropertyExpression<ArcStr>, construct: &str) {
+        let op = opi(ope);
+        if self.non_simple.contains(op) {
+            self.reasons.push(format!(
+                "non-simple property {} is used in {}",
+                op.0.as_ref(),
+                construct
+            ));
+        }
+    }
+
+    fn ope(&mut self, ope: &ObjectPropertyExpression<ArcStr>) {
+        if self.profile == Profile::EL {
+            if let ObjectPropertyExpression::InverseObjectProperty(_) = ope {
+                self.disallow("ObjectInverseOf");
+            }
+        }
+    }
+
+    fn datatype(&mut self, iri: &IRI<ArcStr>) {
+        let local = match [OWL, RDF, RDFS, XSD]
+            .iter()
+            .find_map(|ns| iri.strip_prefix(ns))
+        {
+            Some(local) => local,
+            None => return,
+        };
+        let in_el = EL_DATATYPES.contains(&local);
+        let in_rl =
+            (in_el && local != "real" && local != "rational") || RL_DATATYPES.contains(&local);
+        let allowed = match self.profile {
+            Profile::EL | Profile::QL => in_el,
+            Profile::RL => in_rl,
+            Profile::DL => in_el || in_rl,
+        };
+
+        if !allowed {
+            if in_el || in_rl {
+                self.disallow(&format!("datatype {}", iri.as_ref()));
+            } else {
+                self.reasons.push(format!(
+                    "datatype {} is not in the OWL 2 datatype map",
+                    iri.as_ref()
+                ));
+            }
+        }
+    }
+
+    fn literal(&mut self, l: &Literal<ArcStr>) {
+        if let Literal::Datatype { datatype_iri, .. } = l {
+            self.datatype(datatype_iri);
+        }
+    }
+
+    fn data_range(&mut self, dr: &DataRange<ArcStr>) {
+        match dr {
+            DataRange::Datatype(dt) => self.datatype(&dt.0),
+            DataRange::DataIntersectionOf(v) => v.iter().for_each(|dr| self.data_range(dr)),
+            DataRange::DataOneOf(v) if self.profile == Profile::EL && v.len() > 1 => {
+                self.disallow("DataOneOf with more than one literal")
+            }
+            DataRange::DataOneOf(v) if matches!(self.profile, Profile::EL | Profile::DL) => {
+                v.iter().for_each(|l| self.literal(l))
+            }
+            DataRange::DataUnionOf(v) if self.profile == Profile::DL => {
+                v.iter().for_each(|dr| self.data_range(dr))
+            }
+            DataRange::DataComplementOf(dr) if self.profile == Profile::DL => self.data_range(dr),
+            DataRange::DatatypeRestriction(dt, v) if self.profile == Profile::DL => {
+                self.datatype(&dt.0);
+                v.iter().for_each(|fr| self.literal(&fr.l));
+            }
+            DataRange::DataOneOf(_) => self.disallow("DataOneOf"),
+            DataRange::DataUnionOf(_) => self.disallow("DataUnionOf"),
+            DataRange::DataComplementOf(_) => self.disallow("DataComplementOf"),
+            DataRange::DatatypeRestriction(..) => self.disallow("DatatypeRestriction"),
+        }
+    }
+
+    fn is_thing(ce: &ClassExpression<ArcStr>) -> bool {
+        matches!(ce, ClassExpression::Class(c) if c.0.as_ref() == "http://www.w3.org/2002/07/owl#Thing")
+    }
+
+    /// Checks a class expression in the given position.
+    fn class_expression(&mut self, ce: &ClassExpression<ArcStr>, position: Position) {
+        use ClassExpression::*;
+
+        // Global restrictions on simple properties
+        match ce {
+            ObjectMinCardinality { ope, .. } => self.simple(ope, "ObjectMinCardinality"),
+            ObjectMaxCardinality { ope, .. } => self.simple(ope, "ObjectMaxCardinality"),
+            ObjectExactCardinality { ope, .. } => self.simple(ope, "ObjectExactCardinality"),
+            ObjectHasSelf(ope) => self.simple(ope, "ObjectHasSelf"),
+            _ => {}
+        }
+
+        match self.profile {
+            Profile::DL => self.dl_class_expression(ce, position),
+            Profile::EL => self.el_class_expression(ce),
+            Profile::QL => self.ql_class_expression(ce, position),
+            Profile::RL => self.rl_class_expression(ce, position),
+        }
+    }
+
+    fn dl_class_expression(&mut self, ce: &ClassExpression<ArcStr>, position: Position) {
+        use ClassExpression::*;
+
+        match ce {
+            Class(_) | ObjectOneOf(_) | ObjectHasValue { .. } | ObjectHasSelf(_) => {}
+            ObjectIntersectionOf(v) | ObjectUnionOf(v) => {
+                v.iter().for_each(|ce| self.class_expression(ce, position))
+            }
+            ObjectComplementOf(ce) => self.class_expression(ce, position),
+            ObjectSomeValuesFrom { bce, .. }
+            | ObjectAllValuesFrom { bce, .. }
+            | ObjectMinCardinality { bce, .. }
+            | ObjectMaxCardinality { bce, .. }
+            | ObjectExactCardinality { bce, .. } => self.class_expression(bce, position),
+            DataSomeValuesFrom { dr, .. }
+            | DataAllValuesFrom { dr, .. }
+            | DataMinCardinality { dr, .. }
+            | DataMaxCardinality { dr, .. }
+            | DataExactCardinality { dr, .. } => self.data_range(dr),
+            DataHasValue { l, .. } => self.literal(l),
+        }
+    }
+
+    fn el_class_expression(&mut self, ce: &ClassExpression<ArcStr>) {
+        use ClassExpression::*;
+
+        match ce {
+            Class(_) => {}
+            ObjectIntersectionOf(v) => v.iter().for_each(|ce| self.el_class_expression(ce)),
+            ObjectSomeValuesFrom { ope, bce } => {
+                self.ope(ope);
+                self.class_expression(bce, Position::Sub);
+            }
+            ObjectHasValue { ope, .. } | ObjectHasSelf(ope) => self.ope(ope),
+            ObjectOneOf(v) if v.len() == 1 => {}
+            ObjectOneOf(_) => self.disallow("ObjectOneOf with more than one individual"),
+            DataSomeValuesFrom { dr, .. } => self.data_range(dr),
+            DataHasValue { l, .. } => self.literal(l),
+            ObjectUnionOf(_) => self.disallow("ObjectUnionOf"),
+            ObjectComplementOf(_) => self.disallow("ObjectComplementOf"),
+            ObjectAllValuesFrom { .. } => self.disallow("ObjectAllValuesFrom"),
+            ObjectMinCardinality { .. } => self.disallow("ObjectMinCardinality"),
+            ObjectMaxCardinality { .. } => self.disallow("ObjectMaxCardinality"),
+            ObjectExactCardinality { .. } => self.disallow("ObjectExactCardinality"),
+            DataAllValuesFrom { .. } => self.disallow("DataAllValuesFrom"),
+            DataMinCardinality { .. } => self.disallow("DataMinCardinality"),
+            DataMaxCardinality { .. } => self.disallow("DataMaxCardinality"),
+            DataExactCardinality { .. } => self.disallow("DataExactCardinality"),
+        }
+    }
+
+    fn ql_class_expression(&mut self, ce: &ClassExpression<ArcStr>, position: Position) {
+        use ClassExpression::*;
+
+        match (ce, position) {
+            (Class(_), _) => {}
+            (ObjectSomeValuesFrom { bce, .. }, Position::Sub) if Self::is_thing(bce) => {}
+            (ObjectSomeValuesFrom { .. }, Position::Sub) => self.disallow_in(
+                "ObjectSomeValuesFrom with a filler other than owl:Thing",
+                position,
+            ),
+            (ObjectSomeValuesFrom { bce, .. }, _) if matches!(**bce, Class(_)) => {}
+            (ObjectSomeValuesFrom { .. }, _) => {
+                self.disallow_in("ObjectSomeValuesFrom with a complex filler", position)
+            }
+            (DataSomeValuesFrom { dr, .. }, _) => self.data_range(dr),
+            (ObjectIntersectionOf(v), Position::Super) => {
+                v.iter().for_each(|ce| self.class_expression(ce, position))
+            }
+            (ObjectComplementOf(ce), Position::Super) => self.class_expression(ce, Position::Sub),
+            (ObjectIntersectionOf(_), _) => self.disallow_in("ObjectIntersectionOf", position),
+            (ObjectComplementOf(_), _) => self.disallow_in("ObjectComplementOf", position),
+            (ObjectUnionOf(_), _) => self.disallow("ObjectUnionOf"),
+            (ObjectOneOf(_), _) => self.disallow("ObjectOneOf"),
+            (ObjectAllValuesFrom { .. }, _) => self.disallow("ObjectAllValuesFrom"),
+            (ObjectHasValue { .. }, _) => self.disallow("ObjectHasValue"),
+            (ObjectHasSelf(_), _) => self.disallow("ObjectHasSelf"),
+            (ObjectMinCardinality { .. }, _) => self.disallow("ObjectMinCardinality"),
+            (ObjectMaxCardinality { .. }, _) => self.disallow("ObjectMaxCardinality"),
+            (ObjectExactCardinality { .. }, _) => self.disallow("ObjectExactCardinality"),
+            (DataAllValuesFrom { .. }, _) => self.disallow("DataAllValuesFrom"),
+            (DataHasValue { .. }, _) => self.disallow("DataHasValue"),
+            (DataMinCardinality { .. }, _) => self.disallow("DataMinCardinality"),
+            (DataMaxCardinality { .. }, _) => self.disallow("DataMaxCardinality"),
+            (DataExactCardinality { .. }, _) => self.disallow("DataExactCardinality"),
+        }
+    }
+
+    fn rl_class_expression(&mut self, ce: &ClassExpression<ArcStr>, position: Position) {
+        use ClassExpression::*;
+        use Position::*;
+
+        match (ce, position) {
+            (Class(_), _) if Self::is_thing(ce) => self.disallow_in("owl:Thing", position),
+            (Class(_), _) => {}
+            (ObjectIntersectionOf(v), _) => {
+                v.iter().for_each(|ce| self.class_expression(ce, position))
+            }
+            (ObjectHasValue { .. }, _) => {}
+            (DataHasValue { l, .. }, _) => self.literal(l),
+            (ObjectUnionOf(v), Sub) => v.iter().for_each(|ce| self.class_expression(ce, Sub)),
+            (ObjectOneOf(_), Sub) => {}
+            (ObjectSomeValuesFrom { bce, .. }, Sub) => {
+                if !Self::is_thing(bce) {
+                    self.class_expression(bce, Sub)
+                }
+            }
+            (DataSomeValuesFrom { dr, .. }, Sub) => self.data_range(dr),
+            (ObjectComplementOf(ce), Super) => self.class_expression(ce, Sub),
+            (ObjectAllValuesFrom { bce, .. }, Super) => self.class_expression(bce, Super),
+            (ObjectMaxCardinality { n, bce, .. }, Super) if *n <= 1 => {
+                if !Self::is_thing(bce) {
+                    self.class_expression(bce, Sub)
+                }
+            }
+            (DataAllValuesFrom { dr, .. }, Super) => self.data_range(dr),
+            (DataMaxCardinality { n, dr, .. }, Super) if *n <= 1 => self.data_range(dr),
+            (ObjectMaxCardinality { .. }, Super) | (DataMaxCardinality { .. }, Super) => {
+                self.disallow_in("a maximum cardinality other than 0 or 1", position)
+            }
+            (ObjectUnionOf(_), _) => self.disallow_in("ObjectUnionOf", position),
+            (ObjectOneOf(_), _) => self.disallow_in("ObjectOneOf", position),
+            (ObjectSomeValuesFrom { .. }, _) => self.disallow_in("ObjectSomeValuesFrom", position),
+            (DataSomeValuesFrom { .. }, _) => self.disallow_in("DataSomeValuesFrom", position),
+            (ObjectComplementOf(_), _) => self.disallow_in("ObjectComplementOf", position),
+            (ObjectAllValuesFrom { .. }, _) => self.disallow_in("ObjectAllValuesFrom", position),
+            (DataAllValuesFrom { .. }, _) => self.disallow_in("DataAllValuesFrom", position),
+            (ObjectMaxCardinality { .. }, _) => self.disallow_in("ObjectMaxCardinality", position),
+            (DataMaxCardinality { .. }, _) => self.disallow_in("DataMaxCardinality", position),
+            (ObjectHasSelf(_), _) => self.disallow("ObjectHasSelf"),
+            (ObjectMinCardinality { .. }, _) => self.disallow("ObjectMinCardinality"),
+            (ObjectExactCardinality { .. }, _) => self.disallow("ObjectExactCardinality"),
+            (DataMinCardinality { .. }, _) => self.disallow("DataMinCardinality"),
+            (DataExactCardinality { .. }, _) => self.disallow("DataExactCardinality"),
+        }
+    }
+
+    /// The positions of the class expressions of an `EquivalentClasses` or `DisjointClasses` axiom.
+    fn nary_position(&self, equivalent: bool) -> Position {
+        match self.profile {
+            Profile::RL if equivalent => Position::Equivalent,
+            _ => Position::Sub,
+        }
+    }
+
+    fn component(&mut self, c: &Component<ArcStr>) {
+        use Component::*;
+
+        let profile = self.profile;
+        let el = profile == Profile::EL;
+        let ql = profile == Profile::QL;
+        let rl = profile == Profile::RL;
+
+        match c {
+            OntologyID(_) | DocIRI(_) | Import(_) | OntologyAnnotation(_) => {}
+            DeclareClass(_)
+            | DeclareObjectProperty(_)
+            | DeclareAnnotationProperty(_)
+            | DeclareDataProperty(_)
+            | DeclareNamedIndividual(_) => {}
+            DeclareDatatype(d) => self.datatype(&d.0 .0),
+            SubClassOf(ax) => {
+                self.class_expression(&ax.sub, Position::Sub);
+                self.class_expression(&ax.sup, Position::Super);
+            }
+            EquivalentClasses(ax) => {
+                let position = self.nary_position(true);
+                ax.0.iter()
+                    .for_each(|ce| self.class_expression(ce, position));
+            }
+            DisjointClasses(ax) => {
+                let position = self.nary_position(false);
+                ax.0.iter()
+                    .for_each(|ce| self.class_expression(ce, position));
+            }
+            DisjointUnion(ax) => {
+                if profile == Profile::DL {
+                    ax.1.iter()
+                        .for_each(|ce| self.class_expression(ce, Position::Sub));
+                } else {
+                    self.disallow("DisjointUnion");
+                }
+            }
+            SubObjectPropertyOf(ax) => {
+                match &ax.sub {
+                    SubObjectPropertyExpression::ObjectPropertyChain(_) if ql => {
+                        self.disallow("ObjectPropertyChain")
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyChain(v) => {
+                        v.iter().for_each(|ope| self.ope(ope))
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyExpression(ope) => self.ope(ope),
+                }
+                self.ope(&ax.sup);
+            }
+            EquivalentObjectProperties(ax) => ax.0.iter().for_each(|ope| self.ope(ope)),
+            DisjointObjectProperties(ax) => {
+                if el {
+                    self.disallow("DisjointObjectProperties");
+                }
+                ax.0.iter()
+                    .for_each(|ope| self.simple(ope, "DisjointObjectProperties"));
+            }
+            InverseObjectProperties(_) if el => self.disallow("InverseObjectProperties"),
+            InverseObjectProperties(_) => {}
+            ObjectPropertyDomain(ax) => {
+                self.ope(&ax.ope);
+                self.class_expression(&ax.ce, Position::Super);
+            }
+            ObjectPropertyRange(ax) => {
+                self.ope(&ax.ope);
+                self.class_expression(&ax.ce, Position::Super);
+            }
+            FunctionalObjectProperty(ax) => {
+                if el || ql {
+                    self.disallow("FunctionalObjectProperty");
+                }
+                self.simple(&ax.0, "FunctionalObjectProperty");
+            }
+            InverseFunctionalObjectProperty(ax) => {
+                if el || ql {
+                    self.disallow("InverseFunctionalObjectProperty");
+                }
+                self.simple(&ax.0, "InverseFunctionalObjectProperty");
+            }
+            ReflexiveObjectProperty(ax) => {
+                if rl {
+                    self.disallow("ReflexiveObjectProperty");
+                }
+                self.ope(&ax.0);
+            }
+            IrreflexiveObjectProperty(ax) => {
+                if el {
+                    self.disallow("IrreflexiveObjectProperty");
+                }
+                self.simple(&ax.0, "IrreflexiveObjectProperty");
+            }
+            SymmetricObjectProperty(_) if el => self.disallow("SymmetricObjectProperty"),
+            SymmetricObjectProperty(_) => {}
+            AsymmetricObjectProperty(ax) => {
+                if el {
+                    self.disallow("AsymmetricObjectProperty");
+                }
+                self.simple(&ax.0, "AsymmetricObjectProperty");
+            }
+            TransitiveObjectProperty(ax) => {
+                if ql {
+                    self.disallow("TransitiveObjectProperty");
+                }
+                self.ope(&ax.0);
+            }
+            SubDataPropertyOf(_) | EquivalentDataProperties(_) => {}
+            DisjointDataProperties(_) if el => self.disallow("DisjointDataProperties"),
+            DisjointDataProperties(_) => {}
+            DataPropertyDomain(ax) => self.class_expression(&ax.ce, Position::Super),
+            DataPropertyRange(ax) => self.data_range(&ax.dr),
+            FunctionalDataProperty(_) if ql => self.disallow("FunctionalDataProperty"),
+            FunctionalDataProperty(_) => {}
+            DatatypeDefinition(ax) => self.data_range(&ax.range),
+            HasKey(_) if ql => self.disallow("HasKey"),
+            HasKey(ax) => {
+                self.class_expression(&ax.ce, Position::Sub);
+                for pe in &ax.vpe {
+                    if let PropertyExpression::ObjectPropertyExpression(ope) = pe {
+                        self.ope(ope);
+                    }
+                }
+            }
+            SameIndividual(_) if ql => self.disallow("SameIndividual"),
+            SameIndividual(_) | DifferentIndividuals(_) => {}
+            ClassAssertion(ax) => match &ax.ce {
+                ClassExpression::Class(_) => {}
+                _ if ql => self.disallow("ClassAssertion with a class expression"),
+                ce => self.class_expression(ce, Position::Super),
+            },
+            ObjectPropertyAssertion(ax) => self.ope(&ax.ope),
+            NegativeObjectPropertyAssertion(_) if ql => {
+                self.disallow("NegativeObjectPropertyAssertion")
+            }
+            NegativeObjectPropertyAssertion(ax) => self.ope(&ax.ope),
+            DataPropertyAssertion(ax) => self.literal(&ax.to),
+            NegativeDataPropertyAssertion(_) if ql => {
+                self.disallow("NegativeDataPropertyAssertion")
+            }
+            NegativeDataPropertyAssertion(ax) => self.literal(&ax.to),
+            AnnotationAssertion(_)
+            | SubAnnotationPropertyOf(_)
+            | AnnotationPropertyDomain(_)
+            | AnnotationPropertyRange(_) => {}
+            Rule(_) => self
+                .reasons
+                .push("SWRL rules are not part of OWL 2".to_string()),
+        }
+    }
+}
+
+/// Kinds of entities that must not share an IRI in OWL 2 DL.
+const PUNNING_GROUPS: [&[NamedOWLEntityKind]; 2] = [
+    &[
+        NamedOWLEntityKind::ObjectProperty,
+        NamedOWLEntityKind::DataProperty,
+        NamedOWLEntityKind::AnnotationProperty,
+    ],
+    &[NamedOWLEntityKind::Class, NamedOWLEntityKind::Datatype],
+];
+
+/// Checks `components` against `profile` and returns the violating components with reasons.
+pub fn check_profile(
+    components: &[&AnnotatedComponent<ArcStr>],
+    profile: Profile,
+) -> ProfileReport {
+    let non_simple = non_simple_properties(components.iter().copied());
+
+    let mut declared: HashMap<&IRI<ArcStr>, HashSet<NamedOWLEntityKind>> = HashMap::new();
+    for (iri, kind) in components
+        .iter()
+        .filter_map(|c| declared_entity(&c.component))
+    {
+        declared.entry(iri).or_default().insert(kind);
+    }
+
+    let mut violations = Vec::new();
+    for &c in components {
+        let mut checker = Checker {
+            profile,
+            non_simple: &non_simple,
+            reasons: Vec::new(),
+        };
+        checker.component(&c.component);
+
+        if let Some((iri, kind)) = declared_entity(&c.component) {
+            let kinds = &declared[iri];
+            for group in PUNNING_GROUPS.iter().filter(|g| g.contains(&kind)) {
+                let punned: Vec<_> = group
+                    .iter()
+                    .filter(|k| kinds.contains(k))
+                    .map(|k| kind_name(*k))
+                    .collect();
+                if punned.len() > 1 {
+                    checker.reasons.push(format!(
+                        "{} is declared as {}",
+                        iri.as_ref(),
+                        punned.join(" and ")
+                    ));
+                }
+            }
+        }
+
+        let mut undeclared: Vec<_> = Signature::of(&c.component)
+            .entities()
+            .iter()
+            .filter(|e| {
+                let iri = entity_iri(e);
+                !is_builtin(iri)
+                    && !declared
+                        .get(iri)
+                        .is_some_and(|kinds| kinds.contains(&entity_kind(e)))
+            })
+            .map(|e| {
+                format!(
+                    "undeclared {} {}",
+                    kind_name(entity_kind(e)),
+                    entity_iri(e).as_ref()
+                )
+            })
+            .collect();
+        undeclared.sort();
+        checker.reasons.extend(undeclared);
+
+        violations.extend(checker.reasons.into_iter().map(|reason| (c, reason)));
+    }
+
+    violations.sort();
+    ProfileReport {
+        profile: profile.name().to_string(),
+        violations: violations
+            .into_iter()
+            .map(|(c, reason)| ProfileViolation {
+                component: c.into(),
+                reason,
+                kind: format!("{:?}", c.component.kind())
+                    .trim_start_matches("ComponentKind::")
+                    .to_string(),
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,428 @@
+//! Conversion between ontologies and streams of RDF triples.
+//!
+//! Triples are produced by the OWL to RDF mapping of `horned_owl::io::rdf::writer` and consumed by
+//! the RDF to OWL mapping of `horned_owl::io::rdf::reader`. On the Python side IRIs are
+//! `model.IRI`, blank nodes are `model.AnonymousIndividual` and literals are `model.Literal`.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write as _};
+
+use horned_owl::model::{AnonymousIndividual, ArcStr, Build, IRI};
+use oxrdf::vocab::rdf;
+use oxrdf::{BlankNode, Literal, NamedNode, NamedOrBlankNode, Term, Triple};
+use pretty_rdf::{PLiteral, PNamedNode, PNamedOrBlankNode, PTerm, PTriple, RdfFormatter};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator};
+
+use crate::model;
+
+/// Formatter collecting the triples written by the horned-owl RDF writer.
+pub struct TripleCollector<'a>(pub &'a mut Vec<PTriple<ArcStr>>);
+
+impl RdfFormatter<ArcStr, io::Sink> for TripleCollector<'_> {
+    fn format(&mut self, triple: PTriple<ArcStr>) -> Result<(), io::Error> {
+        self.0.push(triple);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<io::Sink, io::Error> {
+        Ok(io::sink())
+    }
+}
+
+/// An RDF term in horned-owl's model.
+pub enum RdfTerm {
+    Iri(IRI<ArcStr>),
+    BlankNode(AnonymousIndividual<ArcStr>),
+    Literal(horned_owl::model::Literal<ArcStr>),
+}
+
+impl RdfTerm {
+    fn named_node(build: &Build<ArcStr>, node: &PNamedNode<ArcStr>) -> Self {
+        RdfTerm::Iri(build.iri(&*node.iri))
+    }
+
+    fn subject(build: &Build<ArcStr>, node: &PNamedOrBlankNode<ArcStr>) -> Self {
+        match node {
+            PNamedOrBlankNode::NamedNode(nn) => Self::named_node(build, nn),
+            PNamedOrBlankNode::BlankNode(bn) => {
+                RdfTerm::BlankNode(AnonymousIndividual(bn.id.clone()))
+            }
+        }
+    }
+
+    fn object(build: &Build<ArcStr>, term: &PTerm<ArcStr>) -> Self {
+        match term {
+            PTerm::NamedNode(nn) => Self::named_node(build, nn),
+            PTerm::BlankNode(bn) => RdfTerm::BlankNode(AnonymousIndividual(bn.id.clone())),
+            PTerm::Literal(PLiteral::Simple { value }) => {
+                RdfTerm::Literal(horned_owl::model::Literal::Simple {
+                    literal: value.to_string(),
+                })
+            }
+            PTerm::Literal(PLiteral::LanguageTaggedString { value, language }) => {
+                RdfTerm::Literal(horned_owl::model::Literal::Language {
+                    literal: value.to_string(),
+                    lang: language.to_string(),
+                })
+            }
+            PTerm::Literal(PLiteral::Typed { value, datatype }) => {
+                RdfTerm::Literal(horned_owl::model::Literal::Datatype {
+                    literal: value.to_string(),
+                    datatype_iri: build.iri(&*datatype.iri),
+                })
+            }
+        }
+    }
+
+    /// Converts a triple written by the horned-owl RDF writer, using `build` for the IRIs.
+    pub fn triple(build: &Build<ArcStr>, triple: &PTriple<ArcStr>) -> [RdfTerm; 3] {
+        [
+            Self::subject(build, &triple.subject),
+            Self::named_node(build, &triple.predicate),
+            Self::object(build, &triple.object),
+        ]
+    }
+
+    fn into_py(self, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+        Ok(match self {
+            RdfTerm::Iri(iri) => Bound::new(py, model::IRI::from(iri))?.into_any(),
+            RdfTerm::BlankNode(individual) => {
+                Bound::new(py, model::AnonymousIndividual::from(&individual))?.into_any()
+            }
+            RdfTerm::Literal(literal) => model::Literal::from(literal).into_pyobject(py)?,
+        })
+    }
+}
+
+/// A triple of Python terms.
+pub type PyTriple<'py> = (Bound<'py, PyAny>, Bound<'py, PyAny>, Bound<'py, PyAny>);
+
+/// Iterator over the RDF triples of an ontology.
+#[pyclass]
+pub struct TripleIter(std::vec::IntoIter<[RdfTerm; 3]>);
+
+impl TripleIter {
+    pub fn new(triples: Vec<[RdfTerm; 3]>) -> Self {
+        TripleIter(triples.into_iter())
+    }
+}
+
+#[pymethods]
+impl TripleIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<PyTriple<'py>>> {
+        let Some([subject, predicate, object]) = self.0.next() else {
+            return Ok(None);
+        };
+
+        Ok(Some((
+            subject.into_py(py)?,
+            predicate.into_py(py)?,
+            object.into_py(py)?,
+        )))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// An RDF term passed from Python. Plain strings are IRIs.
+#[derive(FromPyObject)]
+pub enum PyTerm {
+    Iri(model::IRI),
+    BlankNode(model::AnonymousIndividual),
+    Literal(model::Literal),
+    String(String),
+}
+
+/// Converts Python terms to `oxrdf` terms.
+///
+/// Blank node labels are kept if they are valid N-Triples labels that are not used yet and are
+/// replaced by fresh labels otherwise.
+#[derive(Default)]
+struct TermConverter {
+    blank_nodes: HashMap<String, BlankNode>,
+    labels: HashSet<String>,
+}
+
+impl TermConverter {
+    fn named_node(&self, iri: String) -> PyResult<NamedNode> {
+        NamedNode::new(iri).map_err(|e| PyValueError::new_err(format!("Invalid IRI: {}", e)))
+    }
+
+    fn blank_node(&mut self, id: String) -> BlankNode {
+        if let Some(bn) = self.blank_nodes.get(&id) {
+            return bn.clone();
+        }
+
+        let bn = BlankNode::new(id.strip_prefix("_:").unwrap_or(&id))
+            .ok()
+            .filter(|bn| !self.labels.contains(bn.as_str()))
+            .unwrap_or_else(|| {
+                (0..)
+                    .map(BlankNode::new_from_unique_id)
+                    .find(|bn| !self.labels.contains(bn.as_str()))
+                    .expect("there are more unique ids than blank nodes")
+            });
+        self.labels.insert(bn.as_str().to_string());
+        self.blank_nodes.insert(id, bn.clone());
+        bn
+    }
+
+    fn typed_literal(&self, value: String, datatype: String) -> PyResult<Literal> {
+        let datatype = self.named_node(datatype)?;
+        // The N-Triples parser rejects these literals
+        if datatype == rdf::LANG_STRING {
+            return Err(PyValueError::new_err(format!(
+                "A literal with datatype {} needs a language tag: {:?}",
+                datatype, value
+            )));
+        }
+
+        Ok(Literal::new_typed_literal(value, datatype))
+    }
+
+    fn language_literal(&self, value: String, lang: String) -> PyResult<Literal> {
+        Literal::new_language_tagged_literal(value, lang)
+            .map_err(|e| PyValueError::new_err(format!("Invalid language tag: {}", e)))
+    }
+
+    fn term(&mut self, term: PyTerm) -> PyResult<Term> {
+        Ok(match term {
+            PyTerm::Iri(iri) => self.named_node(iri.into())?.into(),
+            PyTerm::String(iri) => self.named_node(iri)?.into(),
+            PyTerm::BlankNode(individual) => self.blank_node(individual.0 .0).into(),
+            PyTerm::Literal(literal) => match horned_owl::model::Literal::<ArcStr>::from(literal) {
+                horned_owl::model::Literal::Simple { literal } => {
+                    Literal::new_simple_literal(literal).into()
+                }
+                horned_owl::model::Literal::Language { literal, lang } => {
+                    self.language_literal(literal, lang)?.into()
+                }
+                horned_owl::model::Literal::Datatype {
+                    literal,
+                    datatype_iri,
+                } => self
+                    .typed_literal(literal, datatype_iri.to_string())?
+                    .into(),
+            },
+        })
+    }
+
+    /// Converts an rdflib `URIRef`, `BNode` or `Literal`.
+    fn rdflib_term(&mut self, rdflib: &Rdflib<'_>, term: &Bound<'_, PyAny>) -> PyResult<Term> {
+        if term.is_instance(&rdflib.uri_ref)? {
+            Ok(self.named_node(term.str()?.to_string())?.into())
+        } else if term.is_instance(&rdflib.bnode)? {
+            Ok(self.blank_node(term.str()?.to_string()).into())
+        } else if term.is_instance(&rdflib.literal)? {
+            let value = term.str()?.to_string();
+            let language: Option<String> = term.getattr("language")?.extract()?;
+            let datatype: Option<String> = term.getattr("datatype")?.extract()?;
+
+            Ok(match (language, datatype) {
+                (Some(lang), _) => self.language_literal(value, lang)?.into(),
+                (None, Some(datatype)) => self.typed_literal(value, datatype)?.into(),
+                (None, None) => Literal::new_simple_literal(value).into(),
+            })
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Unsupported rdflib term: {}",
+                term.repr()?
+            )))
+        }
+    }
+
+    fn triple([subject, predicate, object]: [Term; 3]) -> PyResult<Triple> {
+        let subject = match subject {
+            Term::NamedNode(nn) => NamedOrBlankNode::NamedNode(nn),
+            Term::BlankNode(bn) => NamedOrBlankNode::BlankNode(bn),
+            Term::Literal(l) => {
+                return Err(PyValueError::new_err(format!(
+                    "A literal cannot be the subject of a triple: {}",
+                    l
+                )))
+            }
+        };
+        let predicate = match predicate {
+            Term::NamedNode(nn) => nn,
+            t => {
+                return Err(PyValueError::new_err(format!(
+                    "The predicate of a triple must be an IRI: {}",
+                    t
+                )))
+            }
+        };
+
+        Ok(Triple::new(subject, predicate, object))
+    }
+}
+
+/// Reads a Python iterable of triples as N-Triples, one triple at a time.
+///
+/// `OntologyParser::new` takes parsed triples, but the blank nodes of its terms have no public
+/// constructor and are only created by its readers, so the triples are passed to it as N-Triples.
+/// A triple is converted when the reader asks for more input, and only that triple is held as
+/// text. The reader panics on input errors, so a conversion error ends the input instead and is
+/// returned by `finish`.
+pub struct NTriplesReader<'py> {
+    triples: Bound<'py, PyIterator>,
+    rdflib: Option<Rdflib<'py>>,
+    converter: TermConverter,
+    line: Vec<u8>,
+    pos: usize,
+    error: Option<PyErr>,
+}
+
+impl<'py> NTriplesReader<'py> {
+    /// Reads an iterable of Python triples.
+    pub fn new(triples: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Self::with_rdflib(triples, None)
+    }
+
+    /// Reads the triples of an rdflib `Graph`.
+    pub fn rdflib(rdflib: Rdflib<'py>, graph: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Self::with_rdflib(graph, Some(rdflib))
+    }
+
+    fn with_rdflib(triples: &Bound<'py, PyAny>, rdflib: Option<Rdflib<'py>>) -> PyResult<Self> {
+        Ok(NTriplesReader {
+            triples: triples.try_iter()?,
+            rdflib,
+            converter: TermConverter::default(),
+            line: Vec::new(),
+            pos: 0,
+            error: None,
+        })
+    }
+
+    fn term(&mut self, term: &Bound<'py, PyAny>) -> PyResult<Term> {
+        match &self.rdflib {
+            Some(rdflib) => self.converter.rdflib_term(rdflib, term),
+            None => self.converter.term(term.extract()?),
+        }
+    }
+
+    fn next_triple(&mut self) -> PyResult<Option<Triple>> {
+        let Some(triple) = self.triples.next() else {
+            return Ok(None);
+        };
+        let (subject, predicate, object): PyTriple<'py> = triple?.extract()?;
+        let terms = [
+            self.term(&subject)?,
+            self.term(&predicate)?,
+            self.term(&object)?,
+        ];
+        TermConverter::triple(terms).map(Some)
+    }
+
+    /// Returns the error that ended the input, if any.
+    pub fn finish(self) -> PyResult<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl io::Read for NTriplesReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let line = io::BufRead::fill_buf(self)?;
+            let n = line.len().min(buf.len());
+            buf[..n].copy_from_slice(&line[..n]);
+            n
+        };
+        io::BufRead::consume(self, n);
+        Ok(n)
+    }
+}
+
+impl io::BufRead for NTriplesReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.line.len() && self.error.is_none() {
+            self.line.clear();
+            self.pos = 0;
+            match self.next_triple() {
+                Ok(Some(triple)) => writeln!(self.line, "{} .", triple)?,
+                Ok(None) => {}
+                Err(e) => self.error = Some(e),
+            }
+        }
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.line.len());
+    }
+}
+
+/// The rdflib classes used for the conversion. rdflib is imported on first use.
+pub struct Rdflib<'py> {
+    graph: Bound<'py, PyAny>,
+    uri_ref: Bound<'py, PyAny>,
+    bnode: Bound<'py, PyAny>,
+    literal: Bound<'py, PyAny>,
+}
+
+impl<'py> Rdflib<'py> {
+    pub fn import(py: Python<'py>) -> PyResult<Self> {
+        let rdflib = py.import("rdflib")?;
+
+        Ok(Rdflib {
+            graph: rdflib.getattr("Graph")?,
+            uri_ref: rdflib.getattr("URIRef")?,
+            bnode: rdflib.getattr("BNode")?,
+            literal: rdflib.getattr("Literal")?,
+        })
+    }
+
+    /// Creates an empty `Graph`.
+    pub fn graph(&self) -> PyResult<Bound<'py, PyAny>> {
+        self.graph.call0()
+    }
+
+    pub fn uri_ref(&self, iri: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.uri_ref.call1((iri,))
+    }
+
+    fn term(&self, term: &RdfTerm) -> PyResult<Bound<'py, PyAny>> {
+        let py = self.graph.py();
+
+        match term {
+            RdfTerm::Iri(iri) => self.uri_ref(iri.as_ref()),
+            RdfTerm::BlankNode(individual) => {
+                let id: &str = individual.0.as_ref();
+                self.bnode.call1((id.strip_prefix("_:").unwrap_or(id),))
+            }
+            RdfTerm::Literal(horned_owl::model::Literal::Simple { literal }) => {
+                self.literal.call1((literal,))
+            }
+            RdfTerm::Literal(horned_owl::model::Literal::Language { literal, lang }) => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item("lang", lang)?;
+                self.literal.call((literal,), Some(&kwargs))
+            }
+            RdfTerm::Literal(horned_owl::model::Literal::Datatype {
+                literal,
+                datatype_iri,
+            }) => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item("datatype", self.uri_ref(datatype_iri.as_ref())?)?;
+                self.literal.call((literal,), Some(&kwargs))
+            }
+        }
+    }
+
+    /// Converts a triple to a tuple of rdflib terms.
+    pub fn triple(&self, [subject, predicate, object]: &[RdfTerm; 3]) -> PyResult<PyTriple<'py>> {
+        Ok((
+            self.term(subject)?,
+            self.term(predicate)?,
+            self.term(object)?,
+        ))
+    }
+}
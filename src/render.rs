@@ -0,0 +1,654 @@
+//! Rendering of model objects in Manchester or functional syntax
+//!
+//! Entities are written as their label, as prefixed name or as full IRI. The Manchester renderer
+//! follows the axiom style of Protégé (`A SubClassOf part_of some B`). Functional syntax is written
+//! by the horned-owl OFN writer, after replacing all IRIs by placeholders for the chosen names.
+
+use std::convert::Infallible;
+
+use horned_owl::io::ofn::writer::AsFunctional;
+use horned_owl::model::*;
+use horned_owl::vocab::Facet;
+
+use crate::fold::{Fold, Transformable};
+use crate::ontology::PyIndexedOntology;
+use crate::parse::{with_standard_prefixes, KEYWORDS, XSD};
+
+const PLACEHOLDER: &str = "urn:pyhornedowl:name:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    Manchester,
+    Functional,
+}
+
+pub struct Renderer<'a> {
+    ontology: &'a mut PyIndexedOntology,
+    mapping: Option<curie::PrefixMapping>,
+    use_labels: bool,
+    syntax: Syntax,
+}
+
+/// Returns whether `local` can be written as local part of a prefixed name.
+fn is_safe_local(local: &str) -> bool {
+    local.chars().next().is_some_and(|c| c.is_alphanumeric() || c == '_')
+        && !local.ends_with('.')
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_-.".contains(c))
+}
+
+/// Returns whether `label` can be written in Manchester syntax without quotes.
+fn is_bare_label(label: &str) -> bool {
+    label.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && label.chars().all(|c| c.is_alphanumeric() || "_-".contains(c))
+        && !KEYWORDS.contains(&label)
+        && !["Self", "true", "false"].contains(&label)
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(
+        ontology: &'a mut PyIndexedOntology,
+        mapping: &curie::PrefixMapping,
+        syntax: Syntax,
+        use_labels: bool,
+        use_curies: bool,
+    ) -> Self {
+        // Move a default prefix into the mapping so that it is written as `:local`
+        let mapping = use_curies.then(|| {
+            let mapping = with_standard_prefixes(mapping);
+            let mut normalized = curie::PrefixMapping::default();
+            if let Ok(default) = mapping.expand_curie(&curie::Curie::new(None, "")) {
+                normalized.add_prefix("", &default).ok();
+            }
+            for (prefix, iri) in mapping.mappings() {
+                normalized.add_prefix(prefix, iri).ok();
+            }
+            normalized
+        });
+
+        Renderer {
+            ontology,
+            mapping,
+            use_labels,
+            syntax,
+        }
+    }
+
+    fn label(&mut self, iri: &IRI<ArcStr>) -> Option<String> {
+        let labels = self.ontology.labels(iri);
+        labels
+            .iter()
+            .find(|l| !matches!(l, Literal::Language { .. }))
+            .or_else(|| labels.first())
+            .map(|l| l.literal().to_string())
+            .filter(|l| !l.contains('\''))
+    }
+
+    /// Renders an IRI as label, prefixed name or full IRI.
+    pub fn name(&mut self, iri: &IRI<ArcStr>) -> String {
+        if self.use_labels {
+            if let Some(label) = self.label(iri) {
+                return if self.syntax == Syntax::Manchester && is_bare_label(&label) {
+                    label
+                } else {
+                    format!("'{label}'")
+                };
+            }
+        }
+
+        if let Some(curie) = self
+            .mapping
+            .as_ref()
+            .and_then(|m| m.shrink_iri(iri).ok())
+            .map(|c| c.to_string())
+            .filter(|c| c.split_once(':').is_some_and(|(_, local)| is_safe_local(local)))
+        {
+            return curie;
+        }
+
+        format!("<{}>", iri.as_ref())
+    }
+
+    pub fn render(&mut self, obj: &Transformable) -> String {
+        match self.syntax {
+            Syntax::Manchester => self.manchester(obj),
+            Syntax::Functional => self.functional(obj),
+        }
+    }
+
+    fn functional(&mut self, obj: &Transformable) -> String {
+        let mut names = NameFold {
+            renderer: self,
+            build: Build::new_arc(),
+            names: Vec::new(),
+        };
+        let rendered = match obj {
+            Transformable::AnnotatedComponent(e) => names
+                .fold_annotated_component(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::Component(e) => names
+                .fold_component(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::ClassExpression(e) => names
+                .fold_class_expression(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::ObjectPropertyExpression(e) => names
+                .fold_object_property_expression(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::DataRange(e) => names
+                .fold_data_range(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::Individual(e) => names
+                .fold_individual(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::Literal(e) => names
+                .fold_literal(e.into())
+                .map(|e| e.as_functional().to_string()),
+            Transformable::Annotation(e) => names.fold_annotation(e.into()).map(|e| {
+                format!(
+                    "Annotation({} {})",
+                    e.ap.0.as_functional(),
+                    e.av.as_functional()
+                )
+            }),
+        };
+        let mut rendered = match rendered {
+            Ok(rendered) => rendered,
+            Err(e) => match e {},
+        };
+
+        for (i, name) in names.names.iter().enumerate().rev() {
+            rendered = rendered.replace(&format!("<{PLACEHOLDER}{i}>"), name);
+        }
+        rendered
+    }
+
+    fn manchester(&mut self, obj: &Transformable) -> String {
+        match obj {
+            Transformable::AnnotatedComponent(e) => self.annotated_component(&e.into()),
+            Transformable::Component(e) => self.component(&e.into()),
+            Transformable::ClassExpression(e) => self.class_expression(&e.into()),
+            Transformable::ObjectPropertyExpression(e) => {
+                self.object_property_expression(&e.into())
+            }
+            Transformable::DataRange(e) => self.data_range(&e.into()),
+            Transformable::Individual(e) => self.individual(&e.into()),
+            Transformable::Literal(e) => self.literal(&e.into()),
+            Transformable::Annotation(e) => self.annotation(&e.into()),
+        }
+    }
+
+    fn join<T>(&mut self, items: &[T], sep: &str, f: impl Fn(&mut Self, &T) -> String) -> String {
+        items
+            .iter()
+            .map(|i| f(self, i))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+
+    fn individual(&mut self, i: &Individual<ArcStr>) -> String {
+        match i {
+            Individual::Named(i) => self.name(&i.0),
+            Individual::Anonymous(a) => a.0.to_string(),
+        }
+    }
+
+    fn literal(&mut self, l: &Literal<ArcStr>) -> String {
+        match l {
+            Literal::Simple { literal } => quote(literal),
+            Literal::Language { literal, lang } => format!("{}@{}", quote(literal), lang),
+            Literal::Datatype {
+                literal,
+                datatype_iri,
+            } => {
+                let bare = match datatype_iri.strip_prefix(XSD) {
+                    Some("integer") => {
+                        let digits = literal.strip_prefix(['-', '+']).unwrap_or(literal);
+                        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+                    }
+                    Some("decimal") => {
+                        let digits = literal.strip_prefix(['-', '+']).unwrap_or(literal);
+                        digits.split_once('.').is_some_and(|(i, f)| {
+                            !i.is_empty()
+                                && !f.is_empty()
+                                && (i.to_string() + f).chars().all(|c| c.is_ascii_digit())
+                        })
+                    }
+                    Some("boolean") => literal == "true" || literal == "false",
+                    _ => false,
+                };
+                if bare {
+                    literal.clone()
+                } else {
+                    format!("{}^^{}", quote(literal), self.name(datatype_iri))
+                }
+            }
+        }
+    }
+
+    fn object_property_expression(&mut self, ope: &ObjectPropertyExpression<ArcStr>) -> String {
+        match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => self.name(&op.0),
+            ObjectPropertyExpression::InverseObjectProperty(op) => {
+                format!("inverse {}", self.name(&op.0))
+            }
+        }
+    }
+
+    fn data_range(&mut self, dr: &DataRange<ArcStr>) -> String {
+        match dr {
+            DataRange::Datatype(dt) => self.name(&dt.0),
+            DataRange::DataIntersectionOf(v) => {
+                self.join(v, " and ", |s, dr| s.nested_data_range(dr))
+            }
+            DataRange::DataUnionOf(v) => self.join(v, " or ", |s, dr| s.nested_data_range(dr)),
+            DataRange::DataComplementOf(dr) => format!("not {}", self.nested_data_range(dr)),
+            DataRange::DataOneOf(v) => format!("{{{}}}", self.join(v, ", ", Self::literal)),
+            DataRange::DatatypeRestriction(dt, restrictions) => {
+                let restrictions = self.join(restrictions, ", ", |s, fr| {
+                    let facet = match fr.f {
+                        Facet::MinInclusive => ">=".to_string(),
+                        Facet::MinExclusive => ">".to_string(),
+                        Facet::MaxInclusive => "<=".to_string(),
+                        Facet::MaxExclusive => "<".to_string(),
+                        ref f => f.as_ref().trim_start_matches(XSD).to_string(),
+                    };
+                    format!("{} {}", facet, s.literal(&fr.l))
+                });
+                format!("{}[{}]", self.name(&dt.0), restrictions)
+            }
+        }
+    }
+
+    fn nested_data_range(&mut self, dr: &DataRange<ArcStr>) -> String {
+        match dr {
+            DataRange::DataIntersectionOf(_) | DataRange::DataUnionOf(_) => {
+                format!("({})", self.data_range(dr))
+            }
+            dr => self.data_range(dr),
+        }
+    }
+
+    pub fn class_expression(&mut self, ce: &ClassExpression<ArcStr>) -> String {
+        use ClassExpression::*;
+
+        match ce {
+            Class(c) => self.name(&c.0),
+            ObjectIntersectionOf(v) => self.join(v, " and ", |s, ce| match ce {
+                ObjectIntersectionOf(_) | ObjectUnionOf(_) => format!("({})", s.class_expression(ce)),
+                ce => s.class_expression(ce),
+            }),
+            ObjectUnionOf(v) => self.join(v, " or ", |s, ce| match ce {
+                ObjectUnionOf(_) => format!("({})", s.class_expression(ce)),
+                ce => s.class_expression(ce),
+            }),
+            ObjectComplementOf(ce) => format!("not {}", self.filler(ce)),
+            ObjectOneOf(v) => format!("{{{}}}", self.join(v, ", ", Self::individual)),
+            ObjectSomeValuesFrom { ope, bce } => self.restriction(ope, "some", bce),
+            ObjectAllValuesFrom { ope, bce } => self.restriction(ope, "only", bce),
+            ObjectHasValue { ope, i } => format!(
+                "{} value {}",
+                self.object_property_expression(ope),
+                self.individual(i)
+            ),
+            ObjectHasSelf(ope) => format!("{} Self", self.object_property_expression(ope)),
+            ObjectMinCardinality { n, ope, bce } => self.restriction(ope, &format!("min {n}"), bce),
+            ObjectMaxCardinality { n, ope, bce } => self.restriction(ope, &format!("max {n}"), bce),
+            ObjectExactCardinality { n, ope, bce } => {
+                self.restriction(ope, &format!("exactly {n}"), bce)
+            }
+            DataSomeValuesFrom { dp, dr } => self.data_restriction(dp, "some", dr),
+            DataAllValuesFrom { dp, dr } => self.data_restriction(dp, "only", dr),
+            DataHasValue { dp, l } => format!("{} value {}", self.name(&dp.0), self.literal(l)),
+            DataMinCardinality { n, dp, dr } => self.data_restriction(dp, &format!("min {n}"), dr),
+            DataMaxCardinality { n, dp, dr } => self.data_restriction(dp, &format!("max {n}"), dr),
+            DataExactCardinality { n, dp, dr } => {
+                self.data_restriction(dp, &format!("exactly {n}"), dr)
+            }
+        }
+    }
+
+    /// Renders a class expression in a position that binds tighter than `and`.
+    fn filler(&mut self, ce: &ClassExpression<ArcStr>) -> String {
+        match ce {
+            ClassExpression::ObjectIntersectionOf(_) | ClassExpression::ObjectUnionOf(_) => {
+                format!("({})", self.class_expression(ce))
+            }
+            ce => self.class_expression(ce),
+        }
+    }
+
+    fn restriction(
+        &mut self,
+        ope: &ObjectPropertyExpression<ArcStr>,
+        keyword: &str,
+        bce: &ClassExpression<ArcStr>,
+    ) -> String {
+        format!(
+            "{} {} {}",
+            self.object_property_expression(ope),
+            keyword,
+            self.filler(bce)
+        )
+    }
+
+    fn data_restriction(
+        &mut self,
+        dp: &DataProperty<ArcStr>,
+        keyword: &str,
+        dr: &DataRange<ArcStr>,
+    ) -> String {
+        format!(
+            "{} {} {}",
+            self.name(&dp.0),
+            keyword,
+            self.nested_data_range(dr)
+        )
+    }
+
+    fn annotation(&mut self, ann: &Annotation<ArcStr>) -> String {
+        let value = match &ann.av {
+            AnnotationValue::Literal(l) => self.literal(l),
+            AnnotationValue::IRI(iri) => self.name(iri),
+            AnnotationValue::AnonymousIndividual(a) => a.0.to_string(),
+        };
+        format!("{} {}", self.name(&ann.ap.0), value)
+    }
+
+    fn annotated_component(&mut self, c: &AnnotatedComponent<ArcStr>) -> String {
+        let component = self.component(&c.component);
+        if c.ann.is_empty() {
+            return component;
+        }
+        let annotations: Vec<_> = c.ann.iter().map(|a| self.annotation(a)).collect();
+        format!("{} Annotations: {}", component, annotations.join(", "))
+    }
+
+    /// Renders a binary axiom as `a keyword b` or an n-ary one as `Keyword: a, b, c`.
+    fn nary<T>(
+        &mut self,
+        items: &[T],
+        binary: &str,
+        nary: &str,
+        f: impl Fn(&mut Self, &T) -> String,
+    ) -> String {
+        if items.len() == 2 {
+            format!("{} {} {}", f(self, &items[0]), binary, f(self, &items[1]))
+        } else {
+            format!("{}: {}", nary, self.join(items, ", ", f))
+        }
+    }
+
+    fn atom(&mut self, atom: &Atom<ArcStr>) -> String {
+        let iarg = |s: &mut Self, a: &IArgument<ArcStr>| match a {
+            IArgument::Individual(i) => s.individual(i),
+            IArgument::Variable(v) => s.variable(v),
+        };
+        let darg = |s: &mut Self, a: &DArgument<ArcStr>| match a {
+            DArgument::Literal(l) => s.literal(l),
+            DArgument::Variable(v) => s.variable(v),
+        };
+
+        match atom {
+            Atom::BuiltInAtom { pred, args } => {
+                format!("{}({})", self.name(pred), self.join(args, ", ", darg))
+            }
+            Atom::ClassAtom { pred, arg } => {
+                let pred = match pred {
+                    ClassExpression::Class(c) => self.name(&c.0),
+                    ce => format!("({})", self.class_expression(ce)),
+                };
+                format!("{}({})", pred, iarg(self, arg))
+            }
+            Atom::DataPropertyAtom { pred, args } => format!(
+                "{}({}, {})",
+                self.name(&pred.0),
+                darg(self, &args.0),
+                darg(self, &args.1)
+            ),
+            Atom::DataRangeAtom { pred, arg } => {
+                format!("{}({})", self.nested_data_range(pred), darg(self, arg))
+            }
+            Atom::DifferentIndividualsAtom(a, b) => {
+                format!("DifferentFrom({}, {})", iarg(self, a), iarg(self, b))
+            }
+            Atom::ObjectPropertyAtom { pred, args } => format!(
+                "{}({}, {})",
+                self.object_property_expression(pred),
+                iarg(self, &args.0),
+                iarg(self, &args.1)
+            ),
+            Atom::SameIndividualAtom(a, b) => {
+                format!("SameAs({}, {})", iarg(self, a), iarg(self, b))
+            }
+        }
+    }
+
+    fn variable(&mut self, v: &Variable<ArcStr>) -> String {
+        let local = v.0.rsplit(['#', '/', ':']).next().unwrap_or(&v.0);
+        format!("?{local}")
+    }
+
+    pub fn component(&mut self, c: &Component<ArcStr>) -> String {
+        use Component::*;
+
+        match c {
+            OntologyID(id) => {
+                let iris: Vec<_> = [&id.iri, &id.viri]
+                    .into_iter()
+                    .flatten()
+                    .map(|iri| format!("<{}>", iri.as_ref()))
+                    .collect();
+                format!("Ontology: {}", iris.join(" "))
+            }
+            DocIRI(iri) => format!("DocIRI: <{}>", iri.0.as_ref()),
+            Import(iri) => format!("Import: <{}>", iri.0.as_ref()),
+            OntologyAnnotation(a) => format!("Annotations: {}", self.annotation(&a.0)),
+            DeclareClass(d) => format!("Class: {}", self.name(&d.0 .0)),
+            DeclareObjectProperty(d) => format!("ObjectProperty: {}", self.name(&d.0 .0)),
+            DeclareAnnotationProperty(d) => format!("AnnotationProperty: {}", self.name(&d.0 .0)),
+            DeclareDataProperty(d) => format!("DataProperty: {}", self.name(&d.0 .0)),
+            DeclareNamedIndividual(d) => format!("Individual: {}", self.name(&d.0 .0)),
+            DeclareDatatype(d) => format!("Datatype: {}", self.name(&d.0 .0)),
+            SubClassOf(ax) => format!(
+                "{} SubClassOf {}",
+                self.class_expression(&ax.sub),
+                self.class_expression(&ax.sup)
+            ),
+            EquivalentClasses(ax) => {
+                self.nary(&ax.0, "EquivalentTo", "EquivalentClasses", Self::class_expression)
+            }
+            DisjointClasses(ax) => {
+                self.nary(&ax.0, "DisjointWith", "DisjointClasses", Self::class_expression)
+            }
+            DisjointUnion(ax) => format!(
+                "{} DisjointUnionOf {}",
+                self.name(&ax.0 .0),
+                self.join(&ax.1, ", ", Self::class_expression)
+            ),
+            SubObjectPropertyOf(ax) => {
+                let sub = match &ax.sub {
+                    SubObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                        self.join(chain, " o ", Self::object_property_expression)
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyExpression(ope) => {
+                        self.object_property_expression(ope)
+                    }
+                };
+                format!(
+                    "{} SubPropertyOf {}",
+                    sub,
+                    self.object_property_expression(&ax.sup)
+                )
+            }
+            EquivalentObjectProperties(ax) => self.nary(
+                &ax.0,
+                "EquivalentTo",
+                "EquivalentProperties",
+                Self::object_property_expression,
+            ),
+            DisjointObjectProperties(ax) => self.nary(
+                &ax.0,
+                "DisjointWith",
+                "DisjointProperties",
+                Self::object_property_expression,
+            ),
+            InverseObjectProperties(ax) => format!(
+                "{} InverseOf {}",
+                self.name(&ax.0 .0),
+                self.name(&ax.1 .0)
+            ),
+            ObjectPropertyDomain(ax) => format!(
+                "{} Domain {}",
+                self.object_property_expression(&ax.ope),
+                self.class_expression(&ax.ce)
+            ),
+            ObjectPropertyRange(ax) => format!(
+                "{} Range {}",
+                self.object_property_expression(&ax.ope),
+                self.class_expression(&ax.ce)
+            ),
+            FunctionalObjectProperty(ax) => {
+                format!("Functional: {}", self.object_property_expression(&ax.0))
+            }
+            InverseFunctionalObjectProperty(ax) => {
+                format!("InverseFunctional: {}", self.object_property_expression(&ax.0))
+            }
+            ReflexiveObjectProperty(ax) => {
+                format!("Reflexive: {}", self.object_property_expression(&ax.0))
+            }
+            IrreflexiveObjectProperty(ax) => {
+                format!("Irreflexive: {}", self.object_property_expression(&ax.0))
+            }
+            SymmetricObjectProperty(ax) => {
+                format!("Symmetric: {}", self.object_property_expression(&ax.0))
+            }
+            AsymmetricObjectProperty(ax) => {
+                format!("Asymmetric: {}", self.object_property_expression(&ax.0))
+            }
+            TransitiveObjectProperty(ax) => {
+                format!("Transitive: {}", self.object_property_expression(&ax.0))
+            }
+            SubDataPropertyOf(ax) => format!(
+                "{} SubPropertyOf {}",
+                self.name(&ax.sub.0),
+                self.name(&ax.sup.0)
+            ),
+            EquivalentDataProperties(ax) => {
+                self.nary(&ax.0, "EquivalentTo", "EquivalentProperties", |s, dp| {
+                    s.name(&dp.0)
+                })
+            }
+            DisjointDataProperties(ax) => {
+                self.nary(&ax.0, "DisjointWith", "DisjointProperties", |s, dp| {
+                    s.name(&dp.0)
+                })
+            }
+            DataPropertyDomain(ax) => format!(
+                "{} Domain {}",
+                self.name(&ax.dp.0),
+                self.class_expression(&ax.ce)
+            ),
+            DataPropertyRange(ax) => format!(
+                "{} Range {}",
+                self.name(&ax.dp.0),
+                self.data_range(&ax.dr)
+            ),
+            FunctionalDataProperty(ax) => format!("Functional: {}", self.name(&ax.0 .0)),
+            DatatypeDefinition(ax) => format!(
+                "{} EquivalentTo {}",
+                self.name(&ax.kind.0),
+                self.data_range(&ax.range)
+            ),
+            HasKey(ax) => {
+                let keys = self.join(&ax.vpe, ", ", |s, pe| match pe {
+                    PropertyExpression::ObjectPropertyExpression(ope) => {
+                        s.object_property_expression(ope)
+                    }
+                    PropertyExpression::DataProperty(dp) => s.name(&dp.0),
+                    PropertyExpression::AnnotationProperty(ap) => s.name(&ap.0),
+                });
+                format!("{} HasKey {}", self.filler(&ax.ce), keys)
+            }
+            SameIndividual(ax) => self.nary(&ax.0, "SameAs", "SameIndividual", Self::individual),
+            DifferentIndividuals(ax) => {
+                self.nary(&ax.0, "DifferentFrom", "DifferentIndividuals", Self::individual)
+            }
+            ClassAssertion(ax) => format!(
+                "{} Type {}",
+                self.individual(&ax.i),
+                self.class_expression(&ax.ce)
+            ),
+            ObjectPropertyAssertion(ax) => format!(
+                "{} {} {}",
+                self.individual(&ax.from),
+                self.object_property_expression(&ax.ope),
+                self.individual(&ax.to)
+            ),
+            NegativeObjectPropertyAssertion(ax) => format!(
+                "not ({} {} {})",
+                self.individual(&ax.from),
+                self.object_property_expression(&ax.ope),
+                self.individual(&ax.to)
+            ),
+            DataPropertyAssertion(ax) => format!(
+                "{} {} {}",
+                self.individual(&ax.from),
+                self.name(&ax.dp.0),
+                self.literal(&ax.to)
+            ),
+            NegativeDataPropertyAssertion(ax) => format!(
+                "not ({} {} {})",
+                self.individual(&ax.from),
+                self.name(&ax.dp.0),
+                self.literal(&ax.to)
+            ),
+            AnnotationAssertion(ax) => {
+                let subject = match &ax.subject {
+                    AnnotationSubject::IRI(iri) => self.name(iri),
+                    AnnotationSubject::AnonymousIndividual(a) => a.0.to_string(),
+                };
+                format!("{} {}", subject, self.annotation(&ax.ann))
+            }
+            SubAnnotationPropertyOf(ax) => format!(
+                "{} SubPropertyOf {}",
+                self.name(&ax.sub.0),
+                self.name(&ax.sup.0)
+            ),
+            AnnotationPropertyDomain(ax) => {
+                format!("{} Domain {}", self.name(&ax.ap.0), self.name(&ax.iri))
+            }
+            AnnotationPropertyRange(ax) => {
+                format!("{} Range {}", self.name(&ax.ap.0), self.name(&ax.iri))
+            }
+            Rule(r) => format!(
+                "Rule: {} -> {}",
+                self.join(&r.body, ", ", Self::atom),
+                self.join(&r.head, ", ", Self::atom)
+            ),
+        }
+    }
+}
+
+/// Replaces every IRI by a placeholder standing for its rendered name.
+struct NameFold<'r, 'a> {
+    renderer: &'r mut Renderer<'a>,
+    build: Build<ArcStr>,
+    names: Vec<String>,
+}
+
+impl Fold for NameFold<'_, '_> {
+    type Error = Infallible;
+
+    fn fold_iri(&mut self, e: IRI<ArcStr>) -> Result<IRI<ArcStr>, Self::Error> {
+        self.names.push(self.renderer.name(&e));
+        Ok(self
+            .build
+            .iri(format!("{}{}", PLACEHOLDER, self.names.len() - 1)))
+    }
+}
@@ -0,0 +1,250 @@
+//! Full text search for py-horned-owl
+//!
+//! An inverted index from the tokens of annotation literals to the annotation assertions they
+//! occur in. Query tokens match index tokens exactly, as prefix or within a small edit distance.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Bound;
+
+use horned_owl::model::{AnnotatedComponent, ArcAnnotatedComponent, ArcStr, IRI};
+use horned_owl::ontology::indexed::OntologyIndex;
+use pyo3::prelude::*;
+
+use crate::label_index::{label_entry, normalize};
+
+/// Properties searched as definitions by default.
+pub const DEFAULT_DEFINITION_PROPERTIES: [&str; 3] = [
+    "http://purl.obolibrary.org/obo/IAO_0000115",
+    "http://www.w3.org/2004/02/skos/core#definition",
+    "http://purl.org/dc/terms/description",
+];
+
+/// Score of a query token equal to an index token.
+const EXACT_TOKEN: f64 = 1.0;
+/// Score of a query token that is a prefix of an index token.
+const PREFIX_TOKEN: f64 = 0.75;
+/// Score of a query token within edit distance 1 of an index token. Halved for every further edit.
+const FUZZY_TOKEN: f64 = 0.5;
+/// Score of a literal that equals the query (ignoring case and whitespace).
+const EXACT_LITERAL: f64 = 2.0;
+
+/// A single search result.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    /// IRI of the matching entity.
+    #[pyo3(get)]
+    pub iri: String,
+    /// Annotation property of the best matching literal.
+    #[pyo3(get)]
+    pub property: String,
+    /// The best matching literal.
+    #[pyo3(get)]
+    pub text: String,
+    /// Relevance score. Higher is better.
+    #[pyo3(get)]
+    pub score: f64,
+}
+
+#[pymethods]
+impl SearchHit {
+    fn __repr__(&self) -> String {
+        format!(
+            "SearchHit(iri={:?}, property={:?}, text={:?}, score={})",
+            self.iri, self.property, self.text, self.score
+        )
+    }
+}
+
+/// Candidates of a search with their accumulated score, keyed by the address of the component.
+type Candidates<'a> = HashMap<*const AnnotatedComponent<ArcStr>, (&'a ArcAnnotatedComponent, f64)>;
+
+/// Inverted index over the tokens of all literal annotation assertions on IRIs.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    tokens: BTreeMap<String, HashSet<ArcAnnotatedComponent>>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Searches all entities for `query`.
+    ///
+    /// `weight` returns the weight of an annotation property or `None` if the property should not
+    /// be searched. Each entity is scored by its best matching literal, the results are ordered by
+    /// descending score and at most `limit` results are returned.
+    pub fn search<F: Fn(&IRI<ArcStr>) -> Option<f64>>(
+        &self,
+        query: &str,
+        weight: F,
+        limit: usize,
+    ) -> Vec<SearchHit> {
+        let query_tokens: Vec<String> = tokenize(query).collect();
+        if query_tokens.is_empty() {
+            return vec![];
+        }
+
+        let mut candidates: Option<Candidates> = None;
+        for query_token in &query_tokens {
+            let mut matches: Candidates = HashMap::new();
+            for (token, score) in self.matching_tokens(query_token) {
+                for c in &self.tokens[token] {
+                    let entry = matches.entry(ArcAnnotatedComponent::as_ptr(c)).or_insert((c, 0.0));
+                    entry.1 = f64::max(entry.1, score);
+                }
+            }
+
+            candidates = Some(match candidates {
+                None => matches,
+                Some(mut candidates) => {
+                    candidates.retain(|k, _| matches.contains_key(k));
+                    for (k, v) in candidates.iter_mut() {
+                        v.1 += matches[k].1;
+                    }
+                    candidates
+                }
+            });
+        }
+
+        let normalized_query = normalize(query, true, true);
+        let mut best: HashMap<&IRI<ArcStr>, SearchHit> = HashMap::new();
+        for (c, score) in candidates.unwrap_or_default().into_values() {
+            let Some(entry) = label_entry(c) else {
+                continue;
+            };
+            let Some(weight) = weight(entry.property) else {
+                continue;
+            };
+
+            let literal = entry.literal.literal();
+            let quality = if normalize(literal, true, true) == normalized_query {
+                EXACT_LITERAL
+            } else {
+                score / query_tokens.len() as f64
+            };
+            let score = weight * quality;
+
+            if best.get(entry.subject).is_none_or(|hit| {
+                hit.score < score || (hit.score == score && hit.text.as_str() > literal.as_str())
+            }) {
+                best.insert(
+                    entry.subject,
+                    SearchHit {
+                        iri: entry.subject.to_string(),
+                        property: entry.property.to_string(),
+                        text: literal.clone(),
+                        score,
+                    },
+                );
+            }
+        }
+
+        let mut hits: Vec<SearchHit> = best.into_values().collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.iri.cmp(&b.iri)));
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Returns all index tokens matching `query_token` with their score.
+    fn matching_tokens<'a>(&'a self, query_token: &'a str) -> Vec<(&'a String, f64)> {
+        let mut matches: Vec<(&String, f64)> = self
+            .tokens
+            .range::<str, _>((Bound::Included(query_token), Bound::Unbounded))
+            .map(|(t, _)| t)
+            .take_while(|t| t.starts_with(query_token))
+            .map(|t| (t, if t == query_token { EXACT_TOKEN } else { PREFIX_TOKEN }))
+            .collect();
+
+        let max_distance = max_edit_distance(query_token);
+        if max_distance > 0 {
+            let query_len = query_token.chars().count();
+            let prefixes: BTreeSet<&String> = matches.iter().map(|(t, _)| *t).collect();
+
+            for token in self.tokens.keys() {
+                if prefixes.contains(token)
+                    || token.chars().count().abs_diff(query_len) > max_distance
+                {
+                    continue;
+                }
+                if let Some(d) = edit_distance(query_token, token, max_distance) {
+                    matches.push((token, FUZZY_TOKEN / (1 << (d - 1)) as f64));
+                }
+            }
+        }
+
+        matches
+    }
+
+    fn component_tokens(component: &AnnotatedComponent<ArcStr>) -> BTreeSet<String> {
+        label_entry(component)
+            .map(|e| tokenize(e.literal.literal()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl OntologyIndex<ArcStr, ArcAnnotatedComponent> for SearchIndex {
+    fn index_insert(&mut self, cmp: ArcAnnotatedComponent) -> bool {
+        let mut inserted = false;
+        for token in Self::component_tokens(&cmp) {
+            inserted |= self.tokens.entry(token).or_default().insert(cmp.clone());
+        }
+        inserted
+    }
+
+    fn index_remove(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> bool {
+        let mut removed = false;
+        for token in Self::component_tokens(cmp) {
+            if let Some(bucket) = self.tokens.get_mut(&token) {
+                removed |= bucket.remove(cmp);
+                if bucket.is_empty() {
+                    self.tokens.remove(&token);
+                }
+            }
+        }
+        removed
+    }
+}
+
+/// Splits a text into lower case alphanumeric tokens.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Number of typos tolerated in a query token of the given length.
+fn max_edit_distance(token: &str) -> usize {
+    match token.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Edit distance between `a` and `b` counting insertions, deletions, substitutions and
+/// transpositions of adjacent characters (optimal string alignment), if it is at most `max`.
+fn edit_distance(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut before_previous: Vec<usize> = vec![];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 0..a.len() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for j in 0..b.len() {
+            let substitution = previous[j] + usize::from(a[i] != b[j]);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+        if current.iter().min().is_some_and(|m| *m > max) {
+            return None;
+        }
+        before_previous = std::mem::replace(&mut previous, current);
+    }
+
+    Some(previous[b.len()]).filter(|d| *d <= max)
+}
@@ -0,0 +1,551 @@
+//! Signature extraction for py-horned-owl
+//!
+//! Collects the named entities and literals used in model objects with a horned-owl visitor, and
+//! decides which components define an entity rather than merely use it.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+use horned_owl::model::{
+    AnnotatedComponent, AnnotationProperty, AnnotationSubject, ArcAnnotatedComponent, ArcStr, Class,
+    ClassExpression, Component, DataProperty, Datatype, DeclareAnnotationProperty, DeclareClass,
+    DeclareDataProperty, DeclareDatatype, DeclareNamedIndividual, DeclareObjectProperty, Individual,
+    Literal, NamedIndividual, NamedOWLEntity, NamedOWLEntityKind, ObjectProperty,
+    ObjectPropertyExpression, SubObjectPropertyExpression, IRI,
+};
+use horned_owl::ontology::indexed::OntologyIndex;
+use horned_owl::visitor::immutable::{Visit, Walk};
+use pyo3::prelude::*;
+use pyo3::types::PySet;
+
+use crate::model;
+
+/// Values of the horned-owl model that can be traversed by a `Walk`.
+pub trait Walkable {
+    fn walk<V: Visit<ArcStr>>(&self, walk: &mut Walk<ArcStr, V>);
+}
+
+macro_rules! walkable {
+    ($($ty:ident => $method:ident),* $(,)?) => {
+        $(
+            impl Walkable for horned_owl::model::$ty<ArcStr> {
+                fn walk<V: Visit<ArcStr>>(&self, walk: &mut Walk<ArcStr, V>) {
+                    walk.$method(self);
+                }
+            }
+        )*
+    };
+}
+
+walkable!(
+    Class => class,
+    AnonymousIndividual => anonymous_individual,
+    NamedIndividual => named_individual,
+    ObjectProperty => object_property,
+    Datatype => datatype,
+    DataProperty => data_property,
+    FacetRestriction => facet_restriction,
+    ObjectPropertyExpression => object_property_expression,
+    Literal => literal,
+    DataRange => data_range,
+    ClassExpression => class_expression,
+    AnnotationProperty => annotation_property,
+    Annotation => annotation,
+    OntologyAnnotation => ontology_annotation,
+    Import => import,
+    DeclareClass => declare_class,
+    DeclareObjectProperty => declare_object_property,
+    DeclareAnnotationProperty => declare_annotation_property,
+    DeclareDataProperty => declare_data_property,
+    DeclareNamedIndividual => declare_named_individual,
+    DeclareDatatype => declare_datatype,
+    SubClassOf => sub_class_of,
+    EquivalentClasses => equivalent_classes,
+    DisjointClasses => disjoint_classes,
+    DisjointUnion => disjoint_union,
+    SubObjectPropertyOf => sub_object_property_of,
+    EquivalentObjectProperties => equivalent_object_properties,
+    DisjointObjectProperties => disjoint_object_properties,
+    InverseObjectProperties => inverse_object_properties,
+    ObjectPropertyDomain => object_property_domain,
+    ObjectPropertyRange => object_property_range,
+    FunctionalObjectProperty => functional_object_property,
+    InverseFunctionalObjectProperty => inverse_functional_object_property,
+    ReflexiveObjectProperty => reflexive_object_property,
+    IrreflexiveObjectProperty => irreflexive_object_property,
+    SymmetricObjectProperty => symmetric_object_property,
+    AsymmetricObjectProperty => asymmetric_object_property,
+    TransitiveObjectProperty => transitive_object_property,
+    SubDataPropertyOf => sub_data_property_of,
+    EquivalentDataProperties => equivalent_data_properties,
+    DisjointDataProperties => disjoint_data_properties,
+    DataPropertyDomain => data_property_domain,
+    DataPropertyRange => data_property_range,
+    FunctionalDataProperty => functional_data_property,
+    DatatypeDefinition => datatype_definition,
+    HasKey => has_key,
+    SameIndividual => same_individual,
+    DifferentIndividuals => different_individuals,
+    ClassAssertion => class_assertion,
+    ObjectPropertyAssertion => object_property_assertion,
+    NegativeObjectPropertyAssertion => negative_object_property_assertion,
+    DataPropertyAssertion => data_property_assertion,
+    NegativeDataPropertyAssertion => negative_data_property_assertion,
+    AnnotationAssertion => annotation_assertion,
+    SubAnnotationPropertyOf => sub_annotation_property_of,
+    AnnotationPropertyDomain => annotation_property_domain,
+    AnnotationPropertyRange => annotation_property_range,
+    DocIRI => dociri,
+    OntologyID => ontology_id,
+    Variable => variable,
+    Atom => atom,
+    Rule => rule,
+    Component => component,
+    AnnotatedComponent => annotated_component,
+);
+
+/// The named entities and literals used in a model object. The datatypes of typed literals are
+/// part of the signature.
+#[derive(Debug, Default)]
+pub struct Signature {
+    entities: HashSet<NamedOWLEntity<ArcStr>>,
+    literals: BTreeSet<Literal<ArcStr>>,
+}
+
+impl Visit<ArcStr> for Signature {
+    fn visit_class(&mut self, e: &Class<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_datatype(&mut self, e: &Datatype<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_object_property(&mut self, e: &ObjectProperty<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_data_property(&mut self, e: &DataProperty<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_annotation_property(&mut self, e: &AnnotationProperty<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_named_individual(&mut self, e: &NamedIndividual<ArcStr>) {
+        self.entities.insert(e.clone().into());
+    }
+
+    fn visit_literal(&mut self, e: &Literal<ArcStr>) {
+        if let Literal::Datatype { datatype_iri, .. } = e {
+            self.entities.insert(Datatype(datatype_iri.clone()).into());
+        }
+        self.literals.insert(e.clone());
+    }
+}
+
+impl Signature {
+    /// Returns the signature of a single model object.
+    pub fn of<T: Walkable>(value: &T) -> Self {
+        let mut walk = Walk::new(Signature::default());
+        value.walk(&mut walk);
+        walk.into_visit()
+    }
+
+    /// Returns the combined signature of several components.
+    pub fn of_components<'a, I: IntoIterator<Item = &'a AnnotatedComponent<ArcStr>>>(
+        components: I,
+    ) -> Self {
+        let mut walk = Walk::new(Signature::default());
+        for component in components {
+            walk.annotated_component(component);
+        }
+        walk.into_visit()
+    }
+
+    pub fn entities(&self) -> &HashSet<NamedOWLEntity<ArcStr>> {
+        &self.entities
+    }
+
+    /// Converts the entities, optionally restricted to a single kind, into a Python set of model
+    /// objects.
+    pub fn entities_into_py<'py>(
+        &self,
+        py: Python<'py>,
+        kind: Option<NamedOWLEntityKind>,
+    ) -> PyResult<Bound<'py, PySet>> {
+        let set = PySet::empty(py)?;
+        for entity in &self.entities {
+            if kind.is_none_or(|k| entity_kind(entity) == k) {
+                set.add(entity_into_py(py, entity)?)?;
+            }
+        }
+        Ok(set)
+    }
+
+    /// Converts the literals into a Python set of model objects.
+    pub fn literals_into_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PySet>> {
+        let set = PySet::empty(py)?;
+        for literal in &self.literals {
+            set.add(model::Literal::from(literal))?;
+        }
+        Ok(set)
+    }
+}
+
+/// Index of the named entities and literals used in the components of an ontology.
+///
+/// Counts the components using each entity and literal, so it must only be told about components
+/// that are actually added to or removed from the ontology.
+#[derive(Debug, Default)]
+pub struct SignatureIndex {
+    entities: HashMap<NamedOWLEntity<ArcStr>, usize>,
+    literals: HashMap<Literal<ArcStr>, usize>,
+}
+
+impl SignatureIndex {
+    /// Returns the entities of the given kind.
+    pub fn entities(
+        &self,
+        kind: NamedOWLEntityKind,
+    ) -> impl Iterator<Item = &NamedOWLEntity<ArcStr>> + '_ {
+        self.entities.keys().filter(move |e| entity_kind(e) == kind)
+    }
+
+    /// Returns the signature of all indexed components.
+    pub fn signature(&self) -> Signature {
+        Signature {
+            entities: self.entities.keys().cloned().collect(),
+            literals: self.literals.keys().cloned().collect(),
+        }
+    }
+}
+
+fn count_insert<T: Eq + Hash>(counts: &mut HashMap<T, usize>, values: impl IntoIterator<Item = T>) {
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+}
+
+fn count_remove<T: Eq + Hash>(counts: &mut HashMap<T, usize>, values: impl IntoIterator<Item = T>) {
+    for value in values {
+        if let Entry::Occupied(mut count) = counts.entry(value) {
+            *count.get_mut() -= 1;
+            if *count.get() == 0 {
+                count.remove();
+            }
+        }
+    }
+}
+
+impl OntologyIndex<ArcStr, ArcAnnotatedComponent> for SignatureIndex {
+    fn index_insert(&mut self, cmp: ArcAnnotatedComponent) -> bool {
+        let signature = Signature::of(cmp.as_ref());
+        count_insert(&mut self.entities, signature.entities);
+        count_insert(&mut self.literals, signature.literals);
+        true
+    }
+
+    fn index_remove(&mut self, cmp: &AnnotatedComponent<ArcStr>) -> bool {
+        let signature = Signature::of(cmp);
+        count_remove(&mut self.entities, signature.entities);
+        count_remove(&mut self.literals, signature.literals);
+        true
+    }
+}
+
+/// Adds `signature`, `classes`, `object_properties`, `data_properties`,
+/// `annotation_properties`, `named_individuals`, `datatypes` and `literals` methods to a
+/// pyclass. `$signature` computes the `Signature` from `$this`.
+#[macro_export]
+macro_rules! signature_methods {
+    ($name:ident, $this:ident => $signature:expr) => {
+        impl $name {
+            fn extract_signature(&self) -> $crate::signature::Signature {
+                let $this = self;
+                $signature
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            /// signature(self) -> Set[typing.Union[model.Class, model.ObjectProperty, model.DataProperty, model.AnnotationProperty, model.NamedIndividual, model.Datatype]]
+            ///
+            /// Returns all named entities used.
+            pub fn signature<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(py, None)
+            }
+
+            /// classes(self) -> Set[model.Class]
+            ///
+            /// Returns all classes used.
+            pub fn classes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::Class),
+                )
+            }
+
+            /// object_properties(self) -> Set[model.ObjectProperty]
+            ///
+            /// Returns all object properties used.
+            pub fn object_properties<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::ObjectProperty),
+                )
+            }
+
+            /// data_properties(self) -> Set[model.DataProperty]
+            ///
+            /// Returns all data properties used.
+            pub fn data_properties<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::DataProperty),
+                )
+            }
+
+            /// annotation_properties(self) -> Set[model.AnnotationProperty]
+            ///
+            /// Returns all annotation properties used.
+            pub fn annotation_properties<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::AnnotationProperty),
+                )
+            }
+
+            /// named_individuals(self) -> Set[model.NamedIndividual]
+            ///
+            /// Returns all named individuals used.
+            pub fn named_individuals<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::NamedIndividual),
+                )
+            }
+
+            /// datatypes(self) -> Set[model.Datatype]
+            ///
+            /// Returns all datatypes used, including the datatypes of typed literals.
+            pub fn datatypes<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().entities_into_py(
+                    py,
+                    Some(horned_owl::model::NamedOWLEntityKind::Datatype),
+                )
+            }
+
+            /// literals(self) -> Set[model.Literal]
+            ///
+            /// Returns all literals used.
+            pub fn literals<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PySet>> {
+                self.extract_signature().literals_into_py(py)
+            }
+        }
+    };
+}
+
+/// Returns the kind of a named entity.
+pub fn entity_kind(entity: &NamedOWLEntity<ArcStr>) -> NamedOWLEntityKind {
+    match entity {
+        NamedOWLEntity::Class(_) => NamedOWLEntityKind::Class,
+        NamedOWLEntity::Datatype(_) => NamedOWLEntityKind::Datatype,
+        NamedOWLEntity::ObjectProperty(_) => NamedOWLEntityKind::ObjectProperty,
+        NamedOWLEntity::DataProperty(_) => NamedOWLEntityKind::DataProperty,
+        NamedOWLEntity::AnnotationProperty(_) => NamedOWLEntityKind::AnnotationProperty,
+        NamedOWLEntity::NamedIndividual(_) => NamedOWLEntityKind::NamedIndividual,
+    }
+}
+
+/// Namespaces of the built-in vocabulary, whose entities need no declaration.
+const BUILTIN_NAMESPACES: [&str; 4] = [
+    "http://www.w3.org/2002/07/owl#",
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+    "http://www.w3.org/2000/01/rdf-schema#",
+    "http://www.w3.org/2001/XMLSchema#",
+];
+
+/// Returns whether `iri` is part of the built-in vocabulary of OWL 2, RDF, RDFS or XSD, such as
+/// `owl:Thing`, `rdfs:label` or `xsd:string`.
+pub fn is_builtin(iri: &IRI<ArcStr>) -> bool {
+    BUILTIN_NAMESPACES.iter().any(|ns| iri.starts_with(ns))
+}
+
+/// Returns the IRI of a named entity.
+pub fn entity_iri(entity: &NamedOWLEntity<ArcStr>) -> &IRI<ArcStr> {
+    match entity {
+        NamedOWLEntity::Class(e) => &e.0,
+        NamedOWLEntity::Datatype(e) => &e.0,
+        NamedOWLEntity::ObjectProperty(e) => &e.0,
+        NamedOWLEntity::DataProperty(e) => &e.0,
+        NamedOWLEntity::AnnotationProperty(e) => &e.0,
+        NamedOWLEntity::NamedIndividual(e) => &e.0,
+    }
+}
+
+/// Returns the IRI and kind of the entity declared by `component`, if it is a declaration.
+pub fn declared_entity(
+    component: &Component<ArcStr>,
+) -> Option<(&IRI<ArcStr>, NamedOWLEntityKind)> {
+    match component {
+        Component::DeclareClass(d) => Some((&d.0 .0, NamedOWLEntityKind::Class)),
+        Component::DeclareObjectProperty(d) => Some((&d.0 .0, NamedOWLEntityKind::ObjectProperty)),
+        Component::DeclareAnnotationProperty(d) => {
+            Some((&d.0 .0, NamedOWLEntityKind::AnnotationProperty))
+        }
+        Component::DeclareDataProperty(d) => Some((&d.0 .0, NamedOWLEntityKind::DataProperty)),
+        Component::DeclareNamedIndividual(d) => {
+            Some((&d.0 .0, NamedOWLEntityKind::NamedIndividual))
+        }
+        Component::DeclareDatatype(d) => Some((&d.0 .0, NamedOWLEntityKind::Datatype)),
+        _ => None,
+    }
+}
+
+/// Returns the declaration of an entity.
+pub fn declaration(entity: NamedOWLEntity<ArcStr>) -> Component<ArcStr> {
+    match entity {
+        NamedOWLEntity::Class(e) => DeclareClass(e).into(),
+        NamedOWLEntity::Datatype(e) => DeclareDatatype(e).into(),
+        NamedOWLEntity::ObjectProperty(e) => DeclareObjectProperty(e).into(),
+        NamedOWLEntity::DataProperty(e) => DeclareDataProperty(e).into(),
+        NamedOWLEntity::AnnotationProperty(e) => DeclareAnnotationProperty(e).into(),
+        NamedOWLEntity::NamedIndividual(e) => DeclareNamedIndividual(e).into(),
+    }
+}
+
+/// Returns the entities used in `components` that are not declared with their kind, except for
+/// built-in entities. The kind of an entity follows from the position it is used in.
+pub fn undeclared_entities(
+    components: &[&AnnotatedComponent<ArcStr>],
+) -> HashSet<NamedOWLEntity<ArcStr>> {
+    let declared: HashSet<_> = components
+        .iter()
+        .filter_map(|c| declared_entity(&c.component))
+        .collect();
+
+    Signature::of_components(components.iter().copied())
+        .entities
+        .into_iter()
+        .filter(|e| {
+            let iri = entity_iri(e);
+            !is_builtin(iri) && !declared.contains(&(iri, entity_kind(e)))
+        })
+        .collect()
+}
+
+/// Converts an entity into the corresponding Python model object.
+pub fn entity_into_py<'py>(
+    py: Python<'py>,
+    entity: &NamedOWLEntity<ArcStr>,
+) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match entity {
+        NamedOWLEntity::Class(e) => model::Class::from(e).into_pyobject(py)?.into_any(),
+        NamedOWLEntity::Datatype(e) => model::Datatype::from(e).into_pyobject(py)?.into_any(),
+        NamedOWLEntity::ObjectProperty(e) => {
+            model::ObjectProperty::from(e).into_pyobject(py)?.into_any()
+        }
+        NamedOWLEntity::DataProperty(e) => {
+            model::DataProperty::from(e).into_pyobject(py)?.into_any()
+        }
+        NamedOWLEntity::AnnotationProperty(e) => {
+            model::AnnotationProperty::from(e).into_pyobject(py)?.into_any()
+        }
+        NamedOWLEntity::NamedIndividual(e) => {
+            model::NamedIndividual::from(e).into_pyobject(py)?.into_any()
+        }
+    })
+}
+
+/// Returns whether `component` defines the entity `iri`, that is whether it declares or
+/// annotates the entity, or whether the entity is the subject of the axiom.
+///
+/// Class axioms define all named classes they relate, property axioms the named properties
+/// they characterise and assertions their source individual.
+pub fn defines(component: &Component<ArcStr>, iri: &IRI<ArcStr>) -> bool {
+    let class =
+        |ce: &ClassExpression<ArcStr>| matches!(ce, ClassExpression::Class(c) if &c.0 == iri);
+    let ope = |ope: &ObjectPropertyExpression<ArcStr>| {
+        matches!(ope, ObjectPropertyExpression::ObjectProperty(op) if &op.0 == iri)
+    };
+    let individual = |i: &Individual<ArcStr>| matches!(i, Individual::Named(n) if &n.0 == iri);
+
+    match component {
+        Component::DeclareClass(d) => &d.0 .0 == iri,
+        Component::DeclareObjectProperty(d) => &d.0 .0 == iri,
+        Component::DeclareAnnotationProperty(d) => &d.0 .0 == iri,
+        Component::DeclareDataProperty(d) => &d.0 .0 == iri,
+        Component::DeclareNamedIndividual(d) => &d.0 .0 == iri,
+        Component::DeclareDatatype(d) => &d.0 .0 == iri,
+        Component::AnnotationAssertion(a) => {
+            matches!(&a.subject, AnnotationSubject::IRI(s) if s == iri)
+        }
+        Component::SubClassOf(ax) => class(&ax.sub),
+        Component::EquivalentClasses(ax) => ax.0.iter().any(class),
+        Component::DisjointClasses(ax) => ax.0.iter().any(class),
+        Component::DisjointUnion(ax) => &ax.0 .0 == iri,
+        Component::SubObjectPropertyOf(ax) => match &ax.sub {
+            SubObjectPropertyExpression::ObjectPropertyExpression(e) => ope(e),
+            SubObjectPropertyExpression::ObjectPropertyChain(_) => false,
+        },
+        Component::EquivalentObjectProperties(ax) => ax.0.iter().any(ope),
+        Component::DisjointObjectProperties(ax) => ax.0.iter().any(ope),
+        Component::InverseObjectProperties(ax) => &ax.0 .0 == iri || &ax.1 .0 == iri,
+        Component::ObjectPropertyDomain(ax) => ope(&ax.ope),
+        Component::ObjectPropertyRange(ax) => ope(&ax.ope),
+        Component::FunctionalObjectProperty(ax) => ope(&ax.0),
+        Component::InverseFunctionalObjectProperty(ax) => ope(&ax.0),
+        Component::ReflexiveObjectProperty(ax) => ope(&ax.0),
+        Component::IrreflexiveObjectProperty(ax) => ope(&ax.0),
+        Component::SymmetricObjectProperty(ax) => ope(&ax.0),
+        Component::AsymmetricObjectProperty(ax) => ope(&ax.0),
+        Component::TransitiveObjectProperty(ax) => ope(&ax.0),
+        Component::SubDataPropertyOf(ax) => &ax.sub.0 == iri,
+        Component::EquivalentDataProperties(ax) => ax.0.iter().any(|dp| &dp.0 == iri),
+        Component::DisjointDataProperties(ax) => ax.0.iter().any(|dp| &dp.0 == iri),
+        Component::DataPropertyDomain(ax) => &ax.dp.0 == iri,
+        Component::DataPropertyRange(ax) => &ax.dp.0 == iri,
+        Component::FunctionalDataProperty(ax) => &ax.0 .0 == iri,
+        Component::DatatypeDefinition(ax) => &ax.kind.0 == iri,
+        Component::HasKey(ax) => class(&ax.ce),
+        Component::SameIndividual(ax) => ax.0.iter().any(individual),
+        Component::DifferentIndividuals(ax) => ax.0.iter().any(individual),
+        Component::ClassAssertion(ax) => individual(&ax.i),
+        Component::ObjectPropertyAssertion(ax) => individual(&ax.from),
+        Component::NegativeObjectPropertyAssertion(ax) => individual(&ax.from),
+        Component::DataPropertyAssertion(ax) => individual(&ax.from),
+        Component::NegativeDataPropertyAssertion(ax) => individual(&ax.from),
+        Component::SubAnnotationPropertyOf(ax) => &ax.sub.0 == iri,
+        Component::AnnotationPropertyDomain(ax) => &ax.ap.0 == iri,
+        Component::AnnotationPropertyRange(ax) => &ax.ap.0 == iri,
+        Component::OntologyID(_)
+        | Component::DocIRI(_)
+        | Component::Import(_)
+        | Component::OntologyAnnotation(_)
+        | Component::Rule(_) => false,
+    }
+}
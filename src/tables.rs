@@ -0,0 +1,236 @@
+//! Tabular export of an ontology
+//!
+//! Flattens the components of an ontology into normalized tables: the entities of the signature,
+//! the annotation assertions, the named subclass edges, the existential restriction edges and the
+//! components in functional syntax. Each table is a list of columns of strings or `None`, which is
+//! converted to a dictionary of lists, a `pyarrow.Table` or a `pandas.DataFrame`.
+
+use std::collections::{BTreeSet, HashMap};
+
+use horned_owl::io::ofn::writer::AsFunctional;
+use horned_owl::model::*;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::signature::{entity_iri, entity_kind, Signature};
+
+/// A table with named columns of strings or `None`.
+pub struct Table {
+    name: &'static str,
+    columns: Vec<(&'static str, Vec<Option<String>>)>,
+}
+
+impl Table {
+    fn new(name: &'static str, columns: &[&'static str]) -> Self {
+        Table {
+            name,
+            columns: columns.iter().map(|c| (*c, Vec::new())).collect(),
+        }
+    }
+
+    fn push<const N: usize>(&mut self, row: [Option<String>; N]) {
+        debug_assert_eq!(N, self.columns.len());
+        for ((_, column), value) in self.columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    /// Converts each column with `column(py, values)` and returns a dictionary from column name to
+    /// the converted column.
+    fn columns_into_py<'py, F>(
+        &self,
+        py: Python<'py>,
+        column: &mut F,
+    ) -> PyResult<Bound<'py, PyDict>>
+    where
+        F: FnMut(Python<'py>, &[Option<String>]) -> PyResult<Bound<'py, PyAny>>,
+    {
+        let columns = PyDict::new(py);
+        for (name, values) in &self.columns {
+            columns.set_item(name, column(py, values)?)?;
+        }
+        Ok(columns)
+    }
+}
+
+/// The tables of an ontology.
+pub struct Tables(Vec<Table>);
+
+impl Tables {
+    /// Returns a dictionary from table name to the table, built by `table` from a dictionary of
+    /// the columns converted by `column`.
+    pub fn into_py<'py, C, T>(
+        &self,
+        py: Python<'py>,
+        mut column: C,
+        mut table: T,
+    ) -> PyResult<Bound<'py, PyDict>>
+    where
+        C: FnMut(Python<'py>, &[Option<String>]) -> PyResult<Bound<'py, PyAny>>,
+        T: FnMut(Bound<'py, PyDict>) -> PyResult<Bound<'py, PyAny>>,
+    {
+        let tables = PyDict::new(py);
+        for t in &self.0 {
+            tables.set_item(t.name, table(t.columns_into_py(py, &mut column)?)?)?;
+        }
+        Ok(tables)
+    }
+}
+
+fn iri(iri: &IRI<ArcStr>) -> Option<String> {
+    Some(iri.to_string())
+}
+
+fn subject(subject: &AnnotationSubject<ArcStr>) -> Option<String> {
+    match subject {
+        AnnotationSubject::IRI(i) => iri(i),
+        AnnotationSubject::AnonymousIndividual(a) => Some(a.0.to_string()),
+    }
+}
+
+/// Returns the value, value type, language and datatype of an annotation value.
+fn value(value: &AnnotationValue<ArcStr>) -> [Option<String>; 4] {
+    match value {
+        AnnotationValue::Literal(l) => {
+            let (lang, datatype) = match l {
+                Literal::Simple { .. } => (None, None),
+                Literal::Language { lang, .. } => (Some(lang.clone()), None),
+                Literal::Datatype { datatype_iri, .. } => (None, iri(datatype_iri)),
+            };
+            [
+                Some(l.literal().to_string()),
+                Some("literal".to_string()),
+                lang,
+                datatype,
+            ]
+        }
+        AnnotationValue::IRI(i) => [iri(i), Some("iri".to_string()), None, None],
+        AnnotationValue::AnonymousIndividual(a) => [
+            Some(a.0.to_string()),
+            Some("anonymous".to_string()),
+            None,
+            None,
+        ],
+    }
+}
+
+/// Returns the existential restrictions `ObjectSomeValuesFrom(p, B)` with a named property and
+/// filler in `sup`, either directly or as operand of an intersection.
+pub fn existentials(sup: &ClassExpression<ArcStr>) -> Vec<(&IRI<ArcStr>, &IRI<ArcStr>)> {
+    let operands = match sup {
+        ClassExpression::ObjectIntersectionOf(operands) => operands.iter().collect(),
+        ce => vec![ce],
+    };
+
+    operands
+        .into_iter()
+        .filter_map(|ce| match ce {
+            ClassExpression::ObjectSomeValuesFrom {
+                ope: ObjectPropertyExpression::ObjectProperty(p),
+                bce,
+            } => match bce.as_ref() {
+                ClassExpression::Class(filler) => Some((&p.0, &filler.0)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Builds the tables of `components` with the entity labels in `labels`.
+///
+/// - `entities`: `iri`, `type` and `label` of each entity in the signature. An IRI used as
+///   several types of entity has one row per type.
+/// - `annotation_assertions`: `subject`, `property`, `value`, `value_type`, `lang` and `datatype`.
+///   The value type is `literal`, `iri` or `anonymous`.
+/// - `subclass_edges`: `subclass` and `superclass` of each `SubClassOf` between named classes.
+/// - `existential_edges`: `subclass`, `property` and `filler` of each `SubClassOf` of a named class
+///   and an existential restriction on a named property and class, also as operand of an
+///   intersection.
+/// - `components`: `kind` and functional syntax `text` of each component.
+pub fn tables<'a, I: IntoIterator<Item = &'a AnnotatedComponent<ArcStr>>>(
+    components: I,
+    labels: &HashMap<&IRI<ArcStr>, &Literal<ArcStr>>,
+) -> Tables {
+    let components: Vec<_> = components.into_iter().collect();
+
+    let mut annotations = Table::new(
+        "annotation_assertions",
+        &[
+            "subject",
+            "property",
+            "value",
+            "value_type",
+            "lang",
+            "datatype",
+        ],
+    );
+    let mut components_table = Table::new("components", &["kind", "text"]);
+    let mut subclass_edges = BTreeSet::new();
+    let mut existential_edges = BTreeSet::new();
+
+    for c in &components {
+        components_table.push([
+            Some(
+                format!("{:?}", c.component.kind())
+                    .trim_start_matches("ComponentKind::")
+                    .to_string(),
+            ),
+            Some(c.as_functional().to_string()),
+        ]);
+
+        match &c.component {
+            Component::AnnotationAssertion(AnnotationAssertion { subject: s, ann }) => {
+                let [v, value_type, lang, datatype] = value(&ann.av);
+                annotations.push([subject(s), iri(&ann.ap.0), v, value_type, lang, datatype]);
+            }
+            Component::SubClassOf(SubClassOf {
+                sub: ClassExpression::Class(sub),
+                sup,
+            }) => {
+                if let ClassExpression::Class(sup) = sup {
+                    subclass_edges.insert((&sub.0, &sup.0));
+                }
+                for (property, filler) in existentials(sup) {
+                    existential_edges.insert((&sub.0, property, filler));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut entities = Table::new("entities", &["iri", "type", "label"]);
+    let signature = Signature::of_components(components.iter().copied());
+    let mut rows: Vec<_> = signature
+        .entities()
+        .iter()
+        .map(|e| (entity_iri(e), format!("{:?}", entity_kind(e))))
+        .collect();
+    rows.sort();
+    for (i, kind) in rows {
+        entities.push([
+            iri(i),
+            Some(kind),
+            labels.get(i).map(|l| l.literal().to_string()),
+        ]);
+    }
+
+    let mut subclass_table = Table::new("subclass_edges", &["subclass", "superclass"]);
+    for (sub, sup) in subclass_edges {
+        subclass_table.push([iri(sub), iri(sup)]);
+    }
+
+    let mut existential_table =
+        Table::new("existential_edges", &["subclass", "property", "filler"]);
+    for (sub, property, filler) in existential_edges {
+        existential_table.push([iri(sub), iri(property), iri(filler)]);
+    }
+
+    Tables(vec![
+        entities,
+        annotations,
+        subclass_table,
+        existential_table,
+        components_table,
+    ])
+}
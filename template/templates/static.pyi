@@ -5,6 +5,15 @@ class IRI:
     parse: Any
     ...
 
+class Var(IRI):
+    """
+    A named placeholder in a pattern. A `Var` can be used wherever an IRI, a class expression, an
+    object property expression, a data range, an individual or a literal is expected.
+    """
+    name: str
+    def __init__(self, name: str):
+        ...
+
 class Facet:
     Length: Facet
     MinLength: Facet
@@ -19,6 +28,13 @@ class Facet:
     LangRange: Facet
     
 
+{% for m in models if m.name == "Component" -%}
+class ComponentKind:
+{%- for v in m.variants %}
+    {{ v }}: ComponentKind
+{%- endfor %}
+{% endfor %}
+
 IRIParam = Union[str, IRI, Tuple[str, bool]]
 """
 One of the following:
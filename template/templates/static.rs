@@ -7,8 +7,9 @@ use std::hash::{Hash, Hasher};
 use horned_owl::io::ofn::writer::AsFunctional;
 
 use horned_owl::model::ArcStr;
-use pyo3::{exceptions::PyKeyError, prelude::*, PyAny, types::PyType};
+use pyo3::{exceptions::PyKeyError, prelude::*, PyAny, types::{PyTuple, PyType}};
 
+use crate::json::{FromDict, ToDict};
 use crate::wrappers::*;
 
 /****************** IRI **********************/
@@ -39,7 +40,7 @@ impl FromCompatible<IRI> for horned_owl::model::IRI<Arc<str>> {
 
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[pyclass(module = "pyhornedowl.model")]
+#[pyclass(module = "pyhornedowl.model", subclass)]
 pub struct IRI(horned_owl::model::IRI<ArcStr>);
 
 impl From<IRI> for horned_owl::model::IRI<ArcStr> {
@@ -86,6 +87,18 @@ impl IRI {
         let builder = horned_owl::model::Build::new_arc();
         IRI(builder.iri(value))
     }
+
+    fn __getstate__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __setstate__(&mut self, state: String) {
+        self.0 = horned_owl::model::Build::new_arc().iri(state);
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (String,))> {
+        Ok((slf.get_type().getattr("parse")?, (slf.borrow().__getstate__(),)))
+    }
 }
 
 impl IRI {
@@ -94,6 +107,97 @@ impl IRI {
     }
 }
 
+/********************* VAR ***********************/
+
+/// Prefix of the IRIs standing in for a `Var` inside the model.
+pub const VAR_PREFIX: &str = "urn:pyhornedowl:var:";
+
+/// Returns the name of the variable encoded in `value`, if any.
+pub fn var_name(value: &str) -> Option<&str> {
+    value.strip_prefix(VAR_PREFIX)
+}
+
+/// Var(name: str)
+///
+/// A named placeholder in a pattern. A `Var` can be used wherever an IRI, a class expression, an
+/// object property expression, a data range, an individual or a literal is expected.
+#[pyclass(module = "pyhornedowl.model", extends = IRI)]
+#[derive(Debug, Clone)]
+pub struct Var {
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl Var {
+    #[new]
+    fn new(name: String) -> (Self, IRI) {
+        let iri = IRI::new(format!("{}{}", VAR_PREFIX, name), &horned_owl::model::Build::new_arc());
+        (Var { name }, iri)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Var({:?})", self.name)
+    }
+
+    fn __getstate__(&self) -> String {
+        self.name.clone()
+    }
+
+    fn __setstate__(mut slf: PyRefMut<'_, Self>, state: String) {
+        slf.name = state;
+        let iri = slf.iri();
+        slf.into_super().0 = iri.0;
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (String,)) {
+        (slf.get_type(), (slf.borrow().name.clone(),))
+    }
+}
+
+impl Var {
+    pub fn iri(&self) -> IRI {
+        IRI::new(format!("{}{}", VAR_PREFIX, self.name), &horned_owl::model::Build::new_arc())
+    }
+}
+
+/// Model types that represent a `Var` in positions typed by an enum such as `ClassExpression`.
+pub trait FromVar {
+    fn from_var(var: &Var) -> Self;
+}
+
+impl FromVar for Class {
+    fn from_var(var: &Var) -> Self {
+        Class(var.iri())
+    }
+}
+
+impl FromVar for ObjectProperty {
+    fn from_var(var: &Var) -> Self {
+        ObjectProperty(var.iri())
+    }
+}
+
+impl FromVar for Datatype {
+    fn from_var(var: &Var) -> Self {
+        Datatype(var.iri())
+    }
+}
+
+impl FromVar for NamedIndividual {
+    fn from_var(var: &Var) -> Self {
+        NamedIndividual(var.iri())
+    }
+}
+
+impl FromVar for SimpleLiteral {
+    fn from_var(var: &Var) -> Self {
+        SimpleLiteral {
+            literal: var.iri().__str__(),
+        }
+    }
+}
+
 /********************* FACET ***********************/
 
 impl FromCompatible<&horned_owl::vocab::Facet> for Facet {
@@ -168,6 +272,11 @@ impl Facet {
     fn __eq__(&self, other: &Self) -> bool {
         self == other
     }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyType>, String))> {
+        let getattr = slf.py().import("builtins")?.getattr("getattr")?;
+        Ok((getattr, (slf.py().get_type::<Self>(), format!("{:?}", slf.borrow()))))
+    }
 }
 
 impl From<&Facet> for horned_owl::vocab::Facet {
@@ -218,6 +327,78 @@ impl From<horned_owl::vocab::Facet> for Facet {
     }
 }
 
+{% for m in models if m.name == "Component" %}
+#[doc = doc!(ComponentKind)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[pyclass(module = "pyhornedowl.model")]
+pub enum ComponentKind {
+{%- for v in m.variants %}
+    {{ v }},
+{%- endfor %}
+}
+
+#[pymethods]
+impl ComponentKind {
+    #[cfg(pyi)]
+    #[classmethod]
+    fn __pyi__(_: &Bound<'_, PyType>) -> String {
+        "class ComponentKind:
+{%- for v in m.variants %}
+    {{ v }}: ComponentKind
+{%- endfor %}
+"
+            .to_owned()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        Hash::hash(&self, &mut s);
+        s.finish()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyType>, String))> {
+        let getattr = slf.py().import("builtins")?.getattr("getattr")?;
+        Ok((getattr, (slf.py().get_type::<Self>(), format!("{:?}", slf.borrow()))))
+    }
+}
+
+impl From<&ComponentKind> for horned_owl::model::ComponentKind {
+    fn from(value: &ComponentKind) -> Self {
+        match value {
+{%- for v in m.variants %}
+            ComponentKind::{{ v }} => horned_owl::model::ComponentKind::{{ v }},
+{%- endfor %}
+        }
+    }
+}
+
+impl From<&horned_owl::model::ComponentKind> for ComponentKind {
+    fn from(value: &horned_owl::model::ComponentKind) -> Self {
+        match value {
+{%- for v in m.variants %}
+            horned_owl::model::ComponentKind::{{ v }} => ComponentKind::{{ v }},
+{%- endfor %}
+        }
+    }
+}
+
+impl From<ComponentKind> for horned_owl::model::ComponentKind {
+    fn from(value: ComponentKind) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<horned_owl::model::ComponentKind> for ComponentKind {
+    fn from(value: horned_owl::model::ComponentKind) -> Self {
+        (&value).into()
+    }
+}
+{% endfor %}
+
 
 /************ Annotations *******************/
 
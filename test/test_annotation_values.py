@@ -0,0 +1,58 @@
+from pyhornedowl.model import *
+
+from test_base import simple_ontology
+
+XREF = "http://www.geneontology.org/formats/oboInOwl#hasDbXref"
+XSD_INT = "http://www.w3.org/2001/XMLSchema#int"
+
+
+def test_annotation_values_keep_literal_types():
+    o = simple_ontology()
+    comment = o.annotation_property("rdfs:comment")
+
+    o.add_component(AnnotationAssertion(o.iri(":A"), Annotation(comment, DatatypeLiteral("3", o.iri(XSD_INT)))))
+    o.add_component(AnnotationAssertion(o.iri(":A"), Annotation(comment, LanguageLiteral("3", "en"))))
+
+    values = [v for v, _ in o.get_annotation_values(":A", "rdfs:comment")]
+
+    datatyped = [v for v in values if isinstance(v, DatatypeLiteral)]
+    tagged = [v for v in values if isinstance(v, LanguageLiteral)]
+
+    assert 2 == len(values)
+    assert XSD_INT == str(datatyped[0].datatype_iri)
+    assert "en" == tagged[0].lang
+    assert ["3", "3"] == o.get_annotations(":A", "rdfs:comment")
+
+
+def test_annotation_values_include_iris():
+    o = simple_ontology()
+    xref = o.annotation_property(XREF)
+
+    o.add_component(AnnotationAssertion(o.iri(":A"), Annotation(xref, o.iri("https://example.org/X"))))
+    o.add_component(AnnotationAssertion(o.iri(":A"), Annotation(xref, AnonymousIndividual("_:x"))))
+
+    values = [v for v, _ in o.get_annotation_values(":A", XREF)]
+
+    assert ["https://example.org/X"] == [str(v) for v in values if isinstance(v, IRI)]
+    assert 1 == len([v for v in values if isinstance(v, AnonymousIndividual)])
+    assert [] == o.get_annotations(":A", XREF)
+
+
+def test_annotation_values_with_axiom_annotations():
+    o = simple_ontology()
+    xref = o.annotation_property(XREF)
+    source = Annotation(o.annotation_property("rdfs:comment"), SimpleLiteral("from source"))
+
+    o.add_component(AnnotationAssertion(o.iri(":B"), Annotation(xref, SimpleLiteral("DB:1"))), [source])
+
+    [(value, annotations)] = o.get_annotation_values(":B", XREF)
+
+    assert "DB:1" == value.literal
+    assert {source} == annotations
+
+
+def test_annotation_values_missing():
+    o = simple_ontology()
+
+    assert [] == o.get_annotation_values(":C", "rdfs:label")
+    assert ["ClassA"] == [v.literal for v, anns in o.get_annotation_values(":A", "rdfs:label") if anns == set()]
@@ -0,0 +1,76 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import simple_ontology, simple_ontology_comps
+
+
+def test_components_by_kind_enum():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.get_components_by_kind(ComponentKind.SubClassOf)}
+    expected = {c for c in simple_ontology_comps() if isinstance(c, SubClassOf)}
+
+    assert expected == actual
+
+
+def test_components_by_kind_class():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.get_components_by_kind(DeclareClass)}
+    expected = {c for c in simple_ontology_comps() if isinstance(c, DeclareClass)}
+
+    assert expected == actual
+
+
+def test_components_by_multiple_kinds():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.get_components_by_kind([ComponentKind.SubClassOf, AnnotationAssertion])}
+    expected = {c for c in simple_ontology_comps() if isinstance(c, (SubClassOf, AnnotationAssertion))}
+
+    assert expected == actual
+
+
+@pytest.mark.parametrize("strategy", [pyhornedowl.IndexCreationStrategy.OnQuery,
+                                      pyhornedowl.IndexCreationStrategy.Explicit])
+def test_components_by_kind_index_strategy(strategy):
+    o = pyhornedowl.PyIndexedOntology(index_strategy=strategy)
+    for c in simple_ontology_comps():
+        o.add_component(c)
+
+    assert 3 == len(o.get_components_by_kind(SubClassOf))
+
+
+def test_components_by_kind_invalid():
+    o = simple_ontology()
+
+    with pytest.raises(TypeError):
+        o.get_components_by_kind(Class)
+
+    with pytest.raises(TypeError):
+        o.get_components_by_kind([SubClassOf, 1])
+
+
+def test_count_by_kind():
+    o = simple_ontology()
+
+    expected = {
+        ComponentKind.DeclareClass: 4,
+        ComponentKind.SubClassOf: 3,
+        ComponentKind.AnnotationAssertion: 2,
+    }
+
+    assert expected == o.count_by_kind()
+
+
+def test_count_by_kind_empty():
+    o = pyhornedowl.PyIndexedOntology()
+
+    assert {} == o.count_by_kind()
+
+
+def test_iter_components_by_kind_enum():
+    o = simple_ontology()
+
+    assert 3 == len(list(o.iter_components(ComponentKind.SubClassOf)))
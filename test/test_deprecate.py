@@ -0,0 +1,110 @@
+from pyhornedowl.model import *
+
+from test_base import simple_ontology, OWL_THING
+
+OWL_DEPRECATED = "http://www.w3.org/2002/07/owl#deprecated"
+IAO_TERM_REPLACED_BY = "http://purl.obolibrary.org/obo/IAO_0100001"
+OBO_IN_OWL_CONSIDER = "http://www.geneontology.org/formats/oboInOwl#consider"
+IAO_OBSOLESCENCE_REASON = "http://purl.obolibrary.org/obo/IAO_0000231"
+
+
+def annotation_values(o, subject, prop):
+    return [str(c.component.ann.av)
+            for c in o.get_components_for_iri(subject)
+            if isinstance(c.component, AnnotationAssertion)
+            and str(c.component.subject) == subject
+            and str(c.component.ann.ap.first) == prop]
+
+
+def test_deprecate_adds_deprecated_flag():
+    o = simple_ontology()
+
+    o.deprecate_entity(":A")
+
+    assert ["true"] == o.get_annotations(":A", "owl:deprecated")
+
+
+def test_deprecate_prefixes_label():
+    o = simple_ontology()
+
+    o.deprecate_entity(":A")
+
+    assert ["obsolete ClassA"] == o.get_annotations(":A", "rdfs:label")
+
+
+def test_deprecate_twice_keeps_label():
+    o = simple_ontology()
+
+    o.deprecate_entity(":A")
+    o.deprecate_entity(":A")
+
+    assert ["obsolete ClassA"] == o.get_annotations(":A", "rdfs:label")
+
+
+def test_deprecate_prefix_needs_space():
+    o = simple_ontology()
+    o.set_label(":A", "obsoleted process")
+
+    o.deprecate_entity(":A")
+
+    assert ["obsolete obsoleted process"] == o.get_annotations(":A", "rdfs:label")
+
+
+def test_deprecate_prefix_ignores_case():
+    o = simple_ontology()
+    o.set_label(":A", "Obsolete ClassA")
+
+    o.deprecate_entity(":A")
+
+    assert ["Obsolete ClassA"] == o.get_annotations(":A", "rdfs:label")
+
+
+def test_deprecate_replaced_by_and_consider():
+    o = simple_ontology()
+
+    o.deprecate_entity(":A", replaced_by=":C", consider=[":D", ":B"])
+
+    assert ["https://example.com/C"] == annotation_values(o, "https://example.com/A", IAO_TERM_REPLACED_BY)
+    assert {"https://example.com/B", "https://example.com/D"} == set(
+        annotation_values(o, "https://example.com/A", OBO_IN_OWL_CONSIDER))
+
+
+def test_deprecate_reason():
+    o = simple_ontology()
+
+    o.deprecate_entity(":A", reason="http://purl.obolibrary.org/obo/IAO_0000227")
+
+    assert ["http://purl.obolibrary.org/obo/IAO_0000227"] == annotation_values(
+        o, "https://example.com/A", IAO_OBSOLESCENCE_REASON)
+
+
+def test_deprecate_removes_defining_axioms():
+    o = simple_ontology()
+
+    removed = o.deprecate_entity(":B")
+
+    assert [SubClassOf(o.clazz(":B"), o.clazz(":A"))] == [c.component for c in removed]
+
+    remaining = [c.component for c in o.get_components()]
+    assert SubClassOf(o.clazz(":B"), o.clazz(":A")) not in remaining
+    assert SubClassOf(o.clazz(":D"), o.clazz(":B")) in remaining
+    assert DeclareClass(o.clazz(":B")) in remaining
+
+
+def test_deprecate_removes_references():
+    o = simple_ontology()
+
+    removed = o.deprecate_entity(":B", remove_references=True)
+
+    expected = {
+        SubClassOf(o.clazz(":B"), o.clazz(":A")),
+        SubClassOf(o.clazz(":D"), o.clazz(":B")),
+    }
+    assert expected == {c.component for c in removed}
+
+    remaining = [c.component for c in o.get_components()]
+    for c in expected:
+        assert c not in remaining
+
+    assert SubClassOf(o.clazz(":D"), o.clazz(OWL_THING)) in remaining
+    assert DeclareClass(o.clazz(":B")) in remaining
@@ -60,3 +60,40 @@ def test_named_individuals(o) -> None:
 def test_datatypes(o) -> None:
     """Test getting datatypes."""
     assert {"https://example.com/H"} == o.get_datatypes()
+
+
+def test_get_entities_include_undeclared(o) -> None:
+    """Test getting entities that are used without declaration."""
+    ex = "https://example.com/"
+    o.add_component(SubClassOf(Class(IRI.parse(ex + "A")),
+                               ObjectSomeValuesFrom(ObjectProperty(IRI.parse(ex + "p")), Class(IRI.parse(ex + "X")))))
+    o.add_component(ClassAssertion(Class(IRI.parse(ex + "A")), NamedIndividual(IRI.parse(ex + "i"))))
+
+    assert {ex + c for c in "ABCD"} == o.get_classes()
+    assert {ex + c for c in "ABCDX"} == o.get_classes(include_undeclared=True)
+    assert {ex + "E", ex + "p"} == o.get_object_properties(include_undeclared=True)
+    assert {ex + "G", ex + "i"} == o.get_named_individuals(include_undeclared=True)
+    assert {ex + "F"} == o.get_data_properties(include_undeclared=True)
+    assert {Class(IRI.parse(ex + c)) for c in "ABCDX"} == o.classes()
+
+
+@pytest.mark.parametrize("strategy", [pyhornedowl.IndexCreationStrategy.OnQuery,
+                                      pyhornedowl.IndexCreationStrategy.OnLoad,
+                                      pyhornedowl.IndexCreationStrategy.Explicit])
+def test_get_entities_include_undeclared_follows_modifications(strategy) -> None:
+    """Test that undeclared entities are updated when components are added or removed."""
+    ex = "https://example.com/"
+    o = pyhornedowl.PyIndexedOntology(strategy)
+    first = SubClassOf(Class(IRI.parse(ex + "A")), Class(IRI.parse(ex + "X")))
+    second = EquivalentClasses([Class(IRI.parse(ex + "X")), Class(IRI.parse(ex + "Y"))])
+
+    o.add_component(first)
+    o.add_component(second)
+    o.add_component(second)
+    assert {ex + c for c in "AXY"} == o.get_classes(include_undeclared=True)
+
+    o.remove_component(second)
+    assert {ex + c for c in "AX"} == o.get_classes(include_undeclared=True)
+
+    o.remove_component(first)
+    assert set() == o.get_classes(include_undeclared=True)
@@ -0,0 +1,114 @@
+import pytest
+from test_base import simple_ontology_comps
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+
+
+def ex(name: str) -> str:
+    return f"https://example.com/{name}"
+
+
+def label(iri: str, literal: Literal) -> AnnotationAssertion:
+    return AnnotationAssertion(IRI.parse(iri), Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)), literal))
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an ontology."""
+    p = ObjectProperty(IRI.parse(ex("p")))
+    a, b, c, e, f = (Class(IRI.parse(ex(n))) for n in "ABCEF")
+
+    components = simple_ontology_comps() + [
+        DeclareObjectProperty(p),
+        SubClassOf(a, ObjectIntersectionOf([b, ObjectSomeValuesFrom(p, c)])),
+        EquivalentClasses([e, f, ObjectSomeValuesFrom(p, a)]),
+        label(ex("C"), LanguageLiteral("Klasse C", "de")),
+        label(ex("p"), SimpleLiteral("part of")),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+def test_to_graph_nodes(o):
+    nodes, _ = o.to_graph()
+
+    assert (ex("A"), {"label": "ClassA"}) in nodes
+    assert (ex("C"), {"label": "Klasse C"}) in nodes
+    assert (ex("E"), {"label": ex("E")}) in nodes
+    assert ex("p") not in [iri for iri, _ in nodes]
+
+
+def test_to_graph_label_matches_get_label(o):
+    for literal in [SimpleLiteral("Zeta"), SimpleLiteral("Beta"), LanguageLiteral("Alpha", "en")]:
+        o.add_component(label(ex("B"), literal))
+
+    nodes, _ = o.to_graph()
+
+    assert "Beta" == o.get_label(ex("B")).literal
+    assert (ex("B"), {"label": "Beta"}) in nodes
+
+
+def test_to_graph_edges(o):
+    _, edges = o.to_graph()
+
+    assert (ex("B"), ex("A"), {"type": "subclass"}) in edges
+    assert (ex("A"), ex("B"), {"type": "subclass"}) in edges
+    assert (ex("A"), ex("C"), {"type": "some_values_from", "property": ex("p"), "label": "part of"}) in edges
+    assert (ex("E"), ex("A"), {"type": "some_values_from", "property": ex("p"), "label": "part of"}) in edges
+    assert (ex("E"), ex("F"), {"type": "equivalent"}) in edges
+
+
+def test_to_graph_edge_types(o):
+    _, edges = o.to_graph(edges=["equivalent"])
+
+    assert [(ex("E"), ex("F"), {"type": "equivalent"})] == edges
+
+
+def test_to_graph_without_labels(o):
+    nodes, edges = o.to_graph(use_labels=False)
+
+    assert (ex("A"), {"label": ex("A")}) in nodes
+    assert (ex("A"), ex("C"), {"type": "some_values_from", "property": ex("p"), "label": ex("p")}) in edges
+
+
+def test_to_graph_invalid_edge_type(o):
+    with pytest.raises(ValueError, match="Unknown edge type 'disjoint'"):
+        o.to_graph(edges=["disjoint"])
+
+
+def test_to_dot(o):
+    dot = o.to_dot()
+
+    assert dot.startswith("digraph {\n")
+    assert f'    "{ex("A")}" [label="ClassA"];\n' in dot
+    assert f'    "{ex("B")}" -> "{ex("A")}" [arrowhead=empty];\n' in dot
+    assert f'    "{ex("A")}" -> "{ex("C")}" [label="part of"];\n' in dot
+    assert f'    "{ex("E")}" -> "{ex("F")}" [dir=none, style=dashed];\n' in dot
+
+
+def test_to_dot_escapes_quotes(o):
+    o.add_component(label(ex("D"), SimpleLiteral('the "D" class')))
+
+    assert f'"{ex("D")}" [label="the \\"D\\" class"];' in o.to_dot()
+
+
+def test_to_networkx(o):
+    networkx = pytest.importorskip("networkx")
+
+    nodes, edges = o.to_graph()
+    graph = networkx.DiGraph()
+    graph.add_nodes_from(nodes)
+    graph.add_edges_from(edges)
+
+    assert "ClassA" == graph.nodes[ex("A")]["label"]
+    assert "part of" == graph.edges[ex("A"), ex("C")]["label"]
@@ -0,0 +1,99 @@
+import pytest
+from pyhornedowl.model import *
+
+from test_base import simple_ontology, simple_ontology_comps
+
+
+def test_iter_components():
+    o = simple_ontology()
+
+    it = o.iter_components()
+
+    assert iter(it) is it
+    assert set(o.get_components()) == set(it)
+
+
+def test_iter_components_is_lazy():
+    o = simple_ontology()
+
+    it = o.iter_components()
+    first = next(it)
+
+    assert isinstance(first, AnnotatedComponent)
+    assert len(simple_ontology_comps()) - 1 == len(list(it))
+
+
+def test_iter_components_by_kind():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.iter_components(SubClassOf)}
+    expected = {c for c in simple_ontology_comps() if isinstance(c, SubClassOf)}
+
+    assert expected == actual
+    assert [] == list(o.iter_components(DisjointClasses))
+
+
+def test_iter_components_invalid_kind():
+    o = simple_ontology()
+
+    with pytest.raises(TypeError):
+        o.iter_components(Class)
+
+    with pytest.raises(TypeError):
+        o.iter_components("SubClassOf")
+
+
+def test_iter_axioms():
+    o = simple_ontology()
+
+    assert set(o.get_axioms()) == set(o.iter_axioms())
+    assert 4 == len(list(o.iter_axioms(DeclareClass)))
+
+
+def test_iter_components_for_iri():
+    o = simple_ontology()
+
+    assert set(o.get_components_for_iri(":A")) == set(o.iter_components_for_iri(":A"))
+
+
+def test_iter_entities():
+    o = simple_ontology()
+    o.declare_object_property(":r")
+
+    assert o.get_classes() == set(o.iter_classes())
+    assert {"https://example.com/r"} == set(o.iter_object_properties())
+    assert [] == list(o.iter_data_properties())
+    assert [] == list(o.iter_annotation_properties())
+    assert [] == list(o.iter_named_individuals())
+    assert [] == list(o.iter_datatypes())
+
+
+def test_concurrent_modification():
+    o = simple_ontology()
+
+    it = o.iter_classes()
+    next(it)
+    o.declare_class(":E")
+
+    with pytest.raises(RuntimeError):
+        next(it)
+
+
+def test_concurrent_removal():
+    o = simple_ontology()
+
+    it = o.iter_components(SubClassOf)
+    c = next(it)
+    o.remove_component(c.component)
+
+    with pytest.raises(RuntimeError):
+        next(it)
+
+
+def test_no_modification_after_failed_change():
+    o = simple_ontology()
+
+    it = o.iter_classes()
+    o.declare_class(":A")
+
+    assert 4 == len(list(it))
@@ -0,0 +1,73 @@
+import json
+
+import pytest
+
+from pyhornedowl.model import *
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+@pytest.mark.parametrize("component", [
+    DeclareClass(Class(e("A"))),
+    SubClassOf(Class(e("A")), ObjectSomeValuesFrom(ObjectProperty(e("p")), Class(e("B")))),
+    SubClassOf(Class(e("A")), ObjectMinCardinality(2, InverseObjectProperty(ObjectProperty(e("p"))), Class(e("B")))),
+    DisjointUnion(Class(e("A")), [Class(e("B")), ObjectComplementOf(Class(e("C")))]),
+    DataPropertyRange(DataProperty(e("age")), DatatypeRestriction(
+        Datatype(IRI.parse("http://www.w3.org/2001/XMLSchema#integer")),
+        [FacetRestriction(Facet.MinInclusive, DatatypeLiteral("18", IRI.parse("http://www.w3.org/2001/XMLSchema#integer")))])),
+    AnnotationAssertion(e("A"), Annotation(AnnotationProperty(e("label")), LanguageLiteral("a", "en"))),
+    ObjectPropertyAssertion(ObjectProperty(e("p")), NamedIndividual(e("i")), AnonymousIndividual("b0")),
+    Rule([ClassAtom(Class(e("A")), Variable(e("x")))],
+         [DataPropertyAtom(DataProperty(e("age")), (Variable(e("x")), SimpleLiteral("1")))]),
+    AnnotatedComponent(DeclareClass(Class(e("A"))), {Annotation(AnnotationProperty(e("c")), SimpleLiteral("c"))}),
+])
+def test_round_trip(component):
+    cls = type(component)
+
+    assert component == cls.from_dict(component.to_dict())
+    assert component == cls.from_json(component.to_json())
+    assert component.to_dict() == json.loads(component.to_json())
+
+
+def test_to_dict():
+    axiom = SubClassOf(Class(e("A")), ObjectSomeValuesFrom(ObjectProperty(e("p")), Class(e("B"))))
+
+    assert {
+        "type": "SubClassOf",
+        "sub": {"type": "Class", "first": "https://example.com/A"},
+        "sup": {
+            "type": "ObjectSomeValuesFrom",
+            "ope": {"type": "ObjectProperty", "first": "https://example.com/p"},
+            "bce": {"type": "Class", "first": "https://example.com/B"},
+        },
+    } == axiom.to_dict()
+
+
+def test_to_dict_facet_and_optional():
+    restriction = FacetRestriction(Facet.Pattern, SimpleLiteral("a.*"))
+
+    assert {"type": "FacetRestriction", "f": "Pattern",
+            "l": {"type": "SimpleLiteral", "literal": "a.*"}} == restriction.to_dict()
+    assert OntologyID(None, None).to_dict() == {"type": "OntologyID", "iri": None, "viri": None}
+
+
+def test_from_dict_wrong_type():
+    with pytest.raises(ValueError, match="Expected a 'SubClassOf' node"):
+        SubClassOf.from_dict({"type": "DeclareClass", "first": {"type": "Class", "first": "https://example.com/A"}})
+
+    with pytest.raises(ValueError):
+        SubClassOf.from_dict({"type": "SubClassOf",
+                              "sub": {"type": "DataProperty", "first": "https://example.com/A"},
+                              "sup": {"type": "Class", "first": "https://example.com/B"}})
+
+
+def test_from_dict_missing_field():
+    with pytest.raises(ValueError, match="Missing field 'sup' in 'SubClassOf' node"):
+        SubClassOf.from_dict({"type": "SubClassOf", "sub": {"type": "Class", "first": "https://example.com/A"}})
+
+
+def test_from_json_invalid():
+    with pytest.raises(ValueError):
+        SubClassOf.from_json("{not json")
@@ -1,4 +1,5 @@
 import pytest
+from pyhornedowl.model import LanguageLiteral, SimpleLiteral
 from test_base import simple_ontology
 
 
@@ -43,3 +44,64 @@ def test_add_label():
     actual = o.get_annotation(":C", "rdfs:label")
 
     assert expected == actual
+
+
+def test_label_for_iri_language_tagged():
+    o = simple_ontology()
+
+    o.set_label(":C", "Klasse C", lang="de")
+    o.set_label(":C", "Class C", lang="en-GB")
+
+    assert "https://example.com/C" == o.get_iri_for_label("Klasse C")
+    assert "https://example.com/C" == o.get_iri_for_label("Klasse C", lang="de")
+    assert "https://example.com/C" == o.get_iri_for_label("Class C", lang="en")
+    assert o.get_iri_for_label("Klasse C", lang="en") is None
+    assert o.get_iri_for_label("Klasse C", lang="") is None
+    assert "https://example.com/A" == o.get_iri_for_label("ClassA", lang="")
+
+
+def test_set_label_with_language_keeps_other_languages():
+    o = simple_ontology()
+
+    o.set_label(":A", "Klasse A", lang="de")
+    o.set_label(":A", "Classe A", lang="fr")
+    o.set_label(":A", "Neue Klasse A", lang="de")
+
+    labels = {(l.literal, getattr(l, "lang", None)) for l in o.get_labels(":A")}
+
+    assert {("ClassA", None), ("Neue Klasse A", "de"), ("Classe A", "fr")} == labels
+
+
+def test_get_label_returns_literal():
+    o = simple_ontology()
+
+    o.set_label(":A", "Klasse A", lang="de")
+
+    label = o.get_label(":A")
+    assert isinstance(label, SimpleLiteral)
+    assert "ClassA" == label.literal
+
+    label = o.get_label(":A", "de")
+    assert isinstance(label, LanguageLiteral)
+    assert "Klasse A" == label.literal
+    assert "de" == label.lang
+
+
+def test_get_label_fallback():
+    o = simple_ontology()
+
+    o.set_label(":C", "Classe C", lang="fr")
+    o.set_label(":C", "Class C", lang="en-US")
+
+    assert "Class C" == o.get_label(":C", ["de", "en", "fr"]).literal
+    assert "Classe C" == o.get_label(":C", ["de", "fr", "en"]).literal
+    assert o.get_label(":C", ["de"]) is None
+    assert o.get_label(":C", ["de", "*"]) is not None
+    assert o.get_label(":C", "") is None
+    assert o.get_label(":D") is None
+
+
+def test_get_labels_empty():
+    o = simple_ontology()
+
+    assert [] == o.get_labels(":C")
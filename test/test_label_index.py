@@ -0,0 +1,123 @@
+import pytest
+import pyhornedowl
+from pyhornedowl import IndexCreationStrategy
+from pyhornedowl.model import *
+
+from test_base import simple_ontology_comps, r
+
+EXACT_SYNONYM = "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym"
+ALT_LABEL = "http://www.w3.org/2004/02/skos/core#altLabel"
+
+STRATEGIES = [IndexCreationStrategy.OnQuery, IndexCreationStrategy.OnLoad, IndexCreationStrategy.Explicit]
+
+
+def ontology(strategy):
+    o = pyhornedowl.PyIndexedOntology(strategy)
+    o.prefix_mapping.add_default_prefix_names()
+    o.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for c in simple_ontology_comps():
+        o.add_component(c)
+
+    return o
+
+
+def add_annotation(o, subject, prop, value):
+    o.add_component(AnnotationAssertion(o.iri(subject), Annotation(o.annotation_property(prop), value)))
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_all_iris_for_label(strategy):
+    o = ontology(strategy)
+    o.set_label(":C", "ClassA")
+
+    assert ["https://example.com/A", "https://example.com/C"] == o.get_iris_for_label("ClassA")
+    assert [] == o.get_iris_for_label("ClassZ")
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_iris_for_label_ignore_case(strategy):
+    o = ontology(strategy)
+
+    assert [] == o.get_iris_for_label("classa")
+    assert ["https://example.com/A"] == o.get_iris_for_label("classa", ignore_case=True)
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_iris_for_label_normalize_whitespace(strategy):
+    o = ontology(strategy)
+    o.set_label(":C", "Class  C ")
+
+    assert [] == o.get_iris_for_label("Class C")
+    assert ["https://example.com/C"] == o.get_iris_for_label("Class C", normalize_whitespace=True)
+    assert ["https://example.com/C"] == o.get_iris_for_label(" class\tc", ignore_case=True, normalize_whitespace=True)
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_iris_for_label_synonyms(strategy):
+    o = ontology(strategy)
+    add_annotation(o, ":C", EXACT_SYNONYM, SimpleLiteral("Foo"))
+    add_annotation(o, ":D", ALT_LABEL, LanguageLiteral("Foo", "en"))
+
+    assert [] == o.get_iris_for_label("Foo")
+    assert ["https://example.com/C", "https://example.com/D"] == o.get_iris_for_label("Foo", synonyms=True)
+    assert "https://example.com/C" != o.get_iri_for_label("Foo")
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_label_index_follows_modifications(strategy):
+    o = ontology(strategy)
+
+    assert ["https://example.com/A"] == o.get_iris_for_label("ClassA")
+
+    o.set_label(":A", "Renamed")
+    assert [] == o.get_iris_for_label("ClassA")
+    assert ["https://example.com/A"] == o.get_iris_for_label("Renamed")
+    assert "https://example.com/A" == o.get_iri_for_label("Renamed")
+
+    o.remove_component(AnnotationAssertion(o.iri(":A"), Annotation(o.annotation_property("rdfs:label"),
+                                                                    SimpleLiteral("Renamed"))))
+    assert [] == o.get_iris_for_label("Renamed")
+    assert o.get_iri_for_label("Renamed") is None
+
+
+def test_set_synonym_properties():
+    o = ontology(IndexCreationStrategy.OnQuery)
+    add_annotation(o, ":C", EXACT_SYNONYM, SimpleLiteral("Foo"))
+    add_annotation(o, ":D", "rdfs:comment", SimpleLiteral("Foo"))
+
+    assert EXACT_SYNONYM in [str(p) for p in o.get_synonym_properties()]
+    assert ["https://example.com/C"] == o.get_iris_for_label("Foo", synonyms=True)
+
+    o.set_synonym_properties(["rdfs:comment"])
+
+    assert ["http://www.w3.org/2000/01/rdf-schema#comment"] == [str(p) for p in o.get_synonym_properties()]
+    assert ["https://example.com/D"] == o.get_iris_for_label("Foo", synonyms=True)
+
+
+def test_build_label_index_explicit():
+    o = ontology(IndexCreationStrategy.Explicit)
+    o.build_label_index()
+    o.set_label(":C", "ClassC")
+
+    assert ["https://example.com/C"] == o.get_iris_for_label("ClassC")
+
+
+def assert_label_indexes(o):
+    assert "https://example.com/A" == o.get_iri_for_label("ClassA")
+    assert ["https://example.com/A"] == o.get_iris_for_label("ClassA")
+    assert "https://example.com/A" == o.search("ClassA")[0].iri
+
+
+def test_on_load_from_rdf():
+    o = pyhornedowl.open_ontology_from_file(r("simple.owl"), index_strategy=IndexCreationStrategy.OnLoad)
+
+    assert_label_indexes(o)
+
+
+def test_on_load_from_triples():
+    triples = pyhornedowl.open_ontology_from_file(r("simple.owl")).to_triples()
+
+    o = pyhornedowl.PyIndexedOntology.from_triples(triples, index_strategy=IndexCreationStrategy.OnLoad)
+
+    assert_label_indexes(o)
@@ -0,0 +1,142 @@
+import re
+
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+RDFS_COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+OWL_DEPRECATED = "http://www.w3.org/2002/07/owl#deprecated"
+OWL_THING = "http://www.w3.org/2002/07/owl#Thing"
+XSD_BOOLEAN = "http://www.w3.org/2001/XMLSchema#boolean"
+IAO_DEFINITION = "http://purl.obolibrary.org/obo/IAO_0000115"
+
+A = Class(IRI.parse("https://example.com/A"))
+B = Class(IRI.parse("https://example.com/B"))
+I = NamedIndividual(IRI.parse("https://example.com/i"))
+
+
+def annotation(subject: IRI, prop: str, value: Literal) -> AnnotationAssertion:
+    return AnnotationAssertion(subject, Annotation(AnnotationProperty(IRI.parse(prop)), value))
+
+
+def deprecated(subject: IRI) -> AnnotationAssertion:
+    return annotation(subject, OWL_DEPRECATED, DatatypeLiteral("true", IRI.parse(XSD_BOOLEAN)))
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an empty ontology."""
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    return onto
+
+
+def add(o, *components: Component) -> None:
+    for component in components:
+        o.add_component(component)
+
+
+def findings(o, rules=None) -> list[tuple[str, str, str]]:
+    return [(f.rule, f.severity, f.subject) for f in o.lint(rules)]
+
+
+def test_lint_clean_ontology(o):
+    add(o,
+        DeclareClass(A),
+        DeclareAnnotationProperty(AnnotationProperty(IRI.parse(IAO_DEFINITION))),
+        annotation(IRI.parse(IAO_DEFINITION), RDFS_LABEL, SimpleLiteral("definition")),
+        annotation(A.first, RDFS_LABEL, SimpleLiteral("a")),
+        annotation(A.first, IAO_DEFINITION, SimpleLiteral("An A.")))
+
+    assert [] == o.lint()
+
+
+def test_lint_missing_label_and_definition(o):
+    add(o, DeclareClass(A), DeclareNamedIndividual(I))
+
+    assert [("missing_label", "ERROR", "https://example.com/A"),
+            ("missing_label", "ERROR", "https://example.com/i"),
+            ("missing_definition", "WARN", "https://example.com/A")] == findings(o)
+    assert DeclareClass(A) == o.lint()[0].component.component
+
+
+def test_lint_multiple_labels(o):
+    add(o,
+        DeclareClass(A),
+        annotation(A.first, RDFS_LABEL, SimpleLiteral("a")),
+        annotation(A.first, RDFS_LABEL, SimpleLiteral("an a")),
+        annotation(A.first, RDFS_LABEL, LanguageLiteral("ein a", "de")))
+
+    assert [("multiple_labels", "ERROR", "https://example.com/A")] * 2 == findings(o, ["multiple_labels"])
+
+
+def test_lint_duplicate_label(o):
+    add(o,
+        DeclareClass(A),
+        DeclareClass(B),
+        annotation(A.first, RDFS_LABEL, SimpleLiteral("same")),
+        annotation(B.first, RDFS_LABEL, SimpleLiteral("same")))
+
+    assert [("duplicate_label", "ERROR", "https://example.com/A"),
+            ("duplicate_label", "ERROR", "https://example.com/B")] == findings(o, ["duplicate_label"])
+
+
+def test_lint_undeclared_entity(o):
+    add(o,
+        DeclareClass(A),
+        SubClassOf(A, B),
+        SubClassOf(A, Class(IRI.parse(OWL_THING))))
+
+    actual = o.lint(["undeclared_entity"])
+
+    assert [("undeclared_entity", "WARN", "https://example.com/B")] == findings(o, ["undeclared_entity"])
+    assert SubClassOf(A, B) == actual[0].component.component
+
+
+def test_lint_punned_entity(o):
+    add(o, DeclareClass(A), DeclareNamedIndividual(NamedIndividual(A.first)))
+
+    assert [("punned_entity", "WARN", "https://example.com/A")] * 2 == findings(o, ["punned_entity"])
+
+
+def test_lint_deprecated_entity_reference(o):
+    add(o,
+        DeclareClass(A),
+        DeclareClass(B),
+        deprecated(B.first),
+        annotation(B.first, RDFS_COMMENT, SimpleLiteral("replaced")),
+        SubClassOf(A, B))
+
+    assert [("deprecated_entity_reference", "WARN", "https://example.com/B")] == findings(
+        o, ["deprecated_entity_reference"])
+
+
+def test_lint_deprecated_entity_needs_no_definition(o):
+    add(o, DeclareClass(A), deprecated(A.first))
+
+    assert [] == o.lint(["missing_definition"])
+
+
+def test_lint_self_referential_subclass(o):
+    add(o, DeclareClass(A), SubClassOf(A, A))
+
+    assert [("self_referential_subclass", "WARN", "https://example.com/A")] == findings(
+        o, ["self_referential_subclass"])
+
+
+def test_lint_configured_severity(o):
+    add(o, DeclareClass(A))
+
+    assert [("missing_label", "INFO", "https://example.com/A")] == findings(o, {"missing_label": "info"})
+
+
+@pytest.mark.parametrize("rules, message", [
+    (["no_such_rule"], "Unknown lint rule 'no_such_rule'"),
+    ({"missing_label": "FATAL"}, "Unknown severity 'FATAL'"),
+])
+def test_lint_invalid_configuration(rules, message, o):
+    with pytest.raises(ValueError, match=re.escape(message)):
+        o.lint(rules)
@@ -0,0 +1,121 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+RDFS_COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+OWL_THING = "http://www.w3.org/2002/07/owl#Thing"
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+XSD_STRING = "http://www.w3.org/2001/XMLSchema#string"
+
+A, B, C, D, E = (Class(IRI.parse(f"https://example.com/{n}")) for n in "ABCDE")
+P, Q, R = (ObjectProperty(IRI.parse(f"https://example.com/{n}")) for n in "pqr")
+
+
+def ontology(*components: Component) -> pyhornedowl.PyIndexedOntology:
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an ontology."""
+    return ontology(
+        DeclareClass(A),
+        DeclareClass(B),
+        DeclareObjectProperty(P),
+        SubClassOf(A, B),
+        SubClassOf(B, ObjectSomeValuesFrom(P, C)),
+        AnnotationAssertion(A.first, Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)), SimpleLiteral("A"))),
+    )
+
+
+@pytest.fixture
+def hierarchy():
+    """Set up the test case with a class hierarchy of depth 3 and two roots."""
+    return ontology(
+        SubClassOf(A, B),
+        SubClassOf(B, C),
+        SubClassOf(D, C),
+        SubClassOf(C, Class(IRI.parse(OWL_THING))),
+        SubClassOf(E, ObjectSomeValuesFrom(P, C)),
+    )
+
+
+def test_metrics_counts(o):
+    metrics = o.metrics()
+
+    assert 2 == metrics.component_counts[ComponentKind.SubClassOf]
+    assert 2 == metrics.component_counts[ComponentKind.DeclareClass]
+    assert ComponentKind.Rule not in metrics.component_counts
+    assert {"Class": 3, "ObjectProperty": 1, "AnnotationProperty": 1} == metrics.entity_counts
+    assert 6 == metrics.axiom_count
+    assert 2 == metrics.logical_axiom_count
+    assert 1 == metrics.annotation_axiom_count
+    assert {"https://example.com/": 4, "http://www.w3.org/2000/01/rdf-schema#": 1} == metrics.namespaces
+
+
+def test_metrics_hierarchy(hierarchy):
+    metrics = hierarchy.metrics()
+
+    assert 3 == metrics.max_depth
+    assert 2 == metrics.root_class_count
+
+
+def test_metrics_hierarchy_with_cycle():
+    o = ontology(SubClassOf(A, B), SubClassOf(B, A))
+
+    assert 2 == o.metrics().max_depth
+
+
+def test_metrics_deep_hierarchy():
+    n = 200_000
+    axioms = "\n".join(f"SubClassOf(:C{i + 1} :C{i})" for i in range(n))
+    o = pyhornedowl.open_ontology_from_string(f"Prefix(:=<https://example.com/>)\nOntology(\n{axioms}\n)", "ofn")
+
+    assert n + 1 == o.metrics().max_depth
+
+
+def test_metrics_empty_ontology():
+    metrics = pyhornedowl.PyIndexedOntology().metrics()
+
+    assert {} == metrics.component_counts
+    assert 0 == metrics.axiom_count
+    assert 0 == metrics.max_depth
+    assert 0 == metrics.root_class_count
+
+
+def test_expressivity():
+    i = NamedIndividual(IRI.parse("https://example.com/i"))
+    age = DataProperty(IRI.parse("https://example.com/age"))
+
+    assert "AL" == ontology(SubClassOf(A, B)).metrics().expressivity
+    assert "EL" == ontology(SubClassOf(A, ObjectSomeValuesFrom(P, B))).metrics().expressivity
+    assert "EL+R" == ontology(SubClassOf(A, ObjectSomeValuesFrom(P, B)),
+                              TransitiveObjectProperty(P),
+                              SubObjectPropertyOf([P, Q], P)).metrics().expressivity
+    assert "ALC" == ontology(SubClassOf(A, ObjectComplementOf(ObjectSomeValuesFrom(P, B)))).metrics().expressivity
+    assert "SHIN(D)" == ontology(SubClassOf(A, ObjectUnionOf([B, ObjectSomeValuesFrom(P, C)])),
+                                 TransitiveObjectProperty(P),
+                                 SubObjectPropertyOf(Q, P),
+                                 InverseObjectProperties(P, R),
+                                 SubClassOf(A, ObjectMaxCardinality(1, Q, Class(IRI.parse(OWL_THING)))),
+                                 DataPropertyRange(age, Datatype(IRI.parse(XSD_INTEGER)))).metrics().expressivity
+    assert "SROIQ" == ontology(SubClassOf(A, ObjectComplementOf(ObjectUnionOf([B, C]))),
+                               SubObjectPropertyOf([P, Q], P),
+                               SubClassOf(A, ObjectHasValue(InverseObjectProperty(P), i)),
+                               SubClassOf(A, ObjectMinCardinality(2, P, B))).metrics().expressivity
+
+
+def test_expressivity_ignores_annotations():
+    o = ontology(SubClassOf(A, B),
+                 AnnotationAssertion(A.first, Annotation(AnnotationProperty(IRI.parse(RDFS_COMMENT)),
+                                                         DatatypeLiteral("x", IRI.parse(XSD_STRING)))))
+
+    assert "AL" == o.metrics().expressivity
@@ -0,0 +1,126 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import simple_ontology
+
+
+def ontology_ids(o):
+    return [c.component for c in o.get_components() if isinstance(c.component, OntologyID)]
+
+
+def test_set_iri():
+    o = simple_ontology()
+
+    o.set_iri("https://example.com/onto")
+
+    assert "https://example.com/onto" == str(o.get_iri())
+    assert o.get_version_iri() is None
+    assert 1 == len(ontology_ids(o))
+
+
+def test_set_version_iri_keeps_iri():
+    o = simple_ontology()
+
+    o.set_iri("https://example.com/onto")
+    o.set_version_iri("https://example.com/onto/1.0")
+
+    assert "https://example.com/onto" == str(o.get_iri())
+    assert "https://example.com/onto/1.0" == str(o.get_version_iri())
+    assert 1 == len(ontology_ids(o))
+
+
+def test_set_iri_replaces_existing():
+    o = simple_ontology()
+
+    o.set_iri("https://example.com/onto")
+    o.set_version_iri("https://example.com/onto/1.0")
+    o.set_iri("https://example.com/other")
+
+    assert "https://example.com/other" == str(o.get_iri())
+    assert "https://example.com/onto/1.0" == str(o.get_version_iri())
+    assert 1 == len(ontology_ids(o))
+
+
+def test_unset_iri():
+    o = simple_ontology()
+
+    o.set_iri("https://example.com/onto")
+    o.set_iri(None)
+
+    assert o.get_iri() is None
+    assert [] == ontology_ids(o)
+
+
+def test_set_iri_roundtrip():
+    o = simple_ontology()
+
+    o.set_iri("https://example.org/onto")
+    o.set_version_iri("https://example.org/onto/1.0")
+    o.add_import("https://example.org/imported")
+    o.prefix_mapping.remove_prefix("")
+
+    o2 = pyhornedowl.open_ontology_from_string(o.save_to_string("ofn"), "ofn")
+
+    assert "https://example.org/onto" == str(o2.get_iri())
+    assert "https://example.org/onto/1.0" == str(o2.get_version_iri())
+    assert ["https://example.org/imported"] == [str(i) for i in o2.get_imports()]
+
+
+def test_imports():
+    o = simple_ontology()
+
+    assert [] == o.get_imports()
+
+    assert o.add_import("https://example.com/a")
+    assert o.add_import(":b")
+    assert not o.add_import("https://example.com/a")
+
+    assert {"https://example.com/a", "https://example.com/b"} == {str(i) for i in o.get_imports()}
+
+    assert o.remove_import("https://example.com/a")
+    assert not o.remove_import("https://example.com/a")
+
+    assert ["https://example.com/b"] == [str(i) for i in o.get_imports()]
+
+
+def test_ontology_annotations():
+    o = simple_ontology()
+
+    assert [] == o.get_ontology_annotations()
+
+    o.add_ontology_annotation("rdfs:comment", "A comment")
+    o.add_ontology_annotation("rdfs:comment", "Another comment")
+    o.add_ontology_annotation("rdfs:label", LanguageLiteral("Ontologie", "de"))
+
+    comments = o.get_ontology_annotations("rdfs:comment")
+    assert {"A comment", "Another comment"} == {a.av.literal for a in comments}
+    assert 3 == len(o.get_ontology_annotations())
+
+    labels = o.get_ontology_annotations("rdfs:label")
+    assert 1 == len(labels)
+    assert "de" == labels[0].av.lang
+
+
+def test_set_ontology_annotation_replaces():
+    o = simple_ontology()
+
+    o.add_ontology_annotation("rdfs:comment", "A comment")
+    o.add_ontology_annotation("rdfs:comment", "Another comment")
+    o.set_ontology_annotation("rdfs:comment", "Only comment")
+
+    assert ["Only comment"] == [a.av.literal for a in o.get_ontology_annotations("rdfs:comment")]
+
+
+def test_remove_ontology_annotation():
+    o = simple_ontology()
+
+    o.add_ontology_annotation("rdfs:comment", "A comment")
+    o.add_ontology_annotation("rdfs:comment", "Another comment")
+    o.add_ontology_annotation("rdfs:seeAlso", o.iri(":A"))
+
+    assert o.remove_ontology_annotation("rdfs:comment", "A comment")
+    assert ["Another comment"] == [a.av.literal for a in o.get_ontology_annotations("rdfs:comment")]
+
+    assert o.remove_ontology_annotation("rdfs:comment")
+    assert not o.remove_ontology_annotation("rdfs:comment")
+    assert 1 == len(o.get_ontology_annotations())
@@ -0,0 +1,166 @@
+import re
+
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import RDFS_LABEL, OWL_THING
+
+XSD = "http://www.w3.org/2001/XMLSchema#"
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+part_of = ObjectProperty(e("part_of"))
+age = DataProperty(e("age"))
+cell, nucleus, cytoplasm = (Class(e(n)) for n in ["cell", "nucleus", "cytoplasm"])
+
+
+def ontology():
+    o = pyhornedowl.PyIndexedOntology()
+    o.prefix_mapping.add_prefix("ex", "https://example.com/")
+    o.prefix_mapping.add_prefix("", "https://example.com/")
+
+    o.declare_class("ex:nucleus")
+    o.declare_data_property("ex:age")
+    label = AnnotationProperty(IRI.parse(RDFS_LABEL))
+    o.add_component(AnnotationAssertion(e("nucleus"), Annotation(label, SimpleLiteral("cell nucleus"))))
+    o.add_component(AnnotationAssertion(e("part_of"), Annotation(label, SimpleLiteral("part of"))))
+
+    return o
+
+
+def test_parse_class_expression():
+    o = ontology()
+
+    actual = o.parse_class_expression("ex:part_of some (ex:nucleus and not ex:cytoplasm)")
+
+    assert part_of.some(nucleus & ~cytoplasm) == actual
+
+
+def test_parse_class_expression_with_labels():
+    o = ontology()
+
+    actual = o.parse_class_expression("'part of' some 'cell nucleus' or :cytoplasm")
+
+    assert ObjectUnionOf([part_of.some(nucleus), cytoplasm]) == actual
+
+
+def test_parse_class_expression_precedence():
+    o = ontology()
+
+    actual = o.parse_class_expression("ex:cell and ex:part_of only ex:nucleus or not ex:cytoplasm")
+
+    assert ObjectUnionOf([ObjectIntersectionOf([cell, ObjectAllValuesFrom(part_of, nucleus)]),
+                          ObjectComplementOf(cytoplasm)]) == actual
+
+
+def test_parse_restrictions():
+    o = ontology()
+    i = NamedIndividual(e("i"))
+
+    assert ObjectMinCardinality(2, part_of, nucleus) == o.parse_class_expression("ex:part_of min 2 ex:nucleus")
+    assert (ObjectExactCardinality(1, part_of, Class(IRI.parse(OWL_THING)))
+            == o.parse_class_expression("ex:part_of exactly 1"))
+    assert ObjectHasValue(part_of, i) == o.parse_class_expression("ex:part_of value ex:i")
+    assert ObjectHasSelf(part_of) == o.parse_class_expression("ex:part_of Self")
+    assert (ObjectSomeValuesFrom(InverseObjectProperty(part_of), cell)
+            == o.parse_class_expression("inverse ex:part_of some ex:cell"))
+    assert ObjectOneOf([i, NamedIndividual(e("j"))]) == o.parse_class_expression("{ex:i, ex:j}")
+
+
+def test_parse_data_restrictions():
+    o = ontology()
+    integer = Datatype(IRI.parse(XSD + "integer"))
+
+    assert DataSomeValuesFrom(age, integer) == o.parse_class_expression("ex:age some xsd:integer")
+    assert (DataHasValue(age, DatatypeLiteral("42", IRI.parse(XSD + "integer")))
+            == o.parse_class_expression("ex:age value 42"))
+    assert (DataSomeValuesFrom(age, DatatypeRestriction(integer, [
+        FacetRestriction(Facet.MinInclusive, DatatypeLiteral("18", IRI.parse(XSD + "integer")))]))
+            == o.parse_class_expression("ex:age some integer[>= 18]"))
+    assert (DataHasValue(DataProperty(e("name")), LanguageLiteral("Zelle", "de"))
+            == o.parse_class_expression('ex:name value "Zelle"@de'))
+
+
+@pytest.mark.parametrize("expression, message", [
+    ("ex:part_of some", "expected class expression, found end of input"),
+    ("ex:part_of some and ex:cell", "expected class expression, found 'and'"),
+    ("(ex:cell and ex:nucleus", "expected ')'"),
+    ("foo:cell", "undefined prefix 'foo'"),
+    ("'unknown label'", "no entity with label 'unknown label'"),
+    ("ex:cell ex:nucleus", "unexpected 'ex:nucleus'"),
+    ("inverse ex:part_of", "expected a restriction keyword, found end of input"),
+    ("inverse (ex:part_of) ex:cell", "expected a restriction keyword, found 'ex:cell'"),
+    ('ex:name value "Zelle"@', "expected a language tag"),
+])
+def test_parse_class_expression_errors(expression, message):
+    o = ontology()
+
+    with pytest.raises(ValueError, match=re.escape(message)):
+        o.parse_class_expression(expression)
+
+
+def test_parse_class_expression_error_points_at_position():
+    o = ontology()
+
+    with pytest.raises(ValueError) as err:
+        o.parse_class_expression("ex:cell and or ex:nucleus")
+
+    assert "at column 13" in str(err.value)
+    assert str(err.value).endswith("\n    ex:cell and or ex:nucleus\n                ^^")
+
+
+def test_parse_axiom():
+    o = ontology()
+
+    actual = o.parse_axiom("SubClassOf(:cell ObjectSomeValuesFrom(ex:part_of 'cell nucleus'))")
+
+    assert SubClassOf(cell, part_of.some(nucleus)) == actual.component
+    assert set() == actual.ann
+
+
+def test_parse_axiom_with_annotation():
+    o = ontology()
+    comment = AnnotationProperty(IRI.parse("http://www.w3.org/2000/01/rdf-schema#comment"))
+
+    actual = o.parse_axiom('SubClassOf(Annotation(rdfs:comment "it\'s asserted") :nucleus :cell)')
+
+    assert SubClassOf(nucleus, cell) == actual.component
+    assert {Annotation(comment, SimpleLiteral("it's asserted"))} == actual.ann
+
+
+def test_parse_axiom_can_be_added():
+    o = ontology()
+
+    axiom = o.parse_axiom("Declaration(Class(:cell))")
+    o.add_component(axiom.component, axiom.ann)
+
+    assert "https://example.com/cell" in o.get_classes()
+
+
+@pytest.mark.parametrize("axiom, message", [
+    ("SubClassOf(:cell)", "at column"),
+    ("SubClassOf(foo:cell :nucleus)", "undefined prefix"),
+    ("SubClassOf(:cell 'no such label')", "no entity with label 'no such label'"),
+    ("", "expected an axiom"),
+    ("SubClassOf(:cell :nucleus) SubClassOf(:nucleus :cell)", "expected a single axiom, found 2"),
+    ("SubClassOf(:cell é)", "at column 18"),
+    ("ééé", "at column 1"),
+])
+def test_parse_axiom_errors(axiom, message):
+    o = ontology()
+
+    with pytest.raises(ValueError, match=re.escape(message)):
+        o.parse_axiom(axiom)
+
+
+def test_parse_axiom_error_points_into_input():
+    o = ontology()
+
+    with pytest.raises(ValueError) as err:
+        o.parse_axiom("SubClassOf('cell nucleus' foo:cell)")
+
+    assert str(err.value).endswith("\n    SubClassOf('cell nucleus' foo:cell)\n                              ^^^^^^^^")
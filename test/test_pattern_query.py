@@ -0,0 +1,99 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+def part_of_ontology(strategy=pyhornedowl.IndexCreationStrategy.OnQuery):
+    o = pyhornedowl.PyIndexedOntology(index_strategy=strategy)
+    o.prefix_mapping.add_prefix("ex", "https://example.com/")
+
+    part_of = o.object_property("ex:part_of")
+    has_part = o.object_property("ex:has_part")
+    a, b, c, d = (o.class_(f"ex:{n}") for n in "ABCD")
+
+    o.add_component(SubClassOf(a, ObjectSomeValuesFrom(part_of, b)))
+    o.add_component(SubClassOf(b, ObjectSomeValuesFrom(part_of, c & d)))
+    o.add_component(SubClassOf(c, ObjectSomeValuesFrom(has_part, a)))
+    o.add_component(SubClassOf(c, d))
+    o.add_component(SubClassOf(d, ObjectSomeValuesFrom(part_of, d)))
+    o.add_component(ClassAssertion(a, o.named_individual("ex:i")))
+    o.add_component(AnnotationAssertion(a.first, Annotation(o.annotation_property("ex:note"),
+                                                            SimpleLiteral("note"))))
+
+    return o
+
+
+@pytest.mark.parametrize("strategy", [pyhornedowl.IndexCreationStrategy.OnQuery,
+                                      pyhornedowl.IndexCreationStrategy.Explicit])
+def test_match_fixed_property(strategy):
+    o = part_of_ontology(strategy)
+    part_of = o.object_property("ex:part_of")
+
+    actual = o.match_pattern(SubClassOf(Var("x"), ObjectSomeValuesFrom(part_of, Var("y"))))
+
+    expected = [
+        (o.class_("ex:A"), o.class_("ex:B")),
+        (o.class_("ex:B"), o.class_("ex:C") & o.class_("ex:D")),
+        (o.class_("ex:D"), o.class_("ex:D")),
+    ]
+    assert {(str(x), str(y)) for x, y in expected} == {(str(b["x"]), str(b["y"])) for b in actual}
+
+
+def test_match_variable_property():
+    o = part_of_ontology()
+
+    actual = o.match_pattern(SubClassOf(o.class_("ex:C"), ObjectSomeValuesFrom(Var("p"), Var("y"))))
+
+    assert [{"p": o.object_property("ex:has_part"), "y": o.class_("ex:A")}] == actual
+
+
+def test_match_repeated_variable():
+    o = part_of_ontology()
+    part_of = o.object_property("ex:part_of")
+
+    actual = o.match_pattern(SubClassOf(Var("x"), ObjectSomeValuesFrom(part_of, Var("x"))))
+
+    assert [{"x": o.class_("ex:D")}] == actual
+
+
+def test_match_any_class_expression():
+    o = part_of_ontology()
+
+    actual = o.match_pattern(SubClassOf(o.class_("ex:C"), Var("super")))
+
+    expected = {str(o.class_("ex:D")), str(ObjectSomeValuesFrom(o.object_property("ex:has_part"), o.class_("ex:A")))}
+    assert expected == {str(b["super"]) for b in actual}
+
+
+def test_match_individual():
+    o = part_of_ontology()
+
+    actual = o.match_pattern(ClassAssertion(Var("c"), Var("i")))
+
+    assert [{"c": o.class_("ex:A"), "i": o.named_individual("ex:i")}] == actual
+
+
+def test_match_iri_and_literal():
+    o = part_of_ontology()
+
+    actual = o.match_pattern(AnnotationAssertion(Var("s"), Annotation(AnnotationProperty(Var("p")), Var("v"))))
+
+    assert 1 == len(actual)
+    assert "https://example.com/A" == str(actual[0]["s"])
+    assert o.annotation_property("ex:note") == actual[0]["p"]
+    assert SimpleLiteral("note") == actual[0]["v"]
+
+
+def test_match_no_result():
+    o = part_of_ontology()
+
+    assert [] == o.match_pattern(SubClassOf(o.class_("ex:A"), o.class_("ex:B")))
+    assert [] == o.match_pattern(EquivalentClasses([Var("x"), Var("y")]))
+
+
+def test_var():
+    x = Var("x")
+
+    assert "x" == x.name
+    assert isinstance(x, IRI)
+    assert 'Var("x")' == repr(x)
@@ -0,0 +1,163 @@
+import copy
+import pickle
+
+import pytest
+from test_base import simple_ontology_comps
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+RDFS_COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an ontology."""
+    p, q = ObjectProperty(e("p")), ObjectProperty(e("q"))
+    age = DataProperty(e("age"))
+    i = NamedIndividual(e("i"))
+    integer = Datatype(IRI.parse(XSD_INTEGER))
+    a, b, c = Class(e("A")), Class(e("B")), Class(e("C"))
+
+    components = simple_ontology_comps() + [
+        SubClassOf(a, ObjectIntersectionOf([b, ObjectMinCardinality(2, InverseObjectProperty(p), c)])),
+        EquivalentClasses([c, DataSomeValuesFrom(age, DatatypeRestriction(
+            integer, [FacetRestriction(Facet.MinInclusive, DatatypeLiteral("18", integer.first))]))]),
+        SubObjectPropertyOf([p, q], p),
+        DataPropertyAssertion(age, i, DatatypeLiteral("42", integer.first)),
+        ClassAssertion(ObjectOneOf([i, AnonymousIndividual("_:b0")]), i),
+        HasKey(c, [p, age]),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    onto.add_component(AnnotationAssertion(c.first, Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)),
+                                                               LanguageLiteral("Klasse C", "de"))),
+                       [Annotation(AnnotationProperty(IRI.parse(RDFS_COMMENT)), SimpleLiteral("x"))])
+
+    return onto
+
+
+@pytest.mark.parametrize("component", [
+    DeclareClass(Class(e("A"))),
+    SubClassOf(Class(e("A")), ObjectSomeValuesFrom(ObjectProperty(e("p")), Class(e("B")))),
+    DisjointUnion(Class(e("A")), [Class(e("B")), ObjectComplementOf(Class(e("C")))]),
+    DataPropertyRange(DataProperty(e("age")), DatatypeRestriction(
+        Datatype(IRI.parse("http://www.w3.org/2001/XMLSchema#integer")),
+        [FacetRestriction(Facet.MinInclusive, SimpleLiteral("18"))])),
+    AnnotationAssertion(e("A"), Annotation(AnnotationProperty(e("label")), LanguageLiteral("a", "en"))),
+    ObjectPropertyAssertion(ObjectProperty(e("p")), NamedIndividual(e("i")), AnonymousIndividual("b0")),
+    Rule([ClassAtom(Class(e("A")), Variable(e("x")))],
+         [DataPropertyAtom(DataProperty(e("age")), (Variable(e("x")), SimpleLiteral("1")))]),
+    AnnotatedComponent(DeclareClass(Class(e("A"))), {Annotation(AnnotationProperty(e("c")), SimpleLiteral("c"))}),
+])
+def test_pickle_model(component):
+    actual = pickle.loads(pickle.dumps(component))
+
+    assert component == actual
+    assert type(component) is type(actual)
+    assert component == copy.copy(component)
+    assert component == copy.deepcopy(component)
+
+
+def test_pickle_iri_and_var():
+    assert "https://example.com/A" == str(pickle.loads(pickle.dumps(e("A"))))
+    assert Facet.Pattern == pickle.loads(pickle.dumps(Facet.Pattern))
+    assert ComponentKind.SubClassOf == pickle.loads(pickle.dumps(ComponentKind.SubClassOf))
+
+    var = pickle.loads(pickle.dumps(Var("x")))
+    assert isinstance(var, Var)
+    assert "x" == var.name
+    assert str(Var("x")) == str(var)
+
+
+def test_model_state():
+    sub_class_of = SubClassOf(Class(e("A")), Class(e("B")))
+
+    sub_class_of.__setstate__(SubClassOf(Class(e("C")), Class(e("D"))).__getstate__())
+
+    assert SubClassOf(Class(e("C")), Class(e("D"))) == sub_class_of
+
+
+def test_pickle_prefix_mapping(o):
+    mapping = o.prefix_mapping
+
+    actual = pickle.loads(pickle.dumps(mapping))
+
+    assert list(mapping) == list(actual)
+    assert "https://example.com/A" == actual.expand_curie(":A")
+
+
+def test_pickle_ontology(o):
+    o.set_synonym_properties([e("synonym")])
+
+    actual = pickle.loads(pickle.dumps(o))
+
+    assert set(o.get_axioms()) == set(actual.get_axioms())
+    assert list(o.prefix_mapping) == list(actual.prefix_mapping)
+    assert ["https://example.com/synonym"] == [str(p) for p in actual.get_synonym_properties()]
+    assert o.get_superclasses("https://example.com/D") == actual.get_superclasses("https://example.com/D")
+    assert SimpleLiteral("ClassA") == actual.get_label("https://example.com/A")
+
+
+def test_pickle_ontology_with_index_strategy():
+    o = pyhornedowl.PyIndexedOntology(pyhornedowl.IndexCreationStrategy.Explicit)
+    o.add_component(DeclareClass(Class(e("A"))))
+
+    actual = pickle.loads(pickle.dumps(o))
+
+    assert [DeclareClass(Class(e("A")))] == [a.component for a in actual.get_axioms()]
+
+
+def test_ontology_state_is_compact():
+    o = pyhornedowl.PyIndexedOntology()
+    for i in range(100):
+        o.add_component(SubClassOf(Class(e(f"C{i}")), Class(e(f"C{i // 2}"))))
+
+    state = o.__getstate__()
+
+    assert isinstance(state, bytes)
+    assert len(state) < len(o.save_to_string("ofn").encode()) / 2
+
+
+def test_ontology_invalid_state(o):
+    with pytest.raises(ValueError, match="Invalid ontology encoding"):
+        pyhornedowl.PyIndexedOntology().__setstate__(b"not an ontology")
+
+    with pytest.raises(ValueError, match="Invalid ontology encoding"):
+        pyhornedowl.PyIndexedOntology().__setstate__(o.__getstate__()[:-1])
+
+
+@pytest.mark.parametrize("copy_fn", [copy.copy, copy.deepcopy])
+def test_copy_ontology(copy_fn, o):
+
+    actual = copy_fn(o)
+    actual.add_component(DeclareClass(Class(e("E"))))
+    actual.prefix_mapping.add_prefix("other", "https://other.com/")
+
+    assert set(o.get_axioms()) | {AnnotatedComponent(DeclareClass(Class(e("E"))), set())} == set(actual.get_axioms())
+    assert "other" not in o.prefix_mapping
+
+
+@pytest.mark.parametrize("copy_fn", [copy.copy, copy.deepcopy, lambda o: pickle.loads(pickle.dumps(o))])
+def test_copy_ontology_keeps_synonyms_in_label_index(copy_fn):
+    o = pyhornedowl.PyIndexedOntology(pyhornedowl.IndexCreationStrategy.OnLoad)
+    o.set_synonym_properties(["http://x/syn"])
+    o.add_component(AnnotationAssertion(IRI.parse("http://x/A"),
+                                        Annotation(AnnotationProperty(IRI.parse("http://x/syn")), SimpleLiteral("alpha"))))
+
+    actual = copy_fn(o)
+
+    assert ["http://x/A"] == actual.get_iris_for_label("alpha", synonyms=True)
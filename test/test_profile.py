@@ -0,0 +1,157 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+OWL_THING = Class(IRI.parse("http://www.w3.org/2002/07/owl#Thing"))
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+XSD_DOUBLE = "http://www.w3.org/2001/XMLSchema#double"
+
+A, B, C = (Class(IRI.parse(f"https://example.com/{n}")) for n in "ABC")
+P, Q, R = (ObjectProperty(IRI.parse(f"https://example.com/{n}")) for n in "pqr")
+AGE = DataProperty(IRI.parse("https://example.com/age"))
+I = NamedIndividual(IRI.parse("https://example.com/i"))
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an ontology declaring the entities used by the tests."""
+    components = [
+        DeclareClass(A),
+        DeclareClass(B),
+        DeclareClass(C),
+        DeclareObjectProperty(P),
+        DeclareObjectProperty(Q),
+        DeclareObjectProperty(R),
+        DeclareDataProperty(AGE),
+        DeclareNamedIndividual(I),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+def add(o, *components: Component) -> None:
+    for component in components:
+        o.add_component(component)
+
+
+def reasons(report) -> list[str]:
+    return [v.reason for v in report.violations]
+
+
+@pytest.mark.parametrize("profile", ["EL", "QL", "RL", "DL"])
+def test_simple_ontology_is_in_profile(profile, o):
+    add(o, SubClassOf(A, B))
+
+    report = o.check_profile(profile)
+
+    assert report.is_in_profile
+    assert report
+    assert profile == report.profile
+
+
+def test_el_ontology(o):
+    add(o,
+        SubClassOf(A, ObjectIntersectionOf([B, ObjectSomeValuesFrom(P, C)])),
+        TransitiveObjectProperty(P),
+        SubObjectPropertyOf([P, Q], P),
+        SubClassOf(A, DataHasValue(AGE, DatatypeLiteral("42", IRI.parse(XSD_INTEGER)))))
+
+    assert [] == reasons(o.check_profile("EL"))
+
+
+def test_el_disallowed_constructors(o):
+    add(o,
+        SubClassOf(A, ObjectUnionOf([B, C])),
+        SubClassOf(A, ObjectSomeValuesFrom(InverseObjectProperty(P), C)),
+        FunctionalObjectProperty(Q))
+
+    report = o.check_profile("EL")
+
+    assert not report
+    assert {"ObjectUnionOf is not allowed in OWL 2 EL",
+            "ObjectInverseOf is not allowed in OWL 2 EL",
+            "FunctionalObjectProperty is not allowed in OWL 2 EL"} == set(reasons(report))
+    violation = next(v for v in report.violations if "FunctionalObjectProperty" in v.reason)
+    assert FunctionalObjectProperty(Q) == violation.component.component
+
+
+def test_el_disallowed_datatype(o):
+    add(o, DataPropertyRange(AGE, Datatype(IRI.parse(XSD_DOUBLE))))
+
+    assert ["datatype http://www.w3.org/2001/XMLSchema#double is not allowed in OWL 2 EL"] == reasons(
+        o.check_profile("EL"))
+    assert [] == reasons(o.check_profile("RL"))
+
+
+def test_non_simple_property_in_cardinality(o):
+    add(o,
+        TransitiveObjectProperty(P),
+        SubObjectPropertyOf(P, Q),
+        SubClassOf(A, ObjectMaxCardinality(1, Q, OWL_THING)),
+        SubClassOf(A, ObjectMaxCardinality(1, R, OWL_THING)))
+
+    assert ["non-simple property https://example.com/q is used in ObjectMaxCardinality"] == reasons(
+        o.check_profile("DL"))
+
+
+def test_non_simple_property_from_chain(o):
+    add(o, SubObjectPropertyOf([P, Q], R), FunctionalObjectProperty(R))
+
+    assert ["non-simple property https://example.com/r is used in FunctionalObjectProperty"] == reasons(
+        o.check_profile("DL"))
+
+
+def test_undeclared_entities(o):
+    add(o, SubClassOf(A, Class(IRI.parse("https://example.com/Undeclared"))), SubClassOf(A, OWL_THING))
+
+    assert ["undeclared class https://example.com/Undeclared"] == reasons(o.check_profile("DL"))
+
+
+def test_punned_entities(o):
+    o.declare_data_property(":p")
+
+    assert ["https://example.com/p is declared as object property and data property"] * 2 == reasons(
+        o.check_profile("DL"))
+
+
+def test_ql_positions(o):
+    add(o,
+        SubClassOf(ObjectSomeValuesFrom(P, OWL_THING), A),
+        SubClassOf(A, ObjectSomeValuesFrom(P, B)),
+        SubClassOf(A, ObjectComplementOf(B)),
+        SubClassOf(ObjectSomeValuesFrom(P, B), C),
+        SubClassOf(ObjectIntersectionOf([A, B]), C))
+
+    assert ["ObjectIntersectionOf is not allowed as subclass expression in OWL 2 QL",
+            "ObjectSomeValuesFrom with a filler other than owl:Thing is not allowed as subclass expression in OWL 2 QL"
+            ] == sorted(reasons(o.check_profile("QL")))
+
+
+def test_rl_positions(o):
+    add(o,
+        SubClassOf(ObjectUnionOf([A, B]), ObjectAllValuesFrom(P, C)),
+        SubClassOf(A, ObjectMaxCardinality(1, P, B)),
+        SubClassOf(A, ObjectSomeValuesFrom(P, B)),
+        SubClassOf(A, ObjectMaxCardinality(2, P, OWL_THING)),
+        ReflexiveObjectProperty(Q))
+
+    assert ["ObjectSomeValuesFrom is not allowed as superclass expression in OWL 2 RL",
+            "ReflexiveObjectProperty is not allowed in OWL 2 RL",
+            "a maximum cardinality other than 0 or 1 is not allowed as superclass expression in OWL 2 RL",
+            ] == sorted(reasons(o.check_profile("RL")))
+
+
+def test_check_profile_accepts_lower_case(o):
+    assert "EL" == o.check_profile("el").profile
+
+
+def test_check_profile_unknown_profile(o):
+    with pytest.raises(ValueError, match="Unknown profile 'OWL'"):
+        o.check_profile("OWL")
@@ -0,0 +1,128 @@
+import pytest
+from test_base import simple_ontology_comps
+
+import pyhornedowl
+from pyhornedowl import model
+
+rdflib = pytest.importorskip("rdflib")
+
+RDF_TYPE = rdflib.URIRef("http://www.w3.org/1999/02/22-rdf-syntax-ns#type")
+RDFS_LABEL = rdflib.URIRef("http://www.w3.org/2000/01/rdf-schema#label")
+OWL_CLASS = rdflib.URIRef("http://www.w3.org/2002/07/owl#Class")
+OWL_RESTRICTION = rdflib.URIRef("http://www.w3.org/2002/07/owl#Restriction")
+OWL_ON_PROPERTY = rdflib.URIRef("http://www.w3.org/2002/07/owl#onProperty")
+OWL_SOME_VALUES_FROM = rdflib.URIRef("http://www.w3.org/2002/07/owl#someValuesFrom")
+OWL_OBJECT_PROPERTY = rdflib.URIRef("http://www.w3.org/2002/07/owl#ObjectProperty")
+RDFS_SUB_CLASS_OF = rdflib.URIRef("http://www.w3.org/2000/01/rdf-schema#subClassOf")
+RDF_LANG_STRING = rdflib.URIRef("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString")
+XSD_INTEGER = rdflib.URIRef("http://www.w3.org/2001/XMLSchema#integer")
+
+
+def ex(name: str):
+    return rdflib.URIRef(f"https://example.com/{name}")
+
+
+def some_values_from(sub: str, prop: str, filler: str) -> model.SubClassOf:
+    return model.SubClassOf(model.Class(model.IRI.parse(sub)), model.ObjectSomeValuesFrom(
+        model.ObjectProperty(model.IRI.parse(prop)), model.Class(model.IRI.parse(filler))))
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an o."""
+    p = model.ObjectProperty(model.IRI.parse(ex("p")))
+    age = model.DataProperty(model.IRI.parse(ex("age")))
+    i = model.NamedIndividual(model.IRI.parse(ex("i")))
+
+    components = simple_ontology_comps() + [
+        model.DeclareObjectProperty(p),
+        model.DeclareDataProperty(age),
+        model.DeclareNamedIndividual(i),
+        some_values_from(ex("A"), ex("p"), ex("C")),
+        model.DataPropertyAssertion(age, i, model.DatatypeLiteral("42", model.IRI.parse(XSD_INTEGER))),
+        model.AnnotationAssertion(model.IRI.parse(ex("C")), model.Annotation(
+            model.AnnotationProperty(model.IRI.parse(RDFS_LABEL)), model.LanguageLiteral("Klasse C", "de"))),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("ex", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+def test_to_rdflib(o):
+    graph = o.to_rdflib()
+
+    assert isinstance(graph, rdflib.Graph)
+    assert (ex("A"), RDF_TYPE, OWL_CLASS) in set(graph)
+    assert (ex("A"), RDFS_LABEL, rdflib.Literal("ClassA")) in set(graph)
+    assert (ex("C"), RDFS_LABEL, rdflib.Literal("Klasse C", lang="de")) in set(graph)
+    assert (ex("i"), ex("age"), rdflib.Literal("42", datatype=XSD_INTEGER)) in set(graph)
+    assert any(isinstance(s, rdflib.BNode) for s, p, o in graph if o == OWL_RESTRICTION)
+
+
+def test_to_rdflib_namespaces(o):
+    graph = o.to_rdflib()
+
+    assert ("ex", rdflib.URIRef("https://example.com/")) in set(graph.namespaces())
+
+
+def test_round_trip(o):
+    actual = pyhornedowl.open_ontology_from_rdflib(o.to_rdflib())
+
+    assert set(o.get_axioms()) == set(actual.get_axioms())
+    assert "https://example.com/" == actual.prefix_mapping["ex"]
+
+
+def test_open_ontology_from_rdflib():
+    graph = rdflib.Graph()
+    graph.bind("ex", rdflib.URIRef("https://example.com/"))
+    restriction = rdflib.BNode()
+    for triple in [(ex("A"), RDF_TYPE, OWL_CLASS),
+                   (ex("B"), RDF_TYPE, OWL_CLASS),
+                   (ex("p"), RDF_TYPE, OWL_OBJECT_PROPERTY),
+                   (ex("A"), RDFS_LABEL, rdflib.Literal("A")),
+                   (ex("A"), RDFS_SUB_CLASS_OF, restriction),
+                   (restriction, RDF_TYPE, OWL_RESTRICTION),
+                   (restriction, OWL_ON_PROPERTY, ex("p")),
+                   (restriction, OWL_SOME_VALUES_FROM, ex("B"))]:
+        graph.add(triple)
+
+    o = pyhornedowl.open_ontology_from_rdflib(graph, index_strategy=pyhornedowl.IndexCreationStrategy.OnLoad)
+
+    assert "https://example.com/" == o.prefix_mapping["ex"]
+    assert "A" == o.get_label("ex:A").literal
+    assert some_values_from(ex("A"), ex("p"), ex("B")) in {a.component for a in o.get_axioms()}
+
+
+def test_open_ontology_from_rdflib_invalid_literal():
+    graph = rdflib.Graph()
+    graph.add((ex("A"), RDFS_LABEL, rdflib.Literal("A", datatype=RDF_LANG_STRING)))
+
+    with pytest.raises(ValueError, match="needs a language tag"):
+        pyhornedowl.open_ontology_from_rdflib(graph)
+
+
+def test_open_ontology_from_rdflib_blank_node_labels():
+    graph = rdflib.Graph()
+    for triple in [(ex("A"), RDF_TYPE, OWL_CLASS),
+                   (ex("B"), RDF_TYPE, OWL_CLASS),
+                   (ex("C"), RDF_TYPE, OWL_CLASS),
+                   (ex("p"), RDF_TYPE, OWL_OBJECT_PROPERTY)]:
+        graph.add(triple)
+    for node, filler in [(rdflib.BNode("1"), ex("B")), (rdflib.BNode("x y"), ex("C"))]:
+        for triple in [(ex("A"), RDFS_SUB_CLASS_OF, node),
+                       (node, RDF_TYPE, OWL_RESTRICTION),
+                       (node, OWL_ON_PROPERTY, ex("p")),
+                       (node, OWL_SOME_VALUES_FROM, filler)]:
+            graph.add(triple)
+
+    o = pyhornedowl.open_ontology_from_rdflib(graph)
+
+    components = {a.component for a in o.get_axioms()}
+    assert some_values_from(ex("A"), ex("p"), ex("B")) in components
+    assert some_values_from(ex("A"), ex("p"), ex("C")) in components
@@ -0,0 +1,112 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import RDFS_LABEL
+
+XSD = "http://www.w3.org/2001/XMLSchema#"
+
+
+def ontology():
+    o = pyhornedowl.PyIndexedOntology()
+    o.prefix_mapping.add_prefix("ex", "https://example.com/")
+
+    label = AnnotationProperty(IRI.parse(RDFS_LABEL))
+    o.add_component(AnnotationAssertion(o.iri("https://example.com/nucleus"),
+                                        Annotation(label, SimpleLiteral("cell nucleus"))))
+    o.add_component(AnnotationAssertion(o.iri("https://example.com/part_of"),
+                                        Annotation(label, SimpleLiteral("part_of"))))
+    return o
+
+
+def test_render_manchester():
+    o = ontology()
+    part_of = o.object_property("ex:part_of")
+    ax = SubClassOf(o.class_("ex:cell"), part_of.some(o.class_("ex:nucleus") & ~o.class_("ex:cytoplasm")))
+
+    assert "ex:cell SubClassOf part_of some ('cell nucleus' and not ex:cytoplasm)" == o.render(ax)
+    assert ("ex:cell SubClassOf ex:part_of some (ex:nucleus and not ex:cytoplasm)"
+            == o.render(ax, use_labels=False))
+    assert ("<https://example.com/cell> SubClassOf <https://example.com/part_of> some "
+            "(<https://example.com/nucleus> and not <https://example.com/cytoplasm>)"
+            == o.render(ax, use_labels=False, use_curies=False))
+
+
+def test_render_functional():
+    o = ontology()
+    part_of = o.object_property("ex:part_of")
+    ax = SubClassOf(o.class_("ex:cell"), part_of.some(o.class_("ex:nucleus")))
+
+    assert "SubClassOf(ex:cell ObjectSomeValuesFrom('part_of' 'cell nucleus'))" == o.render(ax, "functional")
+    assert ("SubClassOf(ex:cell ObjectSomeValuesFrom(ex:part_of ex:nucleus))"
+            == o.render(ax, "functional", use_labels=False))
+
+
+def test_render_annotated_component():
+    o = ontology()
+    comment = AnnotationProperty(IRI.parse("http://www.w3.org/2000/01/rdf-schema#comment"))
+    c = AnnotatedComponent(SubClassOf(o.class_("ex:A"), o.class_("ex:B")), {Annotation(comment, SimpleLiteral("x"))})
+
+    assert 'ex:A SubClassOf ex:B Annotations: rdfs:comment "x"' == o.render(c)
+    assert 'SubClassOf(Annotation(rdfs:comment "x") ex:A ex:B)' == o.render(c, "functional")
+
+
+def test_render_class_expressions():
+    o = ontology()
+    p = o.object_property("ex:p")
+    age = DataProperty(o.iri("https://example.com/age"))
+    a, b, c = (o.class_(f"ex:{n}") for n in "ABC")
+    integer = Datatype(o.iri(XSD + "integer"))
+
+    assert "(ex:A or ex:B) and ex:C" == o.render(ObjectIntersectionOf([a | b, c]))
+    assert "ex:A or ex:B and ex:C" == o.render(ObjectUnionOf([a, b & c]))
+    assert "inverse ex:p only ex:A" == o.render(ObjectAllValuesFrom(InverseObjectProperty(p), a))
+    assert "ex:p min 2 ex:A" == o.render(ObjectMinCardinality(2, p, a))
+    assert "ex:p value ex:i" == o.render(ObjectHasValue(p, NamedIndividual(o.iri("https://example.com/i"))))
+    assert "ex:age value 42" == o.render(DataHasValue(age, DatatypeLiteral("42", o.iri(XSD + "integer"))))
+    assert 'ex:age value "Zelle"@de' == o.render(DataHasValue(age, LanguageLiteral("Zelle", "de")))
+    assert ("ex:age some xsd:integer[>= 18, < 65]" == o.render(DataSomeValuesFrom(age, DatatypeRestriction(integer, [
+        FacetRestriction(Facet.MinInclusive, DatatypeLiteral("18", o.iri(XSD + "integer"))),
+        FacetRestriction(Facet.MaxExclusive, DatatypeLiteral("65", o.iri(XSD + "integer")))]))))
+
+
+def test_render_axioms():
+    o = ontology()
+    p = o.object_property("ex:p")
+    a, b, c = (o.class_(f"ex:{n}") for n in "ABC")
+    i = NamedIndividual(o.iri("https://example.com/i"))
+
+    assert "ex:A EquivalentTo ex:B" == o.render(EquivalentClasses([a, b]))
+    assert "DisjointClasses: ex:A, ex:B, ex:C" == o.render(DisjointClasses([a, b, c]))
+    assert "Transitive: ex:p" == o.render(TransitiveObjectProperty(p))
+    assert "ex:i Type ex:A" == o.render(ClassAssertion(a, i))
+    assert "ex:i ex:p ex:i" == o.render(ObjectPropertyAssertion(p, i, i))
+    assert "Class: ex:A" == o.render(DeclareClass(a))
+
+
+@pytest.mark.parametrize("expression", [
+    "part_of some ('cell nucleus' and not ex:cytoplasm)",
+    "ex:A or ex:B and ex:C",
+    "ex:p exactly 1 ex:A",
+    "{ex:i, ex:j}",
+])
+def test_render_round_trips_through_parser(expression):
+    o = ontology()
+
+    ce = o.parse_class_expression(expression)
+
+    assert ce == o.parse_class_expression(o.render(ce))
+
+
+def test_render_functional_round_trips_through_parser():
+    o = ontology()
+    ax = o.parse_axiom("SubClassOf(ex:cell ObjectSomeValuesFrom(ex:part_of ex:nucleus))")
+
+    assert ax == o.parse_axiom(o.render(ax, "functional"))
+
+
+def test_render_unknown_syntax():
+    o = ontology()
+
+    with pytest.raises(ValueError):
+        o.render(o.class_("ex:A"), "turtle")
@@ -0,0 +1,100 @@
+import pytest
+import pyhornedowl
+from pyhornedowl import IndexCreationStrategy
+from pyhornedowl.model import *
+
+from test_base import simple_ontology
+
+EXACT_SYNONYM = "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym"
+DEFINITION = "http://purl.obolibrary.org/obo/IAO_0000115"
+
+
+def add_annotation(o, subject, prop, value):
+    o.add_component(AnnotationAssertion(o.iri(subject), Annotation(o.annotation_property(prop), SimpleLiteral(value))))
+
+
+def search_ontology(strategy=IndexCreationStrategy.OnQuery):
+    o = pyhornedowl.PyIndexedOntology(strategy)
+    o.prefix_mapping.add_prefix("", "https://example.com/")
+    o.prefix_mapping.add_default_prefix_names()
+
+    o.set_label(":heart", "heart")
+    o.set_label(":heart_valve", "heart valve")
+    o.set_label(":cardiac_muscle", "cardiac muscle tissue")
+    add_annotation(o, ":cardiac_muscle", EXACT_SYNONYM, "heart muscle")
+    o.set_label(":blood", "blood")
+    add_annotation(o, ":blood", DEFINITION, "A fluid pumped through the body by the heart.")
+    add_annotation(o, ":blood", "rdfs:comment", "Not to be confused with lymph.")
+
+    return o
+
+
+def iris(hits):
+    return [h.iri.removeprefix("https://example.com/") for h in hits]
+
+
+def test_search_ranking():
+    o = search_ontology()
+
+    hits = o.search("heart")
+
+    assert ["heart", "heart_valve", "cardiac_muscle", "blood"] == iris(hits)
+    assert [h.score for h in hits] == sorted([h.score for h in hits], reverse=True)
+    assert "heart muscle" == hits[2].text
+    assert EXACT_SYNONYM == hits[2].property
+
+
+def test_search_prefix():
+    o = search_ontology()
+
+    assert ["heart_valve"] == iris(o.search("heart val"))
+    assert ["cardiac_muscle"] == iris(o.search("card"))
+
+
+def test_search_fuzzy():
+    o = search_ontology()
+
+    assert "cardiac_muscle" == iris(o.search("cardaic"))[0]
+    assert "heart_valve" == iris(o.search("hart valv"))[0]
+    assert [] == o.search("xyz")
+
+
+def test_search_limit():
+    o = search_ontology()
+
+    assert ["heart", "heart_valve"] == iris(o.search("heart", limit=2))
+
+
+def test_search_properties():
+    o = search_ontology()
+
+    assert [] == o.search("lymph")
+    assert ["blood"] == iris(o.search("lymph", properties=["rdfs:comment"]))
+    assert ["heart", "heart_valve"] == iris(o.search("heart", properties=["rdfs:label"]))
+
+
+def test_search_follows_modifications():
+    o = search_ontology()
+
+    o.set_label(":heart", "cor")
+    assert "heart" not in iris(o.search("heart"))
+    assert ["heart"] == iris(o.search("cor"))
+
+    o.remove_component(AnnotationAssertion(o.iri(":heart"), Annotation(o.annotation_property("rdfs:label"), SimpleLiteral("cor"))))
+    assert [] == o.search("cor")
+
+
+def test_search_explicit():
+    o = search_ontology(IndexCreationStrategy.Explicit)
+
+    with pytest.raises(ValueError):
+        o.search("heart")
+
+    o.build_search_index()
+    assert "heart" == iris(o.search("heart"))[0]
+
+
+def test_search_on_load():
+    o = search_ontology(IndexCreationStrategy.OnLoad)
+
+    assert "heart" == iris(o.search("heart"))[0]
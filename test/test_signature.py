@@ -0,0 +1,84 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import simple_ontology, RDFS_LABEL, OWL_THING
+
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+def test_class_expression_signature():
+    part_of = ObjectProperty(e("part_of"))
+    ce = part_of.some(Class(e("A")) & ~Class(e("B")))
+
+    assert {part_of, Class(e("A")), Class(e("B"))} == ce.signature()
+    assert {Class(e("A")), Class(e("B"))} == ce.classes()
+    assert {part_of} == ce.object_properties()
+    assert set() == ce.named_individuals()
+
+
+def test_entity_signature():
+    assert {Class(e("A"))} == Class(e("A")).signature()
+    assert {NamedIndividual(e("i"))} == NamedIndividual(e("i")).named_individuals()
+
+
+def test_data_restriction_signature():
+    age = DataProperty(e("age"))
+    ce = DataHasValue(age, DatatypeLiteral("42", IRI.parse(XSD_INTEGER)))
+
+    assert {age, Datatype(IRI.parse(XSD_INTEGER))} == ce.signature()
+    assert {age} == ce.data_properties()
+    assert {Datatype(IRI.parse(XSD_INTEGER))} == ce.datatypes()
+    assert {DatatypeLiteral("42", IRI.parse(XSD_INTEGER))} == ce.literals()
+
+
+def test_annotated_component_signature():
+    ap = AnnotationProperty(IRI.parse(RDFS_LABEL))
+    c = AnnotatedComponent(SubClassOf(Class(e("A")), Class(e("B"))),
+                           {Annotation(ap, SimpleLiteral("comment"))})
+
+    assert {Class(e("A")), Class(e("B")), ap} == c.signature()
+    assert {ap} == c.annotation_properties()
+    assert {SimpleLiteral("comment")} == c.literals()
+
+
+def test_ontology_signature():
+    o = simple_ontology()
+
+    expected_classes = {Class(e(n)) for n in "ABCD"} | {Class(IRI.parse(OWL_THING))}
+
+    assert expected_classes == o.classes()
+    assert {AnnotationProperty(IRI.parse(RDFS_LABEL))} == o.annotation_properties()
+    assert expected_classes | o.annotation_properties() == o.signature()
+    assert {SimpleLiteral("ClassA"), SimpleLiteral("ClassB")} == o.literals()
+    assert set() == o.object_properties()
+
+
+def test_empty_ontology_signature():
+    o = pyhornedowl.PyIndexedOntology()
+
+    assert set() == o.signature()
+    assert set() == o.literals()
+
+
+def test_ontology_signature_from_signature_index():
+    o = pyhornedowl.PyIndexedOntology()
+    label = AnnotationAssertion(e("A"), Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)), SimpleLiteral("A")))
+    o.add_component(DeclareClass(Class(e("A"))))
+    # Builds the signature index
+    o.get_classes(include_undeclared=True)
+    o.add_component(SubClassOf(Class(e("A")), Class(e("B"))))
+    o.add_component(label)
+
+    assert {Class(e("A")), Class(e("B"))} == o.classes()
+    assert {SimpleLiteral("A")} == o.literals()
+
+    o.remove_component(SubClassOf(Class(e("A")), Class(e("B"))))
+    o.remove_component(label)
+
+    assert {Class(e("A"))} == o.classes()
+    assert set() == o.literals()
+    assert set() == o.annotation_properties()
@@ -0,0 +1,144 @@
+import pytest
+from test_base import simple_ontology_comps
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+RDFS_COMMENT = "http://www.w3.org/2000/01/rdf-schema#comment"
+RDFS_SEE_ALSO = "http://www.w3.org/2000/01/rdf-schema#seeAlso"
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+def ex(name: str) -> str:
+    return f"https://example.com/{name}"
+
+
+def annotate(o, subject, prop, value):
+    o.add_component(AnnotationAssertion(IRI.parse(subject), Annotation(AnnotationProperty(IRI.parse(prop)), value)))
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an o."""
+    p = ObjectProperty(IRI.parse(ex("p")))
+    age = DataProperty(IRI.parse(ex("age")))
+    i = NamedIndividual(IRI.parse(ex("i")))
+    a, b, c = (Class(IRI.parse(ex(n))) for n in "ABC")
+
+    components = simple_ontology_comps() + [
+        DeclareObjectProperty(p),
+        DeclareDataProperty(age),
+        DeclareNamedIndividual(i),
+        SubClassOf(a, ObjectIntersectionOf([b, ObjectSomeValuesFrom(p, c)])),
+        DataPropertyAssertion(age, i, DatatypeLiteral("42", IRI.parse(XSD_INTEGER))),
+        ClassAssertion(a, i),
+        AnnotationAssertion(c.first, Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)),
+                                                LanguageLiteral("Klasse C", "de"))),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", "https://example.com/")
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+def rows(table):
+    return list(zip(*table.values()))
+
+
+def test_to_tables_columns(o):
+    tables = o.to_tables()
+
+    assert {
+        "entities": ["iri", "type", "label"],
+        "annotation_assertions": ["subject", "property", "value", "value_type", "lang", "datatype"],
+        "subclass_edges": ["subclass", "superclass"],
+        "existential_edges": ["subclass", "property", "filler"],
+        "components": ["kind", "text"],
+    } == {name: list(table) for name, table in tables.items()}
+
+
+def test_to_tables_entities(o):
+    entities = rows(o.to_tables()["entities"])
+
+    assert (ex("A"), "Class", "ClassA") in entities
+    assert (ex("C"), "Class", "Klasse C") in entities
+    assert (ex("p"), "ObjectProperty", None) in entities
+    assert (ex("age"), "DataProperty", None) in entities
+    assert (ex("i"), "NamedIndividual", None) in entities
+    assert (XSD_INTEGER, "Datatype", None) in entities
+
+
+def test_to_tables_entities_label_matches_get_label(o):
+    for label in [SimpleLiteral("Zeta"), SimpleLiteral("Beta"), LanguageLiteral("Alpha", "en")]:
+        annotate(o, ex("B"), RDFS_LABEL, label)
+
+    entities = rows(o.to_tables()["entities"])
+
+    assert "Beta" == o.get_label(ex("B")).literal
+    assert (ex("B"), "Class", "Beta") in entities
+
+
+def test_to_tables_annotation_assertions(o):
+    annotations = rows(o.to_tables()["annotation_assertions"])
+
+    assert (ex("A"), RDFS_LABEL, "ClassA", "literal", None, None) in annotations
+    assert (ex("C"), RDFS_LABEL, "Klasse C", "literal", "de", None) in annotations
+
+
+def test_to_tables_annotation_assertion_datatype(o):
+    annotate(o, ex("A"), RDFS_COMMENT, DatatypeLiteral("1", IRI.parse(XSD_INTEGER)))
+
+    annotations = rows(o.to_tables()["annotation_assertions"])
+
+    assert (ex("A"), RDFS_COMMENT, "1", "literal", None, XSD_INTEGER) in annotations
+
+
+def test_to_tables_annotation_assertion_value_type(o):
+    for value in [IRI.parse(ex("B")), SimpleLiteral(ex("B")), AnonymousIndividual("_:b0")]:
+        annotate(o, ex("A"), RDFS_SEE_ALSO, value)
+
+    annotations = rows(o.to_tables()["annotation_assertions"])
+
+    assert (ex("A"), RDFS_SEE_ALSO, ex("B"), "iri", None, None) in annotations
+    assert (ex("A"), RDFS_SEE_ALSO, ex("B"), "literal", None, None) in annotations
+    assert (ex("A"), RDFS_SEE_ALSO, "_:b0", "anonymous", None, None) in annotations
+
+
+def test_to_tables_edges(o):
+    tables = o.to_tables()
+
+    assert (ex("B"), ex("A")) in rows(tables["subclass_edges"])
+    assert (ex("A"), ex("B")) not in rows(tables["subclass_edges"])
+    assert [(ex("A"), ex("p"), ex("C"))] == rows(tables["existential_edges"])
+
+
+def test_to_tables_components(o):
+    components = rows(o.to_tables()["components"])
+
+    assert len(o.get_components()) == len(components)
+    assert ("DeclareObjectProperty", f"Declaration(ObjectProperty(<{ex('p')}>))") in components
+    assert ("ClassAssertion", f"ClassAssertion(<{ex('A')}> <{ex('i')}>)") in components
+
+
+def test_to_arrow(o):
+    pyarrow = pytest.importorskip("pyarrow")
+
+    tables = o.to_arrow()
+
+    assert isinstance(tables["entities"], pyarrow.Table)
+    assert o.to_tables()["subclass_edges"] == tables["subclass_edges"].to_pydict()
+
+
+def test_to_pandas(o):
+    pandas = pytest.importorskip("pandas")
+
+    tables = o.to_pandas()
+
+    assert isinstance(tables["entities"], pandas.DataFrame)
+    assert o.to_tables()["existential_edges"] == tables["existential_edges"].to_dict("list")
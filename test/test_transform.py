@@ -0,0 +1,94 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+part_of = ObjectProperty(e("part_of"))
+has_part = ObjectProperty(e("has_part"))
+A, B, C, D = (Class(e(n)) for n in "ABCD")
+
+
+def test_replace_property_in_restriction():
+    def swap(node):
+        if isinstance(node, ObjectSomeValuesFrom) and node.ope == part_of:
+            return ObjectSomeValuesFrom(has_part, node.bce)
+
+    axiom = SubClassOf(A, part_of.some(B & part_of.some(C)))
+
+    assert SubClassOf(A, has_part.some(B & has_part.some(C))) == pyhornedowl.transform(axiom, swap)
+
+
+def test_flatten_nested_intersections():
+    def flatten(node):
+        if isinstance(node, ObjectIntersectionOf):
+            operands = []
+            for ce in node.first:
+                operands.extend(ce.first if isinstance(ce, ObjectIntersectionOf) else [ce])
+            return ObjectIntersectionOf(operands)
+
+    ce = ObjectIntersectionOf([A, ObjectIntersectionOf([B, ObjectIntersectionOf([C, D])])])
+
+    assert ObjectIntersectionOf([A, B, C, D]) == pyhornedowl.transform(ce, flatten)
+
+
+def test_replace_class_with_expression():
+    def expand(node):
+        if node == B:
+            return part_of.some(C)
+
+    axiom = SubClassOf(A, B)
+
+    assert SubClassOf(A, part_of.some(C)) == pyhornedowl.transform(axiom, expand)
+
+
+def test_rename_entity_everywhere():
+    def rename(node):
+        if node == part_of:
+            return has_part
+
+    i = NamedIndividual(e("i"))
+    j = NamedIndividual(e("j"))
+
+    assert (ObjectPropertyAssertion(has_part, i, j)
+            == pyhornedowl.transform(ObjectPropertyAssertion(part_of, i, j), rename))
+    assert TransitiveObjectProperty(has_part) == pyhornedowl.transform(TransitiveObjectProperty(part_of), rename)
+
+
+def test_nodes_are_visited_bottom_up():
+    visited = []
+    pyhornedowl.transform(SubClassOf(A, part_of.some(B)), lambda n: visited.append(type(n).__name__))
+
+    assert ["Class", "ObjectProperty", "Class", "ObjectSomeValuesFrom", "SubClassOf"] == visited
+
+
+def test_annotated_component():
+    ap = AnnotationProperty(IRI.parse("http://www.w3.org/2000/01/rdf-schema#comment"))
+
+    def upper(node):
+        if isinstance(node, SimpleLiteral):
+            return SimpleLiteral(node.literal.upper())
+
+    c = AnnotatedComponent(SubClassOf(A, B), {Annotation(ap, SimpleLiteral("note"))})
+    actual = pyhornedowl.transform(c, upper)
+
+    assert SubClassOf(A, B) == actual.component
+    assert {Annotation(ap, SimpleLiteral("NOTE"))} == actual.ann
+
+
+def test_identity_keeps_ontology_components():
+    o = pyhornedowl.PyIndexedOntology()
+    o.add_component(SubClassOf(A, part_of.some(B | ~C)))
+    o.add_component(DataPropertyRange(DataProperty(e("age")),
+                                      DataUnionOf([Datatype(e("int")), DataOneOf([SimpleLiteral("x")])])))
+
+    for c in o.get_axioms():
+        assert c.component == pyhornedowl.transform(c.component, lambda n: None)
+
+
+def test_invalid_replacement():
+    with pytest.raises(TypeError):
+        pyhornedowl.transform(SubClassOf(A, B), lambda n: part_of if n == B else None)
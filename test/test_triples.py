@@ -0,0 +1,142 @@
+import pytest
+from test_base import simple_ontology_comps
+
+import pyhornedowl
+from pyhornedowl.model import *
+
+RDF_TYPE = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+RDFS_LABEL = "http://www.w3.org/2000/01/rdf-schema#label"
+OWL_CLASS = "http://www.w3.org/2002/07/owl#Class"
+OWL_OBJECT_PROPERTY = "http://www.w3.org/2002/07/owl#ObjectProperty"
+OWL_RESTRICTION = "http://www.w3.org/2002/07/owl#Restriction"
+OWL_ON_PROPERTY = "http://www.w3.org/2002/07/owl#onProperty"
+OWL_SOME_VALUES_FROM = "http://www.w3.org/2002/07/owl#someValuesFrom"
+RDFS_SUBCLASS_OF = "http://www.w3.org/2000/01/rdf-schema#subClassOf"
+RDF_LANG_STRING = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString"
+XSD_INTEGER = "http://www.w3.org/2001/XMLSchema#integer"
+
+
+EX = "https://example.com/"
+
+
+@pytest.fixture
+def o():
+    """Set up the test case with an ontology."""
+    p = ObjectProperty(IRI.parse(EX + "p"))
+    age = DataProperty(IRI.parse(EX + "age"))
+    i = NamedIndividual(IRI.parse(EX + "i"))
+    integer = Datatype(IRI.parse(XSD_INTEGER))
+    a, b, c = (Class(IRI.parse(EX + n)) for n in "ABC")
+
+    components = simple_ontology_comps() + [
+        DeclareObjectProperty(p),
+        DeclareDataProperty(age),
+        DeclareNamedIndividual(i),
+        SubClassOf(a, ObjectIntersectionOf([b, ObjectSomeValuesFrom(p, c)])),
+        EquivalentClasses([c, DataSomeValuesFrom(age, DatatypeRestriction(
+            integer, [FacetRestriction(Facet.MinInclusive, DatatypeLiteral("18", integer.first))]))]),
+        DataPropertyAssertion(age, i, DatatypeLiteral("42", integer.first)),
+        ClassAssertion(a, i),
+        AnnotationAssertion(c.first, Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)),
+                                                LanguageLiteral("Klasse C", "de"))),
+    ]
+
+    onto = pyhornedowl.PyIndexedOntology()
+    onto.prefix_mapping.add_default_prefix_names()
+    onto.prefix_mapping.add_prefix("", EX)
+
+    for component in components:
+        onto.add_component(component)
+
+    return onto
+
+
+def as_str(term):
+    if isinstance(term, IRI):
+        return str(term)
+    if isinstance(term, AnonymousIndividual):
+        return f"_:{term.first}"
+    return term
+
+
+def test_to_triples(o):
+    triples = [tuple(as_str(t) for t in triple) for triple in o.to_triples()]
+
+    assert ("https://example.com/A", RDF_TYPE, OWL_CLASS) in triples
+    assert ("https://example.com/A", RDFS_LABEL, SimpleLiteral("ClassA")) in triples
+    assert ("https://example.com/C", RDFS_LABEL, LanguageLiteral("Klasse C", "de")) in triples
+    assert ("https://example.com/i", "https://example.com/age",
+            DatatypeLiteral("42", IRI.parse(XSD_INTEGER))) in triples
+
+
+def test_to_triples_blank_nodes(o):
+    triples = list(o.to_triples())
+
+    restrictions = [s for s, p, o in triples
+                    if str(p) == RDF_TYPE and str(o) == "http://www.w3.org/2002/07/owl#Restriction"]
+
+    assert len(restrictions) == 2
+    assert all(isinstance(s, AnonymousIndividual) for s in restrictions)
+
+
+def test_round_trip(o):
+
+    actual = pyhornedowl.PyIndexedOntology.from_triples(o.to_triples())
+
+    assert set(o.get_axioms()) == set(actual.get_axioms())
+
+
+def test_from_triples_with_strings(o):
+    triples = [(str(s) if isinstance(s, IRI) else s, str(p), str(t) if isinstance(t, IRI) else t)
+               for s, p, t in o.to_triples()]
+
+    actual = pyhornedowl.PyIndexedOntology.from_triples(triples)
+
+    assert set(o.get_axioms()) == set(actual.get_axioms())
+
+
+def test_from_triples_index_strategy():
+    actual = pyhornedowl.PyIndexedOntology.from_triples(
+        [("https://example.com/A", RDF_TYPE, OWL_CLASS),
+         ("https://example.com/A", RDFS_LABEL, SimpleLiteral("A"))],
+        index_strategy=pyhornedowl.IndexCreationStrategy.OnLoad)
+
+    assert SimpleLiteral("A") == actual.get_label("https://example.com/A")
+    assert {"https://example.com/A"} == set(actual.get_classes())
+
+
+def test_from_triples_invalid():
+    with pytest.raises(ValueError, match="literal cannot be the subject"):
+        pyhornedowl.PyIndexedOntology.from_triples([(SimpleLiteral("A"), RDF_TYPE, OWL_CLASS)])
+
+    with pytest.raises(ValueError, match="predicate of a triple must be an IRI"):
+        pyhornedowl.PyIndexedOntology.from_triples(
+            [("https://example.com/A", AnonymousIndividual("b0"), OWL_CLASS)])
+
+    with pytest.raises(ValueError, match="Invalid IRI"):
+        pyhornedowl.PyIndexedOntology.from_triples([("not an iri", RDF_TYPE, OWL_CLASS)])
+
+
+def test_from_triples_lang_string_without_language():
+    with pytest.raises(ValueError, match="needs a language tag"):
+        pyhornedowl.PyIndexedOntology.from_triples(
+            [("https://example.com/A", RDFS_LABEL, DatatypeLiteral("x", IRI.parse(RDF_LANG_STRING)))])
+
+
+def test_from_triples_blank_node_labels():
+    def restriction(node, filler):
+        return [(node, RDF_TYPE, OWL_RESTRICTION),
+                (node, OWL_ON_PROPERTY, "https://example.com/p"),
+                (node, OWL_SOME_VALUES_FROM, filler),
+                ("https://example.com/A", RDFS_SUBCLASS_OF, node)]
+
+    actual = pyhornedowl.PyIndexedOntology.from_triples(
+        [(f"https://example.com/{c}", RDF_TYPE, OWL_CLASS) for c in "ABC"]
+        + [("https://example.com/p", RDF_TYPE, OWL_OBJECT_PROPERTY)]
+        + restriction(AnonymousIndividual("1"), "https://example.com/B")
+        + restriction(AnonymousIndividual("x y"), "https://example.com/C"))
+
+    p = ObjectProperty(IRI.parse(EX + "p"))
+    expected = {SubClassOf(Class(IRI.parse(EX + "A")), ObjectSomeValuesFrom(p, Class(IRI.parse(EX + c))))
+                for c in "BC"}
+    assert expected == {a.component for a in actual.get_axioms() if isinstance(a.component, SubClassOf)}
@@ -0,0 +1,51 @@
+import pyhornedowl
+from pyhornedowl.model import *
+
+
+def e(iri: str) -> IRI:
+    return IRI.parse(f"https://example.com/{iri}")
+
+
+def ontology():
+    o = pyhornedowl.PyIndexedOntology()
+    o.add_component(DeclareClass(Class(e("A"))))
+    o.add_component(SubClassOf(Class(e("A")), ObjectSomeValuesFrom(ObjectProperty(e("p")), Class(e("B")))))
+    o.add_component(DataPropertyAssertion(DataProperty(e("age")), NamedIndividual(e("i")),
+                                          DatatypeLiteral("42", IRI.parse("http://www.w3.org/2001/XMLSchema#integer"))))
+    o.add_component(AnnotationAssertion(e("A"), Annotation(AnnotationProperty(IRI.parse(
+        "http://www.w3.org/2000/01/rdf-schema#label")), SimpleLiteral("A"))))
+    return o
+
+
+def test_get_undeclared_entities():
+    o = ontology()
+
+    actual = o.get_undeclared_entities()
+
+    assert {Class(e("B")), ObjectProperty(e("p")), DataProperty(e("age")), NamedIndividual(e("i"))} == actual
+
+
+def test_get_undeclared_entities_of_other_type():
+    o = ontology()
+    o.add_component(DeclareNamedIndividual(NamedIndividual(e("B"))))
+
+    assert Class(e("B")) in o.get_undeclared_entities()
+
+
+def test_add_missing_declarations():
+    o = ontology()
+
+    added = o.add_missing_declarations()
+
+    assert {DeclareClass(Class(e("B"))), DeclareObjectProperty(ObjectProperty(e("p"))),
+            DeclareDataProperty(DataProperty(e("age"))), DeclareNamedIndividual(NamedIndividual(e("i")))} == set(added)
+    assert set() == o.get_undeclared_entities()
+    assert {"https://example.com/A", "https://example.com/B"} == o.get_classes()
+    assert {"https://example.com/p"} == o.get_object_properties()
+
+
+def test_add_missing_declarations_is_idempotent():
+    o = ontology()
+    o.add_missing_declarations()
+
+    assert [] == o.add_missing_declarations()
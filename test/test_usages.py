@@ -0,0 +1,77 @@
+import pytest
+import pyhornedowl
+from pyhornedowl.model import *
+
+from test_base import simple_ontology, RDFS_LABEL
+
+
+def a(iri: str) -> Class:
+    return Class(IRI.parse(f"https://example.com/{iri}"))
+
+
+def test_defining_axioms():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.get_defining_axioms("https://example.com/B")}
+    expected = {
+        DeclareClass(a("B")),
+        SubClassOf(a("B"), a("A")),
+        AnnotationAssertion(IRI.parse("https://example.com/B"),
+                            Annotation(AnnotationProperty(IRI.parse(RDFS_LABEL)), SimpleLiteral("ClassB"))),
+    }
+
+    assert expected == actual
+
+
+def test_usages():
+    o = simple_ontology()
+
+    actual = {c.component for c in o.get_usages("https://example.com/B")}
+
+    assert {SubClassOf(a("D"), a("B"))} == actual
+
+
+@pytest.mark.parametrize("strategy", [pyhornedowl.IndexCreationStrategy.OnQuery,
+                                      pyhornedowl.IndexCreationStrategy.Explicit])
+def test_defining_axioms_and_usages_partition_components(strategy):
+    o = pyhornedowl.PyIndexedOntology(index_strategy=strategy)
+    o.prefix_mapping.add_prefix("ex", "https://example.com/")
+    part_of = o.object_property("ex:part_of")
+    i = o.named_individual("ex:i")
+    j = o.named_individual("ex:j")
+
+    o.add_component(ObjectPropertyAssertion(part_of, i, j))
+    o.add_component(ClassAssertion(a("A"), j))
+    o.add_component(TransitiveObjectProperty(part_of))
+    o.add_component(SubClassOf(a("A"), part_of.some(a("B"))))
+
+    assert {ObjectPropertyAssertion(part_of, i, j)} == {c.component for c in o.get_defining_axioms("ex:i")}
+    assert set() == {c.component for c in o.get_usages("ex:i")}
+    assert {ClassAssertion(a("A"), j)} == {c.component for c in o.get_defining_axioms("ex:j")}
+    assert {ObjectPropertyAssertion(part_of, i, j)} == {c.component for c in o.get_usages("ex:j")}
+    assert {TransitiveObjectProperty(part_of)} == {c.component for c in o.get_defining_axioms("ex:part_of")}
+    assert ({ObjectPropertyAssertion(part_of, i, j), SubClassOf(a("A"), part_of.some(a("B")))}
+            == {c.component for c in o.get_usages("ex:part_of")})
+
+
+def test_component_signature():
+    part_of = ObjectProperty(IRI.parse("https://example.com/part_of"))
+    axiom = SubClassOf(a("A"), part_of.some(a("B") & a("C")))
+
+    assert {a("A"), a("B"), a("C"), part_of} == axiom.signature()
+
+
+def test_component_signature_includes_literal_datatypes():
+    dp = DataProperty(IRI.parse("https://example.com/age"))
+    i = NamedIndividual(IRI.parse("https://example.com/i"))
+    integer = "http://www.w3.org/2001/XMLSchema#integer"
+    axiom = DataPropertyAssertion(dp, i, DatatypeLiteral("42", IRI.parse(integer)))
+
+    assert {dp, i, Datatype(IRI.parse(integer))} == axiom.signature()
+
+
+def test_annotation_assertion_signature():
+    ap = AnnotationProperty(IRI.parse(RDFS_LABEL))
+    axiom = AnnotationAssertion(IRI.parse("https://example.com/A"), Annotation(ap, SimpleLiteral("A")))
+
+    assert {ap} == axiom.signature()